@@ -0,0 +1,166 @@
+//! Dead-lettering of undecodable events.
+//!
+//! By default an event that fails to decode fails every pipeline subscribed to it, and indexing
+//! stalls until the model is fixed. With dead-lettering enabled, pipelines skip such events
+//! instead and `UndecodableEventsHandler` records them in `undecodable_events`, once per
+//! subscribed pipeline. Once the model is fixed, [`replay_undecodable_events`] decodes the
+//! recorded events again and writes them through the pipelines that skipped them.
+
+use crate::event_index::EventRouter;
+use crate::events::DeepbookEvent;
+use crate::handlers::EventMeta;
+use anyhow::Context;
+use async_trait::async_trait;
+use deepbook_schema::models::UndecodableEvent;
+use deepbook_schema::schema::undecodable_events;
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use sui_pg_db::{Connection, Db};
+use sui_types::parse_sui_struct_tag;
+use tracing::{info, warn};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Skip and record undecodable events instead of failing the pipelines that subscribe to them.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether undecodable events are dead-lettered
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// A pipeline that can write a dead-lettered event once it decodes. Implemented by every
+/// `define_handler!` pipeline; the others need their events to be reindexed instead, since their
+/// rows depend on more than the event itself.
+#[async_trait]
+pub(crate) trait Replay: Send + Sync {
+    /// Write the rows of `event`. Returns the number of rows written.
+    async fn replay<'a>(
+        &self,
+        event: &DeepbookEvent,
+        meta: &EventMeta,
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize>;
+}
+
+/// Replays the events of pipeline `H` without constructing it: rows of `define_handler!`
+/// pipelines only depend on the event.
+pub(crate) struct Replayer<H>(PhantomData<fn() -> H>);
+
+impl<H> Default for Replayer<H> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Outcome of [`replay_undecodable_events`]
+#[derive(Debug, Default)]
+pub struct ReplaySummary {
+    /// Events that decoded and were written by their pipeline
+    pub replayed: usize,
+    /// Events that still fail to decode
+    pub undecodable: usize,
+    /// Events left in place because their pipeline is not running, cannot replay events, or no
+    /// longer indexes them
+    pub skipped: usize,
+}
+
+/// Decode the events in `undecodable_events` again, optionally only those of `pipeline`. Events
+/// that decode are written by their pipeline and removed from the table; the others stay, with
+/// their error updated.
+///
+/// Only pipelines subscribed to `router` can replay their events.
+pub async fn replay_undecodable_events(
+    db: &Db,
+    router: &EventRouter,
+    pipeline: Option<&str>,
+) -> anyhow::Result<ReplaySummary> {
+    let mut conn = db
+        .connect()
+        .await
+        .context("Failed to connect to database to replay undecodable events")?;
+
+    let mut query = undecodable_events::table
+        .order_by((
+            undecodable_events::checkpoint,
+            undecodable_events::event_digest,
+        ))
+        .into_boxed();
+    if let Some(pipeline) = pipeline {
+        query = query.filter(undecodable_events::pipeline.eq(pipeline));
+    }
+    let rows: Vec<UndecodableEvent> = query
+        .load(&mut conn)
+        .await
+        .context("Failed to load undecodable events")?;
+
+    let index = router.index();
+    let mut summary = ReplaySummary::default();
+    for row in rows {
+        let Some(replayer) = router.replayer(&row.pipeline) else {
+            warn!(
+                pipeline = row.pipeline,
+                event_digest = row.event_digest,
+                "Pipeline cannot replay events, skipping"
+            );
+            summary.skipped += 1;
+            continue;
+        };
+        let event_type = parse_sui_struct_tag(&row.event_type)
+            .with_context(|| format!("Invalid event type {}", row.event_type))?;
+        let Some(kind) = index.kind_of(&event_type) else {
+            warn!(
+                pipeline = row.pipeline,
+                event_digest = row.event_digest,
+                "{} is not indexed, skipping",
+                row.event_type
+            );
+            summary.skipped += 1;
+            continue;
+        };
+
+        let event = match DeepbookEvent::decode(kind, &event_type, &row.contents) {
+            Ok(Some(event)) => event,
+            Ok(None) => {
+                summary.skipped += 1;
+                continue;
+            }
+            Err(e) => {
+                diesel::update(undecodable_events::table.find((&row.pipeline, &row.event_digest)))
+                    .set(undecodable_events::error.eq(format!("{e:#}")))
+                    .execute(&mut conn)
+                    .await?;
+                summary.undecodable += 1;
+                continue;
+            }
+        };
+
+        let meta = EventMeta::new(
+            &row.digest,
+            &row.sender,
+            row.checkpoint,
+            row.checkpoint_timestamp_ms,
+            &row.package,
+            row.event_index as usize,
+        );
+        // Pipelines ignore rows they already have, so replaying again after a failure between
+        // these two statements is harmless.
+        replayer
+            .replay(&event, &meta, &mut conn)
+            .await
+            .with_context(|| {
+                format!("Failed to replay {} for {}", row.event_digest, row.pipeline)
+            })?;
+        diesel::delete(undecodable_events::table.find((&row.pipeline, &row.event_digest)))
+            .execute(&mut conn)
+            .await?;
+        summary.replayed += 1;
+    }
+
+    info!(?summary, "Replayed undecodable events");
+    Ok(summary)
+}
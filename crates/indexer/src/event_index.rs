@@ -0,0 +1,540 @@
+//! Event dispatch index and routing stage.
+//!
+//! Instead of every handler rebuilding the struct tags of its event type for every event it
+//! sees, the indexer's [`EventRouter`] keeps one `(package address, module, name) -> EventKind`
+//! index over all subscribed event types and all known package versions.
+//! [`EventRouter::route`] uses it to classify a checkpoint's events and decode each of them into
+//! a [`DeepbookEvent`] once; the result is cached and shared by every pipeline processing that
+//! checkpoint, so each handler only visits its own, already decoded, events.
+//!
+//! Pipelines are added with [`EventRouter::subscribe`], which subscribes them to their
+//! [`Subscriber::KINDS`]. The index and the routing cache are rebuilt whenever a new event type
+//! is registered, and the index whenever [`EventRouter::scan`] discovers a package version.
+//! Discovery is its own stage, run by the `package_versions` pipeline: once started with
+//! [`EventRouter::discover_from`], a checkpoint is routed only after every checkpoint up to it
+//! was scanned for upgrades, whichever pipeline gets to it first.
+
+use crate::dead_letter::Replay;
+use crate::events::{DeepbookEvent, FromDeepbookEvent};
+use crate::handlers::EventMeta;
+use crate::package_versions::{discover_upgrades, PackageUpgrade};
+use crate::traits::get_package_addresses_for_module;
+use crate::{family_modules, get_module_type, get_package_addresses, DeepbookEnv, ModuleType};
+use deepbook_package_registry::PackageFamily;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use prometheus::{
+    register_histogram_with_registry, register_int_counter_vec_with_registry, Histogram,
+    IntCounterVec, Registry,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::Instant;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::types::full_checkpoint_content::{Checkpoint, ExecutedTransaction};
+use tokio::sync::watch;
+use tracing::{debug, warn};
+
+/// Number of routed checkpoints kept for pipelines that are behind the fastest one.
+const ROUTE_CACHE_CAPACITY: usize = 1024;
+const DECODE_DURATION_SEC_BUCKETS: &[f64] = &[
+    0.0001, 0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0,
+];
+
+/// A Move event type, independent of the package version that emitted it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventKind {
+    pub module: &'static str,
+    pub name: &'static str,
+}
+
+type EventKey = (AccountAddress, &'static str, &'static str);
+
+/// Dispatch index of one environment.
+#[derive(Default)]
+pub struct EventIndex {
+    by_type: HashMap<EventKey, EventKind>,
+    /// Every known package address, across all package families
+    packages: HashSet<AccountAddress>,
+}
+
+impl EventIndex {
+    fn build(env: DeepbookEnv, kinds: impl IntoIterator<Item = EventKind>) -> Self {
+        let kinds: Vec<EventKind> = kinds.into_iter().collect();
+        let mut index = Self {
+            packages: env.package_addresses().into_iter().collect(),
+            ..Default::default()
+        };
+        // An event is classified by the family its package belongs to, and only matches kinds
+        // of modules that family defines: module names such as `registry` are not unique
+        // across packages.
+        for family in PackageFamily::ALL {
+            let modules = family_modules(family);
+            let family_kinds: Vec<EventKind> = kinds
+                .iter()
+                .filter(|kind| modules.contains(&kind.module))
+                .copied()
+                .collect();
+            if family_kinds.is_empty() {
+                continue;
+            }
+            for address in get_package_addresses(env, family) {
+                let Ok(address) = AccountAddress::from_str(&address) else {
+                    continue;
+                };
+                for kind in &family_kinds {
+                    index
+                        .by_type
+                        .insert((address, kind.module, kind.name), *kind);
+                }
+            }
+        }
+        // Sui framework types
+        for kind in kinds
+            .iter()
+            .filter(|kind| get_module_type(kind.module) == ModuleType::Sui)
+        {
+            let Ok(addresses) = get_package_addresses_for_module(kind.module, env) else {
+                continue;
+            };
+            for address in addresses {
+                let address = AccountAddress::new(*address.inner());
+                index
+                    .by_type
+                    .insert((address, kind.module, kind.name), *kind);
+            }
+        }
+        index
+    }
+
+    /// The subscribed event kind of `event_type`, if any. Type parameters are ignored, since
+    /// events may carry phantom type parameters that don't affect their layout.
+    pub fn kind_of(&self, event_type: &StructTag) -> Option<EventKind> {
+        self.by_type
+            .get(&(
+                event_type.address,
+                event_type.module.as_str(),
+                event_type.name.as_str(),
+            ))
+            .copied()
+    }
+
+    /// Whether `address` is one of the environment's package versions
+    pub fn is_package(&self, address: &AccountAddress) -> bool {
+        self.packages.contains(address)
+    }
+}
+
+/// A subscribed event of a checkpoint, decoded by [`EventRouter::route`].
+pub(crate) struct RoutedEvent {
+    pub kind: EventKind,
+    /// Index of the emitting transaction in the checkpoint
+    pub tx_index: usize,
+    pub meta: EventMeta,
+    /// The decoded event, or why it could not be decoded. Failures are kept per event, so they
+    /// only fail the pipelines that subscribe to the event.
+    pub event: Result<DeepbookEvent, String>,
+}
+
+impl RoutedEvent {
+    /// The decoded event, as seen by `pipeline`. An event that failed to decode is an error, or
+    /// `None` when undecodable events are dead-lettered (see [`crate::dead_letter`]).
+    pub fn decoded_for(&self, pipeline: &str) -> anyhow::Result<Option<&DeepbookEvent>> {
+        let error = match &self.event {
+            Ok(event) => return Ok(Some(event)),
+            Err(error) => error,
+        };
+        if crate::dead_letter::is_enabled() {
+            debug!(
+                pipeline,
+                event_digest = self.meta.event_digest(),
+                "Skipping undecodable {} event",
+                self.kind.name
+            );
+            if let Some(metrics) = METRICS.get() {
+                metrics
+                    .undecodable_events
+                    .with_label_values(&[pipeline, self.kind.name])
+                    .inc();
+            }
+            return Ok(None);
+        }
+        anyhow::bail!(
+            "Failed to decode {}::{} event {}: {error}",
+            self.kind.module,
+            self.kind.name,
+            self.meta.event_digest()
+        )
+    }
+}
+
+/// The decoded subscribed events of one checkpoint, in checkpoint order.
+#[derive(Default)]
+pub(crate) struct CheckpointEvents {
+    events: Vec<RoutedEvent>,
+    by_kind: HashMap<EventKind, Vec<usize>>,
+}
+
+impl CheckpointEvents {
+    /// Every event of `kind`
+    pub fn of_kind(&self, kind: EventKind) -> impl Iterator<Item = &RoutedEvent> {
+        self.by_kind
+            .get(&kind)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(|&i| &self.events[i])
+    }
+
+    /// Every event of any of `kinds`, interleaved in checkpoint order
+    pub fn of_kinds<'a>(
+        &'a self,
+        kinds: &'a [EventKind],
+    ) -> impl Iterator<Item = &'a RoutedEvent> + 'a {
+        self.events
+            .iter()
+            .filter(|event| kinds.contains(&event.kind))
+    }
+
+    /// Every event of type `T` with its metadata, as seen by `pipeline` (see
+    /// [`RoutedEvent::decoded_for`]).
+    pub fn events<'a, T: FromDeepbookEvent>(
+        &'a self,
+        pipeline: &'a str,
+    ) -> impl Iterator<Item = anyhow::Result<(&'a EventMeta, &'a T)>> + 'a {
+        self.of_kind(T::KIND)
+            .filter_map(move |routed| match routed.decoded_for(pipeline) {
+                Ok(event) => Some(Ok((&routed.meta, T::from_event(event?)?))),
+                Err(e) => Some(Err(e)),
+            })
+    }
+
+    /// Every event that failed to decode
+    pub fn undecodable(&self) -> impl Iterator<Item = (&RoutedEvent, &str)> {
+        self.events
+            .iter()
+            .filter_map(|routed| Some((routed, routed.event.as_ref().err()?.as_str())))
+    }
+}
+
+/// Metrics of the routing stage
+pub struct EventDecodeMetrics {
+    pub decode_duration: Histogram,
+    pub decoded_events: IntCounterVec,
+    pub decode_failures: IntCounterVec,
+    pub undecodable_events: IntCounterVec,
+}
+
+impl EventDecodeMetrics {
+    fn new(registry: &Registry) -> Self {
+        Self {
+            decode_duration: register_histogram_with_registry!(
+                "checkpoint_event_decode_duration_seconds",
+                "Time taken to route and decode the subscribed events of a checkpoint",
+                DECODE_DURATION_SEC_BUCKETS.to_vec(),
+                registry
+            )
+            .unwrap(),
+            decoded_events: register_int_counter_vec_with_registry!(
+                "checkpoint_events_decoded_total",
+                "Number of events decoded by the routing stage by event type",
+                &["event"],
+                registry
+            )
+            .unwrap(),
+            decode_failures: register_int_counter_vec_with_registry!(
+                "checkpoint_event_decode_failures_total",
+                "Number of events that failed to decode by event type",
+                &["event"],
+                registry
+            )
+            .unwrap(),
+            undecodable_events: register_int_counter_vec_with_registry!(
+                "undecodable_events_total",
+                "Number of undecodable events skipped and dead-lettered by pipeline and event type",
+                &["pipeline", "event"],
+                registry
+            )
+            .unwrap(),
+        }
+    }
+}
+
+static METRICS: OnceLock<EventDecodeMetrics> = OnceLock::new();
+
+/// Register the routing stage's metrics with `registry`. Without it, routing is not measured.
+pub fn init_metrics(registry: &Registry) {
+    METRICS.get_or_init(|| EventDecodeMetrics::new(registry));
+}
+
+type RoutedCheckpoint = Arc<OnceLock<Arc<CheckpointEvents>>>;
+
+/// Routed checkpoints by sequence number, evicted oldest first.
+#[derive(Default)]
+struct RouteCache {
+    routes: HashMap<u64, RoutedCheckpoint>,
+    order: VecDeque<u64>,
+}
+
+impl RouteCache {
+    fn get_or_insert(&mut self, checkpoint: u64) -> RoutedCheckpoint {
+        if let Some(cell) = self.routes.get(&checkpoint) {
+            return Arc::clone(cell);
+        }
+        if self.order.len() >= ROUTE_CACHE_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.routes.remove(&oldest);
+            }
+        }
+        self.order.push_back(checkpoint);
+        Arc::clone(self.routes.entry(checkpoint).or_default())
+    }
+
+    fn clear(&mut self) {
+        self.routes.clear();
+        self.order.clear();
+    }
+}
+
+/// Progress of package upgrade discovery. Checkpoints are scanned in any order, but a checkpoint
+/// is only routed once it and every checkpoint before it have been scanned, so its events are
+/// matched against every version published up to it.
+struct Discovery {
+    /// First checkpoint not scanned yet
+    next: watch::Sender<u64>,
+    /// Checkpoints after `next` that were already scanned
+    ahead: Mutex<BTreeSet<u64>>,
+}
+
+/// A pipeline that reads its events from an [`EventRouter`].
+pub trait Subscriber: Processor {
+    /// Event types the pipeline processes
+    const KINDS: &'static [EventKind];
+
+    /// Subscribe the pipeline to its event types. Called by [`EventRouter::subscribe`].
+    fn register(router: &EventRouter) {
+        for &kind in Self::KINDS {
+            router.register(Self::NAME, kind);
+        }
+    }
+}
+
+/// Routing stage of one indexer. Owns the dispatch index of its environment, the checkpoints
+/// routed so far, and which pipeline subscribes to which event type; every pipeline of the
+/// indexer shares it.
+pub struct EventRouter {
+    env: DeepbookEnv,
+    subscribers: RwLock<HashMap<EventKind, BTreeSet<&'static str>>>,
+    index: RwLock<Option<Arc<EventIndex>>>,
+    routes: Mutex<RouteCache>,
+    replayers: RwLock<BTreeMap<&'static str, Arc<dyn Replay>>>,
+    discovery: OnceLock<Discovery>,
+}
+
+impl EventRouter {
+    pub fn new(env: DeepbookEnv) -> Self {
+        Self {
+            env,
+            subscribers: Default::default(),
+            index: Default::default(),
+            routes: Default::default(),
+            replayers: Default::default(),
+            discovery: OnceLock::new(),
+        }
+    }
+
+    pub fn env(&self) -> DeepbookEnv {
+        self.env
+    }
+
+    /// Subscribe the pipeline built by `new` to its event types and return it.
+    pub fn subscribe<H: Subscriber>(self: &Arc<Self>, new: impl FnOnce(Arc<Self>) -> H) -> H {
+        H::register(self);
+        new(Arc::clone(self))
+    }
+
+    /// Subscribe `pipeline` to `kind`, so [`Self::route`] picks up its events.
+    pub fn register(&self, pipeline: &'static str, kind: EventKind) {
+        let is_new_kind = {
+            let mut subscribers = self.subscribers.write().unwrap();
+            let is_new_kind = !subscribers.contains_key(&kind);
+            subscribers.entry(kind).or_default().insert(pipeline);
+            is_new_kind
+        };
+        if is_new_kind {
+            self.invalidate();
+        }
+    }
+
+    /// Let `pipeline` write its dead-lettered events once they decode (see
+    /// [`crate::dead_letter`]).
+    pub(crate) fn register_replay(&self, pipeline: &'static str, replayer: Arc<dyn Replay>) {
+        self.replayers.write().unwrap().insert(pipeline, replayer);
+    }
+
+    pub(crate) fn replayer(&self, pipeline: &str) -> Option<Arc<dyn Replay>> {
+        self.replayers.read().unwrap().get(pipeline).cloned()
+    }
+
+    /// Pipelines subscribed to `kind`
+    pub fn subscribers(&self, kind: EventKind) -> Vec<&'static str> {
+        self.subscribers
+            .read()
+            .unwrap()
+            .get(&kind)
+            .map(|pipelines| pipelines.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Drop the index and every routed checkpoint, e.g. after a new package version is
+    /// discovered.
+    pub fn invalidate(&self) {
+        *self.index.write().unwrap() = None;
+        self.routes.lock().unwrap().clear();
+    }
+
+    /// The dispatch index, built on first use.
+    pub fn index(&self) -> Arc<EventIndex> {
+        if let Some(index) = self.index.read().unwrap().as_ref() {
+            return Arc::clone(index);
+        }
+        let mut index = self.index.write().unwrap();
+        let index = index.get_or_insert_with(|| {
+            let subscribers = self.subscribers.read().unwrap();
+            Arc::new(EventIndex::build(self.env, subscribers.keys().copied()))
+        });
+        Arc::clone(index)
+    }
+
+    /// Hold back checkpoint `first` and every later one until [`Self::scan`] has scanned it and
+    /// the checkpoints before it. Without it, checkpoints are routed as they arrive, against the
+    /// package versions known at that point.
+    pub fn discover_from(&self, first: u64) {
+        let discovery = Discovery {
+            next: watch::Sender::new(first),
+            ahead: Default::default(),
+        };
+        if self.discovery.set(discovery).is_err() {
+            warn!("Package upgrade discovery already started, ignoring start at {first}");
+        }
+    }
+
+    /// Find the package upgrades published in `checkpoint`, add them to the index, and mark the
+    /// checkpoint scanned. Returns every upgrade found, with its transaction.
+    pub fn scan<'c>(
+        &self,
+        checkpoint: &'c Checkpoint,
+    ) -> Vec<(&'c ExecutedTransaction, PackageUpgrade)> {
+        let mut upgrades = vec![];
+        for tx in &checkpoint.transactions {
+            for upgrade in discover_upgrades(tx, &checkpoint.object_set, self.env) {
+                upgrades.push((tx, upgrade));
+            }
+        }
+        // Checkpoints already routed were decoded without these versions, but cannot contain
+        // their events: a package emits nothing before it is published.
+        if !upgrades.is_empty() {
+            *self.index.write().unwrap() = None;
+        }
+        self.mark_scanned(checkpoint.summary.sequence_number);
+        upgrades
+    }
+
+    fn mark_scanned(&self, checkpoint: u64) {
+        let Some(discovery) = self.discovery.get() else {
+            return;
+        };
+        let mut ahead = discovery.ahead.lock().unwrap();
+        let next = *discovery.next.borrow();
+        if checkpoint < next {
+            return;
+        }
+        ahead.insert(checkpoint);
+        let mut frontier = next;
+        while ahead.remove(&frontier) {
+            frontier += 1;
+        }
+        if frontier != next {
+            discovery.next.send_replace(frontier);
+        }
+    }
+
+    /// Decode the subscribed events of `checkpoint`. The first pipeline to reach a checkpoint
+    /// does the work; the others wait for it and share the result.
+    pub(crate) async fn route(&self, checkpoint: &Checkpoint) -> Arc<CheckpointEvents> {
+        let sequence_number = checkpoint.summary.sequence_number;
+        if let Some(discovery) = self.discovery.get() {
+            let mut next = discovery.next.subscribe();
+            if *next.borrow() <= sequence_number {
+                debug!(sequence_number, "Waiting for package upgrade discovery");
+            }
+            // The sender lives as long as the router, so this only returns once scanned
+            let _ = next.wait_for(|next| *next > sequence_number).await;
+        }
+        let cell = self.routes.lock().unwrap().get_or_insert(sequence_number);
+        Arc::clone(cell.get_or_init(|| Arc::new(self.decode_checkpoint(checkpoint))))
+    }
+
+    fn decode_checkpoint(&self, checkpoint: &Checkpoint) -> CheckpointEvents {
+        let started = Instant::now();
+        let metrics = METRICS.get();
+        let mut routed = CheckpointEvents::default();
+        let dispatch = self.index();
+        for (tx_index, tx) in checkpoint.transactions.iter().enumerate() {
+            let Some(events) = &tx.events else {
+                continue;
+            };
+            let mut tx_meta = None;
+            for (event_index, ev) in events.data.iter().enumerate() {
+                let Some(kind) = dispatch.kind_of(&ev.type_) else {
+                    continue;
+                };
+                let event = match DeepbookEvent::decode(kind, &ev.type_, &ev.contents) {
+                    Ok(Some(event)) => Ok(event),
+                    Ok(None) => {
+                        debug!("Skipping unsupported {} event", ev.type_);
+                        continue;
+                    }
+                    Err(e) => {
+                        warn!("Failed to decode {} event: {e}", ev.type_);
+                        if let Some(metrics) = metrics {
+                            metrics
+                                .decode_failures
+                                .with_label_values(&[kind.name])
+                                .inc();
+                        }
+                        Err(format!("{e:#}"))
+                    }
+                };
+                if let (Some(metrics), Ok(event)) = (metrics, &event) {
+                    metrics
+                        .decoded_events
+                        .with_label_values(&[event.name()])
+                        .inc();
+                }
+
+                let tx_meta =
+                    tx_meta.get_or_insert_with(|| EventMeta::from_checkpoint_tx(checkpoint, tx));
+                routed
+                    .by_kind
+                    .entry(kind)
+                    .or_default()
+                    .push(routed.events.len());
+                routed.events.push(RoutedEvent {
+                    kind,
+                    tx_index,
+                    meta: tx_meta.with_index(event_index),
+                    event,
+                });
+            }
+        }
+
+        if let Some(metrics) = metrics {
+            metrics
+                .decode_duration
+                .observe(started.elapsed().as_secs_f64());
+        }
+        routed
+    }
+}
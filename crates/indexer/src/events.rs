@@ -0,0 +1,298 @@
+//! Typed DeepBook events.
+//!
+//! [`DeepbookEvent`] has one variant per Move event type the indexer understands. The routing
+//! stage in [`crate::event_index`] decodes every subscribed event of a checkpoint into it once,
+//! and each pipeline reads the variants it needs instead of decoding the BCS bytes again.
+//!
+//! A variant decodes its type from every package version: compatible Sui upgrades cannot change
+//! the fields of an existing struct, so an event whose fields change is published as a new type
+//! (`RebateEventV2` next to `RebateEvent`) and gets a variant of its own.
+
+use crate::event_index::EventKind;
+use crate::models::account::account_events::{
+    AccountCreated, AppAuthorized, AppDeauthorized, Deposited, FundsSettled, Withdrawn,
+};
+use crate::models::deepbook::balance_manager::{
+    BalanceEvent, BalanceManagerEvent, DeepBookReferralCreatedEvent, DeepBookReferralSetEvent,
+};
+use crate::models::deepbook::deep_price::PriceAdded;
+use crate::models::deepbook::ewma::EWMAUpdate;
+use crate::models::deepbook::governance::TradeParamsUpdateEvent;
+use crate::models::deepbook::order::{OrderCanceled, OrderModified};
+use crate::models::deepbook::order_info::{OrderExpired, OrderFilled, OrderPlaced};
+use crate::models::deepbook::pool::{
+    BookParamsUpdated, DeepBurned, PoolCreated, ReferralClaimed, ReferralFeeEvent,
+};
+use crate::models::deepbook::state::{
+    ProposalEvent, RebateEvent, RebateEventV2, StakeEvent, TakerFeePenaltyApplied, VoteEvent,
+};
+use crate::models::deepbook::vault::FlashLoanBorrowed;
+use crate::models::deepbook_core_account::account_data::DeepbookCoreAccountInitialized;
+use crate::models::deepbook_margin::margin_manager::{
+    DepositCollateralEvent, LiquidationEvent, LoanBorrowedEvent, LoanRepaidEvent,
+    MarginManagerCreatedEvent, WithdrawCollateralEvent,
+};
+use crate::models::deepbook_margin::margin_pool::{
+    AssetSupplied, AssetWithdrawn, DeepbookPoolUpdated as MarginPoolDeepbookPoolUpdated,
+    InterestParamsUpdated, MaintainerFeesWithdrawn, MarginPoolConfigUpdated, MarginPoolCreated,
+    ProtocolFeesWithdrawn, SupplierCapMinted, SupplyReferralMinted,
+};
+use crate::models::deepbook_margin::margin_registry::{
+    CurrentPriceUpdated, DeepbookPoolConfigUpdated, DeepbookPoolRegistered,
+    DeepbookPoolUpdated as RegistryDeepbookPoolUpdated, MaintainerCapUpdated, MaxPriceAgeUpdated,
+    PauseCapUpdated, PriceToleranceUpdated,
+};
+use crate::models::deepbook_margin::protocol_fees::{
+    ProtocolFeesIncreasedEvent, ReferralFeesClaimedEvent,
+};
+use crate::models::deepbook_margin::tpsl::{
+    ConditionalOrderAdded, ConditionalOrderCancelled, ConditionalOrderExecuted,
+    ConditionalOrderInsufficientFunds,
+};
+use crate::models::deepbook_predict::builder_code_events::{
+    BuilderCodeCreated, BuilderCodeSet, BuilderFeesClaimed,
+};
+use crate::models::deepbook_predict::config_events::{MarketCreated, MarketSettled};
+use crate::models::deepbook_predict::order_events::{
+    LiquidatedOrderRedeemed, LiveOrderRedeemed, OrderLiquidated, OrderMinted, SettledOrderRedeemed,
+};
+use crate::models::deepbook_predict::vault_events::{
+    RequestCancelled, SupplyFilled, SupplyRequested, WithdrawFilled, WithdrawRequested,
+};
+use crate::models::deepbook_sessions::sessions::{SessionAuthorized, SessionRevoked};
+use crate::models::margin_liquidation::liquidation_vault::LiquidationByVault;
+use crate::models::propbook::block_scholes_store::{
+    BlockScholesBatchIngested, BlockScholesObservationRecorded, BsRead, SVIParams,
+};
+use crate::models::propbook::oracle_lane::{ObservationInserted, ObservationRecorded, OracleRead};
+use crate::models::propbook::pyth_feed::RawSpot;
+use crate::models::propbook::registry::{
+    BlockScholesStoresRegistered, OracleBound, OracleRebound, OracleSourceRegistered,
+};
+use crate::models::sui::sui::SUI;
+use crate::traits::MoveStruct;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Decodes the BCS contents of an event of a known kind. `Ok(None)` means the event is of a
+/// known kind but a payload the indexer does not handle.
+type Decoder = fn(EventKind, &StructTag, &[u8]) -> anyhow::Result<Option<DeepbookEvent>>;
+
+/// Extracts a typed event from a [`DeepbookEvent`].
+pub trait FromDeepbookEvent: MoveStruct + Sized {
+    fn from_event(event: &DeepbookEvent) -> Option<&Self>;
+}
+
+macro_rules! deepbook_events {
+    {
+        events { $($variant:ident($ty:ty)),* $(,)? }
+        by_payload { $($payload_variant:ident($payload_ty:ty)),* $(,)? }
+    } => {
+        /// A decoded event. Phantom type parameters (pool assets) are decoded as `SUI`, since
+        /// they do not change the layout.
+        #[derive(Debug, Clone)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum DeepbookEvent {
+            $($variant($ty),)*
+            $($payload_variant($payload_ty),)*
+        }
+
+        impl DeepbookEvent {
+            /// Name of the Move event type, for logs and metrics
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(DeepbookEvent::$variant(_) => <$ty as MoveStruct>::NAME,)*
+                    $(DeepbookEvent::$payload_variant(_) => <$payload_ty as MoveStruct>::NAME,)*
+                }
+            }
+        }
+
+        $(
+            impl FromDeepbookEvent for $ty {
+                fn from_event(event: &DeepbookEvent) -> Option<&Self> {
+                    match event {
+                        DeepbookEvent::$variant(event) => Some(event),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+
+        static DECODERS: LazyLock<HashMap<EventKind, Decoder>> = LazyLock::new(|| {
+            let mut decoders: HashMap<EventKind, Decoder> = HashMap::new();
+            $(
+                decoders.insert(<$ty as MoveStruct>::KIND, |_, _, contents| {
+                    Ok(Some(DeepbookEvent::$variant(bcs::from_bytes(contents)?)))
+                });
+            )*
+            $(
+                decoders.insert(<$payload_ty as MoveStruct>::KIND, decode_by_payload);
+            )*
+            decoders
+        });
+    };
+}
+
+deepbook_events! {
+    events {
+        // DeepBook core
+        BalanceEvent(BalanceEvent),
+        BalanceManagerEvent(BalanceManagerEvent),
+        DeepBookReferralCreatedEvent(DeepBookReferralCreatedEvent),
+        DeepBookReferralSetEvent(DeepBookReferralSetEvent),
+        OrderPlaced(OrderPlaced),
+        OrderModified(OrderModified),
+        OrderCanceled(OrderCanceled),
+        OrderExpired(OrderExpired),
+        OrderFilled(OrderFilled),
+        FlashLoanBorrowed(FlashLoanBorrowed),
+        PriceAdded(PriceAdded),
+        ProposalEvent(ProposalEvent),
+        RebateEvent(RebateEvent),
+        RebateEventV2(RebateEventV2),
+        StakeEvent(StakeEvent),
+        TakerFeePenaltyApplied(TakerFeePenaltyApplied),
+        VoteEvent(VoteEvent),
+        EWMAUpdate(EWMAUpdate),
+        TradeParamsUpdateEvent(TradeParamsUpdateEvent),
+        PoolCreated(PoolCreated<SUI, SUI>),
+        BookParamsUpdated(BookParamsUpdated<SUI, SUI>),
+        DeepBurned(DeepBurned<SUI, SUI>),
+        ReferralFeeEvent(ReferralFeeEvent),
+        ReferralClaimed(ReferralClaimed),
+
+        // DeepBook margin
+        MarginManagerCreatedEvent(MarginManagerCreatedEvent),
+        DepositCollateralEvent(DepositCollateralEvent),
+        WithdrawCollateralEvent(WithdrawCollateralEvent),
+        LoanBorrowedEvent(LoanBorrowedEvent),
+        LoanRepaidEvent(LoanRepaidEvent),
+        LiquidationEvent(LiquidationEvent),
+        MarginPoolCreated(MarginPoolCreated),
+        MarginPoolDeepbookPoolUpdated(MarginPoolDeepbookPoolUpdated),
+        InterestParamsUpdated(InterestParamsUpdated),
+        MarginPoolConfigUpdated(MarginPoolConfigUpdated),
+        AssetSupplied(AssetSupplied),
+        AssetWithdrawn(AssetWithdrawn),
+        MaintainerFeesWithdrawn(MaintainerFeesWithdrawn),
+        ProtocolFeesWithdrawn(ProtocolFeesWithdrawn),
+        SupplierCapMinted(SupplierCapMinted),
+        SupplyReferralMinted(SupplyReferralMinted),
+        DeepbookPoolRegistered(DeepbookPoolRegistered),
+        RegistryDeepbookPoolUpdated(RegistryDeepbookPoolUpdated),
+        DeepbookPoolConfigUpdated(DeepbookPoolConfigUpdated),
+        MaintainerCapUpdated(MaintainerCapUpdated),
+        PauseCapUpdated(PauseCapUpdated),
+        CurrentPriceUpdated(CurrentPriceUpdated),
+        MaxPriceAgeUpdated(MaxPriceAgeUpdated),
+        PriceToleranceUpdated(PriceToleranceUpdated),
+        ProtocolFeesIncreasedEvent(ProtocolFeesIncreasedEvent),
+        ReferralFeesClaimedEvent(ReferralFeesClaimedEvent),
+        ConditionalOrderAdded(ConditionalOrderAdded),
+        ConditionalOrderCancelled(ConditionalOrderCancelled),
+        ConditionalOrderExecuted(ConditionalOrderExecuted),
+        ConditionalOrderInsufficientFunds(ConditionalOrderInsufficientFunds),
+
+        // Margin liquidation vault
+        LiquidationByVault(LiquidationByVault),
+
+        // Predict
+        MarketCreated(MarketCreated),
+        MarketSettled(MarketSettled),
+        OrderMinted(OrderMinted),
+        OrderLiquidated(OrderLiquidated),
+        LiveOrderRedeemed(LiveOrderRedeemed),
+        SettledOrderRedeemed(SettledOrderRedeemed),
+        LiquidatedOrderRedeemed(LiquidatedOrderRedeemed),
+        SupplyRequested(SupplyRequested),
+        SupplyFilled(SupplyFilled),
+        WithdrawRequested(WithdrawRequested),
+        WithdrawFilled(WithdrawFilled),
+        RequestCancelled(RequestCancelled),
+        BuilderCodeCreated(BuilderCodeCreated),
+        BuilderCodeSet(BuilderCodeSet),
+        BuilderFeesClaimed(BuilderFeesClaimed),
+
+        // Propbook
+        OracleSourceRegistered(OracleSourceRegistered),
+        OracleBound(OracleBound),
+        OracleRebound(OracleRebound),
+        BlockScholesStoresRegistered(BlockScholesStoresRegistered),
+        BlockScholesBatchIngested(BlockScholesBatchIngested),
+
+        // Accounts
+        AccountCreated(AccountCreated),
+        AppAuthorized(AppAuthorized),
+        AppDeauthorized(AppDeauthorized),
+        Deposited(Deposited),
+        Withdrawn(Withdrawn),
+        FundsSettled(FundsSettled),
+        DeepbookCoreAccountInitialized(DeepbookCoreAccountInitialized),
+        SessionAuthorized(SessionAuthorized),
+        SessionRevoked(SessionRevoked),
+    }
+    // Generic over a payload that changes the layout, so the variant depends on the type
+    // parameters; see `decode_by_payload`.
+    by_payload {
+        BlockScholesValueObservation(BlockScholesObservationRecorded<BsRead<u128>>),
+        BlockScholesSviObservation(BlockScholesObservationRecorded<BsRead<SVIParams>>),
+        OracleObservationRecorded(ObservationRecorded<OracleRead<RawSpot>>),
+        OracleObservationInserted(ObservationInserted<OracleRead<RawSpot>>),
+    }
+}
+
+impl DeepbookEvent {
+    /// Decode an event of `kind`. Fails if the contents do not match the layout, or if no
+    /// variant exists for `kind`.
+    pub fn decode(
+        kind: EventKind,
+        event_type: &StructTag,
+        contents: &[u8],
+    ) -> anyhow::Result<Option<Self>> {
+        let decoder = DECODERS.get(&kind).ok_or_else(|| {
+            anyhow::anyhow!("No decoder for event {}::{}", kind.module, kind.name)
+        })?;
+        decoder(kind, event_type, contents)
+    }
+}
+
+/// Returns `V` from an `Event<Read<V>>` event type.
+fn observation_value_type(event_type: &StructTag) -> Option<&TypeTag> {
+    match event_type.type_params.first()? {
+        TypeTag::Struct(read) => read.type_params.first(),
+        _ => None,
+    }
+}
+
+fn decode_by_payload(
+    kind: EventKind,
+    event_type: &StructTag,
+    contents: &[u8],
+) -> anyhow::Result<Option<DeepbookEvent>> {
+    let value_type = observation_value_type(event_type);
+    let is_struct = |name: &str| matches!(value_type, Some(TypeTag::Struct(value)) if value.name.as_str() == name);
+
+    let event = if kind == BlockScholesObservationRecorded::<BsRead<u128>>::KIND {
+        // Spot and forward series carry a u128, SVI series an `SVIParams`
+        match value_type {
+            Some(TypeTag::U128) => {
+                DeepbookEvent::BlockScholesValueObservation(bcs::from_bytes(contents)?)
+            }
+            _ if is_struct("SVIParams") => {
+                DeepbookEvent::BlockScholesSviObservation(bcs::from_bytes(contents)?)
+            }
+            _ => return Ok(None),
+        }
+    } else if !is_struct("RawSpot") {
+        // Oracle lanes are generic over their payload; only Pyth spot lanes exist today.
+        return Ok(None);
+    } else if kind == ObservationRecorded::<OracleRead<RawSpot>>::KIND {
+        DeepbookEvent::OracleObservationRecorded(bcs::from_bytes(contents)?)
+    } else if kind == ObservationInserted::<OracleRead<RawSpot>>::KIND {
+        DeepbookEvent::OracleObservationInserted(bcs::from_bytes(contents)?)
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(event))
+}
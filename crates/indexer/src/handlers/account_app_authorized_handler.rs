@@ -0,0 +1,19 @@
+use crate::models::account::account_events::AppAuthorized;
+use deepbook_schema::models::AccountAppAuthorized;
+
+define_handler! {
+    name: AccountAppAuthorizedHandler,
+    processor_name: "account_app_authorized",
+    event_type: AppAuthorized,
+    db_model: AccountAppAuthorized,
+    table: account_app_authorized,
+    map_event: |event, meta| AccountAppAuthorized {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        app: event.app.to_string(),
+    }
+}
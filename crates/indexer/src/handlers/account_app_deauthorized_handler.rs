@@ -0,0 +1,19 @@
+use crate::models::account::account_events::AppDeauthorized;
+use deepbook_schema::models::AccountAppDeauthorized;
+
+define_handler! {
+    name: AccountAppDeauthorizedHandler,
+    processor_name: "account_app_deauthorized",
+    event_type: AppDeauthorized,
+    db_model: AccountAppDeauthorized,
+    table: account_app_deauthorized,
+    map_event: |event, meta| AccountAppDeauthorized {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        app: event.app.to_string(),
+    }
+}
@@ -0,0 +1,23 @@
+use crate::models::account::account_events::AccountCreated;
+use deepbook_schema::models::AccountCreated as AccountCreatedModel;
+
+define_handler! {
+    name: AccountCreatedHandler,
+    processor_name: "account_created",
+    event_type: AccountCreated,
+    db_model: AccountCreatedModel,
+    table: account_created,
+    map_event: |event, meta| AccountCreatedModel {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        account_id: event.account_id.to_string(),
+        wrapper_id: event.wrapper_id.to_string(),
+        owner: event.owner.to_string(),
+        self_owned: event.self_owned,
+        referrer_account_id: event.referrer_account_id.map(|id| id.to_string()),
+    }
+}
@@ -0,0 +1,22 @@
+use crate::models::account::account_events::FundsSettled;
+use deepbook_schema::models::AccountFundsSettled;
+
+define_handler! {
+    name: AccountFundsSettledHandler,
+    processor_name: "account_funds_settled",
+    event_type: FundsSettled,
+    db_model: AccountFundsSettled,
+    table: account_funds_settled,
+    map_event: |event, meta| AccountFundsSettled {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        account_id: event.account_id.to_string(),
+        coin_type: event.coin_type.to_string(),
+        amount: event.amount as i64,
+        new_balance: event.new_balance as i64,
+    }
+}
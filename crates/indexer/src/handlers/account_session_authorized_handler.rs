@@ -0,0 +1,21 @@
+use crate::models::deepbook_sessions::sessions::SessionAuthorized;
+use deepbook_schema::models::AccountSessionAuthorized;
+
+define_handler! {
+    name: AccountSessionAuthorizedHandler,
+    processor_name: "account_session_authorized",
+    event_type: SessionAuthorized,
+    db_model: AccountSessionAuthorized,
+    table: account_session_authorized,
+    map_event: |event, meta| AccountSessionAuthorized {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        account_id: event.account_id.to_string(),
+        session: event.session.to_string(),
+        expires_at_ms: event.expires_at_ms as i64,
+    }
+}
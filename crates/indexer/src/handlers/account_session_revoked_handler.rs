@@ -0,0 +1,21 @@
+use crate::models::deepbook_sessions::sessions::SessionRevoked;
+use deepbook_schema::models::AccountSessionRevoked;
+
+define_handler! {
+    name: AccountSessionRevokedHandler,
+    processor_name: "account_session_revoked",
+    event_type: SessionRevoked,
+    db_model: AccountSessionRevoked,
+    table: account_session_revoked,
+    map_event: |event, meta| AccountSessionRevoked {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        account_id: event.account_id.to_string(),
+        session: event.session.to_string(),
+        expires_at_ms: event.expires_at_ms as i64,
+    }
+}
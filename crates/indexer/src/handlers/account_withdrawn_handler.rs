@@ -0,0 +1,22 @@
+use crate::models::account::account_events::Withdrawn;
+use deepbook_schema::models::AccountWithdrawn;
+
+define_handler! {
+    name: AccountWithdrawnHandler,
+    processor_name: "account_withdrawn",
+    event_type: Withdrawn,
+    db_model: AccountWithdrawn,
+    table: account_withdrawn,
+    map_event: |event, meta| AccountWithdrawn {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        account_id: event.account_id.to_string(),
+        coin_type: event.coin_type.to_string(),
+        amount: event.amount as i64,
+        new_balance: event.new_balance as i64,
+    }
+}
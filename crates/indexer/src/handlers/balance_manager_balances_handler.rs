@@ -0,0 +1,228 @@
+use crate::event_index::{EventKind, EventRouter, Subscriber};
+use crate::events::DeepbookEvent;
+use crate::models::deepbook::balance_manager::BalanceEvent;
+use crate::traits::MoveStruct;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use deepbook_schema::models::BalanceManagerBalance;
+use deepbook_schema::schema::balance_manager_balances;
+use diesel::upsert::excluded;
+use diesel::ExpressionMethods;
+use diesel_async::RunQueryDsl;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use sui_indexer_alt_framework::pipeline::sequential::Handler;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::postgres::{Connection, Db};
+use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
+use tracing::debug;
+
+/// Rows per insert statement, well below Postgres' bind parameter limit
+const INSERT_CHUNK_SIZE: usize = 1000;
+
+/// Maintains `balance_manager_balances`, the net amount of each asset deposited into each
+/// balance manager. Runs as a sequential pipeline, since every balance event is added to the
+/// running total left by the previous ones and must be applied exactly once.
+///
+/// Only events after the pipeline's first checkpoint are counted, so it needs to index from the
+/// first core package checkpoint for the totals to match the `balances` table.
+pub struct BalanceManagerBalancesHandler {
+    router: Arc<EventRouter>,
+}
+
+impl BalanceManagerBalancesHandler {
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
+    }
+}
+
+impl Subscriber for BalanceManagerBalancesHandler {
+    const KINDS: &'static [EventKind] = &[BalanceEvent::KIND];
+}
+
+/// A deposit (positive) or withdrawal (negative) of one asset, produced in checkpoint order. The
+/// u64 amount is widened so that it keeps its sign, and net balances are summed as `NUMERIC`.
+#[derive(Debug, Clone)]
+pub struct BalanceChange {
+    balance_manager_id: String,
+    asset: String,
+    amount: i128,
+    checkpoint: i64,
+    timestamp_ms: i64,
+}
+
+/// The net change to each balance touched by a batch, so each balance is written once per commit.
+#[derive(Default)]
+pub struct BalancesBatch {
+    balances: BTreeMap<(String, String), BalanceManagerBalance>,
+}
+
+#[async_trait]
+impl Processor for BalanceManagerBalancesHandler {
+    const NAME: &'static str = "balance_manager_balances";
+    type Value = BalanceChange;
+
+    async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
+        let mut results = vec![];
+
+        for routed_event in routed.of_kind(BalanceEvent::KIND) {
+            let meta = &routed_event.meta;
+            let Some(DeepbookEvent::BalanceEvent(event)) = routed_event.decoded_for(Self::NAME)?
+            else {
+                continue;
+            };
+            let amount = i128::from(event.amount);
+            let change = BalanceChange {
+                balance_manager_id: event.balance_manager_id.to_string(),
+                asset: event.asset.to_string(),
+                amount: if event.deposit { amount } else { -amount },
+                checkpoint: meta.checkpoint(),
+                timestamp_ms: meta.checkpoint_timestamp_ms(),
+            };
+            debug!("Observed balance change {:?}", change);
+            results.push(change);
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl Handler for BalanceManagerBalancesHandler {
+    type Store = Db;
+    type Batch = BalancesBatch;
+
+    fn batch(&self, batch: &mut Self::Batch, values: std::vec::IntoIter<Self::Value>) {
+        for change in values {
+            let BalanceChange {
+                balance_manager_id,
+                asset,
+                amount,
+                checkpoint,
+                timestamp_ms,
+            } = change;
+            batch
+                .balances
+                .entry((balance_manager_id.clone(), asset.clone()))
+                .and_modify(|balance| {
+                    balance.net_deposited += BigDecimal::from(amount);
+                    balance.last_updated_at = timestamp_ms;
+                    balance.checkpoint = checkpoint;
+                })
+                .or_insert(BalanceManagerBalance {
+                    balance_manager_id,
+                    asset,
+                    net_deposited: BigDecimal::from(amount),
+                    last_updated_at: timestamp_ms,
+                    checkpoint,
+                });
+        }
+    }
+
+    async fn commit<'a>(
+        &self,
+        batch: &Self::Batch,
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize> {
+        let rows: Vec<BalanceManagerBalance> = batch.balances.values().cloned().collect();
+        let mut changed = 0;
+
+        // The batch holds deltas, so existing balances are added to rather than replaced.
+        for chunk in rows.chunks(INSERT_CHUNK_SIZE) {
+            changed += diesel::insert_into(balance_manager_balances::table)
+                .values(chunk)
+                .on_conflict((
+                    balance_manager_balances::balance_manager_id,
+                    balance_manager_balances::asset,
+                ))
+                .do_update()
+                .set((
+                    balance_manager_balances::net_deposited
+                        .eq(balance_manager_balances::net_deposited
+                            + excluded(balance_manager_balances::net_deposited)),
+                    balance_manager_balances::last_updated_at
+                        .eq(excluded(balance_manager_balances::last_updated_at)),
+                    balance_manager_balances::checkpoint
+                        .eq(excluded(balance_manager_balances::checkpoint)),
+                ))
+                .execute(conn)
+                .await?;
+        }
+        Ok(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeepbookEnv;
+    use deepbook_schema::MIGRATIONS;
+    use diesel::{QueryDsl, SelectableHelper};
+    use sui_pg_db::temp::TempDb;
+    use sui_pg_db::DbArgs;
+
+    const MANAGER: &str = "0xb1";
+    const SUI: &str = "0x2::sui::SUI";
+
+    fn handler() -> BalanceManagerBalancesHandler {
+        BalanceManagerBalancesHandler::new(Arc::new(EventRouter::new(DeepbookEnv::Testnet)))
+    }
+
+    fn change(asset: &str, amount: i128, checkpoint: i64) -> BalanceChange {
+        BalanceChange {
+            balance_manager_id: MANAGER.to_string(),
+            asset: asset.to_string(),
+            amount,
+            checkpoint,
+            timestamp_ms: checkpoint * 1000,
+        }
+    }
+
+    fn batch_of(changes: Vec<BalanceChange>) -> BalancesBatch {
+        let mut batch = BalancesBatch::default();
+        handler().batch(&mut batch, changes.into_iter());
+        batch
+    }
+
+    #[test]
+    fn batch_nets_changes_per_asset_without_overflow() {
+        let max = i128::from(u64::MAX);
+        let batch = batch_of(vec![
+            change(SUI, max, 1),
+            change("0x5::usdc::USDC", 10, 1),
+            change(SUI, max, 2),
+            change(SUI, -5, 3),
+        ]);
+
+        assert_eq!(batch.balances.len(), 2);
+        let sui = &batch.balances[&(MANAGER.to_string(), SUI.to_string())];
+        assert_eq!(sui.net_deposited, BigDecimal::from(2 * max - 5));
+        assert_eq!((sui.checkpoint, sui.last_updated_at), (3, 3000));
+    }
+
+    #[tokio::test]
+    async fn commit_adds_to_existing_balances() {
+        let temp_db = TempDb::new().expect("postgres binaries must be on PATH");
+        let url = temp_db.database().url().clone();
+        let db = Db::for_write(url, DbArgs::default()).await.unwrap();
+        db.run_migrations(Some(&MIGRATIONS)).await.unwrap();
+        let mut conn = db.connect().await.unwrap();
+
+        let max = i128::from(u64::MAX);
+        let batch = batch_of(vec![change(SUI, max, 1)]);
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 1);
+        let batch = batch_of(vec![change(SUI, max, 2), change(SUI, -max, 3)]);
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 1);
+        let batch = batch_of(vec![change(SUI, max, 4)]);
+        handler().commit(&batch, &mut conn).await.unwrap();
+
+        let balance: BalanceManagerBalance = balance_manager_balances::table
+            .find((MANAGER, SUI))
+            .select(BalanceManagerBalance::as_select())
+            .get_result(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(balance.net_deposited, BigDecimal::from(2 * max));
+        assert_eq!(balance.checkpoint, 4);
+    }
+}
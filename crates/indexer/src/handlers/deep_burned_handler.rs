@@ -1,8 +1,7 @@
-use crate::handlers::{is_deepbook_tx, try_extract_move_call_package};
+use crate::event_index::{EventKind, EventRouter, Subscriber};
 use crate::models::deepbook::pool::DeepBurned as DeepBurnedEvent;
 use crate::models::sui::sui::SUI;
 use crate::traits::MoveStruct;
-use crate::DeepbookEnv;
 use async_trait::async_trait;
 use deepbook_schema::models::DeepBurned;
 use deepbook_schema::schema::deep_burned;
@@ -12,61 +11,45 @@ use sui_indexer_alt_framework::pipeline::Processor;
 use sui_indexer_alt_framework::postgres::handler::Handler;
 use sui_indexer_alt_framework::postgres::Connection;
 use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
-use sui_types::transaction::TransactionDataAPI;
 use tracing::debug;
 
 pub struct DeepBurnedHandler {
-    env: DeepbookEnv,
+    router: Arc<EventRouter>,
 }
 
 impl DeepBurnedHandler {
-    pub fn new(env: DeepbookEnv) -> Self {
-        Self { env }
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
     }
 }
 
+impl Subscriber for DeepBurnedHandler {
+    const KINDS: &'static [EventKind] = &[DeepBurnedEvent::<SUI, SUI>::KIND];
+}
+
 #[async_trait]
 impl Processor for DeepBurnedHandler {
     const NAME: &'static str = "deep_burned";
     type Value = DeepBurned;
 
     async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
         let mut results = vec![];
 
-        for tx in &checkpoint.transactions {
-            if !is_deepbook_tx(tx, &checkpoint.object_set, self.env) {
-                continue;
-            }
-            let Some(events) = &tx.events else {
-                continue;
+        for routed_event in routed.events::<DeepBurnedEvent<SUI, SUI>>(Self::NAME) {
+            let (meta, event) = routed_event?;
+            let data = DeepBurned {
+                digest: meta.digest(),
+                event_digest: meta.event_digest(),
+                sender: meta.sender(),
+                checkpoint: meta.checkpoint(),
+                checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+                package: meta.package(),
+                pool_id: event.pool_id.to_string(),
+                burned_amount: event.deep_burned as i64,
             };
-
-            let package = try_extract_move_call_package(tx).unwrap_or_default();
-            let checkpoint_timestamp_ms = checkpoint.summary.timestamp_ms as i64;
-            let checkpoint_seq = checkpoint.summary.sequence_number as i64;
-            let digest = tx.transaction.digest();
-
-            for (index, ev) in events.data.iter().enumerate() {
-                // Match base type (ignore type parameters)
-                if !DeepBurnedEvent::<SUI, SUI>::matches_event_type(&ev.type_, self.env) {
-                    continue;
-                }
-
-                // Can use <SUI,SUI> since it doesn't affect deserialization
-                let event: DeepBurnedEvent<SUI, SUI> = bcs::from_bytes(&ev.contents)?;
-                let data = DeepBurned {
-                    digest: digest.to_string(),
-                    event_digest: format!("{digest}{index}"),
-                    sender: tx.transaction.sender().to_string(),
-                    checkpoint: checkpoint_seq,
-                    checkpoint_timestamp_ms,
-                    package: package.clone(),
-                    pool_id: event.pool_id.to_string(),
-                    burned_amount: event.deep_burned as i64,
-                };
-                debug!("Observed Deepbook DeepBurned {:?}", data);
-                results.push(data);
-            }
+            debug!("Observed Deepbook DeepBurned {:?}", data);
+            results.push(data);
         }
         Ok(results)
     }
@@ -0,0 +1,22 @@
+use crate::models::deepbook_core_account::account_data::DeepbookCoreAccountInitialized;
+use deepbook_schema::models::DeepbookCoreAccountInitialized as DeepbookCoreAccountInitializedModel;
+
+define_handler! {
+    name: DeepbookCoreAccountInitializedHandler,
+    processor_name: "deepbook_core_account_initialized",
+    event_type: DeepbookCoreAccountInitialized,
+    db_model: DeepbookCoreAccountInitializedModel,
+    table: deepbook_core_account_initialized,
+    map_event: |event, meta| DeepbookCoreAccountInitializedModel {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        account_id: event.account_id.to_string(),
+        account_owner: event.account_owner.to_string(),
+        wrapper_id: event.wrapper_id.to_string(),
+        balance_manager_id: event.balance_manager_id.to_string(),
+    }
+}
@@ -0,0 +1,169 @@
+use crate::event_index::{EventKind, EventRouter, Subscriber};
+use crate::events::DeepbookEvent;
+use crate::handlers::EventMeta;
+use crate::models::deepbook_margin::margin_manager::LiquidationEvent;
+use crate::models::margin_liquidation::liquidation_vault::LiquidationByVault;
+use crate::traits::MoveStruct;
+use async_trait::async_trait;
+use deepbook_schema::models::LiquidationByVault as LiquidationByVaultModel;
+use deepbook_schema::schema::liquidation_by_vault;
+use diesel_async::RunQueryDsl;
+use std::sync::Arc;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::postgres::handler::Handler;
+use sui_indexer_alt_framework::postgres::Connection;
+use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
+use tracing::debug;
+
+/// Indexes `LiquidationByVault` events and links each one to the `liquidation` row written by
+/// `LiquidationHandler` for the same transaction. The vault emits its event right after
+/// `margin_manager::liquidate` emits `LiquidationEvent`, so the match is the closest preceding
+/// `LiquidationEvent` for the same margin manager and margin pool.
+const LIQUIDATION_KINDS: [EventKind; 2] = [LiquidationEvent::KIND, LiquidationByVault::KIND];
+
+pub struct LiquidationByVaultHandler {
+    router: Arc<EventRouter>,
+}
+
+impl LiquidationByVaultHandler {
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
+    }
+}
+
+impl Subscriber for LiquidationByVaultHandler {
+    const KINDS: &'static [EventKind] = &LIQUIDATION_KINDS;
+}
+
+#[async_trait]
+impl Processor for LiquidationByVaultHandler {
+    const NAME: &'static str = "liquidation_by_vault";
+    type Value = LiquidationByVaultModel;
+
+    async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
+        let mut results = vec![];
+        // Metadata and event of every margin liquidation seen so far in the current transaction
+        let mut liquidations: Vec<(&EventMeta, &LiquidationEvent)> = vec![];
+        let mut current_tx = None;
+
+        for routed_event in routed.of_kinds(&LIQUIDATION_KINDS) {
+            if current_tx != Some(routed_event.tx_index) {
+                current_tx = Some(routed_event.tx_index);
+                liquidations.clear();
+            }
+            let Some(event) = routed_event.decoded_for(Self::NAME)? else {
+                continue;
+            };
+            match event {
+                DeepbookEvent::LiquidationEvent(liquidation) => {
+                    liquidations.push((&routed_event.meta, liquidation));
+                }
+                DeepbookEvent::LiquidationByVault(event) => {
+                    let liquidation_meta = liquidations
+                        .iter()
+                        .rev()
+                        .find(|(_, liquidation)| {
+                            liquidation.margin_manager_id == event.margin_manager_id
+                                && liquidation.margin_pool_id == event.margin_pool_id
+                        })
+                        .map(|(meta, _)| (*meta).clone());
+                    results.push(process_liquidation_by_vault(
+                        event,
+                        routed_event.meta.clone(),
+                        liquidation_meta,
+                    ));
+                    debug!(
+                        "Observed liquidation vault liquidation {}",
+                        routed_event.meta.event_digest()
+                    );
+                }
+                _ => {}
+            }
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl Handler for LiquidationByVaultHandler {
+    async fn commit<'a>(
+        values: &[Self::Value],
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize> {
+        Ok(diesel::insert_into(liquidation_by_vault::table)
+            .values(values)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?)
+    }
+}
+
+fn process_liquidation_by_vault(
+    event: &LiquidationByVault,
+    meta: EventMeta,
+    liquidation_meta: Option<EventMeta>,
+) -> LiquidationByVaultModel {
+    LiquidationByVaultModel {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        vault_id: event.vault_id.to_string(),
+        margin_manager_id: event.margin_manager_id.to_string(),
+        margin_pool_id: event.margin_pool_id.to_string(),
+        base_in: event.base_in as i64,
+        base_out: event.base_out as i64,
+        quote_in: event.quote_in as i64,
+        quote_out: event.quote_out as i64,
+        repay_balance_remaining: event.repay_balance_remaining as i64,
+        base_liquidation: event.base_liquidation,
+        liquidation_event_digest: liquidation_meta.map(|meta| meta.event_digest()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::{decode, meta, object_id};
+    use deepbook_package_registry::PackageFamily;
+
+    fn vault_liquidation() -> LiquidationByVault {
+        decode(
+            &LiquidationByVault {
+                vault_id: object_id(1),
+                margin_manager_id: object_id(2),
+                margin_pool_id: object_id(3),
+                base_in: 0,
+                base_out: 250,
+                quote_in: 1_000,
+                quote_out: 0,
+                repay_balance_remaining: 40,
+                base_liquidation: true,
+            },
+            PackageFamily::MarginLiquidation,
+        )
+    }
+
+    #[test]
+    fn links_the_margin_liquidation() {
+        let liquidation_meta = EventMeta::new("digest", "0x5e", 42, 1_700_000_000_000, "0xfeed", 1);
+        let row =
+            process_liquidation_by_vault(&vault_liquidation(), meta(), Some(liquidation_meta));
+        assert_eq!(row.event_digest, "digest3");
+        assert_eq!(row.vault_id, object_id(1).to_string());
+        assert_eq!(row.base_out, 250);
+        assert_eq!(row.quote_in, 1_000);
+        assert_eq!(row.repay_balance_remaining, 40);
+        assert!(row.base_liquidation);
+        assert_eq!(row.liquidation_event_digest.as_deref(), Some("digest1"));
+    }
+
+    #[test]
+    fn unmatched_liquidation_has_no_link() {
+        let row = process_liquidation_by_vault(&vault_liquidation(), meta(), None);
+        assert_eq!(row.liquidation_event_digest, None);
+    }
+}
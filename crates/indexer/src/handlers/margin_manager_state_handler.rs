@@ -0,0 +1,751 @@
+use crate::event_index::{EventKind, EventRouter, Subscriber};
+use crate::events::DeepbookEvent;
+use crate::handlers::current_price_updated_handler::CurrentPriceUpdatedHandler;
+use crate::handlers::margin_pool_created_handler::MarginPoolCreatedHandler;
+use crate::handlers::{count_skipped, EventMeta};
+use crate::models::deepbook_margin::margin_manager::{
+    DepositCollateralEvent, LiquidationEvent, LoanBorrowedEvent, LoanRepaidEvent,
+    MarginManagerCreatedEvent, WithdrawCollateralEvent,
+};
+use crate::models::deepbook_margin::margin_registry::CurrentPriceUpdated;
+use crate::traits::MoveStruct;
+use anyhow::ensure;
+use async_trait::async_trait;
+use deepbook_schema::schema::{margin_manager_state, watermarks};
+use diesel::dsl::exists;
+use diesel::sql_types::{Array, BigInt, Integer, Text};
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use sui_indexer_alt_framework::pipeline::sequential::Handler;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::postgres::{Connection, Db};
+use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
+use tracing::{debug, warn};
+
+/// Pipelines whose tables the state is built from
+pub const DEPENDENCIES: [&str; 2] = [
+    <MarginPoolCreatedHandler as Processor>::NAME,
+    <CurrentPriceUpdatedHandler as Processor>::NAME,
+];
+
+/// Event types that change the state of a margin manager
+const MARGIN_STATE_KINDS: [EventKind; 7] = [
+    MarginManagerCreatedEvent::KIND,
+    DepositCollateralEvent::KIND,
+    WithdrawCollateralEvent::KIND,
+    LoanBorrowedEvent::KIND,
+    LoanRepaidEvent::KIND,
+    LiquidationEvent::KIND,
+    CurrentPriceUpdated::KIND,
+];
+
+/// Maintains `margin_manager_state`, the assets, debts and risk ratio of every margin manager.
+/// Runs as a sequential pipeline, since most events move the state left by the previous ones:
+///
+/// - a created margin manager starts empty, with the assets and margin pools of its DeepBook pool;
+/// - deposits add collateral, and borrows add the loan to both the assets and the debt of its
+///   side, which repayments take away again;
+/// - withdrawals and liquidations report the remaining assets and debts, replacing the running
+///   amounts;
+/// - price updates set the current price of every margin manager of the pool.
+///
+/// Amounts are normalized by the decimals in `pools`. The risk ratio, assets over debts valued at
+/// the current pool price (or the last Pyth prices before any price update), is computed again for
+/// every margin manager a batch touches, and is null without debt. Trades and accrued interest
+/// emit none of these events, so the amounts drift from the chain between withdrawals and
+/// liquidations.
+///
+/// Margin pools and prices are read from the tables of the `margin_pool_created` and
+/// `current_price_updated` pipelines, so those pipelines must run alongside this one (see
+/// [`check_dependencies`]). A change that cannot be applied to a tracked margin manager, because
+/// its DeepBook pool is not in `pools` or its margin pool is not indexed, is skipped with a
+/// warning and counted in `pipeline_skipped_changes_total`, rather than stopping the pipeline.
+///
+/// Only margin managers created after the pipeline's first checkpoint are tracked, so it needs to
+/// index from the first margin package checkpoint to hold every margin manager.
+pub struct MarginManagerStateHandler {
+    router: Arc<EventRouter>,
+}
+
+impl MarginManagerStateHandler {
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
+    }
+}
+
+impl Subscriber for MarginManagerStateHandler {
+    const KINDS: &'static [EventKind] = &MARGIN_STATE_KINDS;
+}
+
+/// A change to one margin manager, or to every margin manager of a pool, produced in checkpoint
+/// order.
+#[derive(Debug, Clone)]
+pub struct MarginStateChange {
+    checkpoint: i64,
+    timestamp_ms: i64,
+    change: Change,
+}
+
+#[derive(Debug, Clone)]
+enum Change {
+    Created {
+        margin_manager_id: String,
+        deepbook_pool_id: String,
+    },
+    Deposited {
+        margin_manager_id: String,
+        asset: String,
+        amount: i64,
+        pyth: PythPrice,
+    },
+    /// Borrowed (positive `amount`) from or repaid (negative `amount`) to `margin_pool_id`
+    Loan {
+        margin_manager_id: String,
+        margin_pool_id: String,
+        amount: i64,
+    },
+    /// Remaining amounts reported by a withdrawal or liquidation
+    Remaining {
+        margin_manager_id: String,
+        base_asset: i64,
+        quote_asset: i64,
+        base_debt: i64,
+        quote_debt: i64,
+        base_pyth: PythPrice,
+        quote_pyth: PythPrice,
+    },
+    Price {
+        deepbook_pool_id: String,
+        price: i64,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PythPrice {
+    price: i64,
+    decimals: i32,
+}
+
+#[async_trait]
+impl Processor for MarginManagerStateHandler {
+    const NAME: &'static str = "margin_manager_state";
+    type Value = MarginStateChange;
+
+    async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
+        let mut results = vec![];
+
+        for routed_event in routed.of_kinds(&MARGIN_STATE_KINDS) {
+            let meta = &routed_event.meta;
+            let Some(event) = routed_event.decoded_for(Self::NAME)? else {
+                continue;
+            };
+            let change = match event {
+                DeepbookEvent::MarginManagerCreatedEvent(event) => Change::Created {
+                    margin_manager_id: event.margin_manager_id.to_string(),
+                    deepbook_pool_id: event.deepbook_pool_id.to_string(),
+                },
+                DeepbookEvent::DepositCollateralEvent(event) => Change::Deposited {
+                    margin_manager_id: event.margin_manager_id.to_string(),
+                    asset: event.asset.name.clone(),
+                    amount: event.amount as i64,
+                    pyth: PythPrice::new(event.pyth_price, event.pyth_decimals),
+                },
+                DeepbookEvent::WithdrawCollateralEvent(event) => Change::Remaining {
+                    margin_manager_id: event.margin_manager_id.to_string(),
+                    base_asset: event.remaining_base_asset as i64,
+                    quote_asset: event.remaining_quote_asset as i64,
+                    base_debt: event.remaining_base_debt as i64,
+                    quote_debt: event.remaining_quote_debt as i64,
+                    base_pyth: PythPrice::new(event.base_pyth_price, event.base_pyth_decimals),
+                    quote_pyth: PythPrice::new(event.quote_pyth_price, event.quote_pyth_decimals),
+                },
+                DeepbookEvent::LiquidationEvent(event) => Change::Remaining {
+                    margin_manager_id: event.margin_manager_id.to_string(),
+                    base_asset: event.remaining_base_asset as i64,
+                    quote_asset: event.remaining_quote_asset as i64,
+                    base_debt: event.remaining_base_debt as i64,
+                    quote_debt: event.remaining_quote_debt as i64,
+                    base_pyth: PythPrice::new(event.base_pyth_price, event.base_pyth_decimals),
+                    quote_pyth: PythPrice::new(event.quote_pyth_price, event.quote_pyth_decimals),
+                },
+                DeepbookEvent::LoanBorrowedEvent(event) => Change::Loan {
+                    margin_manager_id: event.margin_manager_id.to_string(),
+                    margin_pool_id: event.margin_pool_id.to_string(),
+                    amount: event.loan_amount as i64,
+                },
+                DeepbookEvent::LoanRepaidEvent(event) => Change::Loan {
+                    margin_manager_id: event.margin_manager_id.to_string(),
+                    margin_pool_id: event.margin_pool_id.to_string(),
+                    amount: -(event.repay_amount as i64),
+                },
+                DeepbookEvent::CurrentPriceUpdated(event) => Change::Price {
+                    deepbook_pool_id: event.pool_id.to_string(),
+                    price: event.price as i64,
+                },
+                _ => continue,
+            };
+            debug!("Observed margin manager state change {:?}", change);
+            results.push(MarginStateChange::new(meta, change));
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl Handler for MarginManagerStateHandler {
+    type Store = Db;
+    type Batch = Vec<MarginStateChange>;
+
+    fn batch(&self, batch: &mut Self::Batch, values: std::vec::IntoIter<Self::Value>) {
+        batch.extend(values);
+    }
+
+    async fn commit<'a>(
+        &self,
+        batch: &Self::Batch,
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize> {
+        // Margin managers and pools whose risk ratios need computing again
+        let mut managers = BTreeSet::new();
+        let mut pools = BTreeSet::new();
+        let mut changed = 0;
+
+        // Each change builds on the previous ones, so they are applied one at a time, in order.
+        for MarginStateChange {
+            checkpoint,
+            timestamp_ms,
+            change,
+        } in batch
+        {
+            changed += match change {
+                Change::Created {
+                    margin_manager_id,
+                    deepbook_pool_id,
+                } => {
+                    managers.insert(margin_manager_id);
+                    let rows = diesel::sql_query(INSERT_CREATED)
+                        .bind::<Text, _>(margin_manager_id)
+                        .bind::<Text, _>(deepbook_pool_id)
+                        .bind::<BigInt, _>(*timestamp_ms)
+                        .bind::<BigInt, _>(*checkpoint)
+                        .execute(conn)
+                        .await?;
+                    if rows == 0 {
+                        warn!(
+                            margin_manager_id,
+                            "Skipping margin manager: its DeepBook pool {deepbook_pool_id} is \
+                             not in `pools`"
+                        );
+                        count_skipped(Self::NAME, "unknown_pool", 1);
+                    }
+                    rows
+                }
+                Change::Deposited {
+                    margin_manager_id,
+                    asset,
+                    amount,
+                    pyth,
+                } => {
+                    managers.insert(margin_manager_id);
+                    let rows = diesel::sql_query(UPDATE_DEPOSITED)
+                        .bind::<Text, _>(margin_manager_id)
+                        .bind::<Text, _>(asset)
+                        .bind::<BigInt, _>(*amount)
+                        .bind::<BigInt, _>(pyth.price)
+                        .bind::<Integer, _>(pyth.decimals)
+                        .bind::<BigInt, _>(*timestamp_ms)
+                        .execute(conn)
+                        .await?;
+                    skip_unapplied(rows, margin_manager_id, "unknown_pool", conn).await?
+                }
+                Change::Loan {
+                    margin_manager_id,
+                    margin_pool_id,
+                    amount,
+                } => {
+                    managers.insert(margin_manager_id);
+                    let rows = diesel::sql_query(UPDATE_LOAN)
+                        .bind::<Text, _>(margin_manager_id)
+                        .bind::<Text, _>(margin_pool_id)
+                        .bind::<BigInt, _>(*amount)
+                        .bind::<BigInt, _>(*timestamp_ms)
+                        .execute(conn)
+                        .await?;
+                    skip_unapplied(rows, margin_manager_id, "unknown_margin_pool", conn).await?
+                }
+                Change::Remaining {
+                    margin_manager_id,
+                    base_asset,
+                    quote_asset,
+                    base_debt,
+                    quote_debt,
+                    base_pyth,
+                    quote_pyth,
+                } => {
+                    managers.insert(margin_manager_id);
+                    let rows = diesel::sql_query(UPDATE_REMAINING)
+                        .bind::<Text, _>(margin_manager_id)
+                        .bind::<BigInt, _>(*base_asset)
+                        .bind::<BigInt, _>(*quote_asset)
+                        .bind::<BigInt, _>(*base_debt)
+                        .bind::<BigInt, _>(*quote_debt)
+                        .bind::<BigInt, _>(base_pyth.price)
+                        .bind::<Integer, _>(base_pyth.decimals)
+                        .bind::<BigInt, _>(quote_pyth.price)
+                        .bind::<Integer, _>(quote_pyth.decimals)
+                        .bind::<BigInt, _>(*timestamp_ms)
+                        .execute(conn)
+                        .await?;
+                    skip_unapplied(rows, margin_manager_id, "unknown_pool", conn).await?
+                }
+                Change::Price {
+                    deepbook_pool_id,
+                    price,
+                } => {
+                    pools.insert(deepbook_pool_id);
+                    diesel::sql_query(UPDATE_PRICE)
+                        .bind::<Text, _>(deepbook_pool_id)
+                        .bind::<BigInt, _>(*price)
+                        .bind::<BigInt, _>(*timestamp_ms)
+                        .execute(conn)
+                        .await?
+                }
+            };
+        }
+
+        if !managers.is_empty() || !pools.is_empty() {
+            let managers: Vec<&str> = managers.into_iter().map(String::as_str).collect();
+            let pools: Vec<&str> = pools.into_iter().map(String::as_str).collect();
+            diesel::sql_query(UPDATE_RISK_RATIO)
+                .bind::<Array<Text>, _>(managers)
+                .bind::<Array<Text>, _>(pools)
+                .execute(conn)
+                .await?;
+        }
+        Ok(changed)
+    }
+}
+
+/// Checks, once at startup, that both `DEPENDENCIES` run alongside this pipeline, as reported by
+/// `runs`. Warns if either has indexed fewer checkpoints than this pipeline, since until it
+/// catches up, changes that need its rows are skipped.
+pub async fn check_dependencies(db: &Db, runs: impl Fn(&str) -> bool) -> anyhow::Result<()> {
+    let name = MarginManagerStateHandler::NAME;
+    for pipeline in DEPENDENCIES {
+        ensure!(
+            runs(pipeline),
+            "The {name} pipeline reads the table of the {pipeline} pipeline, which must run \
+             alongside it"
+        );
+    }
+
+    let pipelines: Vec<&str> = DEPENDENCIES.into_iter().chain([name]).collect();
+    let mut conn = db.connect().await?;
+    let indexed: BTreeMap<String, i64> = watermarks::table
+        .filter(watermarks::pipeline.eq_any(pipelines))
+        .select((watermarks::pipeline, watermarks::checkpoint_hi_inclusive))
+        .load(&mut conn)
+        .await?
+        .into_iter()
+        .collect();
+    let Some(&checkpoint_hi) = indexed.get(name) else {
+        return Ok(());
+    };
+    for pipeline in DEPENDENCIES {
+        if indexed.get(pipeline).is_none_or(|&hi| hi < checkpoint_hi) {
+            warn!(
+                "The {pipeline} pipeline is behind {name} (checkpoint {checkpoint_hi}); changes \
+                 that need its rows are skipped until it catches up"
+            );
+        }
+    }
+    Ok(())
+}
+
+/// A change to `margin_manager_id` that matched no row. If the margin manager is tracked, the
+/// change could not be applied, because of `reason`, and is skipped with a warning and counted.
+/// Changes to margin managers created before the pipeline's first checkpoint match no row
+/// either, and are skipped silently.
+async fn skip_unapplied(
+    rows: usize,
+    margin_manager_id: &str,
+    reason: &str,
+    conn: &mut Connection<'_>,
+) -> anyhow::Result<usize> {
+    if rows > 0 {
+        return Ok(rows);
+    }
+    let tracked: bool = diesel::select(exists(margin_manager_state::table.find(margin_manager_id)))
+        .get_result(conn)
+        .await?;
+    if tracked {
+        warn!(margin_manager_id, reason, "Skipping margin manager change");
+        count_skipped(MarginManagerStateHandler::NAME, reason, 1);
+    }
+    Ok(0)
+}
+
+impl MarginStateChange {
+    fn new(meta: &EventMeta, change: Change) -> Self {
+        Self {
+            checkpoint: meta.checkpoint(),
+            timestamp_ms: meta.checkpoint_timestamp_ms(),
+            change,
+        }
+    }
+}
+
+impl PythPrice {
+    fn new(price: u64, decimals: u8) -> Self {
+        Self {
+            price: price as i64,
+            decimals: decimals as i32,
+        }
+    }
+}
+
+/// A margin manager starts without assets or debt, with the assets of its DeepBook pool, the
+/// margin pools lending them and the pool's last price at or before its checkpoint.
+/// Binds: margin manager, DeepBook pool, timestamp (ms), checkpoint.
+const INSERT_CREATED: &str = r#"
+    INSERT INTO margin_manager_state (
+        margin_manager_id, deepbook_pool_id, base_margin_pool_id, quote_margin_pool_id,
+        base_asset_id, base_asset_symbol, quote_asset_id, quote_asset_symbol,
+        base_asset, quote_asset, base_debt, quote_debt, current_price, created_at, updated_at
+    )
+    SELECT
+        $1, v.pool_id,
+        (SELECT m.margin_pool_id FROM margin_pool_created m
+         WHERE regexp_replace(m.asset_type, '^0x', '') = regexp_replace(p.base_asset_id, '^0x', '')
+         ORDER BY m.checkpoint DESC LIMIT 1),
+        (SELECT m.margin_pool_id FROM margin_pool_created m
+         WHERE regexp_replace(m.asset_type, '^0x', '') = regexp_replace(p.quote_asset_id, '^0x', '')
+         ORDER BY m.checkpoint DESC LIMIT 1),
+        p.base_asset_id, p.base_asset_symbol, p.quote_asset_id, p.quote_asset_symbol,
+        0, 0, 0, 0,
+        (SELECT c.price FROM current_price_updated c
+         WHERE c.pool_id = v.pool_id AND c.checkpoint <= $4
+         ORDER BY c.checkpoint DESC LIMIT 1),
+        to_timestamp($3 / 1000.0) AT TIME ZONE 'UTC',
+        to_timestamp($3 / 1000.0) AT TIME ZONE 'UTC'
+    FROM (SELECT $2::text AS pool_id) v
+    JOIN pools p ON p.pool_id = v.pool_id
+    ON CONFLICT (margin_manager_id) DO UPDATE SET
+        deepbook_pool_id = excluded.deepbook_pool_id,
+        base_margin_pool_id = excluded.base_margin_pool_id,
+        quote_margin_pool_id = excluded.quote_margin_pool_id,
+        base_asset_id = excluded.base_asset_id,
+        base_asset_symbol = excluded.base_asset_symbol,
+        quote_asset_id = excluded.quote_asset_id,
+        quote_asset_symbol = excluded.quote_asset_symbol,
+        base_asset = excluded.base_asset,
+        quote_asset = excluded.quote_asset,
+        base_debt = excluded.base_debt,
+        quote_debt = excluded.quote_debt,
+        current_price = excluded.current_price,
+        updated_at = excluded.updated_at
+"#;
+
+/// Collateral deposited in the base or quote asset, with its Pyth price. Deposits of any other
+/// asset (e.g. DEEP for fees) only touch `updated_at`.
+/// Binds: margin manager, asset type, amount, Pyth price, Pyth decimals, timestamp (ms).
+const UPDATE_DEPOSITED: &str = r#"
+    WITH target AS (
+        SELECT
+            s.margin_manager_id,
+            regexp_replace(s.base_asset_id, '^0x', '') = regexp_replace($2, '^0x', '') AS is_base,
+            regexp_replace(s.quote_asset_id, '^0x', '') = regexp_replace($2, '^0x', '') AS is_quote,
+            power(10::numeric, p.base_asset_decimals) AS base_scale,
+            power(10::numeric, p.quote_asset_decimals) AS quote_scale
+        FROM margin_manager_state s
+        JOIN pools p ON p.pool_id = s.deepbook_pool_id
+        WHERE s.margin_manager_id = $1
+    )
+    UPDATE margin_manager_state s SET
+        base_asset = COALESCE(s.base_asset, 0)
+            + CASE WHEN t.is_base THEN $3 / t.base_scale ELSE 0 END,
+        quote_asset = COALESCE(s.quote_asset, 0)
+            + CASE WHEN t.is_quote THEN $3 / t.quote_scale ELSE 0 END,
+        base_pyth_price = CASE WHEN t.is_base THEN $4 ELSE s.base_pyth_price END,
+        base_pyth_decimals = CASE WHEN t.is_base THEN $5 ELSE s.base_pyth_decimals END,
+        quote_pyth_price = CASE WHEN t.is_quote THEN $4 ELSE s.quote_pyth_price END,
+        quote_pyth_decimals = CASE WHEN t.is_quote THEN $5 ELSE s.quote_pyth_decimals END,
+        updated_at = to_timestamp($6 / 1000.0) AT TIME ZONE 'UTC'
+    FROM target t
+    WHERE s.margin_manager_id = t.margin_manager_id
+"#;
+
+/// A loan lands in (or a repayment leaves) the margin manager's balance, so it moves the assets
+/// and the debt of the side its margin pool lends. Repayments include interest, so debts stop at
+/// zero.
+/// Binds: margin manager, margin pool, signed amount, timestamp (ms).
+const UPDATE_LOAN: &str = r#"
+    WITH target AS (
+        SELECT
+            s.margin_manager_id,
+            regexp_replace(s.base_asset_id, '^0x', '')
+                = regexp_replace(m.asset_type, '^0x', '') AS is_base,
+            regexp_replace(s.quote_asset_id, '^0x', '')
+                = regexp_replace(m.asset_type, '^0x', '') AS is_quote,
+            power(10::numeric, p.base_asset_decimals) AS base_scale,
+            power(10::numeric, p.quote_asset_decimals) AS quote_scale
+        FROM margin_manager_state s
+        JOIN pools p ON p.pool_id = s.deepbook_pool_id
+        JOIN margin_pool_created m ON m.margin_pool_id = $2
+        WHERE s.margin_manager_id = $1
+        LIMIT 1
+    )
+    UPDATE margin_manager_state s SET
+        base_asset = GREATEST(COALESCE(s.base_asset, 0)
+            + CASE WHEN t.is_base THEN $3 / t.base_scale ELSE 0 END, 0),
+        quote_asset = GREATEST(COALESCE(s.quote_asset, 0)
+            + CASE WHEN t.is_quote THEN $3 / t.quote_scale ELSE 0 END, 0),
+        base_debt = GREATEST(COALESCE(s.base_debt, 0)
+            + CASE WHEN t.is_base THEN $3 / t.base_scale ELSE 0 END, 0),
+        quote_debt = GREATEST(COALESCE(s.quote_debt, 0)
+            + CASE WHEN t.is_quote THEN $3 / t.quote_scale ELSE 0 END, 0),
+        base_margin_pool_id = CASE WHEN t.is_base THEN $2 ELSE s.base_margin_pool_id END,
+        quote_margin_pool_id = CASE WHEN t.is_quote THEN $2 ELSE s.quote_margin_pool_id END,
+        updated_at = to_timestamp($4 / 1000.0) AT TIME ZONE 'UTC'
+    FROM target t
+    WHERE s.margin_manager_id = t.margin_manager_id
+"#;
+
+/// Binds: margin manager, remaining base asset, quote asset, base debt and quote debt, base Pyth
+/// price and decimals, quote Pyth price and decimals, timestamp (ms).
+const UPDATE_REMAINING: &str = r#"
+    UPDATE margin_manager_state s SET
+        base_asset = $2 / power(10::numeric, p.base_asset_decimals),
+        quote_asset = $3 / power(10::numeric, p.quote_asset_decimals),
+        base_debt = $4 / power(10::numeric, p.base_asset_decimals),
+        quote_debt = $5 / power(10::numeric, p.quote_asset_decimals),
+        base_pyth_price = $6,
+        base_pyth_decimals = $7,
+        quote_pyth_price = $8,
+        quote_pyth_decimals = $9,
+        updated_at = to_timestamp($10 / 1000.0) AT TIME ZONE 'UTC'
+    FROM pools p
+    WHERE p.pool_id = s.deepbook_pool_id AND s.margin_manager_id = $1
+"#;
+
+/// Binds: DeepBook pool, price, timestamp (ms).
+const UPDATE_PRICE: &str = r#"
+    UPDATE margin_manager_state SET
+        current_price = $2,
+        updated_at = to_timestamp($3 / 1000.0) AT TIME ZONE 'UTC'
+    WHERE deepbook_pool_id = $1
+"#;
+
+/// Assets over debts, both valued in the quote asset, capped at the on-chain maximum of 1000.
+/// `current_price` is scaled like order prices; Pyth prices are used until the pool has one.
+/// Binds: margin managers, DeepBook pools.
+const UPDATE_RISK_RATIO: &str = r#"
+    UPDATE margin_manager_state s SET
+        risk_ratio = CASE
+            WHEN v.debt_value > 0 THEN LEAST(v.asset_value / v.debt_value, 1000)
+        END
+    FROM (
+        SELECT
+            m.margin_manager_id,
+            COALESCE(m.base_asset, 0) * q.price + COALESCE(m.quote_asset, 0) AS asset_value,
+            COALESCE(m.base_debt, 0) * q.price + COALESCE(m.quote_debt, 0) AS debt_value
+        FROM margin_manager_state m
+        JOIN pools p ON p.pool_id = m.deepbook_pool_id
+        CROSS JOIN LATERAL (
+            SELECT COALESCE(
+                m.current_price
+                    / power(10::numeric, 9 - p.base_asset_decimals + p.quote_asset_decimals),
+                (m.base_pyth_price / power(10::numeric, m.base_pyth_decimals))
+                    / NULLIF(m.quote_pyth_price / power(10::numeric, m.quote_pyth_decimals), 0)
+            ) AS price
+        ) q
+        WHERE m.margin_manager_id = ANY($1) OR m.deepbook_pool_id = ANY($2)
+    ) v
+    WHERE s.margin_manager_id = v.margin_manager_id
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeepbookEnv;
+    use deepbook_schema::MIGRATIONS;
+    use diesel::dsl::sql;
+    use diesel::sql_types::{Double, Nullable};
+    use sui_pg_db::temp::TempDb;
+    use sui_pg_db::DbArgs;
+
+    const MANAGER: &str = "0xa1";
+    const POOL: &str = "0xb1";
+    const MARGIN_POOL: &str = "0xc1";
+
+    async fn setup() -> (TempDb, Db) {
+        let temp_db = TempDb::new().expect("postgres binaries must be on PATH");
+        let url = temp_db.database().url().clone();
+        let db = Db::for_write(url, DbArgs::default()).await.unwrap();
+        db.run_migrations(Some(&MIGRATIONS)).await.unwrap();
+        (temp_db, db)
+    }
+
+    async fn execute(conn: &mut Connection<'_>, query: &str) {
+        diesel::sql_query(query).execute(conn).await.unwrap();
+    }
+
+    /// The margin pool lending SUI
+    async fn index_margin_pool(conn: &mut Connection<'_>) {
+        execute(
+            conn,
+            &format!(
+                "INSERT INTO margin_pool_created (event_digest, digest, sender, checkpoint, \
+                 timestamp, checkpoint_timestamp_ms, package, margin_pool_id, maintainer_cap_id, \
+                 asset_type, config_json, onchain_timestamp) VALUES ('e1', 'd1', '0x0', 1, \
+                 NOW(), 0, '0x0', '{MARGIN_POOL}', '0x0', '0x2::sui::SUI', '{{}}', 0)"
+            ),
+        )
+        .await;
+    }
+
+    async fn set_watermark(conn: &mut Connection<'_>, pipeline: &str, checkpoint: i64) {
+        execute(
+            conn,
+            &format!(
+                "INSERT INTO watermarks (pipeline, epoch_hi_inclusive, checkpoint_hi_inclusive, \
+                 tx_hi, timestamp_ms_hi_inclusive, reader_lo, pruner_timestamp, pruner_hi) \
+                 VALUES ('{pipeline}', 0, {checkpoint}, 0, 0, 0, NOW(), 0)"
+            ),
+        )
+        .await;
+    }
+
+    async fn is_tracked(conn: &mut Connection<'_>) -> bool {
+        diesel::select(exists(margin_manager_state::table.find(MANAGER)))
+            .get_result(conn)
+            .await
+            .unwrap()
+    }
+
+    async fn register_pool(conn: &mut Connection<'_>) {
+        execute(
+            conn,
+            &format!(
+                "INSERT INTO pools (pool_id, pool_name, base_asset_id, base_asset_decimals, \
+                 base_asset_symbol, base_asset_name, quote_asset_id, quote_asset_decimals, \
+                 quote_asset_symbol, quote_asset_name, min_size, lot_size, tick_size) VALUES \
+                 ('{POOL}', 'SUI_USDC', '0x2::sui::SUI', 9, 'SUI', 'Sui', '0x5::usdc::USDC', 6, \
+                 'USDC', 'USDC', 1, 1, 1)"
+            ),
+        )
+        .await;
+    }
+
+    fn change(checkpoint: i64, change: Change) -> MarginStateChange {
+        MarginStateChange {
+            checkpoint,
+            timestamp_ms: 1_700_000_000_000,
+            change,
+        }
+    }
+
+    fn created() -> Change {
+        Change::Created {
+            margin_manager_id: MANAGER.to_string(),
+            deepbook_pool_id: POOL.to_string(),
+        }
+    }
+
+    fn borrowed(margin_pool_id: &str) -> Change {
+        Change::Loan {
+            margin_manager_id: MANAGER.to_string(),
+            margin_pool_id: margin_pool_id.to_string(),
+            amount: 2_000_000_000,
+        }
+    }
+
+    fn handler() -> MarginManagerStateHandler {
+        MarginManagerStateHandler::new(Arc::new(EventRouter::new(DeepbookEnv::Testnet)))
+    }
+
+    #[tokio::test]
+    async fn dependencies_must_run_alongside() {
+        let (_temp_db, db) = setup().await;
+        let all = |_: &str| true;
+        let without_prices = |pipeline: &str| pipeline != DEPENDENCIES[1];
+        assert!(check_dependencies(&db, without_prices).await.is_err());
+        assert!(check_dependencies(&db, all).await.is_ok());
+
+        // A dependency that is behind only warns
+        let mut conn = db.connect().await.unwrap();
+        set_watermark(&mut conn, MarginManagerStateHandler::NAME, 10).await;
+        set_watermark(&mut conn, DEPENDENCIES[0], 5).await;
+        assert!(check_dependencies(&db, all).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn skips_margin_managers_without_pool() {
+        let (_temp_db, db) = setup().await;
+        let mut conn = db.connect().await.unwrap();
+        index_margin_pool(&mut conn).await;
+
+        let batch = vec![change(5, created()), change(6, borrowed(MARGIN_POOL))];
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 0);
+        assert!(!is_tracked(&mut conn).await);
+    }
+
+    #[tokio::test]
+    async fn skips_loans_without_margin_pool() {
+        let (_temp_db, db) = setup().await;
+        let mut conn = db.connect().await.unwrap();
+        register_pool(&mut conn).await;
+        index_margin_pool(&mut conn).await;
+
+        let batch = vec![
+            change(5, created()),
+            change(5, borrowed("0xc2")),
+            change(6, borrowed(MARGIN_POOL)),
+        ];
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 2);
+        assert!(is_tracked(&mut conn).await);
+    }
+
+    #[tokio::test]
+    async fn skips_untracked_margin_managers() {
+        let (_temp_db, db) = setup().await;
+        let mut conn = db.connect().await.unwrap();
+        register_pool(&mut conn).await;
+        index_margin_pool(&mut conn).await;
+
+        let batch = vec![change(5, borrowed(MARGIN_POOL))];
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn applies_loans_to_the_lent_side() {
+        let (_temp_db, db) = setup().await;
+        let mut conn = db.connect().await.unwrap();
+        register_pool(&mut conn).await;
+        index_margin_pool(&mut conn).await;
+
+        let batch = vec![change(5, created()), change(5, borrowed(MARGIN_POOL))];
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 2);
+
+        let (base_asset, base_debt, base_margin_pool_id): (
+            Option<f64>,
+            Option<f64>,
+            Option<String>,
+        ) = margin_manager_state::table
+            .find(MANAGER)
+            .select((
+                sql::<Nullable<Double>>("base_asset::FLOAT8"),
+                sql::<Nullable<Double>>("base_debt::FLOAT8"),
+                margin_manager_state::base_margin_pool_id,
+            ))
+            .get_result(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(base_asset, Some(2.0));
+        assert_eq!(base_debt, Some(2.0));
+        assert_eq!(base_margin_pool_id.as_deref(), Some(MARGIN_POOL));
+    }
+}
@@ -1,13 +1,12 @@
-use crate::DeepbookEnv;
-use std::sync::Arc;
-use sui_indexer_alt_framework::types::full_checkpoint_content::{
-    Checkpoint, ExecutedTransaction, ObjectSet,
-};
+use prometheus::{register_int_counter_vec_with_registry, IntCounterVec, Registry};
+use std::sync::{Arc, OnceLock};
+use sui_indexer_alt_framework::types::full_checkpoint_content::{Checkpoint, ExecutedTransaction};
 use sui_types::effects::TransactionEffectsAPI;
 use sui_types::transaction::{Command, TransactionDataAPI};
 
 /// Captures common transaction metadata for event processing.
 /// Used by the `define_handler!` macro to avoid repetitive field extraction.
+#[derive(Clone)]
 pub struct EventMeta {
     digest: Arc<str>,
     sender: Arc<str>,
@@ -18,6 +17,24 @@ pub struct EventMeta {
 }
 
 impl EventMeta {
+    pub fn new(
+        digest: &str,
+        sender: &str,
+        checkpoint: i64,
+        checkpoint_timestamp_ms: i64,
+        package: &str,
+        event_index: usize,
+    ) -> Self {
+        Self {
+            digest: digest.into(),
+            sender: sender.into(),
+            checkpoint,
+            checkpoint_timestamp_ms,
+            package: package.into(),
+            event_index,
+        }
+    }
+
     pub fn from_checkpoint_tx(checkpoint: &Checkpoint, tx: &ExecutedTransaction) -> Self {
         Self {
             digest: tx.effects.transaction_digest().to_string().into(),
@@ -40,6 +57,10 @@ impl EventMeta {
         }
     }
 
+    pub fn event_index(&self) -> usize {
+        self.event_index
+    }
+
     pub fn event_digest(&self) -> String {
         format!("{}{}", self.digest, self.event_index)
     }
@@ -67,8 +88,9 @@ impl EventMeta {
 
 /// Macro to generate a complete handler from minimal configuration.
 ///
-/// This macro generates the handler struct, constructor, `Processor` impl,
-/// and `Handler` impl from a declarative specification.
+/// This macro generates the handler struct, constructor, `Subscriber` impl, `Processor` impl,
+/// and `Handler` impl from a declarative specification. `map_event` becomes the handler's
+/// `map_event` function and borrows the event, which the routing stage shares between pipelines.
 ///
 /// # Example
 /// ```ignore
@@ -96,12 +118,30 @@ macro_rules! define_handler {
         map_event: |$ev:ident, $meta:ident| $body:expr
     } => {
         pub struct $handler {
-            env: $crate::DeepbookEnv,
+            router: std::sync::Arc<$crate::event_index::EventRouter>,
         }
 
         impl $handler {
-            pub fn new(env: $crate::DeepbookEnv) -> Self {
-                Self { env }
+            pub fn new(router: std::sync::Arc<$crate::event_index::EventRouter>) -> Self {
+                Self { router }
+            }
+
+            /// The row written for `event`
+            pub fn map_event($ev: &$event, $meta: &$crate::handlers::EventMeta) -> $model {
+                $body
+            }
+        }
+
+        impl $crate::event_index::Subscriber for $handler {
+            const KINDS: &'static [$crate::event_index::EventKind] =
+                &[<$event as $crate::traits::MoveStruct>::KIND];
+
+            fn register(router: &$crate::event_index::EventRouter) {
+                router.register($proc_name, <$event as $crate::traits::MoveStruct>::KIND);
+                router.register_replay(
+                    $proc_name,
+                    std::sync::Arc::new($crate::dead_letter::Replayer::<Self>::default()),
+                );
             }
         }
 
@@ -114,31 +154,35 @@ macro_rules! define_handler {
                 &self,
                 checkpoint: &std::sync::Arc<sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint>,
             ) -> anyhow::Result<Vec<Self::Value>> {
-                use $crate::handlers::{is_deepbook_tx, EventMeta};
-                use $crate::traits::MoveStruct;
-
+                let routed = self.router.route(checkpoint).await;
                 let mut results = vec![];
-                for tx in &checkpoint.transactions {
-                    if !is_deepbook_tx(tx, &checkpoint.object_set, self.env) {
-                        continue;
-                    }
-                    let Some(events) = &tx.events else { continue };
-
-                    let base_meta = EventMeta::from_checkpoint_tx(checkpoint, tx);
-
-                    for (index, ev) in events.data.iter().enumerate() {
-                        if <$event>::matches_event_type(&ev.type_, self.env) {
-                            let $ev: $event = bcs::from_bytes(&ev.contents)?;
-                            let $meta = base_meta.with_index(index);
-                            results.push($body);
-                            tracing::debug!("Observed {} event", $proc_name);
-                        }
-                    }
+                for routed_event in routed.events::<$event>($proc_name) {
+                    let (meta, event) = routed_event?;
+                    results.push(Self::map_event(event, meta));
+                    tracing::debug!("Observed {} event", $proc_name);
                 }
                 Ok(results)
             }
         }
 
+        #[async_trait::async_trait]
+        impl $crate::dead_letter::Replay for $crate::dead_letter::Replayer<$handler> {
+            async fn replay<'a>(
+                &self,
+                event: &$crate::events::DeepbookEvent,
+                meta: &$crate::handlers::EventMeta,
+                conn: &mut sui_pg_db::Connection<'a>,
+            ) -> anyhow::Result<usize> {
+                use $crate::events::FromDeepbookEvent;
+                use sui_indexer_alt_framework::postgres::handler::Handler;
+
+                let Some(event) = <$event>::from_event(event) else {
+                    anyhow::bail!("{} events are not indexed by {}", event.name(), $proc_name);
+                };
+                <$handler>::commit(&[<$handler>::map_event(event, meta)], conn).await
+            }
+        }
+
         #[async_trait::async_trait]
         impl sui_indexer_alt_framework::postgres::handler::Handler for $handler {
             async fn commit<'a>(
@@ -155,8 +199,17 @@ macro_rules! define_handler {
         }
     };
 }
+pub mod account_app_authorized_handler;
+pub mod account_app_deauthorized_handler;
+pub mod account_created_handler;
+pub mod account_deposited_handler;
+pub mod account_funds_settled_handler;
+pub mod account_session_authorized_handler;
+pub mod account_session_revoked_handler;
+pub mod account_withdrawn_handler;
 pub mod asset_supplied_handler;
 pub mod asset_withdrawn_handler;
+pub mod balance_manager_balances_handler;
 pub mod balance_manager_event_handler;
 pub mod balances_handler;
 pub mod book_params_updated_handler;
@@ -166,6 +219,7 @@ pub mod conditional_order_executed_handler;
 pub mod conditional_order_insufficient_funds_handler;
 pub mod current_price_updated_handler;
 pub mod deep_burned_handler;
+pub mod deepbook_core_account_initialized_handler;
 pub mod deepbook_pool_config_updated_handler;
 pub mod deepbook_pool_registered_handler;
 pub mod deepbook_pool_updated_handler;
@@ -176,21 +230,48 @@ pub mod deposit_collateral_handler;
 pub mod ewma_update_handler;
 pub mod flash_loan_handler;
 pub mod interest_params_updated_handler;
+pub mod liquidation_by_vault_handler;
 pub mod liquidation_handler;
 pub mod loan_borrowed_handler;
 pub mod loan_repaid_handler;
 pub mod maintainer_cap_updated_handler;
 pub mod maintainer_fees_withdrawn_handler;
 pub mod margin_manager_created_handler;
+pub mod margin_manager_state_handler;
 pub mod margin_pool_config_updated_handler;
 pub mod margin_pool_created_handler;
 pub mod max_price_age_updated_handler;
+pub mod ohclv_handler;
+pub mod open_orders_handler;
 pub mod order_fill_handler;
 pub mod order_update_handler;
+pub mod package_versions_handler;
 pub mod pause_cap_updated_handler;
 pub mod pool_created_handler;
 pub mod pool_price_handler;
+pub mod predict_builder_code_created_handler;
+pub mod predict_builder_code_set_handler;
+pub mod predict_builder_fees_claimed_handler;
+pub mod predict_liquidated_order_redeemed_handler;
+pub mod predict_live_order_redeemed_handler;
+pub mod predict_market_created_handler;
+pub mod predict_market_settled_handler;
+pub mod predict_order_liquidated_handler;
+pub mod predict_order_minted_handler;
+pub mod predict_request_cancelled_handler;
+pub mod predict_settled_order_redeemed_handler;
+pub mod predict_supply_filled_handler;
+pub mod predict_supply_requested_handler;
+pub mod predict_withdraw_filled_handler;
+pub mod predict_withdraw_requested_handler;
 pub mod price_tolerance_updated_handler;
+pub mod propbook_block_scholes_batch_ingested_handler;
+pub mod propbook_block_scholes_observations_handler;
+pub mod propbook_block_scholes_stores_registered_handler;
+pub mod propbook_oracle_bound_handler;
+pub mod propbook_oracle_observations_handler;
+pub mod propbook_oracle_rebound_handler;
+pub mod propbook_oracle_source_registered_handler;
 pub mod proposals_handler;
 pub mod protocol_fees_increased_handler;
 pub mod protocol_fees_withdrawn_handler;
@@ -199,59 +280,54 @@ pub mod rebates_v2_handler;
 pub mod referral_claimed_handler;
 pub mod referral_fee_event_handler;
 pub mod referral_fees_claimed_handler;
+pub mod rewards_handler;
 pub mod stakes_handler;
 pub mod supplier_cap_minted_handler;
 pub mod supply_referral_minted_handler;
 pub mod taker_fee_penalty_handler;
 pub mod trade_params_update_handler;
+pub mod trader_volume_handler;
+pub mod undecodable_events_handler;
 pub mod vote_handler;
 pub mod withdraw_collateral_handler;
 
-pub(crate) fn is_deepbook_tx(
-    tx: &ExecutedTransaction,
-    checkpoint_objects: &ObjectSet,
-    env: DeepbookEnv,
-) -> bool {
-    let deepbook_addresses = env.package_addresses();
-    let deepbook_packages = env.package_ids();
+#[cfg(test)]
+mod tests;
 
-    // Check input objects against all known package versions
-    let has_deepbook_input = tx.input_objects(checkpoint_objects).any(|obj| {
-        obj.data
-            .type_()
-            .map(|t| deepbook_addresses.iter().any(|addr| t.address() == *addr))
-            .unwrap_or_default()
-    });
-
-    if has_deepbook_input {
-        return true;
-    }
+/// Metrics of the sequential pipelines' commits
+pub struct HandlerMetrics {
+    pub skipped_changes: IntCounterVec,
+}
 
-    // Check if transaction has deepbook events from any version
-    if let Some(events) = &tx.events {
-        let has_deepbook_event = events.data.iter().any(|event| {
-            deepbook_addresses
-                .iter()
-                .any(|addr| event.type_.address == *addr)
-        });
-        if has_deepbook_event {
-            return true;
+impl HandlerMetrics {
+    fn new(registry: &Registry) -> Self {
+        Self {
+            skipped_changes: register_int_counter_vec_with_registry!(
+                "pipeline_skipped_changes_total",
+                "Number of changes a pipeline could not apply and skipped, by pipeline and reason",
+                &["pipeline", "reason"],
+                registry
+            )
+            .unwrap(),
         }
     }
+}
 
-    // Check if transaction calls a deepbook function from any version
-    let txn_kind = tx.transaction.kind();
-    let has_deepbook_call = txn_kind.iter_commands().any(|cmd| {
-        if let Command::MoveCall(move_call) = cmd {
-            deepbook_packages
-                .iter()
-                .any(|pkg| *pkg == move_call.package)
-        } else {
-            false
-        }
-    });
+static METRICS: OnceLock<HandlerMetrics> = OnceLock::new();
+
+/// Register the handlers' metrics with `registry`. Without it, skipped changes are only logged.
+pub fn init_metrics(registry: &Registry) {
+    METRICS.get_or_init(|| HandlerMetrics::new(registry));
+}
 
-    has_deepbook_call
+/// Count `count` changes that `pipeline` skipped for `reason`
+pub(crate) fn count_skipped(pipeline: &str, reason: &str, count: usize) {
+    if let Some(metrics) = METRICS.get() {
+        metrics
+            .skipped_changes
+            .with_label_values(&[pipeline, reason])
+            .inc_by(count as u64);
+    }
 }
 
 pub(crate) fn try_extract_move_call_package(tx: &ExecutedTransaction) -> Option<String> {
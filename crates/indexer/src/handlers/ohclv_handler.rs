@@ -0,0 +1,495 @@
+use crate::event_index::{EventKind, EventRouter, Subscriber};
+use crate::events::DeepbookEvent;
+use crate::handlers::count_skipped;
+use crate::models::deepbook::order_info::OrderFilled;
+use crate::traits::MoveStruct;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use deepbook_schema::schema::pools;
+use diesel::sql_types::{Array, BigInt, Integer, Numeric, Text};
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use sui_indexer_alt_framework::pipeline::sequential::Handler;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::postgres::{Connection, Db};
+use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
+use tracing::{debug, warn};
+
+const MINUTE_MS: i64 = 60 * 1000;
+const DAY_MS: i64 = 24 * 60 * MINUTE_MS;
+
+/// Rows per insert statement, so each statement's arrays stay a manageable size
+const INSERT_CHUNK_SIZE: usize = 1000;
+
+/// Maintains the `ohclv_1m` and `ohclv_1d` candles from order fills, as checkpoints are indexed.
+///
+/// Runs as a sequential pipeline: fills reach it in checkpoint order, and each batch is merged
+/// into the candles in the same transaction that advances the pipeline's watermark. A restart
+/// resumes after the last committed checkpoint, so no fill is counted twice or skipped, and the
+/// candles are the same however the checkpoints were batched. Candles only merge associatively
+/// (open from the earliest fill, close from the latest, extremes and sums of the rest), so a
+/// batch's minute candles are also rolled up into its daily candles before both are written.
+///
+/// Prices and volumes are normalized with the decimals in `pools`, so fills of a pool missing from
+/// it are skipped with a warning and counted in `pipeline_skipped_changes_total`, like the
+/// `update_ohclv_*` procedures drop them. Once the pool is added, its earlier candles can be
+/// filled in with those procedures.
+///
+/// Only fills after the pipeline's first checkpoint are counted. Earlier history can be filled in
+/// once with the `update_ohclv_1m` and `update_ohclv_1d` procedures, up to that checkpoint.
+pub struct OhclvHandler {
+    router: Arc<EventRouter>,
+}
+
+impl OhclvHandler {
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
+    }
+}
+
+impl Subscriber for OhclvHandler {
+    const KINDS: &'static [EventKind] = &[OrderFilled::KIND];
+}
+
+/// One fill, in on-chain units.
+#[derive(Debug, Clone)]
+pub struct Fill {
+    pool_id: String,
+    timestamp_ms: i64,
+    price: i64,
+    base_quantity: u64,
+    quote_quantity: u64,
+}
+
+/// A candle in on-chain units; prices and quantities are normalized by the pool's decimals when
+/// written. Volumes are sums of u64 quantities, so they are kept wide enough not to overflow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candle {
+    open: i64,
+    high: i64,
+    low: i64,
+    close: i64,
+    base_volume: i128,
+    quote_volume: i128,
+    trade_count: i32,
+    first_trade_timestamp: i64,
+    last_trade_timestamp: i64,
+}
+
+impl Candle {
+    fn new(fill: &Fill) -> Self {
+        Self {
+            open: fill.price,
+            high: fill.price,
+            low: fill.price,
+            close: fill.price,
+            base_volume: fill.base_quantity.into(),
+            quote_volume: fill.quote_quantity.into(),
+            trade_count: 1,
+            first_trade_timestamp: fill.timestamp_ms,
+            last_trade_timestamp: fill.timestamp_ms,
+        }
+    }
+
+    /// Merge `later`, a candle of the same bucket made of fills that come after this one's.
+    fn merge(&mut self, later: &Candle) {
+        self.high = self.high.max(later.high);
+        self.low = self.low.min(later.low);
+        self.close = later.close;
+        self.base_volume += later.base_volume;
+        self.quote_volume += later.quote_volume;
+        self.trade_count += later.trade_count;
+        self.last_trade_timestamp = later.last_trade_timestamp;
+    }
+}
+
+/// Candles of the fills in a batch, keyed by pool and the start of their minute.
+#[derive(Default)]
+pub struct OhclvBatch {
+    minutes: BTreeMap<(String, i64), Candle>,
+}
+
+#[async_trait]
+impl Processor for OhclvHandler {
+    const NAME: &'static str = "ohclv";
+    type Value = Fill;
+
+    async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
+        let mut results = vec![];
+
+        for routed_event in routed.of_kind(OrderFilled::KIND) {
+            let meta = &routed_event.meta;
+            let Some(DeepbookEvent::OrderFilled(event)) = routed_event.decoded_for(Self::NAME)?
+            else {
+                continue;
+            };
+            let fill = Fill {
+                pool_id: event.pool_id.to_string(),
+                timestamp_ms: meta.checkpoint_timestamp_ms(),
+                price: event.price as i64,
+                base_quantity: event.base_quantity,
+                quote_quantity: event.quote_quantity,
+            };
+            debug!("Observed fill {:?}", fill);
+            results.push(fill);
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl Handler for OhclvHandler {
+    type Store = Db;
+    type Batch = OhclvBatch;
+
+    fn batch(&self, batch: &mut Self::Batch, values: std::vec::IntoIter<Self::Value>) {
+        for fill in values {
+            let candle = Candle::new(&fill);
+            let minute = fill.timestamp_ms - fill.timestamp_ms.rem_euclid(MINUTE_MS);
+            batch
+                .minutes
+                .entry((fill.pool_id, minute))
+                .and_modify(|existing| existing.merge(&candle))
+                .or_insert(candle);
+        }
+    }
+
+    async fn commit<'a>(
+        &self,
+        batch: &Self::Batch,
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize> {
+        let pool_ids: BTreeSet<&str> = batch
+            .minutes
+            .keys()
+            .map(|(pool_id, _)| pool_id.as_str())
+            .collect();
+        let registered: BTreeSet<String> = pools::table
+            .filter(pools::pool_id.eq_any(pool_ids.iter().copied()))
+            .select(pools::pool_id)
+            .load::<String>(conn)
+            .await?
+            .into_iter()
+            .collect();
+        let missing: Vec<&str> = pool_ids
+            .into_iter()
+            .filter(|pool_id| !registered.contains(*pool_id))
+            .collect();
+        if !missing.is_empty() {
+            let skipped: i32 = batch
+                .minutes
+                .iter()
+                .filter(|((pool_id, _), _)| !registered.contains(pool_id))
+                .map(|(_, candle)| candle.trade_count)
+                .sum();
+            warn!(
+                ?missing,
+                "Skipping {skipped} fills of pools missing from `pools`, which are needed to \
+                 normalize them"
+            );
+            count_skipped(Self::NAME, "unknown_pool", skipped as usize);
+        }
+        let minutes: BTreeMap<_, _> = batch
+            .minutes
+            .iter()
+            .filter(|((pool_id, _), _)| registered.contains(pool_id))
+            .collect();
+
+        let mut days: BTreeMap<(&String, i64), Candle> = BTreeMap::new();
+        for ((pool_id, minute), candle) in &minutes {
+            let day = minute - minute.rem_euclid(DAY_MS);
+            days.entry((pool_id, day))
+                .and_modify(|existing| existing.merge(candle))
+                .or_insert(**candle);
+        }
+
+        let minutes: Vec<_> = minutes
+            .into_iter()
+            .map(|((pool_id, minute), candle)| (pool_id, *minute, candle))
+            .collect();
+        let days: Vec<_> = days
+            .iter()
+            .map(|((pool_id, day), candle)| (*pool_id, *day, candle))
+            .collect();
+
+        let mut changed = 0;
+        for chunk in minutes.chunks(INSERT_CHUNK_SIZE) {
+            changed += merge_candles(UPSERT_OHCLV_1M, chunk, conn).await?;
+        }
+        for chunk in days.chunks(INSERT_CHUNK_SIZE) {
+            changed += merge_candles(UPSERT_OHCLV_1D, chunk, conn).await?;
+        }
+        Ok(changed)
+    }
+}
+
+/// Merge `candles`, as `(pool_id, bucket start in ms, candle)`, into the table of `query`.
+async fn merge_candles(
+    query: &str,
+    candles: &[(&String, i64, &Candle)],
+    conn: &mut Connection<'_>,
+) -> anyhow::Result<usize> {
+    let column = |f: fn(&Candle) -> i64| candles.iter().map(|(_, _, c)| f(c)).collect::<Vec<_>>();
+    let volume = |f: fn(&Candle) -> i128| {
+        candles
+            .iter()
+            .map(|(_, _, c)| BigDecimal::from(f(c)))
+            .collect::<Vec<_>>()
+    };
+    let pool_ids: Vec<&str> = candles
+        .iter()
+        .map(|(pool_id, _, _)| pool_id.as_str())
+        .collect();
+    let buckets: Vec<i64> = candles.iter().map(|(_, bucket, _)| *bucket).collect();
+    let trade_counts: Vec<i32> = candles.iter().map(|(_, _, c)| c.trade_count).collect();
+
+    Ok(diesel::sql_query(query)
+        .bind::<Array<Text>, _>(pool_ids)
+        .bind::<Array<BigInt>, _>(buckets)
+        .bind::<Array<BigInt>, _>(column(|c| c.open))
+        .bind::<Array<BigInt>, _>(column(|c| c.high))
+        .bind::<Array<BigInt>, _>(column(|c| c.low))
+        .bind::<Array<BigInt>, _>(column(|c| c.close))
+        .bind::<Array<Numeric>, _>(volume(|c| c.base_volume))
+        .bind::<Array<Numeric>, _>(volume(|c| c.quote_volume))
+        .bind::<Array<Integer>, _>(trade_counts)
+        .bind::<Array<BigInt>, _>(column(|c| c.first_trade_timestamp))
+        .bind::<Array<BigInt>, _>(column(|c| c.last_trade_timestamp))
+        .execute(conn)
+        .await?)
+}
+
+/// Upsert into `$table` candles given as parallel arrays, normalized with the decimals in `pools`
+/// like the `update_ohclv_*` procedures. The pools must be in `pools`, or their candles are
+/// dropped by the join. `$bucket` turns `c.bucket_ms` into the table's
+/// `bucket_time`.
+macro_rules! upsert_ohclv {
+    ($table:literal, $bucket:literal) => {
+        concat!(
+            "INSERT INTO ",
+            $table,
+            " (
+                pool_id, bucket_time, open, high, low, close, base_volume, quote_volume,
+                trade_count, first_trade_timestamp, last_trade_timestamp
+            )
+            SELECT
+                c.pool_id,
+                ",
+            $bucket,
+            ",
+                c.open::numeric / price_scale,
+                c.high::numeric / price_scale,
+                c.low::numeric / price_scale,
+                c.close::numeric / price_scale,
+                c.base_volume / power(10::numeric, p.base_asset_decimals),
+                c.quote_volume / power(10::numeric, p.quote_asset_decimals),
+                c.trade_count,
+                c.first_trade_timestamp,
+                c.last_trade_timestamp
+            FROM UNNEST($1::text[], $2::bigint[], $3::bigint[], $4::bigint[], $5::bigint[],
+                        $6::bigint[], $7::numeric[], $8::numeric[], $9::int[], $10::bigint[],
+                        $11::bigint[])
+                AS c(pool_id, bucket_ms, open, high, low, close, base_volume, quote_volume,
+                     trade_count, first_trade_timestamp, last_trade_timestamp)
+            JOIN pools p ON p.pool_id = c.pool_id
+            CROSS JOIN LATERAL (
+                SELECT power(10::numeric, 9 - p.base_asset_decimals + p.quote_asset_decimals)
+                    AS price_scale
+            ) s
+            ON CONFLICT (pool_id, bucket_time) DO UPDATE SET
+                open = CASE
+                    WHEN EXCLUDED.first_trade_timestamp < ",
+            $table,
+            ".first_trade_timestamp
+                    THEN EXCLUDED.open ELSE ",
+            $table,
+            ".open
+                END,
+                high = GREATEST(EXCLUDED.high, ",
+            $table,
+            ".high),
+                low = LEAST(EXCLUDED.low, ",
+            $table,
+            ".low),
+                close = CASE
+                    WHEN EXCLUDED.last_trade_timestamp >= ",
+            $table,
+            ".last_trade_timestamp
+                    THEN EXCLUDED.close ELSE ",
+            $table,
+            ".close
+                END,
+                base_volume = ",
+            $table,
+            ".base_volume + EXCLUDED.base_volume,
+                quote_volume = ",
+            $table,
+            ".quote_volume + EXCLUDED.quote_volume,
+                trade_count = ",
+            $table,
+            ".trade_count + EXCLUDED.trade_count,
+                first_trade_timestamp =
+                    LEAST(EXCLUDED.first_trade_timestamp, ",
+            $table,
+            ".first_trade_timestamp),
+                last_trade_timestamp =
+                    GREATEST(EXCLUDED.last_trade_timestamp, ",
+            $table,
+            ".last_trade_timestamp)"
+        )
+    };
+}
+
+const UPSERT_OHCLV_1M: &str = upsert_ohclv!(
+    "ohclv_1m",
+    "to_timestamp(c.bucket_ms / 1000.0) AT TIME ZONE 'UTC'"
+);
+const UPSERT_OHCLV_1D: &str = upsert_ohclv!(
+    "ohclv_1d",
+    "(to_timestamp(c.bucket_ms / 1000.0) AT TIME ZONE 'UTC')::date"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeepbookEnv;
+    use deepbook_schema::schema::{ohclv_1d, ohclv_1m};
+    use deepbook_schema::MIGRATIONS;
+    use sui_pg_db::temp::TempDb;
+    use sui_pg_db::DbArgs;
+
+    fn fill(pool_id: &str, timestamp_ms: i64, price: i64, base_quantity: u64) -> Fill {
+        Fill {
+            pool_id: pool_id.to_string(),
+            timestamp_ms,
+            price,
+            base_quantity,
+            quote_quantity: base_quantity * 2,
+        }
+    }
+
+    fn handler() -> OhclvHandler {
+        OhclvHandler::new(Arc::new(EventRouter::new(DeepbookEnv::Mainnet)))
+    }
+
+    fn batch(fills: Vec<Vec<Fill>>) -> OhclvBatch {
+        let handler = handler();
+        let mut batch = OhclvBatch::default();
+        for values in fills {
+            handler.batch(&mut batch, values.into_iter());
+        }
+        batch
+    }
+
+    #[test]
+    fn merge_keeps_open_and_takes_close() {
+        let mut candle = Candle::new(&fill("0x1", 1_000, 10, 5));
+        candle.merge(&Candle::new(&fill("0x1", 2_000, 30, 1)));
+        candle.merge(&Candle::new(&fill("0x1", 3_000, 20, 2)));
+
+        assert_eq!(
+            candle,
+            Candle {
+                open: 10,
+                high: 30,
+                low: 10,
+                close: 20,
+                base_volume: 8,
+                quote_volume: 16,
+                trade_count: 3,
+                first_trade_timestamp: 1_000,
+                last_trade_timestamp: 3_000,
+            }
+        );
+    }
+
+    #[test]
+    fn merge_does_not_overflow_volumes() {
+        let mut candle = Candle::new(&fill("0x1", 1_000, 10, u64::MAX / 2));
+        candle.merge(&Candle::new(&fill("0x1", 2_000, 10, u64::MAX / 2)));
+        assert_eq!(candle.base_volume, 2 * (u64::MAX / 2) as i128);
+        assert_eq!(candle.quote_volume, 4 * (u64::MAX / 2) as i128);
+    }
+
+    #[test]
+    fn batch_buckets_fills_by_pool_and_minute() {
+        let batch = batch(vec![vec![
+            fill("0x1", 59_999, 10, 1),
+            fill("0x1", 60_000, 11, 1),
+            fill("0x2", 60_001, 12, 1),
+            fill("0x1", 119_999, 13, 1),
+        ]]);
+
+        let keys: Vec<_> = batch.minutes.keys().cloned().collect();
+        assert_eq!(
+            keys,
+            [
+                ("0x1".to_string(), 0),
+                ("0x1".to_string(), 60_000),
+                ("0x2".to_string(), 60_000),
+            ]
+        );
+        let minute = batch.minutes[&("0x1".to_string(), 60_000)];
+        assert_eq!((minute.open, minute.close, minute.trade_count), (11, 13, 2));
+    }
+
+    #[test]
+    fn batch_is_independent_of_checkpoint_grouping() {
+        let fills = || {
+            vec![
+                fill("0x1", 1_000, 10, 1),
+                fill("0x1", 2_000, 12, 3),
+                fill("0x1", 3_000, 9, 2),
+            ]
+        };
+        let together = batch(vec![fills()]);
+        let mut split = fills();
+        let rest = split.split_off(1);
+        let apart = batch(vec![split, rest]);
+
+        assert_eq!(together.minutes, apart.minutes);
+    }
+
+    #[tokio::test]
+    async fn commit_skips_fills_of_unknown_pools() {
+        let temp_db = TempDb::new().expect("postgres binaries must be on PATH");
+        let url = temp_db.database().url().clone();
+        let db = Db::for_write(url, DbArgs::default()).await.unwrap();
+        db.run_migrations(Some(&MIGRATIONS)).await.unwrap();
+        let mut conn = db.connect().await.unwrap();
+        diesel::sql_query(
+            "INSERT INTO pools (pool_id, pool_name, base_asset_id, base_asset_decimals, \
+             base_asset_symbol, base_asset_name, quote_asset_id, quote_asset_decimals, \
+             quote_asset_symbol, quote_asset_name, min_size, lot_size, tick_size) VALUES \
+             ('0x1', 'SUI_USDC', '0x2::sui::SUI', 9, 'SUI', 'Sui', '0x5::usdc::USDC', 6, \
+             'USDC', 'USDC', 1, 1, 1)",
+        )
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+        let batch = batch(vec![vec![
+            fill("0x1", 60_000, 10, 5),
+            fill("0x2", 60_000, 12, 1),
+            fill("0x1", 61_000, 11, 2),
+        ]]);
+        // One minute and one day candle of the known pool
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 2);
+
+        let minutes: Vec<(String, i32)> = ohclv_1m::table
+            .select((ohclv_1m::pool_id, ohclv_1m::trade_count))
+            .load(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(minutes, [("0x1".to_string(), 2)]);
+        let days: Vec<String> = ohclv_1d::table
+            .select(ohclv_1d::pool_id)
+            .load(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(days, ["0x1"]);
+    }
+}
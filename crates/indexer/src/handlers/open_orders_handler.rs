@@ -0,0 +1,841 @@
+use crate::event_index::{EventKind, EventRouter, Subscriber};
+use crate::events::DeepbookEvent;
+use crate::handlers::EventMeta;
+use crate::models::deepbook::order::{OrderCanceled, OrderModified};
+use crate::models::deepbook::order_info::{OrderExpired, OrderFilled, OrderPlaced};
+use crate::traits::MoveStruct;
+use async_trait::async_trait;
+use deepbook_schema::models::{OpenOrder, OpenOrderStatus};
+use deepbook_schema::schema::open_orders;
+use diesel::sql_types::{Array, BigInt, Text};
+use diesel::upsert::excluded;
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use sui_indexer_alt_framework::pipeline::sequential::Handler;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::postgres::{Connection, Db};
+use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
+use sui_types::base_types::ObjectID;
+use tracing::debug;
+
+/// Event types that change the set of open orders
+const OPEN_ORDER_KINDS: [EventKind; 5] = [
+    OrderPlaced::KIND,
+    OrderModified::KIND,
+    OrderFilled::KIND,
+    OrderCanceled::KIND,
+    OrderExpired::KIND,
+];
+
+/// Rows per insert statement, well below Postgres' bind parameter limit
+const INSERT_CHUNK_SIZE: usize = 1000;
+
+/// Maintains `open_orders`, the orders currently resting on a book, and `order_book_levels`, their
+/// quantity aggregated by pool, side and price. Runs as a sequential pipeline, since each event
+/// updates the state left by the previous ones: an order is inserted when placed, updated when
+/// modified or filled as maker, and removed once fully filled, canceled or expired. Both tables
+/// are written in the same transaction, so the levels always match the open orders.
+///
+/// Only orders placed after the pipeline's first checkpoint are tracked, so it needs to index
+/// from the first core package checkpoint to hold every open order.
+pub struct OpenOrdersHandler {
+    router: Arc<EventRouter>,
+}
+
+impl OpenOrdersHandler {
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
+    }
+}
+
+impl Subscriber for OpenOrdersHandler {
+    const KINDS: &'static [EventKind] = &OPEN_ORDER_KINDS;
+}
+
+/// A change to one order, produced in checkpoint order.
+#[derive(Debug, Clone)]
+pub struct OpenOrderChange {
+    pool_id: String,
+    order_id: String,
+    checkpoint: i64,
+    timestamp_ms: i64,
+    change: Change,
+}
+
+#[derive(Debug, Clone)]
+enum Change {
+    Placed(OpenOrder),
+    Modified(Quantities),
+    /// Filled as maker, by `base_quantity`
+    Filled {
+        base_quantity: i64,
+    },
+    Closed,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Quantities {
+    original_quantity: i64,
+    filled_quantity: i64,
+    quantity: i64,
+}
+
+/// The net change to each order touched by a batch, so each order is written once per commit.
+#[derive(Default)]
+pub struct OpenOrdersBatch {
+    orders: BTreeMap<(String, String), Pending>,
+}
+
+enum Pending {
+    /// The order was placed in this batch, so its whole row is known
+    Row(OpenOrder),
+    /// Changes to a row written by an earlier batch: the quantities of the last modification, if
+    /// any, followed by the quantity filled since.
+    Update {
+        quantities: Option<Quantities>,
+        filled: i64,
+        last_updated_at: i64,
+        checkpoint: i64,
+    },
+    Removed,
+}
+
+impl OpenOrdersBatch {
+    fn apply(&mut self, change: OpenOrderChange) {
+        let OpenOrderChange {
+            pool_id,
+            order_id,
+            checkpoint,
+            timestamp_ms,
+            change,
+        } = change;
+        let pending = self.orders.remove(&(pool_id.clone(), order_id.clone()));
+        let pending = match (pending, change) {
+            (_, Change::Placed(row)) => Pending::Row(row),
+            (_, Change::Closed) | (Some(Pending::Removed), _) => Pending::Removed,
+            (Some(Pending::Row(mut row)), Change::Modified(quantities)) => {
+                row.original_quantity = quantities.original_quantity;
+                row.filled_quantity = quantities.filled_quantity;
+                row.quantity = quantities.quantity;
+                row.status = OpenOrderStatus::from_filled_quantity(row.filled_quantity);
+                row.last_updated_at = timestamp_ms;
+                row.checkpoint = checkpoint;
+                Pending::Row(row)
+            }
+            (Some(Pending::Row(mut row)), Change::Filled { base_quantity }) => {
+                row.filled_quantity += base_quantity;
+                row.quantity -= base_quantity;
+                if row.quantity <= 0 {
+                    Pending::Removed
+                } else {
+                    row.status = OpenOrderStatus::from_filled_quantity(row.filled_quantity);
+                    row.last_updated_at = timestamp_ms;
+                    row.checkpoint = checkpoint;
+                    Pending::Row(row)
+                }
+            }
+            (Some(Pending::Update { .. }) | None, Change::Modified(quantities)) => {
+                Pending::Update {
+                    quantities: Some(quantities),
+                    filled: 0,
+                    last_updated_at: timestamp_ms,
+                    checkpoint,
+                }
+            }
+            (
+                Some(Pending::Update {
+                    quantities, filled, ..
+                }),
+                Change::Filled { base_quantity },
+            ) => Pending::Update {
+                quantities,
+                filled: filled + base_quantity,
+                last_updated_at: timestamp_ms,
+                checkpoint,
+            },
+            (None, Change::Filled { base_quantity }) => Pending::Update {
+                quantities: None,
+                filled: base_quantity,
+                last_updated_at: timestamp_ms,
+                checkpoint,
+            },
+        };
+        self.orders.insert((pool_id, order_id), pending);
+    }
+}
+
+#[async_trait]
+impl Processor for OpenOrdersHandler {
+    const NAME: &'static str = "open_orders";
+    type Value = OpenOrderChange;
+
+    async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
+        let mut results = vec![];
+        let mut taker_fills = HashMap::new();
+        let mut current_tx = None;
+
+        for routed_event in routed.of_kinds(&OPEN_ORDER_KINDS) {
+            if current_tx != Some(routed_event.tx_index) {
+                current_tx = Some(routed_event.tx_index);
+                taker_fills.clear();
+            }
+            let meta = &routed_event.meta;
+            let Some(event) = routed_event.decoded_for(Self::NAME)? else {
+                continue;
+            };
+            let Some(change) = open_order_change(event, &mut taker_fills, meta) else {
+                continue;
+            };
+            debug!("Observed open order change {:?}", change.change);
+            results.push(change);
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl Handler for OpenOrdersHandler {
+    type Store = Db;
+    type Batch = OpenOrdersBatch;
+
+    fn batch(&self, batch: &mut Self::Batch, values: std::vec::IntoIter<Self::Value>) {
+        for change in values {
+            batch.apply(change);
+        }
+    }
+
+    async fn commit<'a>(
+        &self,
+        batch: &Self::Batch,
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize> {
+        let mut rows = vec![];
+        let mut removed: BTreeMap<&String, Vec<&String>> = BTreeMap::new();
+        // Price levels whose orders changed, to aggregate again
+        let mut touched: BTreeMap<&String, BTreeSet<i64>> = BTreeMap::new();
+        let mut changed = 0;
+
+        for ((pool_id, order_id), pending) in &batch.orders {
+            let order = || open_orders::table.find((pool_id, order_id));
+            let prices: Vec<i64> = match pending {
+                Pending::Row(row) => {
+                    rows.push(row.clone());
+                    touched.entry(pool_id).or_default().insert(row.price);
+                    continue;
+                }
+                Pending::Removed => {
+                    removed.entry(pool_id).or_default().push(order_id);
+                    continue;
+                }
+                Pending::Update {
+                    quantities: Some(quantities),
+                    filled,
+                    last_updated_at,
+                    checkpoint,
+                } => {
+                    let filled_quantity = quantities.filled_quantity + filled;
+                    let quantity = quantities.quantity - filled;
+                    if quantity <= 0 {
+                        removed.entry(pool_id).or_default().push(order_id);
+                        continue;
+                    }
+                    diesel::update(order())
+                        .set((
+                            open_orders::original_quantity.eq(quantities.original_quantity),
+                            open_orders::filled_quantity.eq(filled_quantity),
+                            open_orders::quantity.eq(quantity),
+                            open_orders::status
+                                .eq(OpenOrderStatus::from_filled_quantity(filled_quantity)),
+                            open_orders::last_updated_at.eq(last_updated_at),
+                            open_orders::checkpoint.eq(checkpoint),
+                        ))
+                        .returning(open_orders::price)
+                        .get_results(conn)
+                        .await?
+                }
+                Pending::Update {
+                    quantities: None,
+                    filled,
+                    last_updated_at,
+                    checkpoint,
+                } => {
+                    let prices = diesel::update(order())
+                        .set((
+                            open_orders::filled_quantity.eq(open_orders::filled_quantity + filled),
+                            open_orders::quantity.eq(open_orders::quantity - filled),
+                            open_orders::status.eq(OpenOrderStatus::PartiallyFilled),
+                            open_orders::last_updated_at.eq(last_updated_at),
+                            open_orders::checkpoint.eq(checkpoint),
+                        ))
+                        .returning(open_orders::price)
+                        .get_results(conn)
+                        .await?;
+                    // Fully filled
+                    diesel::delete(order().filter(open_orders::quantity.le(0)))
+                        .execute(conn)
+                        .await?;
+                    prices
+                }
+            };
+            changed += prices.len();
+            touched.entry(pool_id).or_default().extend(prices);
+        }
+
+        for (pool_id, order_ids) in removed {
+            let prices: Vec<i64> = diesel::delete(
+                open_orders::table
+                    .filter(open_orders::pool_id.eq(pool_id))
+                    .filter(open_orders::order_id.eq_any(order_ids)),
+            )
+            .returning(open_orders::price)
+            .get_results(conn)
+            .await?;
+            changed += prices.len();
+            touched.entry(pool_id).or_default().extend(prices);
+        }
+
+        for chunk in rows.chunks(INSERT_CHUNK_SIZE) {
+            changed += diesel::insert_into(open_orders::table)
+                .values(chunk)
+                .on_conflict((open_orders::pool_id, open_orders::order_id))
+                .do_update()
+                .set((
+                    open_orders::balance_manager_id.eq(excluded(open_orders::balance_manager_id)),
+                    open_orders::trader.eq(excluded(open_orders::trader)),
+                    open_orders::client_order_id.eq(excluded(open_orders::client_order_id)),
+                    open_orders::is_bid.eq(excluded(open_orders::is_bid)),
+                    open_orders::price.eq(excluded(open_orders::price)),
+                    open_orders::original_quantity.eq(excluded(open_orders::original_quantity)),
+                    open_orders::filled_quantity.eq(excluded(open_orders::filled_quantity)),
+                    open_orders::quantity.eq(excluded(open_orders::quantity)),
+                    open_orders::status.eq(excluded(open_orders::status)),
+                    open_orders::expire_timestamp.eq(excluded(open_orders::expire_timestamp)),
+                    open_orders::placed_at.eq(excluded(open_orders::placed_at)),
+                    open_orders::last_updated_at.eq(excluded(open_orders::last_updated_at)),
+                    open_orders::checkpoint.eq(excluded(open_orders::checkpoint)),
+                ))
+                .execute(conn)
+                .await?;
+        }
+
+        for (pool_id, prices) in touched {
+            let prices: Vec<i64> = prices.into_iter().collect();
+            aggregate_levels(pool_id, &prices, conn).await?;
+        }
+        Ok(changed)
+    }
+}
+
+/// Aggregate the open orders of `pool_id` at `prices` into `order_book_levels` again
+async fn aggregate_levels(
+    pool_id: &str,
+    prices: &[i64],
+    conn: &mut Connection<'_>,
+) -> anyhow::Result<()> {
+    diesel::sql_query("DELETE FROM order_book_levels WHERE pool_id = $1 AND price = ANY($2)")
+        .bind::<Text, _>(pool_id)
+        .bind::<Array<BigInt>, _>(prices)
+        .execute(conn)
+        .await?;
+    diesel::sql_query(
+        r#"
+        INSERT INTO order_book_levels (pool_id, is_bid, price, quantity, order_count)
+        SELECT pool_id, is_bid, price, SUM(quantity)::BIGINT, COUNT(*)
+        FROM open_orders
+        WHERE pool_id = $1 AND price = ANY($2)
+        GROUP BY pool_id, is_bid, price
+        "#,
+    )
+    .bind::<Text, _>(pool_id)
+    .bind::<Array<BigInt>, _>(prices)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+/// The change `event` makes to an open order. `taker_fills` holds the quantity filled as taker by
+/// each order of the current transaction, keyed by pool and order ID, until the order is placed on
+/// the book.
+fn open_order_change(
+    event: &DeepbookEvent,
+    taker_fills: &mut HashMap<(ObjectID, u128), u64>,
+    meta: &EventMeta,
+) -> Option<OpenOrderChange> {
+    let change = match event {
+        DeepbookEvent::OrderPlaced(event) => {
+            let taker_filled = taker_fills
+                .remove(&(event.pool_id, event.order_id))
+                .unwrap_or_default();
+            process_order_placed(event, taker_filled, meta)
+        }
+        DeepbookEvent::OrderModified(event) => process_order_modified(event, meta),
+        DeepbookEvent::OrderFilled(event) => {
+            *taker_fills
+                .entry((event.pool_id, event.taker_order_id))
+                .or_default() += event.base_quantity;
+            process_order_filled(event, meta)
+        }
+        DeepbookEvent::OrderCanceled(event) => OpenOrderChange {
+            pool_id: event.pool_id.to_string(),
+            order_id: event.order_id.to_string(),
+            checkpoint: meta.checkpoint(),
+            timestamp_ms: meta.checkpoint_timestamp_ms(),
+            change: Change::Closed,
+        },
+        DeepbookEvent::OrderExpired(event) => OpenOrderChange {
+            pool_id: event.pool_id.to_string(),
+            order_id: event.order_id.to_string(),
+            checkpoint: meta.checkpoint(),
+            timestamp_ms: meta.checkpoint_timestamp_ms(),
+            change: Change::Closed,
+        },
+        _ => return None,
+    };
+    Some(change)
+}
+
+/// `placed_quantity` is what remains of the order once it has matched as taker, so the
+/// `taker_filled` quantity is added back to get the order's original quantity.
+fn process_order_placed(
+    order_placed: &OrderPlaced,
+    taker_filled: u64,
+    meta: &EventMeta,
+) -> OpenOrderChange {
+    let pool_id = order_placed.pool_id.to_string();
+    let order_id = order_placed.order_id.to_string();
+    OpenOrderChange {
+        pool_id: pool_id.clone(),
+        order_id: order_id.clone(),
+        checkpoint: meta.checkpoint(),
+        timestamp_ms: meta.checkpoint_timestamp_ms(),
+        change: Change::Placed(OpenOrder {
+            pool_id,
+            order_id,
+            balance_manager_id: order_placed.balance_manager_id.to_string(),
+            trader: order_placed.trader.to_string(),
+            client_order_id: order_placed.client_order_id as i64,
+            is_bid: order_placed.is_bid,
+            price: order_placed.price as i64,
+            original_quantity: (order_placed.placed_quantity + taker_filled) as i64,
+            filled_quantity: taker_filled as i64,
+            quantity: order_placed.placed_quantity as i64,
+            status: OpenOrderStatus::from_filled_quantity(taker_filled as i64),
+            expire_timestamp: order_placed.expire_timestamp as i64,
+            placed_at: meta.checkpoint_timestamp_ms(),
+            last_updated_at: meta.checkpoint_timestamp_ms(),
+            checkpoint: meta.checkpoint(),
+        }),
+    }
+}
+
+fn process_order_modified(order_modified: &OrderModified, meta: &EventMeta) -> OpenOrderChange {
+    // `new_quantity` is the order's total quantity, including what has been filled
+    OpenOrderChange {
+        pool_id: order_modified.pool_id.to_string(),
+        order_id: order_modified.order_id.to_string(),
+        checkpoint: meta.checkpoint(),
+        timestamp_ms: meta.checkpoint_timestamp_ms(),
+        change: Change::Modified(Quantities {
+            original_quantity: order_modified.new_quantity as i64,
+            filled_quantity: order_modified.filled_quantity as i64,
+            quantity: (order_modified.new_quantity - order_modified.filled_quantity) as i64,
+        }),
+    }
+}
+
+fn process_order_filled(order_filled: &OrderFilled, meta: &EventMeta) -> OpenOrderChange {
+    // Only the maker order rests on the book; a taker order that is not fully filled is placed
+    // afterwards with its remaining quantity, and its fills are counted then.
+    OpenOrderChange {
+        pool_id: order_filled.pool_id.to_string(),
+        order_id: order_filled.maker_order_id.to_string(),
+        checkpoint: meta.checkpoint(),
+        timestamp_ms: meta.checkpoint_timestamp_ms(),
+        change: Change::Filled {
+            base_quantity: order_filled.base_quantity as i64,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::tests::{meta, object_id};
+    use crate::DeepbookEnv;
+    use deepbook_schema::schema::order_book_levels;
+    use deepbook_schema::MIGRATIONS;
+    use diesel::SelectableHelper;
+    use sui_pg_db::temp::TempDb;
+    use sui_pg_db::DbArgs;
+    use sui_sdk_types::Address;
+
+    const POOL: u8 = 2;
+
+    fn handler() -> OpenOrdersHandler {
+        OpenOrdersHandler::new(Arc::new(EventRouter::new(DeepbookEnv::Testnet)))
+    }
+
+    fn at(checkpoint: i64) -> EventMeta {
+        EventMeta::new(
+            "digest",
+            "0x5e",
+            checkpoint,
+            1_700_000_000_000 + checkpoint,
+            "0xfeed",
+            0,
+        )
+    }
+
+    fn order_placed(order_id: u128, price: u64, placed_quantity: u64) -> OrderPlaced {
+        OrderPlaced {
+            balance_manager_id: object_id(1),
+            pool_id: object_id(POOL),
+            order_id,
+            client_order_id: 3,
+            trader: Address::new([4; 32]),
+            price,
+            is_bid: true,
+            placed_quantity,
+            expire_timestamp: u64::MAX,
+            timestamp: 1_700_000_000_000,
+        }
+    }
+
+    fn order_filled(
+        pool_id: ObjectID,
+        maker_order_id: u128,
+        taker_order_id: u128,
+        base_quantity: u64,
+    ) -> OrderFilled {
+        OrderFilled {
+            pool_id,
+            maker_order_id,
+            taker_order_id,
+            maker_client_order_id: 0,
+            taker_client_order_id: 0,
+            price: 1_000,
+            taker_is_bid: false,
+            taker_fee: 0,
+            taker_fee_is_deep: false,
+            maker_fee: 0,
+            maker_fee_is_deep: false,
+            base_quantity,
+            quote_quantity: base_quantity * 1_000,
+            maker_balance_manager_id: object_id(1),
+            taker_balance_manager_id: object_id(5),
+            timestamp: 1_700_000_000_000,
+        }
+    }
+
+    fn place(order_id: u128, price: u64, quantity: u64, checkpoint: i64) -> OpenOrderChange {
+        process_order_placed(&order_placed(order_id, price, quantity), 0, &at(checkpoint))
+    }
+
+    fn fill(maker_order_id: u128, base_quantity: u64, checkpoint: i64) -> OpenOrderChange {
+        let event = order_filled(object_id(POOL), maker_order_id, 0, base_quantity);
+        process_order_filled(&event, &at(checkpoint))
+    }
+
+    fn modify(order_id: u128, new_quantity: u64, filled: u64, checkpoint: i64) -> OpenOrderChange {
+        let event = OrderModified {
+            balance_manager_id: object_id(1),
+            pool_id: object_id(POOL),
+            order_id,
+            client_order_id: 3,
+            trader: Address::new([4; 32]),
+            price: 1_000,
+            is_bid: true,
+            previous_quantity: 0,
+            filled_quantity: filled,
+            new_quantity,
+            timestamp: 1_700_000_000_000,
+        };
+        process_order_modified(&event, &at(checkpoint))
+    }
+
+    fn cancel(order_id: u128, checkpoint: i64) -> OpenOrderChange {
+        let event = DeepbookEvent::OrderCanceled(OrderCanceled {
+            balance_manager_id: object_id(1),
+            pool_id: object_id(POOL),
+            order_id,
+            client_order_id: 3,
+            trader: Address::new([4; 32]),
+            price: 1_000,
+            is_bid: true,
+            original_quantity: 0,
+            base_asset_quantity_canceled: 0,
+            timestamp: 1_700_000_000_000,
+        });
+        open_order_change(&event, &mut HashMap::new(), &at(checkpoint)).unwrap()
+    }
+
+    fn expire(order_id: u128, checkpoint: i64) -> OpenOrderChange {
+        let event = DeepbookEvent::OrderExpired(OrderExpired {
+            balance_manager_id: object_id(1),
+            pool_id: object_id(POOL),
+            order_id,
+            client_order_id: 3,
+            trader: Address::new([4; 32]),
+            price: 1_000,
+            is_bid: true,
+            original_quantity: 0,
+            base_asset_quantity_canceled: 0,
+            timestamp: 1_700_000_000_000,
+        });
+        open_order_change(&event, &mut HashMap::new(), &at(checkpoint)).unwrap()
+    }
+
+    fn batch_of(changes: Vec<OpenOrderChange>) -> OpenOrdersBatch {
+        let mut batch = OpenOrdersBatch::default();
+        handler().batch(&mut batch, changes.into_iter());
+        batch
+    }
+
+    fn pending(batch: &OpenOrdersBatch, order_id: u128) -> &Pending {
+        &batch.orders[&(object_id(POOL).to_string(), order_id.to_string())]
+    }
+
+    #[test]
+    fn placed_order_counts_its_taker_fills() {
+        let order_placed = order_placed(7, 1_000, 60);
+
+        let Change::Placed(row) = process_order_placed(&order_placed, 40, &meta()).change else {
+            panic!("expected a placed order");
+        };
+        assert_eq!(row.original_quantity, 100);
+        assert_eq!(row.filled_quantity, 40);
+        assert_eq!(row.quantity, 60);
+        assert_eq!(row.status, OpenOrderStatus::PartiallyFilled);
+
+        let Change::Placed(row) = process_order_placed(&order_placed, 0, &meta()).change else {
+            panic!("expected a placed order");
+        };
+        assert_eq!(row.original_quantity, 60);
+        assert_eq!(row.status, OpenOrderStatus::Placed);
+    }
+
+    #[test]
+    fn taker_fills_are_keyed_by_pool_and_order() {
+        let mut taker_fills = HashMap::new();
+        for event in [
+            order_filled(object_id(POOL), 1, 7, 30),
+            order_filled(object_id(3), 2, 7, 50),
+            order_filled(object_id(POOL), 4, 7, 10),
+        ] {
+            let change = open_order_change(
+                &DeepbookEvent::OrderFilled(event),
+                &mut taker_fills,
+                &meta(),
+            );
+            assert!(matches!(change.unwrap().change, Change::Filled { .. }));
+        }
+
+        let event = DeepbookEvent::OrderPlaced(order_placed(7, 1_000, 60));
+        let change = open_order_change(&event, &mut taker_fills, &meta()).unwrap();
+        let Change::Placed(row) = change.change else {
+            panic!("expected a placed order");
+        };
+        assert_eq!(row.original_quantity, 100);
+        assert_eq!(row.filled_quantity, 40);
+        // The other pool's fills stay with its own order 7
+        assert_eq!(taker_fills.get(&(object_id(3), 7)), Some(&50));
+        assert_eq!(taker_fills.len(), 1);
+    }
+
+    #[test]
+    fn batch_applies_fills_after_a_modification() {
+        let batch = batch_of(vec![modify(1, 80, 10, 1), fill(1, 30, 2), fill(1, 5, 3)]);
+        let Pending::Update {
+            quantities: Some(quantities),
+            filled,
+            checkpoint,
+            ..
+        } = pending(&batch, 1)
+        else {
+            panic!("expected an update");
+        };
+        assert_eq!(quantities.original_quantity, 80);
+        assert_eq!(quantities.filled_quantity, 10);
+        assert_eq!(quantities.quantity, 70);
+        assert_eq!((*filled, *checkpoint), (35, 3));
+
+        let batch = batch_of(vec![
+            place(1, 1_000, 100, 1),
+            fill(1, 20, 2),
+            modify(1, 80, 20, 3),
+        ]);
+        let Pending::Row(row) = pending(&batch, 1) else {
+            panic!("expected a row");
+        };
+        assert_eq!(row.original_quantity, 80);
+        assert_eq!(row.filled_quantity, 20);
+        assert_eq!(row.quantity, 60);
+        assert_eq!(row.status, OpenOrderStatus::PartiallyFilled);
+        assert_eq!(row.checkpoint, 3);
+    }
+
+    #[test]
+    fn batch_removes_fully_filled_orders() {
+        let batch = batch_of(vec![place(1, 1_000, 50, 1), fill(1, 20, 2)]);
+        let Pending::Row(row) = pending(&batch, 1) else {
+            panic!("expected a row");
+        };
+        assert_eq!((row.filled_quantity, row.quantity), (20, 30));
+
+        let batch = batch_of(vec![place(1, 1_000, 50, 1), fill(1, 20, 2), fill(1, 30, 3)]);
+        assert!(matches!(pending(&batch, 1), Pending::Removed));
+        // A fill after the order is gone does not bring it back
+        let batch = batch_of(vec![place(1, 1_000, 50, 1), fill(1, 50, 2), fill(1, 5, 3)]);
+        assert!(matches!(pending(&batch, 1), Pending::Removed));
+    }
+
+    #[test]
+    fn batch_keeps_an_order_placed_again_after_cancellation() {
+        let batch = batch_of(vec![
+            place(1, 1_000, 50, 1),
+            cancel(1, 2),
+            place(1, 2_000, 40, 3),
+        ]);
+        let Pending::Row(row) = pending(&batch, 1) else {
+            panic!("expected a row");
+        };
+        assert_eq!((row.price, row.quantity, row.checkpoint), (2_000, 40, 3));
+
+        let batch = batch_of(vec![place(1, 1_000, 50, 1), cancel(1, 2)]);
+        assert!(matches!(pending(&batch, 1), Pending::Removed));
+    }
+
+    #[test]
+    fn batch_removes_expired_orders() {
+        let batch = batch_of(vec![
+            fill(1, 10, 1),
+            expire(1, 2),
+            modify(2, 80, 0, 1),
+            expire(2, 2),
+        ]);
+        assert!(matches!(pending(&batch, 1), Pending::Removed));
+        assert!(matches!(pending(&batch, 2), Pending::Removed));
+    }
+
+    async fn setup() -> (TempDb, Db) {
+        let temp_db = TempDb::new().expect("postgres binaries must be on PATH");
+        let url = temp_db.database().url().clone();
+        let db = Db::for_write(url, DbArgs::default()).await.unwrap();
+        db.run_migrations(Some(&MIGRATIONS)).await.unwrap();
+        (temp_db, db)
+    }
+
+    /// Orders by ID, as (original quantity, filled quantity, quantity, status)
+    async fn orders(
+        conn: &mut Connection<'_>,
+    ) -> BTreeMap<String, (i64, i64, i64, OpenOrderStatus)> {
+        open_orders::table
+            .select(OpenOrder::as_select())
+            .load(conn)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|order| {
+                let quantities = (
+                    order.original_quantity,
+                    order.filled_quantity,
+                    order.quantity,
+                    order.status,
+                );
+                (order.order_id, quantities)
+            })
+            .collect()
+    }
+
+    /// Levels of the pool as (price, quantity, order count)
+    async fn levels(conn: &mut Connection<'_>) -> Vec<(i64, i64, i64)> {
+        order_book_levels::table
+            .filter(order_book_levels::pool_id.eq(object_id(POOL).to_string()))
+            .select((
+                order_book_levels::price,
+                order_book_levels::quantity,
+                order_book_levels::order_count,
+            ))
+            .order_by(order_book_levels::price)
+            .load(conn)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn commit_updates_orders_of_earlier_batches() {
+        let (_temp_db, db) = setup().await;
+        let mut conn = db.connect().await.unwrap();
+
+        let batch = batch_of(vec![
+            place(1, 1_000, 100, 1),
+            place(2, 1_000, 40, 1),
+            place(3, 2_000, 10, 1),
+            place(4, 3_000, 25, 1),
+        ]);
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 4);
+        assert_eq!(
+            levels(&mut conn).await,
+            vec![(1_000, 140, 2), (2_000, 10, 1), (3_000, 25, 1)]
+        );
+
+        let batch = batch_of(vec![
+            // Modified then filled
+            modify(1, 80, 0, 2),
+            fill(1, 30, 2),
+            // Partially filled
+            fill(2, 15, 2),
+            // Fully filled
+            fill(3, 10, 2),
+            // Modified, then filled to the end
+            modify(4, 20, 5, 2),
+            fill(4, 15, 2),
+        ]);
+        handler().commit(&batch, &mut conn).await.unwrap();
+
+        let orders = orders(&mut conn).await;
+        assert_eq!(
+            orders.get("1"),
+            Some(&(80, 30, 50, OpenOrderStatus::PartiallyFilled))
+        );
+        assert_eq!(
+            orders.get("2"),
+            Some(&(40, 15, 25, OpenOrderStatus::PartiallyFilled))
+        );
+        assert_eq!(orders.len(), 2);
+        // The partially filled level is aggregated again, and the emptied ones are dropped
+        assert_eq!(levels(&mut conn).await, vec![(1_000, 75, 2)]);
+    }
+
+    #[tokio::test]
+    async fn commit_removes_closed_orders_from_levels() {
+        let (_temp_db, db) = setup().await;
+        let mut conn = db.connect().await.unwrap();
+
+        let batch = batch_of(vec![
+            place(1, 1_000, 100, 1),
+            place(2, 1_000, 40, 1),
+            place(3, 2_000, 10, 1),
+        ]);
+        handler().commit(&batch, &mut conn).await.unwrap();
+
+        let batch = batch_of(vec![
+            expire(1, 2),
+            cancel(3, 2),
+            place(3, 2_000, 30, 2),
+            place(5, 2_000, 5, 2),
+        ]);
+        handler().commit(&batch, &mut conn).await.unwrap();
+
+        let orders = orders(&mut conn).await;
+        assert!(!orders.contains_key("1"));
+        assert_eq!(orders.get("3"), Some(&(30, 0, 30, OpenOrderStatus::Placed)));
+        assert_eq!(
+            levels(&mut conn).await,
+            vec![(1_000, 40, 1), (2_000, 35, 2)]
+        );
+    }
+}
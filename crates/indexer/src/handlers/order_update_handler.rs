@@ -1,8 +1,9 @@
-use crate::handlers::{is_deepbook_tx, try_extract_move_call_package};
+use crate::event_index::{EventKind, EventRouter, Subscriber};
+use crate::events::DeepbookEvent;
+use crate::handlers::EventMeta;
 use crate::models::deepbook::order::{OrderCanceled, OrderModified};
 use crate::models::deepbook::order_info::{OrderExpired, OrderPlaced};
 use crate::traits::MoveStruct;
-use crate::DeepbookEnv;
 use async_trait::async_trait;
 use deepbook_schema::models::{OrderUpdate, OrderUpdateStatus};
 use deepbook_schema::schema::order_updates;
@@ -12,65 +13,53 @@ use sui_indexer_alt_framework::pipeline::Processor;
 use sui_indexer_alt_framework::postgres::handler::Handler;
 use sui_indexer_alt_framework::postgres::Connection;
 use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
-use sui_types::transaction::TransactionDataAPI;
 use tracing::debug;
 
-type TransactionMetadata = (String, u64, u64, String, String);
+/// Event types written to `order_updates`
+const ORDER_UPDATE_KINDS: [EventKind; 4] = [
+    OrderPlaced::KIND,
+    OrderModified::KIND,
+    OrderCanceled::KIND,
+    OrderExpired::KIND,
+];
 
 pub struct OrderUpdateHandler {
-    env: DeepbookEnv,
+    router: Arc<EventRouter>,
 }
 
 impl OrderUpdateHandler {
-    pub fn new(env: DeepbookEnv) -> Self {
-        Self { env }
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
     }
 }
 
+impl Subscriber for OrderUpdateHandler {
+    const KINDS: &'static [EventKind] = &ORDER_UPDATE_KINDS;
+}
+
 #[async_trait]
 impl Processor for OrderUpdateHandler {
     const NAME: &'static str = "order_update";
     type Value = OrderUpdate;
 
     async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
         let mut results = vec![];
 
-        for tx in &checkpoint.transactions {
-            if !is_deepbook_tx(tx, &checkpoint.object_set, self.env) {
-                continue;
-            }
-            let Some(events) = &tx.events else {
+        for routed_event in routed.of_kinds(&ORDER_UPDATE_KINDS) {
+            let meta = &routed_event.meta;
+            let Some(event) = routed_event.decoded_for(Self::NAME)? else {
                 continue;
             };
-
-            let package = try_extract_move_call_package(tx).unwrap_or_default();
-            let metadata = (
-                tx.transaction.sender().to_string(),
-                checkpoint.summary.sequence_number,
-                checkpoint.summary.timestamp_ms,
-                tx.transaction.digest().to_string(),
-                package.clone(),
-            );
-
-            for (index, ev) in events.data.iter().enumerate() {
-                if OrderPlaced::matches_event_type(&ev.type_, self.env) {
-                    let event = bcs::from_bytes(&ev.contents)?;
-                    results.push(process_order_placed(event, metadata.clone(), index));
-                    debug!("Observed Deepbook Order Placed {:?}", tx);
-                } else if OrderModified::matches_event_type(&ev.type_, self.env) {
-                    let event = bcs::from_bytes(&ev.contents)?;
-                    results.push(process_order_modified(event, metadata.clone(), index));
-                    debug!("Observed Deepbook Order Modified {:?}", tx);
-                } else if OrderCanceled::matches_event_type(&ev.type_, self.env) {
-                    let event = bcs::from_bytes(&ev.contents)?;
-                    results.push(process_order_canceled(event, metadata.clone(), index));
-                    debug!("Observed Deepbook Order Canceled {:?}", tx);
-                } else if OrderExpired::matches_event_type(&ev.type_, self.env) {
-                    let event = bcs::from_bytes(&ev.contents)?;
-                    results.push(process_order_expired(event, metadata.clone(), index));
-                    debug!("Observed Deepbook Order Expired {:?}", tx);
-                }
-            }
+            let update = match event {
+                DeepbookEvent::OrderPlaced(event) => process_order_placed(event, meta),
+                DeepbookEvent::OrderModified(event) => process_order_modified(event, meta),
+                DeepbookEvent::OrderCanceled(event) => process_order_canceled(event, meta),
+                DeepbookEvent::OrderExpired(event) => process_order_expired(event, meta),
+                _ => continue,
+            };
+            debug!("Observed Deepbook Order {:?}", update.status);
+            results.push(update);
         }
         Ok(results)
     }
@@ -90,19 +79,14 @@ impl Handler for OrderUpdateHandler {
     }
 }
 
-fn process_order_placed(
-    order_placed: OrderPlaced,
-    (sender, checkpoint, checkpoint_timestamp_ms, digest, package): TransactionMetadata,
-    event_index: usize,
-) -> OrderUpdate {
-    let event_digest = format!("{digest}{event_index}");
+fn process_order_placed(order_placed: &OrderPlaced, meta: &EventMeta) -> OrderUpdate {
     OrderUpdate {
-        event_digest,
-        digest,
-        sender,
-        checkpoint: checkpoint as i64,
-        checkpoint_timestamp_ms: checkpoint_timestamp_ms as i64,
-        package,
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
         status: OrderUpdateStatus::Placed,
         pool_id: order_placed.pool_id.to_string(),
         order_id: order_placed.order_id.to_string(),
@@ -118,19 +102,14 @@ fn process_order_placed(
     }
 }
 
-fn process_order_modified(
-    order_modified: OrderModified,
-    (sender, checkpoint, checkpoint_timestamp_ms, digest, package): TransactionMetadata,
-    event_index: usize,
-) -> OrderUpdate {
-    let event_digest = format!("{digest}{event_index}");
+fn process_order_modified(order_modified: &OrderModified, meta: &EventMeta) -> OrderUpdate {
     OrderUpdate {
-        digest,
-        event_digest,
-        sender,
-        checkpoint: checkpoint as i64,
-        checkpoint_timestamp_ms: checkpoint_timestamp_ms as i64,
-        package,
+        digest: meta.digest(),
+        event_digest: meta.event_digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
         status: OrderUpdateStatus::Modified,
         pool_id: order_modified.pool_id.to_string(),
         order_id: order_modified.order_id.to_string(),
@@ -146,19 +125,14 @@ fn process_order_modified(
     }
 }
 
-fn process_order_canceled(
-    order_canceled: OrderCanceled,
-    (sender, checkpoint, checkpoint_timestamp_ms, digest, package): TransactionMetadata,
-    event_index: usize,
-) -> OrderUpdate {
-    let event_digest = format!("{digest}{event_index}");
+fn process_order_canceled(order_canceled: &OrderCanceled, meta: &EventMeta) -> OrderUpdate {
     OrderUpdate {
-        digest,
-        event_digest,
-        sender,
-        checkpoint: checkpoint as i64,
-        checkpoint_timestamp_ms: checkpoint_timestamp_ms as i64,
-        package,
+        digest: meta.digest(),
+        event_digest: meta.event_digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
         status: OrderUpdateStatus::Canceled,
         pool_id: order_canceled.pool_id.to_string(),
         order_id: order_canceled.order_id.to_string(),
@@ -175,19 +149,14 @@ fn process_order_canceled(
     }
 }
 
-fn process_order_expired(
-    order_expired: OrderExpired,
-    (sender, checkpoint, checkpoint_timestamp_ms, digest, package): TransactionMetadata,
-    event_index: usize,
-) -> OrderUpdate {
-    let event_digest = format!("{digest}{event_index}");
+fn process_order_expired(order_expired: &OrderExpired, meta: &EventMeta) -> OrderUpdate {
     OrderUpdate {
-        digest,
-        event_digest,
-        sender,
-        checkpoint: checkpoint as i64,
-        checkpoint_timestamp_ms: checkpoint_timestamp_ms as i64,
-        package,
+        digest: meta.digest(),
+        event_digest: meta.event_digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
         status: OrderUpdateStatus::Expired,
         pool_id: order_expired.pool_id.to_string(),
         order_id: order_expired.order_id.to_string(),
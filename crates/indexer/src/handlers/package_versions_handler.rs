@@ -0,0 +1,70 @@
+use crate::event_index::EventRouter;
+use crate::handlers::EventMeta;
+use async_trait::async_trait;
+use deepbook_schema::models::PackageVersion;
+use deepbook_schema::schema::package_versions;
+use diesel_async::RunQueryDsl;
+use std::sync::Arc;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::postgres::handler::Handler;
+use sui_indexer_alt_framework::postgres::Connection;
+use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
+use tracing::debug;
+
+/// Discovers upgrades of registry packages and persists them to `package_versions`. Every other
+/// pipeline waits for this one to scan a checkpoint before routing it (see
+/// `EventRouter::scan`), and its watermark is where discovery resumes after a restart.
+pub struct PackageVersionsHandler {
+    router: Arc<EventRouter>,
+}
+
+impl PackageVersionsHandler {
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
+    }
+}
+
+#[async_trait]
+impl Processor for PackageVersionsHandler {
+    const NAME: &'static str = "package_versions";
+    type Value = PackageVersion;
+
+    async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let mut results = vec![];
+
+        for (tx, upgrade) in self.router.scan(checkpoint) {
+            let meta = EventMeta::from_checkpoint_tx(checkpoint, tx);
+            debug!(
+                family = upgrade.family.as_str(),
+                package_id = %upgrade.package_id,
+                version = upgrade.version,
+                "Observed package upgrade"
+            );
+            results.push(PackageVersion {
+                package_id: upgrade.package_id.to_string(),
+                original_package_id: upgrade.original_package_id.to_string(),
+                family: upgrade.family.as_str().to_string(),
+                version: upgrade.version as i64,
+                digest: meta.digest(),
+                sender: meta.sender(),
+                checkpoint: meta.checkpoint(),
+                checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+            });
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl Handler for PackageVersionsHandler {
+    async fn commit<'a>(
+        values: &[Self::Value],
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize> {
+        Ok(diesel::insert_into(package_versions::table)
+            .values(values)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?)
+    }
+}
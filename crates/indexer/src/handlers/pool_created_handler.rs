@@ -1,8 +1,7 @@
-use crate::handlers::{is_deepbook_tx, try_extract_move_call_package};
+use crate::event_index::{EventKind, EventRouter, Subscriber};
 use crate::models::deepbook::pool::PoolCreated as PoolCreatedEvent;
 use crate::models::sui::sui::SUI;
 use crate::traits::MoveStruct;
-use crate::DeepbookEnv;
 use async_trait::async_trait;
 use deepbook_schema::models::PoolCreated;
 use deepbook_schema::schema::pool_created;
@@ -12,65 +11,51 @@ use sui_indexer_alt_framework::pipeline::Processor;
 use sui_indexer_alt_framework::postgres::handler::Handler;
 use sui_indexer_alt_framework::postgres::Connection;
 use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
-use sui_types::transaction::TransactionDataAPI;
 use tracing::debug;
 
 pub struct PoolCreatedHandler {
-    env: DeepbookEnv,
+    router: Arc<EventRouter>,
 }
 
 impl PoolCreatedHandler {
-    pub fn new(env: DeepbookEnv) -> Self {
-        Self { env }
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
     }
 }
 
+impl Subscriber for PoolCreatedHandler {
+    const KINDS: &'static [EventKind] = &[PoolCreatedEvent::<SUI, SUI>::KIND];
+}
+
 #[async_trait]
 impl Processor for PoolCreatedHandler {
     const NAME: &'static str = "pool_created";
     type Value = PoolCreated;
 
     async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
         let mut results = vec![];
 
-        for tx in &checkpoint.transactions {
-            if !is_deepbook_tx(tx, &checkpoint.object_set, self.env) {
-                continue;
-            }
-            let Some(events) = &tx.events else {
-                continue;
+        for routed_event in routed.events::<PoolCreatedEvent<SUI, SUI>>(Self::NAME) {
+            let (meta, event) = routed_event?;
+            let data = PoolCreated {
+                digest: meta.digest(),
+                event_digest: meta.event_digest(),
+                sender: meta.sender(),
+                checkpoint: meta.checkpoint(),
+                checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+                package: meta.package(),
+                pool_id: event.pool_id.to_string(),
+                taker_fee: event.taker_fee as i64,
+                maker_fee: event.maker_fee as i64,
+                tick_size: event.tick_size as i64,
+                lot_size: event.lot_size as i64,
+                min_size: event.min_size as i64,
+                whitelisted_pool: event.whitelisted_pool,
+                treasury_address: event.treasury_address.to_string(),
             };
-
-            let package = try_extract_move_call_package(tx).unwrap_or_default();
-            let checkpoint_timestamp_ms = checkpoint.summary.timestamp_ms as i64;
-            let checkpoint_seq = checkpoint.summary.sequence_number as i64;
-            let digest = tx.transaction.digest();
-
-            for (index, ev) in events.data.iter().enumerate() {
-                if !PoolCreatedEvent::<SUI, SUI>::matches_event_type(&ev.type_, self.env) {
-                    continue;
-                }
-
-                let event: PoolCreatedEvent<SUI, SUI> = bcs::from_bytes(&ev.contents)?;
-                let data = PoolCreated {
-                    digest: digest.to_string(),
-                    event_digest: format!("{digest}{index}"),
-                    sender: tx.transaction.sender().to_string(),
-                    checkpoint: checkpoint_seq,
-                    checkpoint_timestamp_ms,
-                    package: package.clone(),
-                    pool_id: event.pool_id.to_string(),
-                    taker_fee: event.taker_fee as i64,
-                    maker_fee: event.maker_fee as i64,
-                    tick_size: event.tick_size as i64,
-                    lot_size: event.lot_size as i64,
-                    min_size: event.min_size as i64,
-                    whitelisted_pool: event.whitelisted_pool,
-                    treasury_address: event.treasury_address.to_string(),
-                };
-                debug!("Observed Deepbook PoolCreated {:?}", data);
-                results.push(data);
-            }
+            debug!("Observed Deepbook PoolCreated {:?}", data);
+            results.push(data);
         }
         Ok(results)
     }
@@ -0,0 +1,21 @@
+use crate::models::deepbook_predict::builder_code_events::BuilderCodeCreated;
+use deepbook_schema::models::PredictBuilderCodeCreated;
+
+define_handler! {
+    name: PredictBuilderCodeCreatedHandler,
+    processor_name: "predict_builder_code_created",
+    event_type: BuilderCodeCreated,
+    db_model: PredictBuilderCodeCreated,
+    table: predict_builder_code_created,
+    map_event: |event, meta| PredictBuilderCodeCreated {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        builder_code_id: event.builder_code_id.to_string(),
+        owner: event.owner.to_string(),
+        builder_code_index: event.builder_code_index as i64,
+    }
+}
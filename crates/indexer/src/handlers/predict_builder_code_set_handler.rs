@@ -0,0 +1,21 @@
+use crate::models::deepbook_predict::builder_code_events::BuilderCodeSet;
+use deepbook_schema::models::PredictBuilderCodeSet;
+
+define_handler! {
+    name: PredictBuilderCodeSetHandler,
+    processor_name: "predict_builder_code_set",
+    event_type: BuilderCodeSet,
+    db_model: PredictBuilderCodeSet,
+    table: predict_builder_code_set,
+    map_event: |event, meta| PredictBuilderCodeSet {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        account_id: event.account_id.to_string(),
+        owner: event.owner.to_string(),
+        builder_code_id: event.builder_code_id.map(|id| id.to_string()),
+    }
+}
@@ -0,0 +1,21 @@
+use crate::models::deepbook_predict::builder_code_events::BuilderFeesClaimed;
+use deepbook_schema::models::PredictBuilderFeesClaimed;
+
+define_handler! {
+    name: PredictBuilderFeesClaimedHandler,
+    processor_name: "predict_builder_fees_claimed",
+    event_type: BuilderFeesClaimed,
+    db_model: PredictBuilderFeesClaimed,
+    table: predict_builder_fees_claimed,
+    map_event: |event, meta| PredictBuilderFeesClaimed {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        builder_code_id: event.builder_code_id.to_string(),
+        owner: event.owner.to_string(),
+        amount: event.amount as i64,
+    }
+}
@@ -0,0 +1,25 @@
+use crate::models::deepbook_predict::order_events::LiquidatedOrderRedeemed;
+use deepbook_schema::models::PredictLiquidatedOrderRedeemed;
+
+define_handler! {
+    name: PredictLiquidatedOrderRedeemedHandler,
+    processor_name: "predict_liquidated_order_redeemed",
+    event_type: LiquidatedOrderRedeemed,
+    db_model: PredictLiquidatedOrderRedeemed,
+    table: predict_liquidated_order_redeemed,
+    map_event: |event, meta| PredictLiquidatedOrderRedeemed {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        expiry_market_id: event.expiry_market_id.to_string(),
+        account_id: event.account_id.to_string(),
+        order_id: event.order_id.to_string(),
+        position_root_id: event.position_root_id.to_string(),
+        owner: event.owner.to_string(),
+        quantity_closed: event.quantity_closed as i64,
+        redeemed_at_ms: event.redeemed_at_ms as i64,
+    }
+}
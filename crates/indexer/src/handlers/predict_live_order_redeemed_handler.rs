@@ -0,0 +1,36 @@
+use crate::models::deepbook_predict::order_events::LiveOrderRedeemed;
+use deepbook_schema::models::PredictLiveOrderRedeemed;
+
+define_handler! {
+    name: PredictLiveOrderRedeemedHandler,
+    processor_name: "predict_live_order_redeemed",
+    event_type: LiveOrderRedeemed,
+    db_model: PredictLiveOrderRedeemed,
+    table: predict_live_order_redeemed,
+    map_event: |event, meta| PredictLiveOrderRedeemed {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        expiry_market_id: event.expiry_market_id.to_string(),
+        account_id: event.account_id.to_string(),
+        order_id: event.order_id.to_string(),
+        position_root_id: event.position_root_id.to_string(),
+        owner: event.owner.to_string(),
+        quantity_closed: event.quantity_closed as i64,
+        remaining_quantity: event.remaining_quantity as i64,
+        replacement_order_id: event.replacement_order_id.map(|id| id.to_string()),
+        redeem_amount: event.redeem_amount as i64,
+        trading_fee: event.trading_fee as i64,
+        builder_fee: event.builder_fee as i64,
+        penalty_fee: event.penalty_fee as i64,
+        builder_code_id: event.builder_code_id.map(|id| id.to_string()),
+        redeemed_at_ms: event.redeemed_at_ms as i64,
+        pyth_spot_source_timestamp_ms: event.pyth_spot_source_timestamp_ms as i64,
+        block_scholes_spot_source_timestamp_ms: event.block_scholes_spot_source_timestamp_ms as i64,
+        block_scholes_forward_source_timestamp_ms: event.block_scholes_forward_source_timestamp_ms as i64,
+        block_scholes_svi_source_timestamp_ms: event.block_scholes_svi_source_timestamp_ms as i64,
+    }
+}
@@ -0,0 +1,40 @@
+use crate::models::deepbook_predict::config_events::MarketCreated;
+use deepbook_schema::models::PredictMarketCreated;
+
+define_handler! {
+    name: PredictMarketCreatedHandler,
+    processor_name: "predict_market_created",
+    event_type: MarketCreated,
+    db_model: PredictMarketCreated,
+    table: predict_market_created,
+    map_event: |event, meta| PredictMarketCreated {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        expiry_market_id: event.expiry_market_id.to_string(),
+        pool_vault_id: event.pool_vault_id.to_string(),
+        propbook_underlying_id: event.propbook_underlying_id as i64,
+        expiry: event.expiry as i64,
+        tick_size: event.tick_size as i64,
+        admission_tick_size: event.admission_tick_size as i64,
+        max_expiry_allocation: event.max_expiry_allocation as i64,
+        initial_expiry_cash: event.initial_expiry_cash as i64,
+        liquidation_ltv: event.liquidation_ltv as i64,
+        max_admission_leverage: event.max_admission_leverage as i64,
+        backing_buffer_lambda: event.backing_buffer_lambda as i64,
+        base_fee: event.base_fee as i64,
+        min_fee: event.min_fee as i64,
+        min_entry_probability: event.min_entry_probability as i64,
+        max_entry_probability: event.max_entry_probability as i64,
+        expiry_fee_window_ms: event.expiry_fee_window_ms as i64,
+        expiry_fee_max_multiplier: event.expiry_fee_max_multiplier as i64,
+        no_leverage_window_ms: event.no_leverage_window_ms as i64,
+        trading_loss_rebate_rate: event.trading_loss_rebate_rate as i64,
+        max_benefit_ratio: event.max_benefit_ratio as i64,
+        lower_benefit_power: event.lower_benefit_power as i64,
+        upper_benefit_power: event.upper_benefit_power as i64,
+    }
+}
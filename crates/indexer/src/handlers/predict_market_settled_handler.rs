@@ -0,0 +1,23 @@
+use crate::models::deepbook_predict::config_events::MarketSettled;
+use deepbook_schema::models::PredictMarketSettled;
+
+define_handler! {
+    name: PredictMarketSettledHandler,
+    processor_name: "predict_market_settled",
+    event_type: MarketSettled,
+    db_model: PredictMarketSettled,
+    table: predict_market_settled,
+    map_event: |event, meta| PredictMarketSettled {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        expiry_market_id: event.expiry_market_id.to_string(),
+        propbook_underlying_id: event.propbook_underlying_id as i64,
+        expiry: event.expiry as i64,
+        settlement_price: event.settlement_price as i64,
+        settled_at_ms: event.settled_at_ms as i64,
+    }
+}
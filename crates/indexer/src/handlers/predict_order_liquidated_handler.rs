@@ -0,0 +1,29 @@
+use crate::models::deepbook_predict::order_events::OrderLiquidated;
+use deepbook_schema::models::PredictOrderLiquidated;
+
+define_handler! {
+    name: PredictOrderLiquidatedHandler,
+    processor_name: "predict_order_liquidated",
+    event_type: OrderLiquidated,
+    db_model: PredictOrderLiquidated,
+    table: predict_order_liquidated,
+    map_event: |event, meta| PredictOrderLiquidated {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        expiry_market_id: event.expiry_market_id.to_string(),
+        order_id: event.order_id.to_string(),
+        quantity: event.quantity as i64,
+        gross_value: event.gross_value as i64,
+        floor_amount: event.floor_amount as i64,
+        liquidation_ltv: event.liquidation_ltv as i64,
+        liquidated_at_ms: event.liquidated_at_ms as i64,
+        pyth_spot_source_timestamp_ms: event.pyth_spot_source_timestamp_ms as i64,
+        block_scholes_spot_source_timestamp_ms: event.block_scholes_spot_source_timestamp_ms as i64,
+        block_scholes_forward_source_timestamp_ms: event.block_scholes_forward_source_timestamp_ms as i64,
+        block_scholes_svi_source_timestamp_ms: event.block_scholes_svi_source_timestamp_ms as i64,
+    }
+}
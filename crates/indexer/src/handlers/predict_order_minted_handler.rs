@@ -0,0 +1,39 @@
+use crate::models::deepbook_predict::order_events::OrderMinted;
+use deepbook_schema::models::PredictOrderMinted;
+
+define_handler! {
+    name: PredictOrderMintedHandler,
+    processor_name: "predict_order_minted",
+    event_type: OrderMinted,
+    db_model: PredictOrderMinted,
+    table: predict_order_minted,
+    map_event: |event, meta| PredictOrderMinted {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        expiry_market_id: event.expiry_market_id.to_string(),
+        account_id: event.account_id.to_string(),
+        order_id: event.order_id.to_string(),
+        position_root_id: event.position_root_id.to_string(),
+        owner: event.owner.to_string(),
+        lower_tick: event.lower_tick as i64,
+        higher_tick: event.higher_tick as i64,
+        leverage: event.leverage as i64,
+        entry_probability: event.entry_probability as i64,
+        quantity: event.quantity as i64,
+        net_premium: event.net_premium as i64,
+        trading_fee: event.trading_fee as i64,
+        fee_incentive_subsidy: event.fee_incentive_subsidy as i64,
+        builder_fee: event.builder_fee as i64,
+        penalty_fee: event.penalty_fee as i64,
+        builder_code_id: event.builder_code_id.map(|id| id.to_string()),
+        minted_at_ms: event.minted_at_ms as i64,
+        pyth_spot_source_timestamp_ms: event.pyth_spot_source_timestamp_ms as i64,
+        block_scholes_spot_source_timestamp_ms: event.block_scholes_spot_source_timestamp_ms as i64,
+        block_scholes_forward_source_timestamp_ms: event.block_scholes_forward_source_timestamp_ms as i64,
+        block_scholes_svi_source_timestamp_ms: event.block_scholes_svi_source_timestamp_ms as i64,
+    }
+}
@@ -0,0 +1,26 @@
+use crate::models::deepbook_predict::vault_events::RequestCancelled;
+use deepbook_schema::models::PredictRequestCancelled;
+
+define_handler! {
+    name: PredictRequestCancelledHandler,
+    processor_name: "predict_request_cancelled",
+    event_type: RequestCancelled,
+    db_model: PredictRequestCancelled,
+    table: predict_request_cancelled,
+    map_event: |event, meta| PredictRequestCancelled {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        pool_vault_id: event.pool_vault_id.to_string(),
+        account_id: event.account_id.to_string(),
+        recipient: event.recipient.to_string(),
+        request_index: event.index as i64,
+        amount: event.amount as i64,
+        is_supply: event.is_supply,
+        reason: event.reason as i16,
+        requests_pending_after: event.requests_pending_after as i64,
+    }
+}
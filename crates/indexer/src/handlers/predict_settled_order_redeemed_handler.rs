@@ -0,0 +1,27 @@
+use crate::models::deepbook_predict::order_events::SettledOrderRedeemed;
+use deepbook_schema::models::PredictSettledOrderRedeemed;
+
+define_handler! {
+    name: PredictSettledOrderRedeemedHandler,
+    processor_name: "predict_settled_order_redeemed",
+    event_type: SettledOrderRedeemed,
+    db_model: PredictSettledOrderRedeemed,
+    table: predict_settled_order_redeemed,
+    map_event: |event, meta| PredictSettledOrderRedeemed {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        expiry_market_id: event.expiry_market_id.to_string(),
+        account_id: event.account_id.to_string(),
+        order_id: event.order_id.to_string(),
+        position_root_id: event.position_root_id.to_string(),
+        owner: event.owner.to_string(),
+        quantity_closed: event.quantity_closed as i64,
+        settlement_price: event.settlement_price as i64,
+        payout_amount: event.payout_amount as i64,
+        redeemed_at_ms: event.redeemed_at_ms as i64,
+    }
+}
@@ -0,0 +1,27 @@
+use crate::models::deepbook_predict::vault_events::SupplyFilled;
+use deepbook_schema::models::PredictSupplyFilled;
+
+define_handler! {
+    name: PredictSupplyFilledHandler,
+    processor_name: "predict_supply_filled",
+    event_type: SupplyFilled,
+    db_model: PredictSupplyFilled,
+    table: predict_supply_filled,
+    map_event: |event, meta| PredictSupplyFilled {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        pool_vault_id: event.pool_vault_id.to_string(),
+        account_id: event.account_id.to_string(),
+        recipient: event.recipient.to_string(),
+        request_index: event.index as i64,
+        dusdc_amount: event.dusdc_amount as i64,
+        shares_minted: event.shares_minted as i64,
+        fee_dusdc: event.fee_dusdc as i64,
+        dusdc_remaining: event.dusdc_remaining as i64,
+        requests_pending_after: event.requests_pending_after as i64,
+    }
+}
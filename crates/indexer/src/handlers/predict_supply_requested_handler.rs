@@ -0,0 +1,25 @@
+use crate::models::deepbook_predict::vault_events::SupplyRequested;
+use deepbook_schema::models::PredictSupplyRequested;
+
+define_handler! {
+    name: PredictSupplyRequestedHandler,
+    processor_name: "predict_supply_requested",
+    event_type: SupplyRequested,
+    db_model: PredictSupplyRequested,
+    table: predict_supply_requested,
+    map_event: |event, meta| PredictSupplyRequested {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        pool_vault_id: event.pool_vault_id.to_string(),
+        account_id: event.account_id.to_string(),
+        recipient: event.recipient.to_string(),
+        request_index: event.index as i64,
+        amount: event.amount as i64,
+        min_plp_out: event.min_plp_out as i64,
+        requests_pending_after: event.requests_pending_after as i64,
+    }
+}
@@ -0,0 +1,27 @@
+use crate::models::deepbook_predict::vault_events::WithdrawFilled;
+use deepbook_schema::models::PredictWithdrawFilled;
+
+define_handler! {
+    name: PredictWithdrawFilledHandler,
+    processor_name: "predict_withdraw_filled",
+    event_type: WithdrawFilled,
+    db_model: PredictWithdrawFilled,
+    table: predict_withdraw_filled,
+    map_event: |event, meta| PredictWithdrawFilled {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        pool_vault_id: event.pool_vault_id.to_string(),
+        account_id: event.account_id.to_string(),
+        recipient: event.recipient.to_string(),
+        request_index: event.index as i64,
+        shares_burned: event.shares_burned as i64,
+        dusdc_amount: event.dusdc_amount as i64,
+        fee_dusdc: event.fee_dusdc as i64,
+        shares_remaining: event.shares_remaining as i64,
+        requests_pending_after: event.requests_pending_after as i64,
+    }
+}
@@ -0,0 +1,25 @@
+use crate::models::deepbook_predict::vault_events::WithdrawRequested;
+use deepbook_schema::models::PredictWithdrawRequested;
+
+define_handler! {
+    name: PredictWithdrawRequestedHandler,
+    processor_name: "predict_withdraw_requested",
+    event_type: WithdrawRequested,
+    db_model: PredictWithdrawRequested,
+    table: predict_withdraw_requested,
+    map_event: |event, meta| PredictWithdrawRequested {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        pool_vault_id: event.pool_vault_id.to_string(),
+        account_id: event.account_id.to_string(),
+        recipient: event.recipient.to_string(),
+        request_index: event.index as i64,
+        amount: event.amount as i64,
+        min_dusdc_out: event.min_dusdc_out as i64,
+        requests_pending_after: event.requests_pending_after as i64,
+    }
+}
@@ -0,0 +1,23 @@
+use crate::models::propbook::block_scholes_store::BlockScholesBatchIngested;
+use deepbook_schema::models::PropbookBlockScholesBatchIngested;
+
+define_handler! {
+    name: PropbookBlockScholesBatchIngestedHandler,
+    processor_name: "propbook_block_scholes_batch_ingested",
+    event_type: BlockScholesBatchIngested,
+    db_model: PropbookBlockScholesBatchIngested,
+    table: propbook_block_scholes_batch_ingested,
+    map_event: |event, meta| PropbookBlockScholesBatchIngested {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        propbook_oracle_id: event.propbook_oracle_id.to_string(),
+        series_kind: event.series_kind as i16,
+        published_at_ms: event.published_at_ms as i64,
+        update_count: event.update_count as i64,
+        applied: event.applied as i64,
+    }
+}
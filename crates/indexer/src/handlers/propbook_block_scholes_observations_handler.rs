@@ -0,0 +1,151 @@
+use crate::event_index::{EventKind, EventRouter, Subscriber};
+use crate::events::DeepbookEvent;
+use crate::handlers::EventMeta;
+use crate::models::propbook::block_scholes_store::{
+    BlockScholesObservationRecorded, BsRead, SVIParams,
+};
+use crate::traits::MoveStruct;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use deepbook_schema::models::PropbookBlockScholesObservation;
+use deepbook_schema::schema::propbook_block_scholes_observations;
+use diesel_async::RunQueryDsl;
+use std::sync::Arc;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::postgres::handler::Handler;
+use sui_indexer_alt_framework::postgres::Connection;
+use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
+use sui_types::digests::TransactionDigest;
+use tracing::debug;
+
+/// Indexes `BlockScholesObservationRecorded` events. Spot and forward series carry a
+/// `BsRead<u128>` payload stored in `value`; SVI series carry a `BsRead<SVIParams>` payload
+/// stored as signed decimals in the `svi_*` columns.
+pub struct PropbookBlockScholesObservationsHandler {
+    router: Arc<EventRouter>,
+}
+
+impl PropbookBlockScholesObservationsHandler {
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
+    }
+}
+
+impl Subscriber for PropbookBlockScholesObservationsHandler {
+    const KINDS: &'static [EventKind] = &[BlockScholesObservationRecorded::<BsRead<u128>>::KIND];
+}
+
+#[async_trait]
+impl Processor for PropbookBlockScholesObservationsHandler {
+    const NAME: &'static str = "propbook_block_scholes_observations";
+    type Value = PropbookBlockScholesObservation;
+
+    async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
+        let mut results = vec![];
+
+        // Unknown payloads are skipped when the event is decoded
+        for routed_event in routed.of_kind(BlockScholesObservationRecorded::<BsRead<u128>>::KIND) {
+            let meta = routed_event.meta.clone();
+            let Some(event) = routed_event.decoded_for(Self::NAME)? else {
+                continue;
+            };
+            let observation = match event {
+                DeepbookEvent::BlockScholesValueObservation(event) => {
+                    process_value_observation(event, meta)?
+                }
+                DeepbookEvent::BlockScholesSviObservation(event) => {
+                    process_svi_observation(event, meta)?
+                }
+                _ => continue,
+            };
+            debug!(
+                "Observed propbook Block Scholes observation {}",
+                observation.event_digest
+            );
+            results.push(observation);
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl Handler for PropbookBlockScholesObservationsHandler {
+    async fn commit<'a>(
+        values: &[Self::Value],
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize> {
+        Ok(
+            diesel::insert_into(propbook_block_scholes_observations::table)
+                .values(values)
+                .on_conflict_do_nothing()
+                .execute(conn)
+                .await?,
+        )
+    }
+}
+
+fn signed(magnitude: u128, is_negative: bool) -> BigDecimal {
+    let value = BigDecimal::from(magnitude);
+    if is_negative {
+        -value
+    } else {
+        value
+    }
+}
+
+fn base_observation<V>(
+    event: &BlockScholesObservationRecorded<BsRead<V>>,
+    meta: EventMeta,
+) -> anyhow::Result<PropbookBlockScholesObservation> {
+    let read = &event.observation;
+    let writer_digest = TransactionDigest::new(read.writer_digest.as_slice().try_into()?);
+
+    Ok(PropbookBlockScholesObservation {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        propbook_oracle_id: event.propbook_oracle_id.to_string(),
+        sid: event.sid.to_string(),
+        series_kind: event.series_kind as i16,
+        expiry_ms: event.expiry_ms as i64,
+        model_timestamp_ms: read.model_timestamp_ms as i64,
+        published_at_ms: read.published_at_ms as i64,
+        recorded_at_ms: read.recorded_at_ms as i64,
+        writer_digest: writer_digest.to_string(),
+        value: None,
+        svi_a: None,
+        svi_b: None,
+        svi_sigma: None,
+        svi_rho: None,
+        svi_m: None,
+    })
+}
+
+fn process_value_observation(
+    event: &BlockScholesObservationRecorded<BsRead<u128>>,
+    meta: EventMeta,
+) -> anyhow::Result<PropbookBlockScholesObservation> {
+    Ok(PropbookBlockScholesObservation {
+        value: Some(BigDecimal::from(event.observation.value)),
+        ..base_observation(event, meta)?
+    })
+}
+
+fn process_svi_observation(
+    event: &BlockScholesObservationRecorded<BsRead<SVIParams>>,
+    meta: EventMeta,
+) -> anyhow::Result<PropbookBlockScholesObservation> {
+    let svi = &event.observation.value;
+    Ok(PropbookBlockScholesObservation {
+        svi_a: Some(signed(svi.a_magnitude, svi.a_is_negative)),
+        svi_b: Some(BigDecimal::from(svi.b)),
+        svi_sigma: Some(BigDecimal::from(svi.sigma)),
+        svi_rho: Some(signed(svi.rho_magnitude, svi.rho_is_negative)),
+        svi_m: Some(signed(svi.m_magnitude, svi.m_is_negative)),
+        ..base_observation(event, meta)?
+    })
+}
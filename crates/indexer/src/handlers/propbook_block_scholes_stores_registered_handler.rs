@@ -0,0 +1,22 @@
+use crate::models::propbook::registry::BlockScholesStoresRegistered;
+use deepbook_schema::models::PropbookBlockScholesStoresRegistered;
+
+define_handler! {
+    name: PropbookBlockScholesStoresRegisteredHandler,
+    processor_name: "propbook_block_scholes_stores_registered",
+    event_type: BlockScholesStoresRegistered,
+    db_model: PropbookBlockScholesStoresRegistered,
+    table: propbook_block_scholes_stores_registered,
+    map_event: |event, meta| PropbookBlockScholesStoresRegistered {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        propbook_underlying_id: event.propbook_underlying_id as i64,
+        value_store_id: event.value_store_id.to_string(),
+        svi_store_id: event.svi_store_id.to_string(),
+        block_scholes_base_asset: event.block_scholes_base_asset.to_string(),
+    }
+}
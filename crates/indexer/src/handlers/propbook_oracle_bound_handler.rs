@@ -0,0 +1,23 @@
+use crate::models::propbook::registry::OracleBound;
+use deepbook_schema::models::PropbookOracleBound;
+
+define_handler! {
+    name: PropbookOracleBoundHandler,
+    processor_name: "propbook_oracle_bound",
+    event_type: OracleBound,
+    db_model: PropbookOracleBound,
+    table: propbook_oracle_bound,
+    map_event: |event, meta| PropbookOracleBound {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        propbook_underlying_id: event.propbook_underlying_id as i64,
+        oracle_kind: event.oracle_kind as i16,
+        source_id: event.source_id as i64,
+        propbook_oracle_id: event.propbook_oracle_id.to_string(),
+        value_kind: event.value_kind as i16,
+    }
+}
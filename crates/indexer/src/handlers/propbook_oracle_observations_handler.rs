@@ -0,0 +1,128 @@
+use crate::event_index::{EventKind, EventRouter, Subscriber};
+use crate::events::DeepbookEvent;
+use crate::handlers::EventMeta;
+use crate::models::propbook::oracle_lane::{ObservationInserted, ObservationRecorded, OracleRead};
+use crate::models::propbook::pyth_feed::RawSpot;
+use crate::traits::MoveStruct;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use deepbook_schema::models::PropbookOracleObservation;
+use deepbook_schema::schema::propbook_oracle_observations;
+use diesel_async::RunQueryDsl;
+use std::sync::Arc;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::postgres::handler::Handler;
+use sui_indexer_alt_framework::postgres::Connection;
+use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
+use sui_types::base_types::ObjectID;
+use sui_types::digests::TransactionDigest;
+use tracing::debug;
+
+/// Indexes `ObservationRecorded` and `ObservationInserted` events emitted by propbook oracle
+/// lanes. Both events carry the same `OracleRead<RawSpot>` payload, so they share one table and
+/// are told apart by the `inserted` column.
+const OBSERVATION_KINDS: [EventKind; 2] = [
+    ObservationRecorded::<OracleRead<RawSpot>>::KIND,
+    ObservationInserted::<OracleRead<RawSpot>>::KIND,
+];
+
+pub struct PropbookOracleObservationsHandler {
+    router: Arc<EventRouter>,
+}
+
+impl PropbookOracleObservationsHandler {
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
+    }
+}
+
+impl Subscriber for PropbookOracleObservationsHandler {
+    const KINDS: &'static [EventKind] = &OBSERVATION_KINDS;
+}
+
+#[async_trait]
+impl Processor for PropbookOracleObservationsHandler {
+    const NAME: &'static str = "propbook_oracle_observations";
+    type Value = PropbookOracleObservation;
+
+    async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
+        let mut results = vec![];
+
+        // Non-RawSpot lanes are skipped when the event is decoded
+        for routed_event in routed.of_kinds(&OBSERVATION_KINDS) {
+            let meta = routed_event.meta.clone();
+            let Some(event) = routed_event.decoded_for(Self::NAME)? else {
+                continue;
+            };
+            let observation = match event {
+                DeepbookEvent::OracleObservationRecorded(event) => {
+                    process_observation(&event.propbook_oracle_id, &event.observation, false, meta)?
+                }
+                DeepbookEvent::OracleObservationInserted(event) => {
+                    process_observation(&event.propbook_oracle_id, &event.observation, true, meta)?
+                }
+                _ => continue,
+            };
+            debug!(
+                "Observed propbook oracle observation {}",
+                observation.event_digest
+            );
+            results.push(observation);
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl Handler for PropbookOracleObservationsHandler {
+    async fn commit<'a>(
+        values: &[Self::Value],
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize> {
+        Ok(diesel::insert_into(propbook_oracle_observations::table)
+            .values(values)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?)
+    }
+}
+
+fn process_observation(
+    propbook_oracle_id: &ObjectID,
+    read: &OracleRead<RawSpot>,
+    inserted: bool,
+    meta: EventMeta,
+) -> anyhow::Result<PropbookOracleObservation> {
+    let spot = &read.value;
+    let writer_digest = TransactionDigest::new(read.writer_digest.as_slice().try_into()?);
+
+    let price = BigDecimal::from(spot.price_magnitude);
+    let exponent = spot.exponent_magnitude as i32;
+
+    Ok(PropbookOracleObservation {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        propbook_oracle_id: propbook_oracle_id.to_string(),
+        inserted,
+        source_timestamp_ms: read.source_timestamp_ms as i64,
+        update_timestamp_ms: read.update_timestamp_ms as i64,
+        writer_digest: writer_digest.to_string(),
+        pyth_source_id: spot.pyth_source_id as i64,
+        price: if spot.price_is_negative {
+            -price
+        } else {
+            price
+        },
+        exponent: if spot.exponent_is_negative {
+            -exponent
+        } else {
+            exponent
+        },
+        feed_update_timestamp_us: spot.feed_update_timestamp_us as i64,
+    })
+}
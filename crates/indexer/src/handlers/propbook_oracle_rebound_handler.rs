@@ -0,0 +1,25 @@
+use crate::models::propbook::registry::OracleRebound;
+use deepbook_schema::models::PropbookOracleRebound;
+
+define_handler! {
+    name: PropbookOracleReboundHandler,
+    processor_name: "propbook_oracle_rebound",
+    event_type: OracleRebound,
+    db_model: PropbookOracleRebound,
+    table: propbook_oracle_rebound,
+    map_event: |event, meta| PropbookOracleRebound {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        propbook_underlying_id: event.propbook_underlying_id as i64,
+        oracle_kind: event.oracle_kind as i16,
+        value_kind: event.value_kind as i16,
+        old_source_id: event.old_source_id as i64,
+        old_propbook_oracle_id: event.old_propbook_oracle_id.to_string(),
+        new_source_id: event.new_source_id as i64,
+        new_propbook_oracle_id: event.new_propbook_oracle_id.to_string(),
+    }
+}
@@ -0,0 +1,21 @@
+use crate::models::propbook::registry::OracleSourceRegistered;
+use deepbook_schema::models::PropbookOracleSourceRegistered;
+
+define_handler! {
+    name: PropbookOracleSourceRegisteredHandler,
+    processor_name: "propbook_oracle_source_registered",
+    event_type: OracleSourceRegistered,
+    db_model: PropbookOracleSourceRegistered,
+    table: propbook_oracle_source_registered,
+    map_event: |event, meta| PropbookOracleSourceRegistered {
+        event_digest: meta.event_digest(),
+        digest: meta.digest(),
+        sender: meta.sender(),
+        checkpoint: meta.checkpoint(),
+        checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+        package: meta.package(),
+        oracle_kind: event.oracle_kind as i16,
+        source_id: event.source_id as i64,
+        propbook_oracle_id: event.propbook_oracle_id.to_string(),
+    }
+}
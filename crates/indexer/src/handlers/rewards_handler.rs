@@ -0,0 +1,376 @@
+use crate::event_index::{EventKind, EventRouter, Subscriber};
+use crate::events::DeepbookEvent;
+use crate::models::deepbook::pool::{ReferralClaimed, ReferralFeeEvent};
+use crate::models::deepbook::state::{RebateEvent, RebateEventV2};
+use crate::traits::MoveStruct;
+use async_trait::async_trait;
+use deepbook_schema::models::{RewardAccountKind, RewardLedgerEntry};
+use deepbook_schema::schema::reward_ledger;
+use diesel::upsert::excluded;
+use diesel::ExpressionMethods;
+use diesel_async::RunQueryDsl;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use sui_indexer_alt_framework::pipeline::sequential::Handler;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::postgres::{Connection, Db};
+use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
+use tracing::debug;
+
+/// Rows per insert statement, well below Postgres' bind parameter limit
+const INSERT_CHUNK_SIZE: usize = 1000;
+
+/// Event types that earn or claim rewards
+const REWARD_KINDS: [EventKind; 4] = [
+    RebateEvent::KIND,
+    RebateEventV2::KIND,
+    ReferralFeeEvent::KIND,
+    ReferralClaimed::KIND,
+];
+
+/// Maintains `reward_ledger`, what each balance manager and referral object has earned and
+/// claimed in each pool:
+///
+/// - referral fees earned by a referral object on its referred trades add to its earnings, and
+///   the owner's claims add to what it has claimed;
+/// - trading rebates are only reported when a balance manager claims them, so each claim adds to
+///   both its earnings and its claims, and nothing is ever shown as owed.
+///
+/// Runs as a sequential pipeline, since every event is added to the totals left by the previous
+/// ones and must be applied exactly once. Only events after the pipeline's first checkpoint are
+/// counted, so it needs to index from the first core package checkpoint for the totals to be
+/// complete.
+pub struct RewardsHandler {
+    router: Arc<EventRouter>,
+}
+
+impl RewardsHandler {
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
+    }
+}
+
+impl Subscriber for RewardsHandler {
+    const KINDS: &'static [EventKind] = &REWARD_KINDS;
+}
+
+/// Amounts of a pool's base and quote assets and DEEP.
+#[derive(Debug, Clone, Copy, Default)]
+struct Amounts {
+    base: i64,
+    quote: i64,
+    deep: i64,
+}
+
+/// Rewards earned or claimed by one account in one pool, produced in checkpoint order.
+#[derive(Debug, Clone)]
+pub struct RewardChange {
+    account_kind: RewardAccountKind,
+    account_id: String,
+    pool_id: String,
+    earned: Amounts,
+    claimed: Amounts,
+    checkpoint: i64,
+    timestamp_ms: i64,
+}
+
+/// The net change to each ledger entry touched by a batch, so each entry is written once per
+/// commit.
+#[derive(Default)]
+pub struct RewardsBatch {
+    entries: BTreeMap<(RewardAccountKind, String, String), RewardLedgerEntry>,
+}
+
+#[async_trait]
+impl Processor for RewardsHandler {
+    const NAME: &'static str = "rewards";
+    type Value = RewardChange;
+
+    async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
+        let mut results = vec![];
+
+        for routed_event in routed.of_kinds(&REWARD_KINDS) {
+            let meta = &routed_event.meta;
+            let Some(event) = routed_event.decoded_for(Self::NAME)? else {
+                continue;
+            };
+            let (account_kind, account_id, pool_id, earned, claimed) = match event {
+                DeepbookEvent::RebateEvent(event) => {
+                    let amounts = Amounts {
+                        deep: event.claim_amount as i64,
+                        ..Default::default()
+                    };
+                    (
+                        RewardAccountKind::BalanceManager,
+                        event.balance_manager_id,
+                        event.pool_id,
+                        amounts,
+                        amounts,
+                    )
+                }
+                DeepbookEvent::RebateEventV2(event) => {
+                    let amounts = Amounts {
+                        base: event.claim_amount.base as i64,
+                        quote: event.claim_amount.quote as i64,
+                        deep: event.claim_amount.deep as i64,
+                    };
+                    (
+                        RewardAccountKind::BalanceManager,
+                        event.balance_manager_id,
+                        event.pool_id,
+                        amounts,
+                        amounts,
+                    )
+                }
+                DeepbookEvent::ReferralFeeEvent(event) => (
+                    RewardAccountKind::Referral,
+                    event.referral_id,
+                    event.pool_id,
+                    Amounts {
+                        base: event.base_fee as i64,
+                        quote: event.quote_fee as i64,
+                        deep: event.deep_fee as i64,
+                    },
+                    Amounts::default(),
+                ),
+                DeepbookEvent::ReferralClaimed(event) => (
+                    RewardAccountKind::Referral,
+                    event.referral_id,
+                    event.pool_id,
+                    Amounts::default(),
+                    Amounts {
+                        base: event.base_amount as i64,
+                        quote: event.quote_amount as i64,
+                        deep: event.deep_amount as i64,
+                    },
+                ),
+                _ => continue,
+            };
+            let change = RewardChange {
+                account_kind,
+                account_id: account_id.to_string(),
+                pool_id: pool_id.to_string(),
+                earned,
+                claimed,
+                checkpoint: meta.checkpoint(),
+                timestamp_ms: meta.checkpoint_timestamp_ms(),
+            };
+            debug!("Observed reward change {:?}", change);
+            results.push(change);
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl Handler for RewardsHandler {
+    type Store = Db;
+    type Batch = RewardsBatch;
+
+    fn batch(&self, batch: &mut Self::Batch, values: std::vec::IntoIter<Self::Value>) {
+        for change in values {
+            let RewardChange {
+                account_kind,
+                account_id,
+                pool_id,
+                earned,
+                claimed,
+                checkpoint,
+                timestamp_ms,
+            } = change;
+            batch
+                .entries
+                .entry((account_kind, account_id.clone(), pool_id.clone()))
+                .and_modify(|entry| {
+                    entry.earned_base += earned.base;
+                    entry.earned_quote += earned.quote;
+                    entry.earned_deep += earned.deep;
+                    entry.claimed_base += claimed.base;
+                    entry.claimed_quote += claimed.quote;
+                    entry.claimed_deep += claimed.deep;
+                    entry.last_updated_at = timestamp_ms;
+                    entry.checkpoint = checkpoint;
+                })
+                .or_insert(RewardLedgerEntry {
+                    account_kind: account_kind.as_ref().to_string(),
+                    account_id,
+                    pool_id,
+                    earned_base: earned.base,
+                    earned_quote: earned.quote,
+                    earned_deep: earned.deep,
+                    claimed_base: claimed.base,
+                    claimed_quote: claimed.quote,
+                    claimed_deep: claimed.deep,
+                    last_updated_at: timestamp_ms,
+                    checkpoint,
+                });
+        }
+    }
+
+    async fn commit<'a>(
+        &self,
+        batch: &Self::Batch,
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize> {
+        let rows: Vec<RewardLedgerEntry> = batch.entries.values().cloned().collect();
+        let mut changed = 0;
+
+        // The batch holds deltas, so existing entries are added to rather than replaced.
+        for chunk in rows.chunks(INSERT_CHUNK_SIZE) {
+            changed += diesel::insert_into(reward_ledger::table)
+                .values(chunk)
+                .on_conflict((
+                    reward_ledger::account_kind,
+                    reward_ledger::account_id,
+                    reward_ledger::pool_id,
+                ))
+                .do_update()
+                .set((
+                    reward_ledger::earned_base
+                        .eq(reward_ledger::earned_base + excluded(reward_ledger::earned_base)),
+                    reward_ledger::earned_quote
+                        .eq(reward_ledger::earned_quote + excluded(reward_ledger::earned_quote)),
+                    reward_ledger::earned_deep
+                        .eq(reward_ledger::earned_deep + excluded(reward_ledger::earned_deep)),
+                    reward_ledger::claimed_base
+                        .eq(reward_ledger::claimed_base + excluded(reward_ledger::claimed_base)),
+                    reward_ledger::claimed_quote
+                        .eq(reward_ledger::claimed_quote + excluded(reward_ledger::claimed_quote)),
+                    reward_ledger::claimed_deep
+                        .eq(reward_ledger::claimed_deep + excluded(reward_ledger::claimed_deep)),
+                    reward_ledger::last_updated_at.eq(excluded(reward_ledger::last_updated_at)),
+                    reward_ledger::checkpoint.eq(excluded(reward_ledger::checkpoint)),
+                ))
+                .execute(conn)
+                .await?;
+        }
+        Ok(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeepbookEnv;
+    use deepbook_schema::MIGRATIONS;
+    use diesel::{QueryDsl, SelectableHelper};
+    use sui_pg_db::temp::TempDb;
+    use sui_pg_db::DbArgs;
+
+    const REFERRAL: &str = "0xa1";
+    const MANAGER: &str = "0xb1";
+    const POOL: &str = "0xc1";
+
+    fn handler() -> RewardsHandler {
+        RewardsHandler::new(Arc::new(EventRouter::new(DeepbookEnv::Testnet)))
+    }
+
+    fn referral_fee(checkpoint: i64, base: i64) -> RewardChange {
+        RewardChange {
+            account_kind: RewardAccountKind::Referral,
+            account_id: REFERRAL.to_string(),
+            pool_id: POOL.to_string(),
+            earned: Amounts {
+                base,
+                quote: 2 * base,
+                deep: 0,
+            },
+            claimed: Amounts::default(),
+            checkpoint,
+            timestamp_ms: checkpoint * 1000,
+        }
+    }
+
+    fn referral_claim(checkpoint: i64, base: i64) -> RewardChange {
+        RewardChange {
+            earned: Amounts::default(),
+            claimed: Amounts {
+                base,
+                ..Default::default()
+            },
+            ..referral_fee(checkpoint, 0)
+        }
+    }
+
+    fn rebate(checkpoint: i64, deep: i64) -> RewardChange {
+        let amounts = Amounts {
+            deep,
+            ..Default::default()
+        };
+        RewardChange {
+            account_kind: RewardAccountKind::BalanceManager,
+            account_id: MANAGER.to_string(),
+            pool_id: POOL.to_string(),
+            earned: amounts,
+            claimed: amounts,
+            checkpoint,
+            timestamp_ms: checkpoint * 1000,
+        }
+    }
+
+    fn batch_of(changes: Vec<RewardChange>) -> RewardsBatch {
+        let mut batch = RewardsBatch::default();
+        handler().batch(&mut batch, changes.into_iter());
+        batch
+    }
+
+    #[test]
+    fn batch_nets_changes_per_account_and_pool() {
+        let batch = batch_of(vec![
+            referral_fee(1, 100),
+            rebate(1, 7),
+            referral_claim(2, 60),
+            referral_fee(3, 10),
+        ]);
+
+        assert_eq!(batch.entries.len(), 2);
+        let referral = &batch.entries[&(
+            RewardAccountKind::Referral,
+            REFERRAL.to_string(),
+            POOL.to_string(),
+        )];
+        assert_eq!(referral.account_kind, "referral");
+        assert_eq!(referral.earned_base, 110);
+        assert_eq!(referral.earned_quote, 220);
+        assert_eq!(referral.claimed_base, 60);
+        assert_eq!(referral.checkpoint, 3);
+        assert_eq!(referral.last_updated_at, 3000);
+
+        let manager = &batch.entries[&(
+            RewardAccountKind::BalanceManager,
+            MANAGER.to_string(),
+            POOL.to_string(),
+        )];
+        assert_eq!(manager.account_kind, "balance_manager");
+        assert_eq!((manager.earned_deep, manager.claimed_deep), (7, 7));
+    }
+
+    #[tokio::test]
+    async fn commit_adds_to_existing_entries() {
+        let temp_db = TempDb::new().expect("postgres binaries must be on PATH");
+        let url = temp_db.database().url().clone();
+        let db = Db::for_write(url, DbArgs::default()).await.unwrap();
+        db.run_migrations(Some(&MIGRATIONS)).await.unwrap();
+        let mut conn = db.connect().await.unwrap();
+
+        let batch = batch_of(vec![referral_fee(1, 100), rebate(1, 7)]);
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 2);
+        let batch = batch_of(vec![referral_claim(2, 60), referral_fee(3, 10)]);
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 1);
+
+        let entries: Vec<RewardLedgerEntry> = reward_ledger::table
+            .select(RewardLedgerEntry::as_select())
+            .order_by(reward_ledger::account_kind)
+            .load(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].earned_deep, 7);
+        let referral = &entries[1];
+        assert_eq!(referral.earned_base, 110);
+        assert_eq!(referral.earned_quote, 220);
+        assert_eq!(referral.claimed_base, 60);
+        assert_eq!(referral.checkpoint, 3);
+    }
+}
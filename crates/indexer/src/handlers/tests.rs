@@ -0,0 +1,428 @@
+//! Decoding and row mapping of events that have no checkpoint fixtures yet. Each event is
+//! serialized to BCS, decoded the way the routing stage decodes it, and mapped by its handler.
+
+use super::account_app_authorized_handler::AccountAppAuthorizedHandler;
+use super::account_deposited_handler::AccountDepositedHandler;
+use super::account_session_authorized_handler::AccountSessionAuthorizedHandler;
+use super::deepbook_core_account_initialized_handler::DeepbookCoreAccountInitializedHandler;
+use super::predict_builder_code_set_handler::PredictBuilderCodeSetHandler;
+use super::predict_market_settled_handler::PredictMarketSettledHandler;
+use super::predict_order_liquidated_handler::PredictOrderLiquidatedHandler;
+use super::predict_supply_filled_handler::PredictSupplyFilledHandler;
+use super::propbook_block_scholes_stores_registered_handler::PropbookBlockScholesStoresRegisteredHandler;
+use super::propbook_oracle_bound_handler::PropbookOracleBoundHandler;
+use super::propbook_oracle_rebound_handler::PropbookOracleReboundHandler;
+use super::EventMeta;
+use crate::event_index::{EventKind, EventRouter};
+use crate::events::{DeepbookEvent, FromDeepbookEvent};
+use crate::models::account::account_events::{AppAuthorized, Deposited};
+use crate::models::deepbook_core_account::account_data::DeepbookCoreAccountInitialized;
+use crate::models::deepbook_predict::builder_code_events::BuilderCodeSet;
+use crate::models::deepbook_predict::config_events::MarketSettled;
+use crate::models::deepbook_predict::order_events::OrderLiquidated;
+use crate::models::deepbook_predict::vault_events::SupplyFilled;
+use crate::models::deepbook_sessions::sessions::SessionAuthorized;
+use crate::models::propbook::oracle_lane::{ObservationRecorded, OracleRead};
+use crate::models::propbook::pyth_feed::RawSpot;
+use crate::models::propbook::registry::{BlockScholesStoresRegistered, OracleBound, OracleRebound};
+use crate::traits::MoveStruct;
+use crate::{get_package_addresses, DeepbookEnv};
+use deepbook_package_registry::PackageFamily;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::u256::U256;
+use std::str::FromStr;
+use std::sync::Arc;
+use sui_sdk_types::Address;
+use sui_types::base_types::ObjectID;
+
+pub(crate) fn meta() -> EventMeta {
+    EventMeta::new("digest", "0x5e", 42, 1_700_000_000_000, "0xfeed", 3)
+}
+
+pub(crate) fn object_id(byte: u8) -> ObjectID {
+    ObjectID::new([byte; 32])
+}
+
+fn address(byte: u8) -> Address {
+    Address::new([byte; 32])
+}
+
+/// `T`'s type as published by the first testnet version of `family`
+pub(crate) fn event_type<T: MoveStruct>(
+    family: PackageFamily,
+    type_params: Vec<TypeTag>,
+) -> StructTag {
+    let package = get_package_addresses(DeepbookEnv::Testnet, family)
+        .into_iter()
+        .next()
+        .expect("family is deployed on testnet");
+    StructTag {
+        address: AccountAddress::from_str(&package).unwrap(),
+        module: Identifier::new(T::MODULE).unwrap(),
+        name: Identifier::new(T::NAME).unwrap(),
+        type_params,
+    }
+}
+
+/// `event` after a round trip through BCS and the routing stage's decoder
+pub(crate) fn decode<T: FromDeepbookEvent + Clone>(event: &T, family: PackageFamily) -> T {
+    let contents = bcs::to_bytes(event).unwrap();
+    let decoded = DeepbookEvent::decode(T::KIND, &event_type::<T>(family, vec![]), &contents)
+        .unwrap()
+        .expect("event type is supported");
+    T::from_event(&decoded).expect("decoded as T").clone()
+}
+
+/// Contents encoded from the Move definitions of each new event type; see the file's header.
+const EVENT_FIXTURES: &str = include_str!("fixtures/events.tsv");
+
+/// `BsRead<value>` or `OracleRead<value>`, the observation of a generic event of `module`
+fn observation_type(module: &str, value: &str) -> TypeTag {
+    let tag = |module: &str, name: &str, type_params| {
+        TypeTag::Struct(Box::new(StructTag {
+            address: AccountAddress::ZERO,
+            module: Identifier::new(module).unwrap(),
+            name: Identifier::new(name).unwrap(),
+            type_params,
+        }))
+    };
+    let value = match value {
+        "u128" => TypeTag::U128,
+        "SVIParams" => tag("block_scholes_store", value, vec![]),
+        _ => tag("pyth_feed", value, vec![]),
+    };
+    let read = if module == "block_scholes_store" {
+        "BsRead"
+    } else {
+        "OracleRead"
+    };
+    tag(module, read, vec![value])
+}
+
+#[test]
+fn event_fixtures_decode() {
+    let fixtures = EVENT_FIXTURES
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'));
+    for line in fixtures {
+        let mut columns = line.split('\t');
+        let (Some(event), Some(contents)) = (columns.next(), columns.next()) else {
+            panic!("malformed fixture: {line}");
+        };
+        let (path, payload) = match event.split_once('<') {
+            Some((path, payload)) => (path, payload.strip_suffix('>')),
+            None => (event, None),
+        };
+        let (module, name) = path.split_once("::").expect("fixture names a module");
+        let event_type = StructTag {
+            address: AccountAddress::ZERO,
+            module: Identifier::new(module).unwrap(),
+            name: Identifier::new(name).unwrap(),
+            type_params: payload
+                .map(|value| vec![observation_type(module, value)])
+                .unwrap_or_default(),
+        };
+
+        let decoded = DeepbookEvent::decode(
+            EventKind { module, name },
+            &event_type,
+            &hex::decode(contents).unwrap(),
+        )
+        .unwrap_or_else(|e| panic!("{event} does not decode: {e}"))
+        .unwrap_or_else(|| panic!("{event} is not indexed"));
+        assert_eq!(decoded.name(), name);
+
+        let debug = format!("{decoded:?}");
+        let checks = columns.next().unwrap_or_default().split("; ");
+        for check in checks.filter(|check| !check.is_empty()) {
+            assert!(debug.contains(check), "{event} lacks `{check}`: {debug}");
+        }
+    }
+}
+
+#[test]
+fn events_are_classified_by_package_family() {
+    let router = Arc::new(EventRouter::new(DeepbookEnv::Testnet));
+    router.subscribe(PropbookOracleBoundHandler::new);
+    let index = router.index();
+
+    let propbook = event_type::<OracleBound>(PackageFamily::Propbook, vec![]);
+    assert_eq!(index.kind_of(&propbook), Some(OracleBound::KIND));
+    // `registry` is a propbook module; the same type name from another family's package is not
+    // a propbook event
+    let core = event_type::<OracleBound>(PackageFamily::Core, vec![]);
+    assert_eq!(index.kind_of(&core), None);
+}
+
+#[test]
+fn predict_market_settled() {
+    let event = decode(
+        &MarketSettled {
+            expiry_market_id: object_id(1),
+            propbook_underlying_id: 7,
+            expiry: 1_700_000_600_000,
+            settlement_price: 65_000_000_000,
+            settled_at_ms: 1_700_000_601_000,
+        },
+        PackageFamily::Predict,
+    );
+    let row = PredictMarketSettledHandler::map_event(&event, &meta());
+    assert_eq!(row.event_digest, "digest3");
+    assert_eq!(row.checkpoint, 42);
+    assert_eq!(row.expiry_market_id, object_id(1).to_string());
+    assert_eq!(row.propbook_underlying_id, 7);
+    assert_eq!(row.settlement_price, 65_000_000_000);
+    assert_eq!(row.settled_at_ms, 1_700_000_601_000);
+}
+
+#[test]
+fn predict_order_liquidated() {
+    let event = decode(
+        &OrderLiquidated {
+            expiry_market_id: object_id(1),
+            order_id: U256::from(9u64),
+            quantity: 100,
+            gross_value: 80,
+            floor_amount: 20,
+            liquidation_ltv: 900_000_000,
+            liquidated_at_ms: 1_700_000_000_500,
+            pyth_spot_source_timestamp_ms: 1,
+            block_scholes_spot_source_timestamp_ms: 2,
+            block_scholes_forward_source_timestamp_ms: 3,
+            block_scholes_svi_source_timestamp_ms: 4,
+        },
+        PackageFamily::Predict,
+    );
+    let row = PredictOrderLiquidatedHandler::map_event(&event, &meta());
+    assert_eq!(row.order_id, "9");
+    assert_eq!(row.quantity, 100);
+    assert_eq!(row.floor_amount, 20);
+    assert_eq!(row.block_scholes_svi_source_timestamp_ms, 4);
+}
+
+#[test]
+fn predict_supply_filled() {
+    let event = decode(
+        &SupplyFilled {
+            pool_vault_id: object_id(2),
+            account_id: object_id(3),
+            recipient: address(4),
+            index: 11,
+            dusdc_amount: 1_000_000,
+            shares_minted: 990_000,
+            fee_dusdc: 10_000,
+            dusdc_remaining: 0,
+            requests_pending_after: 5,
+        },
+        PackageFamily::Predict,
+    );
+    let row = PredictSupplyFilledHandler::map_event(&event, &meta());
+    assert_eq!(row.recipient, address(4).to_string());
+    assert_eq!(row.request_index, 11);
+    assert_eq!(row.shares_minted, 990_000);
+    assert_eq!(row.requests_pending_after, 5);
+}
+
+#[test]
+fn predict_builder_code_set_and_cleared() {
+    let set = decode(
+        &BuilderCodeSet {
+            account_id: object_id(3),
+            owner: address(4),
+            builder_code_id: Some(object_id(5)),
+        },
+        PackageFamily::Predict,
+    );
+    let row = PredictBuilderCodeSetHandler::map_event(&set, &meta());
+    assert_eq!(row.builder_code_id, Some(object_id(5).to_string()));
+
+    let cleared = decode(
+        &BuilderCodeSet {
+            builder_code_id: None,
+            ..set
+        },
+        PackageFamily::Predict,
+    );
+    let row = PredictBuilderCodeSetHandler::map_event(&cleared, &meta());
+    assert_eq!(row.builder_code_id, None);
+}
+
+#[test]
+fn propbook_oracle_bound() {
+    let event = decode(
+        &OracleBound {
+            propbook_underlying_id: 7,
+            oracle_kind: 1,
+            source_id: 12,
+            propbook_oracle_id: object_id(6),
+            value_kind: 2,
+        },
+        PackageFamily::Propbook,
+    );
+    let row = PropbookOracleBoundHandler::map_event(&event, &meta());
+    assert_eq!(row.propbook_underlying_id, 7);
+    assert_eq!(row.oracle_kind, 1);
+    assert_eq!(row.source_id, 12);
+    assert_eq!(row.propbook_oracle_id, object_id(6).to_string());
+    assert_eq!(row.value_kind, 2);
+}
+
+#[test]
+fn propbook_oracle_rebound() {
+    let event = decode(
+        &OracleRebound {
+            propbook_underlying_id: 7,
+            oracle_kind: 1,
+            value_kind: 2,
+            old_source_id: 12,
+            old_propbook_oracle_id: object_id(6),
+            new_source_id: 13,
+            new_propbook_oracle_id: object_id(8),
+        },
+        PackageFamily::Propbook,
+    );
+    let row = PropbookOracleReboundHandler::map_event(&event, &meta());
+    assert_eq!(row.old_source_id, 12);
+    assert_eq!(row.old_propbook_oracle_id, object_id(6).to_string());
+    assert_eq!(row.new_source_id, 13);
+    assert_eq!(row.new_propbook_oracle_id, object_id(8).to_string());
+}
+
+#[test]
+fn propbook_block_scholes_stores_registered() {
+    let event = decode(
+        &BlockScholesStoresRegistered {
+            propbook_underlying_id: 7,
+            value_store_id: object_id(9),
+            svi_store_id: object_id(10),
+            block_scholes_base_asset: "BTC".to_string(),
+        },
+        PackageFamily::Propbook,
+    );
+    let row = PropbookBlockScholesStoresRegisteredHandler::map_event(&event, &meta());
+    assert_eq!(row.value_store_id, object_id(9).to_string());
+    assert_eq!(row.svi_store_id, object_id(10).to_string());
+    assert_eq!(row.block_scholes_base_asset, "BTC");
+}
+
+#[test]
+fn propbook_oracle_observations_decode_by_payload() {
+    let observation = ObservationRecorded {
+        propbook_oracle_id: object_id(6),
+        observation: OracleRead {
+            source_timestamp_ms: 1_700_000_000_000,
+            update_timestamp_ms: 1_700_000_000_100,
+            writer_digest: vec![0; 32],
+            value: RawSpot {
+                pyth_source_id: 12,
+                price_magnitude: 6_500_000,
+                price_is_negative: false,
+                exponent_magnitude: 2,
+                exponent_is_negative: true,
+                feed_update_timestamp_us: 1_700_000_000_000_000,
+            },
+        },
+    };
+    let contents = bcs::to_bytes(&observation).unwrap();
+    // `ObservationRecorded<OracleRead<V>>`, with `V` named `value` in `pyth_feed`
+    let lane_type = |value: &str| {
+        let event =
+            event_type::<ObservationRecorded<OracleRead<RawSpot>>>(PackageFamily::Propbook, vec![]);
+        let value = StructTag {
+            module: Identifier::new("pyth_feed").unwrap(),
+            name: Identifier::new(value).unwrap(),
+            ..event.clone()
+        };
+        let read = StructTag {
+            name: Identifier::new("OracleRead").unwrap(),
+            type_params: vec![TypeTag::Struct(Box::new(value))],
+            ..event.clone()
+        };
+        StructTag {
+            type_params: vec![TypeTag::Struct(Box::new(read))],
+            ..event
+        }
+    };
+    let kind = ObservationRecorded::<OracleRead<RawSpot>>::KIND;
+
+    let decoded = DeepbookEvent::decode(kind, &lane_type("RawSpot"), &contents)
+        .unwrap()
+        .expect("Pyth spot lanes are indexed");
+    let DeepbookEvent::OracleObservationRecorded(decoded) = decoded else {
+        panic!("decoded as {}", decoded.name());
+    };
+    assert_eq!(decoded.observation.value.price_magnitude, 6_500_000);
+    assert!(decoded.observation.value.exponent_is_negative);
+
+    // Other lane payloads are a known kind the indexer does not handle
+    assert!(
+        DeepbookEvent::decode(kind, &lane_type("RawForward"), &contents)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn account_deposited() {
+    let event = decode(
+        &Deposited {
+            account_id: object_id(3),
+            coin_type: "0x2::sui::SUI".to_string(),
+            amount: 500,
+            new_balance: 1_500,
+        },
+        PackageFamily::Account,
+    );
+    let row = AccountDepositedHandler::map_event(&event, &meta());
+    assert_eq!(row.account_id, object_id(3).to_string());
+    assert_eq!(row.coin_type, "0x2::sui::SUI");
+    assert_eq!(row.amount, 500);
+    assert_eq!(row.new_balance, 1_500);
+}
+
+#[test]
+fn account_app_authorized() {
+    let event = decode(
+        &AppAuthorized {
+            app: "0x1::predict::App".to_string(),
+        },
+        PackageFamily::Account,
+    );
+    let row = AccountAppAuthorizedHandler::map_event(&event, &meta());
+    assert_eq!(row.app, "0x1::predict::App");
+    assert_eq!(row.sender, "0x5e");
+}
+
+#[test]
+fn account_session_authorized() {
+    let event = decode(
+        &SessionAuthorized {
+            account_id: object_id(3),
+            session: address(11),
+            expires_at_ms: 1_700_003_600_000,
+        },
+        PackageFamily::Account,
+    );
+    let row = AccountSessionAuthorizedHandler::map_event(&event, &meta());
+    assert_eq!(row.session, address(11).to_string());
+    assert_eq!(row.expires_at_ms, 1_700_003_600_000);
+}
+
+#[test]
+fn deepbook_core_account_initialized() {
+    let event = decode(
+        &DeepbookCoreAccountInitialized {
+            account_id: object_id(3),
+            account_owner: address(4),
+            wrapper_id: object_id(12),
+            balance_manager_id: object_id(13),
+        },
+        PackageFamily::Account,
+    );
+    let row = DeepbookCoreAccountInitializedHandler::map_event(&event, &meta());
+    assert_eq!(row.account_owner, address(4).to_string());
+    assert_eq!(row.wrapper_id, object_id(12).to_string());
+    assert_eq!(row.balance_manager_id, object_id(13).to_string());
+}
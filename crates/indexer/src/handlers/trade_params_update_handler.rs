@@ -1,7 +1,7 @@
-use crate::handlers::{is_deepbook_tx, try_extract_move_call_package};
+use crate::event_index::{EventKind, EventRouter, Subscriber};
+use crate::events::FromDeepbookEvent;
 use crate::models::deepbook::governance::TradeParamsUpdateEvent;
 use crate::traits::MoveStruct;
-use crate::DeepbookEnv;
 use async_trait::async_trait;
 use deepbook_schema::models::TradeParamsUpdate;
 use deepbook_schema::schema::trade_params_update;
@@ -11,70 +11,75 @@ use sui_indexer_alt_framework::pipeline::Processor;
 use sui_indexer_alt_framework::postgres::handler::Handler;
 use sui_indexer_alt_framework::postgres::Connection;
 use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
-use sui_types::transaction::TransactionDataAPI;
 use tracing::debug;
 
 pub struct TradeParamsUpdateHandler {
-    env: DeepbookEnv,
+    router: Arc<EventRouter>,
 }
 
 impl TradeParamsUpdateHandler {
-    pub fn new(env: DeepbookEnv) -> Self {
-        Self { env }
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
     }
 }
 
+impl Subscriber for TradeParamsUpdateHandler {
+    const KINDS: &'static [EventKind] = &[TradeParamsUpdateEvent::KIND];
+}
+
 #[async_trait]
 impl Processor for TradeParamsUpdateHandler {
     const NAME: &'static str = "trade_params_update";
     type Value = TradeParamsUpdate;
 
     async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
         let mut results = vec![];
-        for tx in &checkpoint.transactions {
-            if !is_deepbook_tx(tx, &checkpoint.object_set, self.env) {
-                continue;
-            }
-            let Some(events) = &tx.events else {
-                continue;
-            };
 
-            let package = try_extract_move_call_package(tx).unwrap_or_default();
-            let checkpoint_timestamp_ms = checkpoint.summary.timestamp_ms as i64;
-            let checkpoint_seq = checkpoint.summary.sequence_number as i64;
-            let digest = tx.transaction.digest();
+        // Get package addresses for deepbook
+        let deepbook_addresses = self.router.env().package_addresses();
+        let mut tx_pool_id: Option<(usize, String)> = None;
 
-            // Get package addresses for deepbook
-            let deepbook_addresses = self.env.package_addresses();
-
-            let pool = tx
-                .input_objects(&checkpoint.object_set)
-                .find(|o| matches!(o.data.struct_tag(), Some(struct_tag)
-                        if deepbook_addresses.iter().any(|addr| struct_tag.address == *addr) && struct_tag.name.as_str() == "Pool"));
-            let pool_id = pool
-                .map(|o| o.id().to_hex_uncompressed())
-                .unwrap_or("0x0".to_string());
+        for routed_event in routed.of_kind(TradeParamsUpdateEvent::KIND) {
+            let Some(event) = routed_event
+                .decoded_for(Self::NAME)?
+                .and_then(TradeParamsUpdateEvent::from_event)
+            else {
+                continue;
+            };
+            let meta = &routed_event.meta;
 
-            for (index, ev) in events.data.iter().enumerate() {
-                if !TradeParamsUpdateEvent::matches_event_type(&ev.type_, self.env) {
-                    continue;
-                }
-                let event: TradeParamsUpdateEvent = bcs::from_bytes(&ev.contents)?;
-                let data = TradeParamsUpdate {
-                    digest: digest.to_string(),
-                    event_digest: format!("{digest}{index}"),
-                    sender: tx.transaction.sender().to_string(),
-                    checkpoint: checkpoint_seq,
-                    checkpoint_timestamp_ms,
-                    package: package.clone(),
-                    pool_id: pool_id.clone(),
-                    taker_fee: event.taker_fee as i64,
-                    maker_fee: event.maker_fee as i64,
-                    stake_required: event.stake_required as i64,
-                };
-                debug!("Observed Deepbook Trade Params Update Event {:?}", data);
-                results.push(data);
+            // The pool is an input of the transaction, shared by all its events
+            if tx_pool_id
+                .as_ref()
+                .is_none_or(|(i, _)| *i != routed_event.tx_index)
+            {
+                let tx = &checkpoint.transactions[routed_event.tx_index];
+                let pool = tx
+                    .input_objects(&checkpoint.object_set)
+                    .find(|o| matches!(o.data.struct_tag(), Some(struct_tag)
+                            if deepbook_addresses.iter().any(|addr| struct_tag.address == *addr) && struct_tag.name.as_str() == "Pool"));
+                let pool_id = pool
+                    .map(|o| o.id().to_hex_uncompressed())
+                    .unwrap_or("0x0".to_string());
+                tx_pool_id = Some((routed_event.tx_index, pool_id));
             }
+            let (_, pool_id) = tx_pool_id.as_ref().expect("set above");
+
+            let data = TradeParamsUpdate {
+                digest: meta.digest(),
+                event_digest: meta.event_digest(),
+                sender: meta.sender(),
+                checkpoint: meta.checkpoint(),
+                checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+                package: meta.package(),
+                pool_id: pool_id.clone(),
+                taker_fee: event.taker_fee as i64,
+                maker_fee: event.maker_fee as i64,
+                stake_required: event.stake_required as i64,
+            };
+            debug!("Observed Deepbook Trade Params Update Event {:?}", data);
+            results.push(data);
         }
         Ok(results)
     }
@@ -0,0 +1,408 @@
+use crate::event_index::{EventKind, EventRouter, Subscriber};
+use crate::events::DeepbookEvent;
+use crate::models::deepbook::order_info::OrderFilled;
+use crate::traits::MoveStruct;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use diesel::sql_types::{Array, BigInt, Numeric, Text};
+use diesel_async::RunQueryDsl;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use sui_indexer_alt_framework::pipeline::sequential::Handler;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::postgres::{Connection, Db};
+use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
+use tracing::debug;
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+const DAY_MS: i64 = 24 * HOUR_MS;
+
+/// Rows per insert statement, so each statement's arrays stay a manageable size
+const INSERT_CHUNK_SIZE: usize = 1000;
+
+/// Maintains `trader_volume_1h` and `trader_volume_1d`: each balance manager's maker and taker
+/// volume, trade count and fees per pool, from order fills.
+///
+/// Runs as a sequential pipeline, since each batch is added to the totals already in the tables
+/// and must be applied exactly once. A batch's hourly totals are rolled up into its daily totals
+/// before both are written, in the same transaction that advances the pipeline's watermark.
+///
+/// Only fills after the pipeline's first checkpoint are counted, so it needs to index from the
+/// first core package checkpoint for the totals to cover the whole of `order_fills`.
+pub struct TraderVolumeHandler {
+    router: Arc<EventRouter>,
+}
+
+impl TraderVolumeHandler {
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
+    }
+}
+
+impl Subscriber for TraderVolumeHandler {
+    const KINDS: &'static [EventKind] = &[OrderFilled::KIND];
+}
+
+/// One fill, in on-chain units.
+#[derive(Debug, Clone)]
+pub struct TraderFill {
+    pool_id: String,
+    timestamp_ms: i64,
+    maker_balance_manager_id: String,
+    taker_balance_manager_id: String,
+    taker_is_bid: bool,
+    base_quantity: u64,
+    quote_quantity: u64,
+    maker_fee: u64,
+    maker_fee_is_deep: bool,
+    taker_fee: u64,
+    taker_fee_is_deep: bool,
+}
+
+/// One balance manager's trading in a pool over a bucket. Volumes and fees are sums of u64
+/// amounts, so they're kept in i128 and stored as NUMERIC.
+#[derive(Debug, Default, Clone, Copy)]
+struct Activity {
+    maker_base_volume: i128,
+    maker_quote_volume: i128,
+    taker_base_volume: i128,
+    taker_quote_volume: i128,
+    maker_trade_count: i64,
+    taker_trade_count: i64,
+    deep_fees: i128,
+    base_fees: i128,
+    quote_fees: i128,
+}
+
+impl Activity {
+    /// Count one side of `fill`. A fee not paid in DEEP is paid in the input token of the side:
+    /// quote for a bid, base for an ask.
+    fn add_fill(&mut self, fill: &TraderFill, is_maker: bool) {
+        let (fee, fee_is_deep, is_bid) = if is_maker {
+            (fill.maker_fee, fill.maker_fee_is_deep, !fill.taker_is_bid)
+        } else {
+            (fill.taker_fee, fill.taker_fee_is_deep, fill.taker_is_bid)
+        };
+
+        let (base, quote, fee) = (
+            i128::from(fill.base_quantity),
+            i128::from(fill.quote_quantity),
+            i128::from(fee),
+        );
+        if is_maker {
+            self.maker_base_volume += base;
+            self.maker_quote_volume += quote;
+            self.maker_trade_count += 1;
+        } else {
+            self.taker_base_volume += base;
+            self.taker_quote_volume += quote;
+            self.taker_trade_count += 1;
+        }
+
+        if fee_is_deep {
+            self.deep_fees += fee;
+        } else if is_bid {
+            self.quote_fees += fee;
+        } else {
+            self.base_fees += fee;
+        }
+    }
+
+    fn merge(&mut self, other: &Activity) {
+        self.maker_base_volume += other.maker_base_volume;
+        self.maker_quote_volume += other.maker_quote_volume;
+        self.taker_base_volume += other.taker_base_volume;
+        self.taker_quote_volume += other.taker_quote_volume;
+        self.maker_trade_count += other.maker_trade_count;
+        self.taker_trade_count += other.taker_trade_count;
+        self.deep_fees += other.deep_fees;
+        self.base_fees += other.base_fees;
+        self.quote_fees += other.quote_fees;
+    }
+}
+
+/// Trading in a batch, keyed by pool, balance manager and the start of the hour.
+#[derive(Default)]
+pub struct TraderVolumeBatch {
+    hours: BTreeMap<(String, String, i64), Activity>,
+}
+
+#[async_trait]
+impl Processor for TraderVolumeHandler {
+    const NAME: &'static str = "trader_volume";
+    type Value = TraderFill;
+
+    async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
+        let mut results = vec![];
+
+        for routed_event in routed.of_kind(OrderFilled::KIND) {
+            let meta = &routed_event.meta;
+            let Some(DeepbookEvent::OrderFilled(event)) = routed_event.decoded_for(Self::NAME)?
+            else {
+                continue;
+            };
+            let fill = TraderFill {
+                pool_id: event.pool_id.to_string(),
+                timestamp_ms: meta.checkpoint_timestamp_ms(),
+                maker_balance_manager_id: event.maker_balance_manager_id.to_string(),
+                taker_balance_manager_id: event.taker_balance_manager_id.to_string(),
+                taker_is_bid: event.taker_is_bid,
+                base_quantity: event.base_quantity,
+                quote_quantity: event.quote_quantity,
+                maker_fee: event.maker_fee,
+                maker_fee_is_deep: event.maker_fee_is_deep,
+                taker_fee: event.taker_fee,
+                taker_fee_is_deep: event.taker_fee_is_deep,
+            };
+            debug!("Observed trader fill {:?}", fill);
+            results.push(fill);
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl Handler for TraderVolumeHandler {
+    type Store = Db;
+    type Batch = TraderVolumeBatch;
+
+    fn batch(&self, batch: &mut Self::Batch, values: std::vec::IntoIter<Self::Value>) {
+        for fill in values {
+            let hour = fill.timestamp_ms - fill.timestamp_ms.rem_euclid(HOUR_MS);
+            for (balance_manager_id, is_maker) in [
+                (&fill.maker_balance_manager_id, true),
+                (&fill.taker_balance_manager_id, false),
+            ] {
+                batch
+                    .hours
+                    .entry((fill.pool_id.clone(), balance_manager_id.clone(), hour))
+                    .or_default()
+                    .add_fill(&fill, is_maker);
+            }
+        }
+    }
+
+    async fn commit<'a>(
+        &self,
+        batch: &Self::Batch,
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize> {
+        let mut days: BTreeMap<(&String, &String, i64), Activity> = BTreeMap::new();
+        for ((pool_id, balance_manager_id, hour), activity) in &batch.hours {
+            let day = hour - hour.rem_euclid(DAY_MS);
+            days.entry((pool_id, balance_manager_id, day))
+                .or_default()
+                .merge(activity);
+        }
+
+        let hours: Vec<_> = batch
+            .hours
+            .iter()
+            .map(|((pool_id, balance_manager_id, hour), activity)| {
+                (pool_id, balance_manager_id, *hour, activity)
+            })
+            .collect();
+        let days: Vec<_> = days
+            .iter()
+            .map(|((pool_id, balance_manager_id, day), activity)| {
+                (*pool_id, *balance_manager_id, *day, activity)
+            })
+            .collect();
+
+        let mut changed = 0;
+        for chunk in hours.chunks(INSERT_CHUNK_SIZE) {
+            changed += add_activity(UPSERT_TRADER_VOLUME_1H, chunk, conn).await?;
+        }
+        for chunk in days.chunks(INSERT_CHUNK_SIZE) {
+            changed += add_activity(UPSERT_TRADER_VOLUME_1D, chunk, conn).await?;
+        }
+        Ok(changed)
+    }
+}
+
+/// Add `rows`, as `(pool_id, balance_manager_id, bucket start in ms, activity)`, to the table of
+/// `query`.
+async fn add_activity(
+    query: &str,
+    rows: &[(&String, &String, i64, &Activity)],
+    conn: &mut Connection<'_>,
+) -> anyhow::Result<usize> {
+    let count = |f: fn(&Activity) -> i64| rows.iter().map(|(.., a)| f(a)).collect::<Vec<_>>();
+    let amount = |f: fn(&Activity) -> i128| {
+        rows.iter()
+            .map(|(.., a)| BigDecimal::from(f(a)))
+            .collect::<Vec<_>>()
+    };
+    let pool_ids: Vec<&str> = rows.iter().map(|(pool_id, ..)| pool_id.as_str()).collect();
+    let balance_manager_ids: Vec<&str> = rows.iter().map(|(_, id, ..)| id.as_str()).collect();
+    let buckets: Vec<i64> = rows.iter().map(|(_, _, bucket, _)| *bucket).collect();
+
+    Ok(diesel::sql_query(query)
+        .bind::<Array<Text>, _>(pool_ids)
+        .bind::<Array<Text>, _>(balance_manager_ids)
+        .bind::<Array<BigInt>, _>(buckets)
+        .bind::<Array<Numeric>, _>(amount(|a| a.maker_base_volume))
+        .bind::<Array<Numeric>, _>(amount(|a| a.maker_quote_volume))
+        .bind::<Array<Numeric>, _>(amount(|a| a.taker_base_volume))
+        .bind::<Array<Numeric>, _>(amount(|a| a.taker_quote_volume))
+        .bind::<Array<BigInt>, _>(count(|a| a.maker_trade_count))
+        .bind::<Array<BigInt>, _>(count(|a| a.taker_trade_count))
+        .bind::<Array<Numeric>, _>(amount(|a| a.deep_fees))
+        .bind::<Array<Numeric>, _>(amount(|a| a.base_fees))
+        .bind::<Array<Numeric>, _>(amount(|a| a.quote_fees))
+        .execute(conn)
+        .await?)
+}
+
+/// Upsert into `$table` activity given as parallel arrays, adding to the existing totals.
+macro_rules! upsert_trader_volume {
+    ($table:literal) => {
+        concat!(
+            "INSERT INTO ",
+            $table,
+            " (
+                pool_id, balance_manager_id, bucket_start, maker_base_volume, maker_quote_volume,
+                taker_base_volume, taker_quote_volume, maker_trade_count, taker_trade_count,
+                deep_fees, base_fees, quote_fees
+            )
+            SELECT * FROM UNNEST($1::text[], $2::text[], $3::bigint[], $4::numeric[],
+                                 $5::numeric[], $6::numeric[], $7::numeric[], $8::bigint[],
+                                 $9::bigint[], $10::numeric[], $11::numeric[], $12::numeric[])
+            ON CONFLICT (pool_id, balance_manager_id, bucket_start) DO UPDATE SET
+                maker_base_volume = ",
+            $table,
+            ".maker_base_volume + EXCLUDED.maker_base_volume,
+                maker_quote_volume = ",
+            $table,
+            ".maker_quote_volume + EXCLUDED.maker_quote_volume,
+                taker_base_volume = ",
+            $table,
+            ".taker_base_volume + EXCLUDED.taker_base_volume,
+                taker_quote_volume = ",
+            $table,
+            ".taker_quote_volume + EXCLUDED.taker_quote_volume,
+                maker_trade_count = ",
+            $table,
+            ".maker_trade_count + EXCLUDED.maker_trade_count,
+                taker_trade_count = ",
+            $table,
+            ".taker_trade_count + EXCLUDED.taker_trade_count,
+                deep_fees = ",
+            $table,
+            ".deep_fees + EXCLUDED.deep_fees,
+                base_fees = ",
+            $table,
+            ".base_fees + EXCLUDED.base_fees,
+                quote_fees = ",
+            $table,
+            ".quote_fees + EXCLUDED.quote_fees"
+        )
+    };
+}
+
+const UPSERT_TRADER_VOLUME_1H: &str = upsert_trader_volume!("trader_volume_1h");
+const UPSERT_TRADER_VOLUME_1D: &str = upsert_trader_volume!("trader_volume_1d");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeepbookEnv;
+    use deepbook_schema::models::TraderVolume;
+    use deepbook_schema::MIGRATIONS;
+    use sui_pg_db::temp::TempDb;
+    use sui_pg_db::DbArgs;
+
+    const POOL: &str = "0xa1";
+    const MAKER: &str = "0xb1";
+    const TAKER: &str = "0xb2";
+
+    fn handler() -> TraderVolumeHandler {
+        TraderVolumeHandler::new(Arc::new(EventRouter::new(DeepbookEnv::Testnet)))
+    }
+
+    /// A fill of `quantity` base for `quantity` quote, where the maker pays `quantity` in DEEP
+    /// and the bidding taker pays `quantity` in quote.
+    fn fill(quantity: u64, timestamp_ms: i64) -> TraderFill {
+        TraderFill {
+            pool_id: POOL.to_string(),
+            timestamp_ms,
+            maker_balance_manager_id: MAKER.to_string(),
+            taker_balance_manager_id: TAKER.to_string(),
+            taker_is_bid: true,
+            base_quantity: quantity,
+            quote_quantity: quantity,
+            maker_fee: quantity,
+            maker_fee_is_deep: true,
+            taker_fee: quantity,
+            taker_fee_is_deep: false,
+        }
+    }
+
+    fn batch_of(fills: Vec<TraderFill>) -> TraderVolumeBatch {
+        let mut batch = TraderVolumeBatch::default();
+        handler().batch(&mut batch, fills.into_iter());
+        batch
+    }
+
+    #[test]
+    fn batch_sums_both_sides_without_overflow() {
+        let max = i128::from(u64::MAX);
+        let batch = batch_of(vec![fill(u64::MAX, 1), fill(u64::MAX, 2), fill(1, HOUR_MS)]);
+
+        assert_eq!(batch.hours.len(), 4);
+        let maker = &batch.hours[&(POOL.to_string(), MAKER.to_string(), 0)];
+        assert_eq!(
+            (maker.maker_base_volume, maker.maker_quote_volume),
+            (2 * max, 2 * max)
+        );
+        assert_eq!((maker.maker_trade_count, maker.taker_trade_count), (2, 0));
+        assert_eq!(
+            (maker.deep_fees, maker.base_fees, maker.quote_fees),
+            (2 * max, 0, 0)
+        );
+
+        let taker = &batch.hours[&(POOL.to_string(), TAKER.to_string(), 0)];
+        assert_eq!(
+            (taker.taker_base_volume, taker.taker_quote_volume),
+            (2 * max, 2 * max)
+        );
+        assert_eq!((taker.maker_trade_count, taker.taker_trade_count), (0, 2));
+        assert_eq!(
+            (taker.deep_fees, taker.base_fees, taker.quote_fees),
+            (0, 0, 2 * max)
+        );
+    }
+
+    #[tokio::test]
+    async fn commit_adds_to_existing_totals() {
+        let temp_db = TempDb::new().expect("postgres binaries must be on PATH");
+        let url = temp_db.database().url().clone();
+        let db = Db::for_write(url, DbArgs::default()).await.unwrap();
+        db.run_migrations(Some(&MIGRATIONS)).await.unwrap();
+        let mut conn = db.connect().await.unwrap();
+
+        let max = i128::from(u64::MAX);
+        let batch = batch_of(vec![fill(u64::MAX, 1)]);
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 4);
+        let batch = batch_of(vec![fill(u64::MAX, 2), fill(u64::MAX, HOUR_MS)]);
+        assert_eq!(handler().commit(&batch, &mut conn).await.unwrap(), 6);
+
+        let volumes = |table: &'static str| {
+            diesel::sql_query(format!(
+                "SELECT * FROM {table} WHERE balance_manager_id = '{MAKER}' ORDER BY bucket_start"
+            ))
+        };
+        let hours: Vec<TraderVolume> = volumes("trader_volume_1h").load(&mut conn).await.unwrap();
+        assert_eq!(hours.len(), 2);
+        assert_eq!(hours[0].maker_base_volume, BigDecimal::from(2 * max));
+        assert_eq!(hours[0].maker_trade_count, 2);
+        assert_eq!(hours[1].bucket_start, HOUR_MS);
+
+        let days: Vec<TraderVolume> = volumes("trader_volume_1d").load(&mut conn).await.unwrap();
+        assert_eq!(days.len(), 1);
+        assert_eq!(days[0].maker_quote_volume, BigDecimal::from(3 * max));
+        assert_eq!(days[0].deep_fees, BigDecimal::from(3 * max));
+        assert_eq!(days[0].maker_trade_count, 3);
+    }
+}
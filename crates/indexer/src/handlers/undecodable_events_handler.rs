@@ -0,0 +1,82 @@
+use crate::event_index::EventRouter;
+use async_trait::async_trait;
+use deepbook_schema::models::UndecodableEvent;
+use deepbook_schema::schema::undecodable_events;
+use diesel_async::RunQueryDsl;
+use std::sync::Arc;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::postgres::handler::Handler;
+use sui_indexer_alt_framework::postgres::Connection;
+use sui_indexer_alt_framework::types::full_checkpoint_content::Checkpoint;
+use tracing::debug;
+
+/// Records events that failed to decode in `undecodable_events`, one row per pipeline subscribed
+/// to the event. Only registered when dead-lettering is enabled (see `dead_letter`), in which
+/// case those pipelines skip the events instead of failing.
+pub struct UndecodableEventsHandler {
+    router: Arc<EventRouter>,
+}
+
+impl UndecodableEventsHandler {
+    pub fn new(router: Arc<EventRouter>) -> Self {
+        Self { router }
+    }
+}
+
+#[async_trait]
+impl Processor for UndecodableEventsHandler {
+    const NAME: &'static str = "undecodable_events";
+    type Value = UndecodableEvent;
+
+    async fn process(&self, checkpoint: &Arc<Checkpoint>) -> anyhow::Result<Vec<Self::Value>> {
+        let routed = self.router.route(checkpoint).await;
+        let mut results = vec![];
+
+        for (routed_event, error) in routed.undecodable() {
+            let meta = &routed_event.meta;
+            let Some(event) = checkpoint.transactions[routed_event.tx_index]
+                .events
+                .as_ref()
+                .and_then(|events| events.data.get(meta.event_index()))
+            else {
+                continue;
+            };
+            for pipeline in self.router.subscribers(routed_event.kind) {
+                debug!(
+                    pipeline,
+                    event_digest = meta.event_digest(),
+                    "Observed undecodable {} event",
+                    event.type_
+                );
+                results.push(UndecodableEvent {
+                    pipeline: pipeline.to_string(),
+                    event_digest: meta.event_digest(),
+                    digest: meta.digest(),
+                    sender: meta.sender(),
+                    checkpoint: meta.checkpoint(),
+                    checkpoint_timestamp_ms: meta.checkpoint_timestamp_ms(),
+                    package: meta.package(),
+                    event_index: meta.event_index() as i64,
+                    event_type: event.type_.to_canonical_string(/* with_prefix */ true),
+                    contents: event.contents.clone(),
+                    error: error.to_string(),
+                });
+            }
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl Handler for UndecodableEventsHandler {
+    async fn commit<'a>(
+        values: &[Self::Value],
+        conn: &mut Connection<'a>,
+    ) -> anyhow::Result<usize> {
+        Ok(diesel::insert_into(undecodable_events::table)
+            .values(values)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?)
+    }
+}
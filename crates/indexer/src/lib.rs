@@ -1,68 +1,46 @@
+use deepbook_package_registry::{PackageFamily, PackageRegistry};
+use std::sync::OnceLock;
 use url::Url;
 
+pub mod dead_letter;
+pub mod event_index;
+pub(crate) mod events;
 pub mod handlers;
-pub mod materialized_view_refresh;
 pub(crate) mod models;
-pub mod sandbox;
+pub mod package_versions;
+pub mod scheduled_jobs;
 pub mod traits;
 
-pub const NOT_MAINNET_PACKAGE: &str = "<not on mainnet>";
-
 pub const MAINNET_REMOTE_STORE_URL: &str = "https://checkpoints.mainnet.sui.io";
 pub const TESTNET_REMOTE_STORE_URL: &str = "https://checkpoints.testnet.sui.io";
 
-// Package addresses for different environments
-const MAINNET_PACKAGES: &[&str] = &[
-    "0xb29d83c26cdd2a64959263abbcfc4a6937f0c9fccaf98580ca56faded65be244",
-    "0x2c8d603bc51326b8c13cef9dd07031a408a48dddb541963357661df5d3204809",
-    "0xcaf6ba059d539a97646d47f0b9ddf843e138d215e2a12ca1f4585d386f7aec3a",
-    "0x00c1a56ec8c4c623a848b2ed2f03d23a25d17570b670c22106f336eb933785cc",
-    "0x2d93777cc8b67c064b495e8606f2f8f5fd578450347bbe7b36e0bc03963c1c40", // Latest
-];
+static PACKAGE_REGISTRY: OnceLock<PackageRegistry> = OnceLock::new();
 
-const TESTNET_PACKAGES: &[&str] = &[
-    "0x467e34e75debeea8b89d03aea15755373afc39a7c96c9959549c7f5f689843cf",
-    "0x5d520a3e3059b68530b2ef4080126dbb5d234e0afd66561d0d9bd48127a06044",
-    "0xcd40faffa91c00ce019bfe4a4b46f8d623e20bf331eb28990ee0305e9b9f3e3c",
-    "0x16c4e050b9b19b25ce1365b96861bc50eb7e58383348a39ea8a8e1d063cfef73",
-    "0xc483dba510597205749f2e8410c23f19be31a710aef251f353bc1b97755efd4d",
-    "0x5da5bbf6fb097d108eaf2c2306f88beae4014c90a44b95c7e76a6bfccec5f5ee",
-    "0xa3886aaa8aa831572dd39549242ca004a438c3a55967af9f0387ad2b01595068",
-    "0x9592ac923593f37f4fed15ee15f760ebd4c39729f53ee3e8c214de7a17157769",
-    "0x984757fc7c0e6dd5f15c2c66e881dd6e5aca98b725f3dbd83c445e057ebb790a",
-    "0xfb28c4cbc6865bd1c897d26aecbe1f8792d1509a20ffec692c800660cbec6982",
-    "0x926c446869fa175ec3b0dbf6c4f14604d86a415c1fccd8c8f823cfc46a29baed",
-    "0xa0936c6ea82fbfc0356eedc2e740e260dedaaa9f909a0715b1cc31e9a8283719",
-    "0x9ae1cbfb7475f6a4c2d4d3273335459f8f9d265874c4d161c1966cdcbd4e9ebc",
-    "0xb48d47cb5f56d0f489f48f186d06672df59d64bd2f514b2f0ba40cbb8c8fd487",
-    "0xbc331f09e5c737d45f074ad2d17c3038421b3b9018699e370d88d94938c53d28",
-    "0x23018638bb4f11ef9ffb0de922519bea52f960e7a5891025ca9aaeeaff7d5034",
-    "0x22be4cade64bf2d02412c7e8d0e8beea2f78828b948118d46735315409371a3c", // Latest
-];
+/// Install the package registry used to resolve package addresses.
+/// Must be called at most once, at startup, before any handler processes checkpoints. If it is
+/// never called, the registry compiled into the binary is used.
+pub fn init_package_registry(registry: PackageRegistry) {
+    PACKAGE_REGISTRY
+        .set(registry)
+        .expect("init_package_registry must only be called once");
+}
 
-// Mainnet margin package addresses
-const MAINNET_MARGIN_PACKAGES: &[&str] = &[
-    "0x97d9473771b01f77b0940c589484184b49f6444627ec121314fae6a6d36fb86b",
-    "0xcb4fc91921494ebe6979e201fdb2d67388ffdf6a1b1eb4952526259074de8d0b",
-    "0xfbd322126f1452fd4c89aedbaeb9fd0c44df9b5cedbe70d76bf80dc086031377",
-    "0x7767428727629a08dfd196bd4fc00d8a060e30da33aa63f4087fb3271e615a98",
-    "0x124bb3d8105d6d301c0d40feaa54d65df6b301e4d8ddd5eb8475b0f8a18cff2e", // Latest
-];
-const TESTNET_MARGIN_PACKAGES: &[&str] = &[
-    "0xb8620c24c9ea1a4a41e79613d2b3d1d93648d1bb6f6b789a7c8f261c94110e4b",
-    "0xf978cf2b601c24e40ef82b6e51512b448696b44cb014c0a1162422aa8b9cb811",
-    "0x16d781c327a919dc55390f5cc60d58c7ec4535bb317e88850961222bbd5d4d9e",
-    "0xbf9e1b079fa68ffc54a84533b1c3d357019178b19e9901f262fb925454425177",
-    "0xe673d499eb03f1c31e8079dc73a700f2f085ff7b69c4aff396fad52d07ae6338",
-    "0x229d3cdbb327082a5c6773e8344b16c4040b360235e3cda75e1f232d4e9184cb",
-    "0x3d02a90ae1d2eff63ca8ae9bfd89ffa0f7e12d780563259c8271833c270ae842",
-    "0x3ca7f6ee86b42ebe05ab8de70fbc96832e65615f64f10dbdc1820fa599904c7b",
-    "0xb284008ea0a6ac0a68c41f50a631207cd8d9c197ba0884e0df29ea204256777e",
-    "0xc21637e41d3db1c7ca6258fb4de567ba09d4e41610da44a148b26e99b68e11b5",
-    "0xf0a090340d74ea598d59868378f27d2cc5e46a562ec3a5b26b5117572905d9f3",
-    "0x32e32dd608c4d83f82c64331a547bcb4bbfb819d4591197f2fe442b1661873d8",
-    "0xd6a42f4df4db73d68cbeb52be66698d2fe6a9464f45ad113ca52b0c6ebd918b6",
-];
+/// The active package registry.
+pub fn package_registry() -> &'static PackageRegistry {
+    PACKAGE_REGISTRY.get_or_init(PackageRegistry::builtin)
+}
+
+/// Get the package addresses of `family` for the given environment: the registry's versions,
+/// oldest first, followed by upgrades discovered at runtime
+pub fn get_package_addresses(env: DeepbookEnv, family: PackageFamily) -> Vec<String> {
+    let mut addresses: Vec<String> = package_registry()
+        .package_ids(env.name(), family)
+        .into_iter()
+        .map(String::from)
+        .collect();
+    addresses.extend(package_versions::discovered_package_ids(env, family));
+    addresses
+}
 
 // Module definitions
 /// Core DeepBook modules that handle trading, orders, and pool management
@@ -87,6 +65,23 @@ pub const MARGIN_MODULES: &[&str] = &[
     "tpsl",
 ];
 
+/// Margin liquidation vault modules
+pub const MARGIN_LIQUIDATION_MODULES: &[&str] = &["liquidation_vault"];
+
+/// Predict modules that emit market, order, PLP vault and builder code events
+pub const PREDICT_MODULES: &[&str] = &[
+    "config_events",
+    "order_events",
+    "vault_events",
+    "builder_code_events",
+];
+
+/// Propbook modules that emit oracle registry and Block Scholes feed events
+pub const PROPBOOK_MODULES: &[&str] = &["registry", "block_scholes_store", "oracle_lane"];
+
+/// Account modules that emit account lifecycle, app authorization, custody and session events
+pub const ACCOUNT_MODULES: &[&str] = &["account_events", "account_data", "sessions"];
+
 /// SUI system modules
 pub const SUI_MODULES: &[&str] = &["sui"];
 
@@ -95,10 +90,42 @@ pub const SUI_MODULES: &[&str] = &["sui"];
 pub enum ModuleType {
     Core,
     Margin,
+    MarginLiquidation,
+    Predict,
+    Propbook,
+    Account,
     Sui,
     Unknown,
 }
 
+impl ModuleType {
+    /// The package family defining modules of this type
+    pub fn family(&self) -> Option<PackageFamily> {
+        match self {
+            ModuleType::Core => Some(PackageFamily::Core),
+            ModuleType::Margin => Some(PackageFamily::Margin),
+            ModuleType::MarginLiquidation => Some(PackageFamily::MarginLiquidation),
+            ModuleType::Predict => Some(PackageFamily::Predict),
+            ModuleType::Propbook => Some(PackageFamily::Propbook),
+            ModuleType::Account => Some(PackageFamily::Account),
+            ModuleType::Sui | ModuleType::Unknown => None,
+        }
+    }
+}
+
+/// Modules of `family` that emit indexed events. A module name may appear in several families;
+/// which one an event comes from is decided by its package.
+pub fn family_modules(family: PackageFamily) -> &'static [&'static str] {
+    match family {
+        PackageFamily::Core => CORE_MODULES,
+        PackageFamily::Margin => MARGIN_MODULES,
+        PackageFamily::MarginLiquidation => MARGIN_LIQUIDATION_MODULES,
+        PackageFamily::Predict => PREDICT_MODULES,
+        PackageFamily::Propbook => PROPBOOK_MODULES,
+        PackageFamily::Account => ACCOUNT_MODULES,
+    }
+}
+
 /// Check if a module is a core DeepBook module
 pub fn is_core_module(module: &str) -> bool {
     CORE_MODULES.contains(&module)
@@ -109,17 +136,46 @@ pub fn is_margin_module(module: &str) -> bool {
     MARGIN_MODULES.contains(&module)
 }
 
+/// Check if a module is a margin liquidation vault module
+pub fn is_margin_liquidation_module(module: &str) -> bool {
+    MARGIN_LIQUIDATION_MODULES.contains(&module)
+}
+
+/// Check if a module is a predict module
+pub fn is_predict_module(module: &str) -> bool {
+    PREDICT_MODULES.contains(&module)
+}
+
+/// Check if a module is a propbook module
+pub fn is_propbook_module(module: &str) -> bool {
+    PROPBOOK_MODULES.contains(&module)
+}
+
+/// Check if a module is an account module
+pub fn is_account_module(module: &str) -> bool {
+    ACCOUNT_MODULES.contains(&module)
+}
+
 /// Check if a module is a SUI system module
 pub fn is_sui_module(module: &str) -> bool {
     SUI_MODULES.contains(&module)
 }
 
-/// Get the module type (core, margin, sui, or unknown)
+/// Get the module type (core, margin, margin liquidation, predict, propbook, account, sui, or
+/// unknown)
 pub fn get_module_type(module: &str) -> ModuleType {
     if is_core_module(module) {
         ModuleType::Core
     } else if is_margin_module(module) {
         ModuleType::Margin
+    } else if is_margin_liquidation_module(module) {
+        ModuleType::MarginLiquidation
+    } else if is_predict_module(module) {
+        ModuleType::Predict
+    } else if is_propbook_module(module) {
+        ModuleType::Propbook
+    } else if is_account_module(module) {
+        ModuleType::Account
     } else if is_sui_module(module) {
         ModuleType::Sui
     } else {
@@ -132,6 +188,10 @@ pub fn get_all_known_modules() -> Vec<&'static str> {
     let mut modules = Vec::new();
     modules.extend_from_slice(CORE_MODULES);
     modules.extend_from_slice(MARGIN_MODULES);
+    modules.extend_from_slice(MARGIN_LIQUIDATION_MODULES);
+    modules.extend_from_slice(PREDICT_MODULES);
+    modules.extend_from_slice(PROPBOOK_MODULES);
+    modules.extend_from_slice(ACCOUNT_MODULES);
     modules.extend_from_slice(SUI_MODULES);
     modules
 }
@@ -146,77 +206,93 @@ pub fn get_margin_modules() -> &'static [&'static str] {
     MARGIN_MODULES
 }
 
-/// Get all SUI module names
-pub fn get_sui_modules() -> &'static [&'static str] {
-    SUI_MODULES
+/// Get all margin liquidation vault module names
+pub fn get_margin_liquidation_modules() -> &'static [&'static str] {
+    MARGIN_LIQUIDATION_MODULES
+}
+
+/// Get all predict module names
+pub fn get_predict_modules() -> &'static [&'static str] {
+    PREDICT_MODULES
 }
 
-/// Check if a margin package address is valid
-pub fn is_valid_margin_package(package: &str) -> bool {
-    package != NOT_MAINNET_PACKAGE
+/// Get all propbook module names
+pub fn get_propbook_modules() -> &'static [&'static str] {
+    PROPBOOK_MODULES
 }
 
-/// Check if any margin package addresses are valid for the given environment
-pub fn is_valid_margin_packages(packages: &[&str]) -> bool {
-    packages.iter().any(|&pkg| is_valid_margin_package(pkg))
+/// Get all account module names
+pub fn get_account_modules() -> &'static [&'static str] {
+    ACCOUNT_MODULES
+}
+
+/// Get all SUI module names
+pub fn get_sui_modules() -> &'static [&'static str] {
+    SUI_MODULES
 }
 
 /// Check if margin trading is supported in the given environment
 pub fn is_margin_supported(env: DeepbookEnv) -> bool {
-    if let Some(margin) = sandbox::margin_packages() {
-        return !margin.is_empty();
-    }
-    match env {
-        DeepbookEnv::Mainnet => is_valid_margin_packages(MAINNET_MARGIN_PACKAGES),
-        DeepbookEnv::Testnet => is_valid_margin_packages(TESTNET_MARGIN_PACKAGES),
-    }
+    !get_margin_package_addresses(env).is_empty()
 }
 
 /// Get the margin package addresses for the given environment
-pub fn get_margin_package_addresses(env: DeepbookEnv) -> &'static [&'static str] {
-    if let Some(margin) = sandbox::margin_packages() {
-        return margin;
-    }
-    match env {
-        DeepbookEnv::Mainnet => MAINNET_MARGIN_PACKAGES,
-        DeepbookEnv::Testnet => TESTNET_MARGIN_PACKAGES,
-    }
+pub fn get_margin_package_addresses(env: DeepbookEnv) -> Vec<String> {
+    get_package_addresses(env, PackageFamily::Margin)
 }
 
-/// Get the first valid margin package address for the given environment with validation
-pub fn get_margin_package_address(env: DeepbookEnv) -> Result<&'static str, String> {
-    if let Some(margin) = sandbox::margin_packages() {
-        return margin
-            .first()
-            .copied()
-            .ok_or_else(|| "No margin packages configured in sandbox mode".to_string());
-    }
+/// Get the first margin package address for the given environment with validation
+pub fn get_margin_package_address(env: DeepbookEnv) -> Result<String, String> {
+    get_margin_package_addresses(env)
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            format!(
+                "Margin trading is not supported on {:?}. \
+                The margin package has not been deployed on this network.",
+                env
+            )
+        })
+}
 
-    let packages = get_margin_package_addresses(env);
+/// Get the margin liquidation vault package addresses for the given environment
+pub fn get_margin_liquidation_package_addresses(env: DeepbookEnv) -> Vec<String> {
+    get_package_addresses(env, PackageFamily::MarginLiquidation)
+}
 
-    // Find the first valid package
-    for &package in packages {
-        if is_valid_margin_package(package) {
-            return Ok(package);
-        }
-    }
+/// Check if predict is supported in the given environment
+pub fn is_predict_supported(env: DeepbookEnv) -> bool {
+    !get_predict_package_addresses(env).is_empty()
+}
 
-    Err(format!(
-        "Margin trading is not supported on {:?}. \
-        The margin package has not been deployed on this network.",
-        env
-    ))
+/// Get the predict package addresses for the given environment
+pub fn get_predict_package_addresses(env: DeepbookEnv) -> Vec<String> {
+    get_package_addresses(env, PackageFamily::Predict)
+}
+
+/// Check if propbook is supported in the given environment
+pub fn is_propbook_supported(env: DeepbookEnv) -> bool {
+    !get_propbook_package_addresses(env).is_empty()
+}
+
+/// Get the propbook package addresses for the given environment
+pub fn get_propbook_package_addresses(env: DeepbookEnv) -> Vec<String> {
+    get_package_addresses(env, PackageFamily::Propbook)
+}
+
+/// Check if account indexing is supported in the given environment
+pub fn is_account_supported(env: DeepbookEnv) -> bool {
+    !get_account_package_addresses(env).is_empty()
+}
+
+/// Get the account package addresses for the given environment
+pub fn get_account_package_addresses(env: DeepbookEnv) -> Vec<String> {
+    get_package_addresses(env, PackageFamily::Account)
 }
 
 /// Get all core package addresses for the given environment
-pub fn get_core_package_addresses(env: DeepbookEnv) -> &'static [&'static str] {
-    if let Some(core) = sandbox::core_packages() {
-        return core;
-    }
-    match env {
-        DeepbookEnv::Mainnet => MAINNET_PACKAGES,
-        DeepbookEnv::Testnet => TESTNET_PACKAGES,
-    }
+pub fn get_core_package_addresses(env: DeepbookEnv) -> Vec<String> {
+    get_package_addresses(env, PackageFamily::Core)
 }
 
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
@@ -234,31 +310,21 @@ impl DeepbookEnv {
         Url::parse(url).unwrap()
     }
 
-    /// Get all package addresses (DeepBook + Margin) for this environment
-    fn get_all_package_strings(&self) -> Vec<&str> {
-        // If sandbox mode is active, both overrides are set together by init_package_override
-        // (margin may be an empty slice). Use them instead of the hardcoded constants.
-        if let (Some(core), Some(margin)) = (sandbox::core_packages(), sandbox::margin_packages()) {
-            let mut all = core.to_vec();
-            all.extend_from_slice(margin);
-            return all;
-        }
-
-        let (packages, margin_packages) = match self {
-            DeepbookEnv::Mainnet => (MAINNET_PACKAGES, MAINNET_MARGIN_PACKAGES),
-            DeepbookEnv::Testnet => (TESTNET_PACKAGES, TESTNET_MARGIN_PACKAGES),
-        };
-
-        let mut all_packages = packages.to_vec();
-
-        // Add margin packages if they're not invalid
-        for &margin_package in margin_packages {
-            if margin_package != NOT_MAINNET_PACKAGE {
-                all_packages.push(margin_package);
-            }
+    /// Name of this environment's network in the package registry
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeepbookEnv::Mainnet => "mainnet",
+            DeepbookEnv::Testnet => "testnet",
         }
+    }
 
-        all_packages
+    /// Get all package addresses (DeepBook + Margin + Margin liquidation + Predict + Propbook +
+    /// Account) for this environment
+    fn get_all_package_strings(&self) -> Vec<String> {
+        PackageFamily::ALL
+            .into_iter()
+            .flat_map(|family| get_package_addresses(*self, family))
+            .collect()
     }
 
     pub fn package_ids(&self) -> Vec<sui_types::base_types::ObjectID> {
@@ -1,5 +1,6 @@
 use anyhow::Context;
 use clap::Parser;
+use deepbook_indexer::handlers::balance_manager_balances_handler::BalanceManagerBalancesHandler;
 use deepbook_indexer::handlers::balance_manager_event_handler::BalanceManagerEventHandler;
 use deepbook_indexer::handlers::balances_handler::BalancesHandler;
 use deepbook_indexer::handlers::book_params_updated_handler::BookParamsUpdatedHandler;
@@ -8,8 +9,11 @@ use deepbook_indexer::handlers::deepbook_referral_created_event_handler::DeepBoo
 use deepbook_indexer::handlers::deepbook_referral_set_event_handler::DeepBookReferralSetEventHandler;
 use deepbook_indexer::handlers::ewma_update_handler::EwmaUpdateHandler;
 use deepbook_indexer::handlers::flash_loan_handler::FlashLoanHandler;
+use deepbook_indexer::handlers::ohclv_handler::OhclvHandler;
+use deepbook_indexer::handlers::open_orders_handler::OpenOrdersHandler;
 use deepbook_indexer::handlers::order_fill_handler::OrderFillHandler;
 use deepbook_indexer::handlers::order_update_handler::OrderUpdateHandler;
+use deepbook_indexer::handlers::package_versions_handler::PackageVersionsHandler;
 use deepbook_indexer::handlers::pool_created_handler::PoolCreatedHandler;
 use deepbook_indexer::handlers::pool_price_handler::PoolPriceHandler;
 use deepbook_indexer::handlers::proposals_handler::ProposalsHandler;
@@ -17,17 +21,22 @@ use deepbook_indexer::handlers::rebates_handler::RebatesHandler;
 use deepbook_indexer::handlers::rebates_v2_handler::RebatesV2Handler;
 use deepbook_indexer::handlers::referral_claimed_handler::ReferralClaimedHandler;
 use deepbook_indexer::handlers::referral_fee_event_handler::ReferralFeeEventHandler;
+use deepbook_indexer::handlers::rewards_handler::RewardsHandler;
 use deepbook_indexer::handlers::stakes_handler::StakesHandler;
 use deepbook_indexer::handlers::taker_fee_penalty_handler::TakerFeePenaltyHandler;
 use deepbook_indexer::handlers::trade_params_update_handler::TradeParamsUpdateHandler;
+use deepbook_indexer::handlers::trader_volume_handler::TraderVolumeHandler;
+use deepbook_indexer::handlers::undecodable_events_handler::UndecodableEventsHandler;
 use deepbook_indexer::handlers::vote_handler::VotesHandler;
-use deepbook_indexer::materialized_view_refresh::MaterializedViewRefreshMetrics;
+use deepbook_indexer::scheduled_jobs::{self, ScheduledJobMetrics};
 
 // Margin Manager Events
+use deepbook_indexer::handlers::liquidation_by_vault_handler::LiquidationByVaultHandler;
 use deepbook_indexer::handlers::liquidation_handler::LiquidationHandler;
 use deepbook_indexer::handlers::loan_borrowed_handler::LoanBorrowedHandler;
 use deepbook_indexer::handlers::loan_repaid_handler::LoanRepaidHandler;
 use deepbook_indexer::handlers::margin_manager_created_handler::MarginManagerCreatedHandler;
+use deepbook_indexer::handlers::margin_manager_state_handler::{self, MarginManagerStateHandler};
 
 // Margin Pool Operations Events
 use deepbook_indexer::handlers::asset_supplied_handler::AssetSuppliedHandler;
@@ -67,14 +76,54 @@ use deepbook_indexer::handlers::conditional_order_cancelled_handler::Conditional
 use deepbook_indexer::handlers::conditional_order_executed_handler::ConditionalOrderExecutedHandler;
 use deepbook_indexer::handlers::conditional_order_insufficient_funds_handler::ConditionalOrderInsufficientFundsHandler;
 
+// Predict Events
+use deepbook_indexer::handlers::predict_builder_code_created_handler::PredictBuilderCodeCreatedHandler;
+use deepbook_indexer::handlers::predict_builder_code_set_handler::PredictBuilderCodeSetHandler;
+use deepbook_indexer::handlers::predict_builder_fees_claimed_handler::PredictBuilderFeesClaimedHandler;
+use deepbook_indexer::handlers::predict_liquidated_order_redeemed_handler::PredictLiquidatedOrderRedeemedHandler;
+use deepbook_indexer::handlers::predict_live_order_redeemed_handler::PredictLiveOrderRedeemedHandler;
+use deepbook_indexer::handlers::predict_market_created_handler::PredictMarketCreatedHandler;
+use deepbook_indexer::handlers::predict_market_settled_handler::PredictMarketSettledHandler;
+use deepbook_indexer::handlers::predict_order_liquidated_handler::PredictOrderLiquidatedHandler;
+use deepbook_indexer::handlers::predict_order_minted_handler::PredictOrderMintedHandler;
+use deepbook_indexer::handlers::predict_request_cancelled_handler::PredictRequestCancelledHandler;
+use deepbook_indexer::handlers::predict_settled_order_redeemed_handler::PredictSettledOrderRedeemedHandler;
+use deepbook_indexer::handlers::predict_supply_filled_handler::PredictSupplyFilledHandler;
+use deepbook_indexer::handlers::predict_supply_requested_handler::PredictSupplyRequestedHandler;
+use deepbook_indexer::handlers::predict_withdraw_filled_handler::PredictWithdrawFilledHandler;
+use deepbook_indexer::handlers::predict_withdraw_requested_handler::PredictWithdrawRequestedHandler;
+
+// Account Events
+use deepbook_indexer::handlers::account_app_authorized_handler::AccountAppAuthorizedHandler;
+use deepbook_indexer::handlers::account_app_deauthorized_handler::AccountAppDeauthorizedHandler;
+use deepbook_indexer::handlers::account_created_handler::AccountCreatedHandler;
+use deepbook_indexer::handlers::account_deposited_handler::AccountDepositedHandler;
+use deepbook_indexer::handlers::account_funds_settled_handler::AccountFundsSettledHandler;
+use deepbook_indexer::handlers::account_session_authorized_handler::AccountSessionAuthorizedHandler;
+use deepbook_indexer::handlers::account_session_revoked_handler::AccountSessionRevokedHandler;
+use deepbook_indexer::handlers::account_withdrawn_handler::AccountWithdrawnHandler;
+use deepbook_indexer::handlers::deepbook_core_account_initialized_handler::DeepbookCoreAccountInitializedHandler;
+// Propbook Events
+use deepbook_indexer::event_index::EventRouter;
+use deepbook_indexer::handlers::propbook_block_scholes_batch_ingested_handler::PropbookBlockScholesBatchIngestedHandler;
+use deepbook_indexer::handlers::propbook_block_scholes_observations_handler::PropbookBlockScholesObservationsHandler;
+use deepbook_indexer::handlers::propbook_block_scholes_stores_registered_handler::PropbookBlockScholesStoresRegisteredHandler;
+use deepbook_indexer::handlers::propbook_oracle_bound_handler::PropbookOracleBoundHandler;
+use deepbook_indexer::handlers::propbook_oracle_observations_handler::PropbookOracleObservationsHandler;
+use deepbook_indexer::handlers::propbook_oracle_rebound_handler::PropbookOracleReboundHandler;
+use deepbook_indexer::handlers::propbook_oracle_source_registered_handler::PropbookOracleSourceRegisteredHandler;
+use deepbook_indexer::package_versions;
 use deepbook_indexer::{DeepbookEnv, TESTNET_REMOTE_STORE_URL};
+use deepbook_package_registry::{PackageFamily, PackageRegistry, PackageVersion};
 use deepbook_schema::MIGRATIONS;
 use prometheus::Registry;
 use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::sync::Arc;
 use sui_indexer_alt_framework::ingestion::ingestion_client::IngestionClientArgs;
 use sui_indexer_alt_framework::ingestion::streaming_client::StreamingClientArgs;
 use sui_indexer_alt_framework::ingestion::{ClientArgs, IngestionConfig};
+use sui_indexer_alt_framework::pipeline::Processor;
 use sui_indexer_alt_framework::{Indexer, IndexerArgs};
 use sui_indexer_alt_metrics::db::DbConnectionStatsCollector;
 use sui_indexer_alt_metrics::{MetricsArgs, MetricsService};
@@ -88,6 +137,25 @@ pub enum Package {
     Deepbook,
     /// Index DeepBook margin events (lending, borrowing, liquidations, etc.)
     DeepbookMargin,
+    /// Index Predict events (markets, orders, PLP vault, builder codes)
+    Predict,
+    /// Index Propbook events (oracle registry, Pyth lanes, Block Scholes feeds)
+    Propbook,
+    /// Index Account events (accounts, app authorizations, custody, sessions)
+    Account,
+}
+
+impl Package {
+    /// Registry package families whose events this package's handlers consume
+    fn families(&self) -> &'static [PackageFamily] {
+        match self {
+            Package::Deepbook => &[PackageFamily::Core],
+            Package::DeepbookMargin => &[PackageFamily::Margin, PackageFamily::MarginLiquidation],
+            Package::Predict => &[PackageFamily::Predict],
+            Package::Propbook => &[PackageFamily::Propbook],
+            Package::Account => &[PackageFamily::Account],
+        }
+    }
 }
 
 #[derive(Parser)]
@@ -113,17 +181,41 @@ struct Args {
     /// Packages to index events for (can specify multiple)
     #[clap(long, value_enum, default_values = ["deepbook", "deepbook-margin"])]
     packages: Vec<Package>,
-    /// Materialized view refresh interval in seconds. Set to 0 to disable.
-    #[clap(env, long, default_value_t = 60)]
-    materialized_view_refresh_interval_secs: u64,
+    /// Package registry file (TOML, or JSON with a .json extension). Defaults to the registry
+    /// compiled into the binary. Ignored with the sandbox subcommand.
+    #[clap(env, long)]
+    package_config: Option<PathBuf>,
+    /// Scheduled database jobs file (TOML): materialized view refreshes, procedure calls and
+    /// table pruning, each on its own interval. Defaults to the jobs compiled into the binary.
+    #[clap(env, long)]
+    scheduled_jobs_config: Option<PathBuf>,
+    /// Deprecated: set `interval_secs` in `--scheduled-jobs-config` instead. Overrides the
+    /// interval of the materialized view refresh jobs; 0 disables them.
+    #[clap(env, long)]
+    materialized_view_refresh_interval_secs: Option<u64>,
+    /// Record events that fail to decode in `undecodable_events` and keep indexing, instead of
+    /// failing the pipelines that index them.
+    #[clap(env, long)]
+    dead_letter_undecodable_events: bool,
     #[command(subcommand)]
-    sandbox: Option<Command>,
+    command: Option<Command>,
 }
 
 #[derive(clap::Subcommand)]
 enum Command {
     /// Run the indexer in sandbox mode with custom package IDs
     Sandbox(SandboxArgs),
+    /// Decode the events in `undecodable_events` again and write the ones that now decode
+    /// through their pipelines, then exit. Uses the same --env and --packages as indexing.
+    ReplayUndecodableEvents(ReplayArgs),
+}
+
+#[derive(clap::Args)]
+#[clap(rename_all = "kebab-case")]
+struct ReplayArgs {
+    /// Only replay the events of this pipeline
+    #[clap(long)]
+    pipeline: Option<String>,
 }
 
 #[derive(clap::Args)]
@@ -138,6 +230,20 @@ struct SandboxArgs {
     /// Margin package ID(s) — optional, skip margin indexing if omitted
     #[clap(long)]
     margin_packages: Vec<String>,
+    /// Margin liquidation vault package ID(s) — optional, skip vault liquidation indexing if
+    /// omitted
+    #[clap(long)]
+    margin_liquidation_package_id: Vec<String>,
+    /// Predict package ID(s) — optional, skip predict indexing if omitted
+    #[clap(long)]
+    predict_package_id: Vec<String>,
+    /// Propbook package ID(s) — optional, skip propbook indexing if omitted
+    #[clap(long)]
+    propbook_package_id: Vec<String>,
+    /// Account package ID(s) (account, deepbook_core_account, sessions) — optional, skip account
+    /// indexing if omitted
+    #[clap(long)]
+    account_package_id: Vec<String>,
     /// Path to local checkpoint directory (required for localnet)
     #[clap(long)]
     local_ingestion_path: Option<PathBuf>,
@@ -157,35 +263,80 @@ async fn main() -> Result<(), anyhow::Error> {
 
     let Args {
         db_args,
-        indexer_args,
+        mut indexer_args,
         streaming_args,
         metrics_address,
         database_url,
         env,
         packages,
+        package_config,
+        scheduled_jobs_config,
         materialized_view_refresh_interval_secs,
-        sandbox,
+        dead_letter_undecodable_events,
+        command,
     } = Args::parse();
 
+    let (sandbox, replay) = match command {
+        Some(Command::Sandbox(sb)) => (Some(sb), None),
+        Some(Command::ReplayUndecodableEvents(replay)) => (None, Some(replay)),
+        None => (None, None),
+    };
+
     // Resolve mode-specific config: env, ingestion source, and which packages to index
     let (env, ingestion_args, packages) = match sandbox {
         None => {
             // Production mode — --env is required
             let env = env.context("--env is required when not using a subcommand")?;
+            let registry = PackageRegistry::load_or_builtin(package_config.as_deref())?;
+
+            // Pipelines without a watermark start where the selected packages were first
+            // published, when the registry knows it.
+            if indexer_args.first_checkpoint.is_none() {
+                let families: Vec<_> = packages
+                    .iter()
+                    .flat_map(|p| p.families())
+                    .copied()
+                    .collect();
+                indexer_args.first_checkpoint = registry.first_checkpoint(env.name(), &families);
+            }
+            deepbook_indexer::init_package_registry(registry);
+
             let ingestion = IngestionClientArgs {
                 remote_store_url: Some(env.remote_store_url()),
                 ..Default::default()
             };
             (env, ingestion, packages)
         }
-        Some(Command::Sandbox(sb)) => {
-            // Sandbox mode — override package addresses (even on testnet, because
-            // sandbox deploys its own DeepBook instance), then pick ingestion source
+        Some(sb) => {
+            // Sandbox mode — the registry holds only the packages given on the command line
+            // (even on testnet, because sandbox deploys its own DeepBook instance), filed under
+            // Testnet, the closest DeepbookEnv equivalent for both sandbox variants. Then pick
+            // the ingestion source.
+            let deepbook_env = match sb.env {
+                SandboxEnv::Testnet | SandboxEnv::Localnet => DeepbookEnv::Testnet,
+            };
             let has_margin = !sb.margin_packages.is_empty();
-            deepbook_indexer::sandbox::init_package_override(
-                sb.deepbook_package_id,
-                sb.margin_packages,
-            );
+            let has_predict = !sb.predict_package_id.is_empty();
+            let has_propbook = !sb.propbook_package_id.is_empty();
+            let has_account = !sb.account_package_id.is_empty();
+            let mut registry = PackageRegistry::default();
+            for (family, ids) in [
+                (PackageFamily::Core, sb.deepbook_package_id),
+                (PackageFamily::Margin, sb.margin_packages),
+                (
+                    PackageFamily::MarginLiquidation,
+                    sb.margin_liquidation_package_id,
+                ),
+                (PackageFamily::Predict, sb.predict_package_id),
+                (PackageFamily::Propbook, sb.propbook_package_id),
+                (PackageFamily::Account, sb.account_package_id),
+            ] {
+                let versions = ids.into_iter().map(PackageVersion::new).collect();
+                registry
+                    .set(deepbook_env.name(), family, versions)
+                    .context("Invalid sandbox package ID")?;
+            }
+            deepbook_indexer::init_package_registry(registry);
 
             let ingestion = match sb.env {
                 SandboxEnv::Localnet => IngestionClientArgs {
@@ -204,18 +355,21 @@ async fn main() -> Result<(), anyhow::Error> {
                 },
             };
 
-            // Skip margin handlers if no margin packages provided
+            // Skip margin / predict / propbook / account handlers if no packages provided for them
             let mut packages = packages;
             if !has_margin {
-                packages.retain(|p| matches!(p, Package::Deepbook));
+                packages.retain(|p| !matches!(p, Package::DeepbookMargin));
+            }
+            if !has_predict {
+                packages.retain(|p| !matches!(p, Package::Predict));
+            }
+            if !has_propbook {
+                packages.retain(|p| !matches!(p, Package::Propbook));
+            }
+            if !has_account {
+                packages.retain(|p| !matches!(p, Package::Account));
             }
 
-            // In sandbox mode the OnceLock override handles all package resolution,
-            // so this env is only used for type compatibility in handler constructors.
-            // Map both sandbox variants to Testnet (the closest DeepbookEnv equivalent).
-            let deepbook_env = match sb.env {
-                SandboxEnv::Testnet | SandboxEnv::Localnet => DeepbookEnv::Testnet,
-            };
             (deepbook_env, ingestion, packages)
         }
     };
@@ -234,13 +388,55 @@ async fn main() -> Result<(), anyhow::Error> {
         .await
         .context("Failed to run pending migrations")?;
 
+    // Package upgrades discovered by previous runs
+    let discovered_versions = package_versions::load_discovered_versions(&store, env).await?;
+    tracing::info!(discovered_versions, "Loaded discovered package versions");
+
+    // Only the package_versions pipeline scans checkpoints for upgrades; when --pipeline leaves
+    // it out, checkpoints are routed as they arrive instead.
+    let router = Arc::new(EventRouter::new(env));
+    if indexer_args.pipeline.is_empty()
+        || indexer_args
+            .pipeline
+            .iter()
+            .any(|pipeline| pipeline == PackageVersionsHandler::NAME)
+    {
+        let first_checkpoint = indexer_args.first_checkpoint.unwrap_or(0);
+        router.discover_from(
+            package_versions::next_unscanned_checkpoint(&store, first_checkpoint).await?,
+        );
+    }
+
+    // margin_manager_state reads the tables of other pipelines, which must run alongside it
+    let runs = |pipeline: &str| {
+        indexer_args.pipeline.is_empty() || indexer_args.pipeline.iter().any(|p| p == pipeline)
+    };
+    if packages
+        .iter()
+        .any(|package| matches!(package, Package::DeepbookMargin))
+        && runs(MarginManagerStateHandler::NAME)
+    {
+        margin_manager_state_handler::check_dependencies(&store, runs).await?;
+    }
+
     registry.register(Box::new(DbConnectionStatsCollector::new(
         Some("deepbook_indexer_db"),
         store.clone(),
     )))?;
-    let materialized_view_refresh_metrics = MaterializedViewRefreshMetrics::new(metrics.registry());
+    let mut scheduled_jobs = scheduled_jobs::load_jobs(scheduled_jobs_config.as_deref())?;
+    if let Some(interval_secs) = materialized_view_refresh_interval_secs {
+        tracing::warn!(
+            "--materialized-view-refresh-interval-secs is deprecated; set interval_secs in \
+             --scheduled-jobs-config instead"
+        );
+        scheduled_jobs::set_refresh_interval(&mut scheduled_jobs, interval_secs);
+    }
+    let scheduled_job_metrics = ScheduledJobMetrics::new(metrics.registry());
+    deepbook_indexer::event_index::init_metrics(metrics.registry());
+    deepbook_indexer::handlers::init_metrics(metrics.registry());
 
-    let materialized_view_refresh_db = store.clone();
+    let scheduled_jobs_db = store.clone();
+    let replay_db = store.clone();
 
     let mut indexer = Indexer::new(
         store,
@@ -255,197 +451,572 @@ async fn main() -> Result<(), anyhow::Error> {
     )
     .await?;
 
+    // Package upgrade discovery follows every registry package, whichever packages are indexed.
+    // The other pipelines wait for it to scan each checkpoint before routing it.
+    indexer
+        .concurrent_pipeline(
+            PackageVersionsHandler::new(Arc::clone(&router)),
+            Default::default(),
+        )
+        .await?;
+
+    if dead_letter_undecodable_events {
+        deepbook_indexer::dead_letter::enable();
+        indexer
+            .concurrent_pipeline(
+                UndecodableEventsHandler::new(Arc::clone(&router)),
+                Default::default(),
+            )
+            .await?;
+    }
+
     // Register handlers based on selected packages
     for package in &packages {
         match package {
             Package::Deepbook => {
                 // DeepBook core event handlers
                 indexer
-                    .concurrent_pipeline(BalanceManagerEventHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(BalanceManagerEventHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(BalancesHandler::new(env), Default::default())
+                    .concurrent_pipeline(router.subscribe(BalancesHandler::new), Default::default())
                     .await?;
                 indexer
-                    .concurrent_pipeline(DeepBurnedHandler::new(env), Default::default())
+                    .sequential_pipeline(
+                        router.subscribe(BalanceManagerBalancesHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
                     .concurrent_pipeline(
-                        DeepBookReferralCreatedEventHandler::new(env),
+                        router.subscribe(DeepBurnedHandler::new),
                         Default::default(),
                     )
                     .await?;
                 indexer
                     .concurrent_pipeline(
-                        DeepBookReferralSetEventHandler::new(env),
+                        router.subscribe(DeepBookReferralCreatedEventHandler::new),
                         Default::default(),
                     )
                     .await?;
                 indexer
-                    .concurrent_pipeline(FlashLoanHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(DeepBookReferralSetEventHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(OrderFillHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(FlashLoanHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(OrderUpdateHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(OrderFillHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(PoolPriceHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(OrderUpdateHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(ProposalsHandler::new(env), Default::default())
+                    .sequential_pipeline(
+                        router.subscribe(OpenOrdersHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(RebatesHandler::new(env), Default::default())
+                    .sequential_pipeline(router.subscribe(OhclvHandler::new), Default::default())
                     .await?;
                 indexer
-                    .concurrent_pipeline(RebatesV2Handler::new(env), Default::default())
+                    .sequential_pipeline(
+                        router.subscribe(TraderVolumeHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(ReferralFeeEventHandler::new(env), Default::default())
+                    .sequential_pipeline(router.subscribe(RewardsHandler::new), Default::default())
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PoolPriceHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(ProposalsHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(router.subscribe(RebatesHandler::new), Default::default())
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(RebatesV2Handler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(ReferralFeeEventHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(StakesHandler::new(env), Default::default())
+                    .concurrent_pipeline(router.subscribe(StakesHandler::new), Default::default())
                     .await?;
                 indexer
-                    .concurrent_pipeline(TradeParamsUpdateHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(TradeParamsUpdateHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(VotesHandler::new(env), Default::default())
+                    .concurrent_pipeline(router.subscribe(VotesHandler::new), Default::default())
                     .await?;
                 indexer
-                    .concurrent_pipeline(PoolCreatedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(PoolCreatedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(BookParamsUpdatedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(BookParamsUpdatedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(ReferralClaimedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(ReferralClaimedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(EwmaUpdateHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(EwmaUpdateHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(TakerFeePenaltyHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(TakerFeePenaltyHandler::new),
+                        Default::default(),
+                    )
                     .await?;
             }
             Package::DeepbookMargin => {
                 indexer
-                    .concurrent_pipeline(MarginManagerCreatedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(MarginManagerCreatedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(LoanBorrowedHandler::new(env), Default::default())
+                    .sequential_pipeline(
+                        router.subscribe(MarginManagerStateHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(LoanRepaidHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(LoanBorrowedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(LiquidationHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(LoanRepaidHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(AssetSuppliedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(LiquidationHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(AssetWithdrawnHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(LiquidationByVaultHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(MarginPoolCreatedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(AssetSuppliedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(DeepbookPoolUpdatedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(AssetWithdrawnHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(InterestParamsUpdatedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(MarginPoolCreatedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
                     .concurrent_pipeline(
-                        MarginPoolConfigUpdatedHandler::new(env),
+                        router.subscribe(DeepbookPoolUpdatedHandler::new),
                         Default::default(),
                     )
                     .await?;
                 indexer
-                    .concurrent_pipeline(MaintainerCapUpdatedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(InterestParamsUpdatedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
                     .concurrent_pipeline(
-                        DeepbookPoolRegisteredHandler::new(env),
+                        router.subscribe(MarginPoolConfigUpdatedHandler::new),
                         Default::default(),
                     )
                     .await?;
                 indexer
                     .concurrent_pipeline(
-                        DeepbookPoolUpdatedRegistryHandler::new(env),
+                        router.subscribe(MaintainerCapUpdatedHandler::new),
                         Default::default(),
                     )
                     .await?;
                 indexer
                     .concurrent_pipeline(
-                        DeepbookPoolConfigUpdatedHandler::new(env),
+                        router.subscribe(DeepbookPoolRegisteredHandler::new),
                         Default::default(),
                     )
                     .await?;
                 indexer
                     .concurrent_pipeline(
-                        MaintainerFeesWithdrawnHandler::new(env),
+                        router.subscribe(DeepbookPoolUpdatedRegistryHandler::new),
                         Default::default(),
                     )
                     .await?;
                 indexer
-                    .concurrent_pipeline(ProtocolFeesWithdrawnHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(DeepbookPoolConfigUpdatedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(SupplierCapMintedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(MaintainerFeesWithdrawnHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(SupplyReferralMintedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(ProtocolFeesWithdrawnHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(PauseCapUpdatedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(SupplierCapMintedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(CurrentPriceUpdatedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(SupplyReferralMintedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(PriceToleranceUpdatedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(PauseCapUpdatedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(MaxPriceAgeUpdatedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(CurrentPriceUpdatedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(ProtocolFeesIncreasedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(PriceToleranceUpdatedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(ReferralFeesClaimedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(MaxPriceAgeUpdatedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(ProtocolFeesIncreasedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(ReferralFeesClaimedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
 
                 // Collateral Events
                 indexer
-                    .concurrent_pipeline(DepositCollateralHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(DepositCollateralHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
-                    .concurrent_pipeline(WithdrawCollateralHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(WithdrawCollateralHandler::new),
+                        Default::default(),
+                    )
                     .await?;
 
                 // TPSL (Take Profit / Stop Loss) Events
                 indexer
-                    .concurrent_pipeline(ConditionalOrderAddedHandler::new(env), Default::default())
+                    .concurrent_pipeline(
+                        router.subscribe(ConditionalOrderAddedHandler::new),
+                        Default::default(),
+                    )
                     .await?;
                 indexer
                     .concurrent_pipeline(
-                        ConditionalOrderCancelledHandler::new(env),
+                        router.subscribe(ConditionalOrderCancelledHandler::new),
                         Default::default(),
                     )
                     .await?;
                 indexer
                     .concurrent_pipeline(
-                        ConditionalOrderExecutedHandler::new(env),
+                        router.subscribe(ConditionalOrderExecutedHandler::new),
                         Default::default(),
                     )
                     .await?;
                 indexer
                     .concurrent_pipeline(
-                        ConditionalOrderInsufficientFundsHandler::new(env),
+                        router.subscribe(ConditionalOrderInsufficientFundsHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+            }
+            Package::Predict => {
+                // Market lifecycle Events
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictMarketCreatedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictMarketSettledHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+
+                // Order Events
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictOrderMintedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictLiveOrderRedeemedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictSettledOrderRedeemedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictLiquidatedOrderRedeemedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictOrderLiquidatedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+
+                // PLP Vault Events
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictSupplyRequestedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictWithdrawRequestedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictRequestCancelledHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictSupplyFilledHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictWithdrawFilledHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+
+                // Builder Code Events
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictBuilderCodeCreatedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictBuilderCodeSetHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PredictBuilderFeesClaimedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+            }
+            Package::Propbook => {
+                // Registry Events
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PropbookOracleSourceRegisteredHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PropbookOracleBoundHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PropbookOracleReboundHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PropbookBlockScholesStoresRegisteredHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+
+                // Feed Events
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PropbookOracleObservationsHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PropbookBlockScholesObservationsHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(PropbookBlockScholesBatchIngestedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+            }
+            Package::Account => {
+                // Account lifecycle Events
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(AccountCreatedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(DeepbookCoreAccountInitializedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+
+                // App authorization Events
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(AccountAppAuthorizedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(AccountAppDeauthorizedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+
+                // Custody Events
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(AccountDepositedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(AccountWithdrawnHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(AccountFundsSettledHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+
+                // Session Events
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(AccountSessionAuthorizedHandler::new),
+                        Default::default(),
+                    )
+                    .await?;
+                indexer
+                    .concurrent_pipeline(
+                        router.subscribe(AccountSessionRevokedHandler::new),
                         Default::default(),
                     )
                     .await?;
@@ -453,18 +1024,28 @@ async fn main() -> Result<(), anyhow::Error> {
         }
     }
 
-    let materialized_view_refresh_service =
-        deepbook_indexer::materialized_view_refresh::materialized_view_refresh_service(
-            materialized_view_refresh_db,
-            materialized_view_refresh_metrics,
-            materialized_view_refresh_interval_secs,
-        )?;
+    // Pipelines are subscribed above, so they can replay their events
+    if let Some(replay) = replay {
+        deepbook_indexer::dead_letter::replay_undecodable_events(
+            &replay_db,
+            &router,
+            replay.pipeline.as_deref(),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let scheduled_jobs_service = scheduled_jobs::scheduled_jobs_service(
+        scheduled_jobs_db,
+        scheduled_job_metrics,
+        scheduled_jobs,
+    );
 
     let s_indexer = indexer.run().await?;
     let s_metrics = metrics.run().await?;
     let service = s_indexer.attach(s_metrics);
-    let service = if let Some(s_materialized_view_refresh) = materialized_view_refresh_service {
-        service.attach(s_materialized_view_refresh)
+    let service = if let Some(s_scheduled_jobs) = scheduled_jobs_service {
+        service.attach(s_scheduled_jobs)
     } else {
         service
     };
@@ -952,6 +952,639 @@ pub mod deepbook_margin {
     }
 }
 
+// Margin Liquidation module
+pub mod margin_liquidation {
+    use super::*;
+
+    pub mod liquidation_vault {
+        use super::*;
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct LiquidationByVault {
+            pub vault_id: ObjectID,
+            pub margin_manager_id: ObjectID,
+            pub margin_pool_id: ObjectID,
+            pub base_in: u64,
+            pub base_out: u64,
+            pub quote_in: u64,
+            pub quote_out: u64,
+            pub repay_balance_remaining: u64,
+            pub base_liquidation: bool,
+        }
+
+        impl MoveStruct for LiquidationByVault {
+            const MODULE: &'static str = "liquidation_vault";
+            const NAME: &'static str = "LiquidationByVault";
+        }
+    }
+}
+
+// DeepBook Predict module
+pub mod deepbook_predict {
+    use super::*;
+    use move_core_types::u256::U256;
+
+    pub mod config_events {
+        use super::*;
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct MarketCreated {
+            pub expiry_market_id: ObjectID,
+            pub pool_vault_id: ObjectID,
+            pub propbook_underlying_id: u32,
+            pub expiry: u64,
+            pub tick_size: u64,
+            pub admission_tick_size: u64,
+            pub max_expiry_allocation: u64,
+            pub initial_expiry_cash: u64,
+            pub liquidation_ltv: u64,
+            pub max_admission_leverage: u64,
+            pub backing_buffer_lambda: u64,
+            pub base_fee: u64,
+            pub min_fee: u64,
+            pub min_entry_probability: u64,
+            pub max_entry_probability: u64,
+            pub expiry_fee_window_ms: u64,
+            pub expiry_fee_max_multiplier: u64,
+            pub no_leverage_window_ms: u64,
+            pub trading_loss_rebate_rate: u64,
+            pub max_benefit_ratio: u64,
+            pub lower_benefit_power: u64,
+            pub upper_benefit_power: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct MarketSettled {
+            pub expiry_market_id: ObjectID,
+            pub propbook_underlying_id: u32,
+            pub expiry: u64,
+            pub settlement_price: u64,
+            pub settled_at_ms: u64,
+        }
+
+        impl MoveStruct for MarketCreated {
+            const MODULE: &'static str = "config_events";
+            const NAME: &'static str = "MarketCreated";
+        }
+
+        impl MoveStruct for MarketSettled {
+            const MODULE: &'static str = "config_events";
+            const NAME: &'static str = "MarketSettled";
+        }
+    }
+
+    pub mod order_events {
+        use super::*;
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct OrderMinted {
+            pub expiry_market_id: ObjectID,
+            pub account_id: ObjectID,
+            pub order_id: U256,
+            pub position_root_id: U256,
+            pub owner: Address,
+            pub lower_tick: u64,
+            pub higher_tick: u64,
+            pub leverage: u64,
+            pub entry_probability: u64,
+            pub quantity: u64,
+            pub net_premium: u64,
+            pub trading_fee: u64,
+            pub fee_incentive_subsidy: u64,
+            pub builder_fee: u64,
+            pub penalty_fee: u64,
+            pub builder_code_id: Option<ObjectID>,
+            pub minted_at_ms: u64,
+            pub pyth_spot_source_timestamp_ms: u64,
+            pub block_scholes_spot_source_timestamp_ms: u64,
+            pub block_scholes_forward_source_timestamp_ms: u64,
+            pub block_scholes_svi_source_timestamp_ms: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct LiveOrderRedeemed {
+            pub expiry_market_id: ObjectID,
+            pub account_id: ObjectID,
+            pub order_id: U256,
+            pub position_root_id: U256,
+            pub owner: Address,
+            pub quantity_closed: u64,
+            pub remaining_quantity: u64,
+            pub replacement_order_id: Option<U256>,
+            pub redeem_amount: u64,
+            pub trading_fee: u64,
+            pub builder_fee: u64,
+            pub penalty_fee: u64,
+            pub builder_code_id: Option<ObjectID>,
+            pub redeemed_at_ms: u64,
+            pub pyth_spot_source_timestamp_ms: u64,
+            pub block_scholes_spot_source_timestamp_ms: u64,
+            pub block_scholes_forward_source_timestamp_ms: u64,
+            pub block_scholes_svi_source_timestamp_ms: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct SettledOrderRedeemed {
+            pub expiry_market_id: ObjectID,
+            pub account_id: ObjectID,
+            pub order_id: U256,
+            pub position_root_id: U256,
+            pub owner: Address,
+            pub quantity_closed: u64,
+            pub settlement_price: u64,
+            pub payout_amount: u64,
+            pub redeemed_at_ms: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct LiquidatedOrderRedeemed {
+            pub expiry_market_id: ObjectID,
+            pub account_id: ObjectID,
+            pub order_id: U256,
+            pub position_root_id: U256,
+            pub owner: Address,
+            pub quantity_closed: u64,
+            pub redeemed_at_ms: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct OrderLiquidated {
+            pub expiry_market_id: ObjectID,
+            pub order_id: U256,
+            pub quantity: u64,
+            pub gross_value: u64,
+            pub floor_amount: u64,
+            pub liquidation_ltv: u64,
+            pub liquidated_at_ms: u64,
+            pub pyth_spot_source_timestamp_ms: u64,
+            pub block_scholes_spot_source_timestamp_ms: u64,
+            pub block_scholes_forward_source_timestamp_ms: u64,
+            pub block_scholes_svi_source_timestamp_ms: u64,
+        }
+
+        impl MoveStruct for OrderMinted {
+            const MODULE: &'static str = "order_events";
+            const NAME: &'static str = "OrderMinted";
+        }
+
+        impl MoveStruct for LiveOrderRedeemed {
+            const MODULE: &'static str = "order_events";
+            const NAME: &'static str = "LiveOrderRedeemed";
+        }
+
+        impl MoveStruct for SettledOrderRedeemed {
+            const MODULE: &'static str = "order_events";
+            const NAME: &'static str = "SettledOrderRedeemed";
+        }
+
+        impl MoveStruct for LiquidatedOrderRedeemed {
+            const MODULE: &'static str = "order_events";
+            const NAME: &'static str = "LiquidatedOrderRedeemed";
+        }
+
+        impl MoveStruct for OrderLiquidated {
+            const MODULE: &'static str = "order_events";
+            const NAME: &'static str = "OrderLiquidated";
+        }
+    }
+
+    pub mod vault_events {
+        use super::*;
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct SupplyRequested {
+            pub pool_vault_id: ObjectID,
+            pub account_id: ObjectID,
+            pub recipient: Address,
+            pub index: u64,
+            pub amount: u64,
+            pub min_plp_out: u64,
+            pub requests_pending_after: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct WithdrawRequested {
+            pub pool_vault_id: ObjectID,
+            pub account_id: ObjectID,
+            pub recipient: Address,
+            pub index: u64,
+            pub amount: u64,
+            pub min_dusdc_out: u64,
+            pub requests_pending_after: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct RequestCancelled {
+            pub pool_vault_id: ObjectID,
+            pub account_id: ObjectID,
+            pub recipient: Address,
+            pub index: u64,
+            pub amount: u64,
+            pub is_supply: bool,
+            pub reason: u8,
+            pub requests_pending_after: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct SupplyFilled {
+            pub pool_vault_id: ObjectID,
+            pub account_id: ObjectID,
+            pub recipient: Address,
+            pub index: u64,
+            pub dusdc_amount: u64,
+            pub shares_minted: u64,
+            pub fee_dusdc: u64,
+            pub dusdc_remaining: u64,
+            pub requests_pending_after: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct WithdrawFilled {
+            pub pool_vault_id: ObjectID,
+            pub account_id: ObjectID,
+            pub recipient: Address,
+            pub index: u64,
+            pub shares_burned: u64,
+            pub dusdc_amount: u64,
+            pub fee_dusdc: u64,
+            pub shares_remaining: u64,
+            pub requests_pending_after: u64,
+        }
+
+        impl MoveStruct for SupplyRequested {
+            const MODULE: &'static str = "vault_events";
+            const NAME: &'static str = "SupplyRequested";
+        }
+
+        impl MoveStruct for WithdrawRequested {
+            const MODULE: &'static str = "vault_events";
+            const NAME: &'static str = "WithdrawRequested";
+        }
+
+        impl MoveStruct for RequestCancelled {
+            const MODULE: &'static str = "vault_events";
+            const NAME: &'static str = "RequestCancelled";
+        }
+
+        impl MoveStruct for SupplyFilled {
+            const MODULE: &'static str = "vault_events";
+            const NAME: &'static str = "SupplyFilled";
+        }
+
+        impl MoveStruct for WithdrawFilled {
+            const MODULE: &'static str = "vault_events";
+            const NAME: &'static str = "WithdrawFilled";
+        }
+    }
+
+    pub mod builder_code_events {
+        use super::*;
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct BuilderCodeCreated {
+            pub builder_code_id: ObjectID,
+            pub owner: Address,
+            pub builder_code_index: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct BuilderCodeSet {
+            pub account_id: ObjectID,
+            pub owner: Address,
+            pub builder_code_id: Option<ObjectID>,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct BuilderFeesClaimed {
+            pub builder_code_id: ObjectID,
+            pub owner: Address,
+            pub amount: u64,
+        }
+
+        impl MoveStruct for BuilderCodeCreated {
+            const MODULE: &'static str = "builder_code_events";
+            const NAME: &'static str = "BuilderCodeCreated";
+        }
+
+        impl MoveStruct for BuilderCodeSet {
+            const MODULE: &'static str = "builder_code_events";
+            const NAME: &'static str = "BuilderCodeSet";
+        }
+
+        impl MoveStruct for BuilderFeesClaimed {
+            const MODULE: &'static str = "builder_code_events";
+            const NAME: &'static str = "BuilderFeesClaimed";
+        }
+    }
+}
+
+// Propbook module
+pub mod propbook {
+    use super::*;
+    use move_core_types::u256::U256;
+
+    pub mod registry {
+        use super::*;
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct OracleSourceRegistered {
+            pub oracle_kind: u8,
+            pub source_id: u32,
+            pub propbook_oracle_id: ObjectID,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct OracleBound {
+            pub propbook_underlying_id: u32,
+            pub oracle_kind: u8,
+            pub source_id: u32,
+            pub propbook_oracle_id: ObjectID,
+            pub value_kind: u8,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct BlockScholesStoresRegistered {
+            pub propbook_underlying_id: u32,
+            pub value_store_id: ObjectID,
+            pub svi_store_id: ObjectID,
+            pub block_scholes_base_asset: String,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct OracleRebound {
+            pub propbook_underlying_id: u32,
+            pub oracle_kind: u8,
+            pub value_kind: u8,
+            pub old_source_id: u32,
+            pub old_propbook_oracle_id: ObjectID,
+            pub new_source_id: u32,
+            pub new_propbook_oracle_id: ObjectID,
+        }
+
+        impl MoveStruct for OracleSourceRegistered {
+            const MODULE: &'static str = "registry";
+            const NAME: &'static str = "OracleSourceRegistered";
+        }
+
+        impl MoveStruct for OracleBound {
+            const MODULE: &'static str = "registry";
+            const NAME: &'static str = "OracleBound";
+        }
+
+        impl MoveStruct for BlockScholesStoresRegistered {
+            const MODULE: &'static str = "registry";
+            const NAME: &'static str = "BlockScholesStoresRegistered";
+        }
+
+        impl MoveStruct for OracleRebound {
+            const MODULE: &'static str = "registry";
+            const NAME: &'static str = "OracleRebound";
+        }
+    }
+
+    pub mod block_scholes_store {
+        use super::*;
+
+        /// Block Scholes observation envelope. `V` is `u128` for spot/forward series and
+        /// [`SVIParams`] for SVI series.
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct BsRead<V> {
+            pub model_timestamp_ms: u64,
+            pub published_at_ms: u64,
+            pub recorded_at_ms: u64,
+            pub writer_digest: Vec<u8>,
+            pub value: V,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct SVIParams {
+            pub a_magnitude: u128,
+            pub a_is_negative: bool,
+            pub b: u128,
+            pub sigma: u128,
+            pub rho_magnitude: u128,
+            pub rho_is_negative: bool,
+            pub m_magnitude: u128,
+            pub m_is_negative: bool,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct BlockScholesObservationRecorded<Observation> {
+            pub propbook_oracle_id: ObjectID,
+            pub sid: U256,
+            pub series_kind: u8,
+            pub expiry_ms: u64,
+            pub observation: Observation,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct BlockScholesBatchIngested {
+            pub propbook_oracle_id: ObjectID,
+            pub series_kind: u8,
+            pub published_at_ms: u64,
+            pub update_count: u64,
+            pub applied: u64,
+        }
+
+        impl<Observation: Serialize> MoveStruct for BlockScholesObservationRecorded<Observation> {
+            const MODULE: &'static str = "block_scholes_store";
+            const NAME: &'static str = "BlockScholesObservationRecorded";
+        }
+
+        impl MoveStruct for BlockScholesBatchIngested {
+            const MODULE: &'static str = "block_scholes_store";
+            const NAME: &'static str = "BlockScholesBatchIngested";
+        }
+    }
+
+    pub mod oracle_lane {
+        use super::*;
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct OracleRead<V> {
+            pub source_timestamp_ms: u64,
+            pub update_timestamp_ms: u64,
+            pub writer_digest: Vec<u8>,
+            pub value: V,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct ObservationRecorded<Observation> {
+            pub propbook_oracle_id: ObjectID,
+            pub observation: Observation,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct ObservationInserted<Observation> {
+            pub propbook_oracle_id: ObjectID,
+            pub observation: Observation,
+        }
+
+        impl<Observation: Serialize> MoveStruct for ObservationRecorded<Observation> {
+            const MODULE: &'static str = "oracle_lane";
+            const NAME: &'static str = "ObservationRecorded";
+        }
+
+        impl<Observation: Serialize> MoveStruct for ObservationInserted<Observation> {
+            const MODULE: &'static str = "oracle_lane";
+            const NAME: &'static str = "ObservationInserted";
+        }
+    }
+
+    pub mod pyth_feed {
+        use super::*;
+
+        /// Oracle lane payload of a Pyth feed. Only ever decoded as the value of an
+        /// `OracleRead`, so it has no `MoveStruct` impl.
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct RawSpot {
+            pub pyth_source_id: u32,
+            pub price_magnitude: u64,
+            pub price_is_negative: bool,
+            pub exponent_magnitude: u16,
+            pub exponent_is_negative: bool,
+            pub feed_update_timestamp_us: u64,
+        }
+    }
+}
+
+// Account module
+pub mod account {
+    use super::*;
+
+    pub mod account_events {
+        use super::*;
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct AccountCreated {
+            pub account_id: ObjectID,
+            pub wrapper_id: ObjectID,
+            pub owner: Address,
+            pub self_owned: bool,
+            pub referrer_account_id: Option<ObjectID>,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct AppAuthorized {
+            pub app: String,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct AppDeauthorized {
+            pub app: String,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct Deposited {
+            pub account_id: ObjectID,
+            pub coin_type: String,
+            pub amount: u64,
+            pub new_balance: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct Withdrawn {
+            pub account_id: ObjectID,
+            pub coin_type: String,
+            pub amount: u64,
+            pub new_balance: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct FundsSettled {
+            pub account_id: ObjectID,
+            pub coin_type: String,
+            pub amount: u64,
+            pub new_balance: u64,
+        }
+
+        impl MoveStruct for AccountCreated {
+            const MODULE: &'static str = "account_events";
+            const NAME: &'static str = "AccountCreated";
+        }
+
+        impl MoveStruct for AppAuthorized {
+            const MODULE: &'static str = "account_events";
+            const NAME: &'static str = "AppAuthorized";
+        }
+
+        impl MoveStruct for AppDeauthorized {
+            const MODULE: &'static str = "account_events";
+            const NAME: &'static str = "AppDeauthorized";
+        }
+
+        impl MoveStruct for Deposited {
+            const MODULE: &'static str = "account_events";
+            const NAME: &'static str = "Deposited";
+        }
+
+        impl MoveStruct for Withdrawn {
+            const MODULE: &'static str = "account_events";
+            const NAME: &'static str = "Withdrawn";
+        }
+
+        impl MoveStruct for FundsSettled {
+            const MODULE: &'static str = "account_events";
+            const NAME: &'static str = "FundsSettled";
+        }
+    }
+}
+
+// DeepBook Core Account module
+pub mod deepbook_core_account {
+    use super::*;
+
+    pub mod account_data {
+        use super::*;
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct DeepbookCoreAccountInitialized {
+            pub account_id: ObjectID,
+            pub account_owner: Address,
+            pub wrapper_id: ObjectID,
+            pub balance_manager_id: ObjectID,
+        }
+
+        impl MoveStruct for DeepbookCoreAccountInitialized {
+            const MODULE: &'static str = "account_data";
+            const NAME: &'static str = "DeepbookCoreAccountInitialized";
+        }
+    }
+}
+
+// DeepBook Sessions module
+pub mod deepbook_sessions {
+    use super::*;
+
+    pub mod sessions {
+        use super::*;
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct SessionAuthorized {
+            pub account_id: ObjectID,
+            pub session: Address,
+            pub expires_at_ms: u64,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct SessionRevoked {
+            pub account_id: ObjectID,
+            pub session: Address,
+            pub expires_at_ms: u64,
+        }
+
+        impl MoveStruct for SessionAuthorized {
+            const MODULE: &'static str = "sessions";
+            const NAME: &'static str = "SessionAuthorized";
+        }
+
+        impl MoveStruct for SessionRevoked {
+            const MODULE: &'static str = "sessions";
+            const NAME: &'static str = "SessionRevoked";
+        }
+    }
+}
+
 // SUI module
 pub mod sui {
     pub mod sui {
@@ -0,0 +1,183 @@
+//! Package upgrade discovery.
+//!
+//! Follows the upgrade lineage of every package in the registry: when a transaction writes a
+//! package whose original ID is a known package, the new version is added to the addresses
+//! events are matched against. `PackageVersionsHandler` scans every checkpoint for upgrades
+//! before any other pipeline may route it (see `EventRouter::scan`) and persists them to
+//! `package_versions`. At startup, [`load_discovered_versions`] feeds them back in so they are
+//! known before any checkpoint is processed, and [`next_unscanned_checkpoint`] tells where the
+//! scan resumes.
+
+use crate::handlers::package_versions_handler::PackageVersionsHandler;
+use crate::{package_registry, DeepbookEnv};
+use anyhow::Context;
+use deepbook_package_registry::PackageFamily;
+use deepbook_schema::schema::{package_versions, watermarks};
+use diesel::{OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::RwLock;
+use sui_indexer_alt_framework::pipeline::Processor;
+use sui_indexer_alt_framework::types::full_checkpoint_content::{ExecutedTransaction, ObjectSet};
+use sui_pg_db::Db;
+use sui_types::base_types::ObjectID;
+
+/// Package lineages of one network.
+#[derive(Default)]
+struct Lineages {
+    /// Every known package ID (registry and discovered) and the family it belongs to.
+    known: BTreeMap<ObjectID, PackageFamily>,
+    /// Versions discovered at runtime, in the order they were found.
+    discovered: BTreeMap<PackageFamily, Vec<String>>,
+}
+
+impl Lineages {
+    fn from_registry(env: DeepbookEnv) -> Self {
+        let mut lineages = Self::default();
+        for family in PackageFamily::ALL {
+            for id in package_registry().package_ids(env.name(), family) {
+                if let Ok(id) = ObjectID::from_str(id) {
+                    lineages.known.insert(id, family);
+                }
+            }
+        }
+        lineages
+    }
+}
+
+static LINEAGES: RwLock<BTreeMap<&'static str, Lineages>> = RwLock::new(BTreeMap::new());
+
+/// A new version of a registry package, written by a package upgrade.
+#[derive(Debug, Clone)]
+pub struct PackageUpgrade {
+    pub family: PackageFamily,
+    pub package_id: ObjectID,
+    pub original_package_id: ObjectID,
+    pub version: u64,
+}
+
+/// Package versions of `family` discovered at runtime for the given environment
+pub fn discovered_package_ids(env: DeepbookEnv, family: PackageFamily) -> Vec<String> {
+    let lineages = LINEAGES.read().unwrap();
+    lineages
+        .get(env.name())
+        .and_then(|lineages| lineages.discovered.get(&family))
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Record `package_id` as a version of `family`. Returns true if it was not known yet.
+pub fn record_package_version(
+    env: DeepbookEnv,
+    family: PackageFamily,
+    package_id: ObjectID,
+) -> bool {
+    {
+        let mut lineages = LINEAGES.write().unwrap();
+        let lineages = lineages
+            .entry(env.name())
+            .or_insert_with(|| Lineages::from_registry(env));
+        if lineages.known.contains_key(&package_id) {
+            return false;
+        }
+        lineages.known.insert(package_id, family);
+        lineages
+            .discovered
+            .entry(family)
+            .or_default()
+            .push(package_id.to_string());
+    }
+    tracing::info!(
+        family = family.as_str(),
+        %package_id,
+        "Discovered new package version"
+    );
+    true
+}
+
+fn family_of(env: DeepbookEnv, package_id: &ObjectID) -> Option<PackageFamily> {
+    if let Some(lineages) = LINEAGES.read().unwrap().get(env.name()) {
+        return lineages.known.get(package_id).copied();
+    }
+    let mut lineages = LINEAGES.write().unwrap();
+    let lineages = lineages
+        .entry(env.name())
+        .or_insert_with(|| Lineages::from_registry(env));
+    lineages.known.get(package_id).copied()
+}
+
+/// Find upgrades of known packages written by `tx` and record them. Every matching upgrade is
+/// returned, whether or not it had already been recorded.
+pub fn discover_upgrades(
+    tx: &ExecutedTransaction,
+    checkpoint_objects: &ObjectSet,
+    env: DeepbookEnv,
+) -> Vec<PackageUpgrade> {
+    let mut upgrades = vec![];
+    for obj in tx.output_objects(checkpoint_objects) {
+        let Some(package) = obj.data.try_as_package() else {
+            continue;
+        };
+        let original_package_id = package.original_package_id();
+        // A fresh publish starts its own lineage; only upgrades of known packages are followed.
+        if package.id() == original_package_id {
+            continue;
+        }
+        let Some(family) = family_of(env, &original_package_id) else {
+            continue;
+        };
+        record_package_version(env, family, package.id());
+        upgrades.push(PackageUpgrade {
+            family,
+            package_id: package.id(),
+            original_package_id,
+            version: package.version().value(),
+        });
+    }
+    upgrades
+}
+
+/// Load the versions persisted by previous runs, so events from them are matched from the first
+/// checkpoint on.
+pub async fn load_discovered_versions(db: &Db, env: DeepbookEnv) -> anyhow::Result<usize> {
+    let mut conn = db
+        .connect()
+        .await
+        .context("Failed to connect to database to load package versions")?;
+    let rows: Vec<(String, String)> = package_versions::table
+        .select((package_versions::family, package_versions::package_id))
+        .order_by(package_versions::checkpoint)
+        .load(&mut conn)
+        .await
+        .context("Failed to load package versions")?;
+
+    let mut loaded = 0;
+    for (family, package_id) in rows {
+        let family = PackageFamily::from_str(&family)?;
+        let package_id = ObjectID::from_str(&package_id)
+            .with_context(|| format!("Invalid package ID in package_versions: {package_id}"))?;
+        if record_package_version(env, family, package_id) {
+            loaded += 1;
+        }
+    }
+    Ok(loaded)
+}
+
+/// The first checkpoint `PackageVersionsHandler` has not committed, where discovery resumes:
+/// `first_checkpoint` if the pipeline never ran. Versions it found past that point and already
+/// wrote are loaded by [`load_discovered_versions`], so scanning them again is harmless.
+pub async fn next_unscanned_checkpoint(db: &Db, first_checkpoint: u64) -> anyhow::Result<u64> {
+    let mut conn = db
+        .connect()
+        .await
+        .context("Failed to connect to database to load the package_versions watermark")?;
+    let checkpoint_hi_inclusive: Option<i64> = watermarks::table
+        .find(PackageVersionsHandler::NAME)
+        .select(watermarks::checkpoint_hi_inclusive)
+        .first(&mut conn)
+        .await
+        .optional()
+        .context("Failed to load the package_versions watermark")?;
+    Ok(checkpoint_hi_inclusive.map_or(first_checkpoint, |hi| hi as u64 + 1))
+}
@@ -0,0 +1,552 @@
+//! Database maintenance jobs the indexer runs on a schedule.
+//!
+//! Each job has its own cadence and timeout, and runs on whichever indexer replica first takes
+//! its Postgres advisory lock, so several replicas sharing a database never run the same job at
+//! once. The time of the last run is recorded in `scheduled_job_runs` under that lock, and a
+//! replica whose turn comes before the interval has passed since skips it, so the job runs once
+//! per interval however many replicas there are. Jobs are listed in a TOML file; the one compiled
+//! into the binary ([`BUILTIN_JOBS`]) refreshes `net_deposits_hourly` every minute.
+
+use anyhow::{bail, Context};
+use diesel::sql_types::{BigInt, Bool, Text};
+use diesel::{OptionalExtension, QueryableByName};
+use diesel_async::RunQueryDsl;
+use prometheus::{
+    register_histogram_vec_with_registry, register_int_counter_vec_with_registry, HistogramVec,
+    IntCounterVec, Registry,
+};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use sui_futures::service::Service;
+use sui_pg_db::Db;
+use tokio::time::{interval, MissedTickBehavior};
+
+/// The jobs run when no `--scheduled-jobs-config` is given.
+pub const BUILTIN_JOBS: &str = include_str!("../scheduled_jobs.toml");
+
+const JOB_DURATION_SEC_BUCKETS: &[f64] = &[
+    0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0,
+];
+
+/// Namespaces the advisory lock keys of jobs from any other advisory locks on the database.
+const LOCK_KEY_PREFIX: &str = "deepbook_indexer_job:";
+
+/// Job metrics, labeled by `job`, the job name. They keep the names they had when materialized
+/// view refreshes were the only job, but that label replaced `view`: the built-in refresh is now
+/// `job="refresh_net_deposits_hourly"` rather than `view="net_deposits_hourly"`, and dashboards
+/// and alerts selecting on the old label need updating.
+#[derive(Clone)]
+pub struct ScheduledJobMetrics {
+    pub job_duration: HistogramVec,
+    pub job_successes: IntCounterVec,
+    pub job_failures: IntCounterVec,
+}
+
+impl ScheduledJobMetrics {
+    pub fn new(registry: &Registry) -> Arc<Self> {
+        Arc::new(Self {
+            job_duration: register_histogram_vec_with_registry!(
+                "materialized_view_refresh_duration_seconds",
+                "Time taken to run scheduled jobs by job",
+                &["job"],
+                JOB_DURATION_SEC_BUCKETS.to_vec(),
+                registry
+            )
+            .unwrap(),
+            job_successes: register_int_counter_vec_with_registry!(
+                "materialized_view_refresh_successes_total",
+                "Number of successful scheduled job runs by job",
+                &["job"],
+                registry
+            )
+            .unwrap(),
+            job_failures: register_int_counter_vec_with_registry!(
+                "materialized_view_refresh_failures_total",
+                "Number of failed scheduled job runs by job",
+                &["job"],
+                registry
+            )
+            .unwrap(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct JobsFile {
+    #[serde(default)]
+    jobs: Vec<JobConfig>,
+}
+
+/// One job, as listed in the jobs file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobConfig {
+    /// Unique name, used as the metrics label and for the advisory lock
+    pub name: String,
+    /// Seconds between runs. 0 disables the job.
+    pub interval_secs: u64,
+    /// Seconds a run may take before Postgres cancels it
+    pub timeout_secs: u64,
+    #[serde(flatten)]
+    pub kind: JobKind,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum JobKind {
+    /// `REFRESH MATERIALIZED VIEW CONCURRENTLY view`
+    RefreshMaterializedView { view: String },
+    /// `CALL procedure(start_ms, end_ms)` over the last `window_secs`
+    CallProcedure { procedure: String, window_secs: u64 },
+    /// `DELETE FROM table WHERE timestamp_column < now - retention_secs`, for a column holding
+    /// milliseconds since the epoch
+    PruneTable {
+        table: String,
+        timestamp_column: String,
+        retention_secs: u64,
+    },
+}
+
+/// Load the jobs listed in `path`, or the built-in jobs without one.
+pub fn load_jobs(path: Option<&Path>) -> anyhow::Result<Vec<JobConfig>> {
+    match path {
+        Some(path) => parse_jobs(
+            &std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read scheduled jobs {}", path.display()))?,
+            &path.display().to_string(),
+        ),
+        None => parse_jobs(BUILTIN_JOBS, "built-in jobs"),
+    }
+}
+
+/// Run the materialized view refreshes among `jobs` every `interval_secs` instead, or never if it
+/// is 0. Backs the deprecated `--materialized-view-refresh-interval-secs`, which set the interval
+/// of the only refresh there was.
+pub fn set_refresh_interval(jobs: &mut [JobConfig], interval_secs: u64) {
+    for job in jobs {
+        if let JobKind::RefreshMaterializedView { .. } = job.kind {
+            job.interval_secs = interval_secs;
+        }
+    }
+}
+
+fn parse_jobs(contents: &str, source: &str) -> anyhow::Result<Vec<JobConfig>> {
+    let file: JobsFile = toml::from_str(contents)
+        .with_context(|| format!("Failed to parse scheduled jobs from {source}"))?;
+
+    let mut names = HashSet::new();
+    for job in &file.jobs {
+        if !names.insert(job.name.as_str()) {
+            bail!("Scheduled job `{}` is listed twice in {source}", job.name);
+        }
+        // Validates the identifiers the job interpolates into SQL
+        job.kind.sql(0)?;
+    }
+    Ok(file.jobs)
+}
+
+impl JobKind {
+    /// The statement to run at `now_ms`.
+    fn sql(&self, now_ms: i64) -> anyhow::Result<String> {
+        Ok(match self {
+            JobKind::RefreshMaterializedView { view } => {
+                format!(
+                    "REFRESH MATERIALIZED VIEW CONCURRENTLY {}",
+                    quote_name(view)?
+                )
+            }
+            JobKind::CallProcedure {
+                procedure,
+                window_secs,
+            } => {
+                let start_ms = now_ms - (*window_secs as i64) * 1000;
+                format!(
+                    "CALL {}({start_ms}::BIGINT, {now_ms}::BIGINT)",
+                    quote_name(procedure)?
+                )
+            }
+            JobKind::PruneTable {
+                table,
+                timestamp_column,
+                retention_secs,
+            } => {
+                let cutoff_ms = now_ms - (*retention_secs as i64) * 1000;
+                format!(
+                    "DELETE FROM {} WHERE {} < {cutoff_ms}",
+                    quote_name(table)?,
+                    quote_name(timestamp_column)?
+                )
+            }
+        })
+    }
+}
+
+/// Quote a possibly schema-qualified name, rejecting anything but plain identifiers.
+fn quote_name(raw_name: &str) -> anyhow::Result<String> {
+    let name = raw_name.trim();
+    if name.is_empty() {
+        bail!("Invalid name: name cannot be empty");
+    }
+
+    let mut quoted_parts = Vec::new();
+    for part in name.split('.') {
+        if !is_valid_identifier(part) {
+            bail!("Invalid name `{name}`");
+        }
+        quoted_parts.push(format!("\"{part}\""));
+    }
+    Ok(quoted_parts.join("."))
+}
+
+fn is_valid_identifier(identifier: &str) -> bool {
+    let mut chars = identifier.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+
+    if !(first == '_' || first.is_ascii_alphabetic()) {
+        return false;
+    }
+
+    chars.all(|char| char == '_' || char.is_ascii_alphanumeric())
+}
+
+#[derive(QueryableByName)]
+struct LockAcquired {
+    #[diesel(sql_type = Bool)]
+    acquired: bool,
+}
+
+#[derive(QueryableByName)]
+struct LastRun {
+    #[diesel(sql_type = BigInt)]
+    last_run_at_ms: i64,
+}
+
+/// What a run did, when it did not fail.
+enum RunOutcome {
+    Ran,
+    /// Another replica holds the job's lock
+    Skipped,
+    /// Another replica ran the job less than an interval ago
+    NotDue,
+}
+
+/// Run `job` once if its advisory lock is free and no replica ran it in the last interval.
+///
+/// The lock is taken at session level, since `REFRESH MATERIALIZED VIEW CONCURRENTLY` cannot run
+/// in a transaction, so it is released on the same connection afterwards. The timeout is a
+/// `statement_timeout` rather than a client-side one: Postgres cancels the statement itself, and
+/// the unlock still runs before the connection goes back to the pool.
+async fn run_job_once(db: &Db, job: &JobConfig) -> anyhow::Result<RunOutcome> {
+    let mut conn = db
+        .connect()
+        .await
+        .with_context(|| format!("Failed to connect to database for job {}", job.name))?;
+    let lock_key = format!("{LOCK_KEY_PREFIX}{}", job.name);
+
+    let LockAcquired { acquired } =
+        diesel::sql_query("SELECT pg_try_advisory_lock(hashtext($1)) AS acquired")
+            .bind::<Text, _>(&lock_key)
+            .get_result(&mut conn)
+            .await
+            .with_context(|| format!("Failed to take the lock of job {}", job.name))?;
+    if !acquired {
+        return Ok(RunOutcome::Skipped);
+    }
+
+    let now_ms = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as i64;
+    let result = async {
+        let last_run: Option<LastRun> =
+            diesel::sql_query("SELECT last_run_at_ms FROM scheduled_job_runs WHERE name = $1")
+                .bind::<Text, _>(&job.name)
+                .get_result(&mut conn)
+                .await
+                .optional()?;
+        // A little short of the interval, so that the replica that ran last time is not put
+        // off by its own run when connecting and locking took longer than then
+        let due_after_ms = job.interval_secs.saturating_mul(900) as i64;
+        if last_run.is_some_and(|run| now_ms - run.last_run_at_ms < due_after_ms) {
+            return Ok(RunOutcome::NotDue);
+        }
+
+        diesel::sql_query(format!(
+            "SET statement_timeout = {}",
+            job.timeout_secs.saturating_mul(1000)
+        ))
+        .execute(&mut conn)
+        .await?;
+        let result = diesel::sql_query(job.kind.sql(now_ms)?)
+            .execute(&mut conn)
+            .await;
+        diesel::sql_query("RESET statement_timeout")
+            .execute(&mut conn)
+            .await?;
+        result?;
+
+        diesel::sql_query(
+            "INSERT INTO scheduled_job_runs (name, last_run_at_ms) VALUES ($1, $2) \
+             ON CONFLICT (name) DO UPDATE SET last_run_at_ms = excluded.last_run_at_ms",
+        )
+        .bind::<Text, _>(&job.name)
+        .bind::<BigInt, _>(now_ms)
+        .execute(&mut conn)
+        .await?;
+        anyhow::Ok(RunOutcome::Ran)
+    }
+    .await
+    .with_context(|| format!("Failed to run job {}", job.name));
+
+    diesel::sql_query("SELECT pg_advisory_unlock(hashtext($1))")
+        .bind::<Text, _>(&lock_key)
+        .execute(&mut conn)
+        .await
+        .with_context(|| format!("Failed to release the lock of job {}", job.name))?;
+
+    result
+}
+
+/// A service running every enabled job in `jobs` on its own schedule, or `None` if none are.
+pub fn scheduled_jobs_service(
+    db: Db,
+    metrics: Arc<ScheduledJobMetrics>,
+    jobs: Vec<JobConfig>,
+) -> Option<Service> {
+    let jobs: Vec<_> = jobs
+        .into_iter()
+        .filter(|job| job.interval_secs > 0)
+        .collect();
+    if jobs.is_empty() {
+        tracing::info!("Scheduled jobs disabled because no jobs were configured");
+        return None;
+    }
+
+    let job_names = jobs
+        .iter()
+        .map(|job| job.name.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+    tracing::info!(jobs = job_names, "Starting scheduled jobs");
+
+    Some(jobs.into_iter().fold(Service::new(), |service, job| {
+        service.spawn_aborting(run_job_loop(db.clone(), metrics.clone(), job))
+    }))
+}
+
+async fn run_job_loop(
+    db: Db,
+    metrics: Arc<ScheduledJobMetrics>,
+    job: JobConfig,
+) -> anyhow::Result<()> {
+    let mut ticker = interval(Duration::from_secs(job.interval_secs));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+
+        let started_at = Instant::now();
+        let timer = metrics
+            .job_duration
+            .with_label_values(&[job.name.as_str()])
+            .start_timer();
+        let result = run_job_once(&db, &job).await;
+        let duration_secs = started_at.elapsed().as_secs_f64();
+
+        match result {
+            Ok(RunOutcome::Ran) => {
+                timer.observe_duration();
+                metrics
+                    .job_successes
+                    .with_label_values(&[job.name.as_str()])
+                    .inc();
+                tracing::debug!(job = job.name.as_str(), duration_secs, "Ran scheduled job");
+            }
+            Ok(RunOutcome::Skipped) => {
+                timer.stop_and_discard();
+                tracing::debug!(
+                    job = job.name.as_str(),
+                    "Skipped scheduled job held by another replica"
+                );
+            }
+            Ok(RunOutcome::NotDue) => {
+                timer.stop_and_discard();
+                tracing::debug!(
+                    job = job.name.as_str(),
+                    "Skipped scheduled job run recently by another replica"
+                );
+            }
+            Err(error) => {
+                timer.observe_duration();
+                metrics
+                    .job_failures
+                    .with_label_values(&[job.name.as_str()])
+                    .inc();
+                tracing::error!(
+                    ?error,
+                    job = job.name.as_str(),
+                    duration_secs,
+                    "Failed to run scheduled job"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_jobs_load() {
+        let jobs = load_jobs(None).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].name, "refresh_net_deposits_hourly");
+        assert_eq!(jobs[0].interval_secs, 60);
+    }
+
+    #[test]
+    fn load_jobs_rejects_duplicate_names() {
+        let contents = r#"
+            [[jobs]]
+            name = "refresh"
+            kind = "refresh_materialized_view"
+            view = "net_deposits_hourly"
+            interval_secs = 60
+            timeout_secs = 300
+
+            [[jobs]]
+            name = "refresh"
+            kind = "refresh_materialized_view"
+            view = "other_view"
+            interval_secs = 60
+            timeout_secs = 300
+        "#;
+        let error = parse_jobs(contents, "test").unwrap_err();
+        assert!(error.to_string().contains("listed twice"));
+    }
+
+    #[test]
+    fn load_jobs_rejects_invalid_identifiers() {
+        let contents = r#"
+            [[jobs]]
+            name = "prune"
+            kind = "prune_table"
+            table = "events; DROP TABLE pools"
+            timestamp_column = "checkpoint_timestamp_ms"
+            retention_secs = 60
+            interval_secs = 60
+            timeout_secs = 60
+        "#;
+        assert!(parse_jobs(contents, "test").is_err());
+    }
+
+    #[test]
+    fn load_jobs_reads_files() {
+        let path = std::env::temp_dir().join(format!("scheduled_jobs_{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"
+            [[jobs]]
+            name = "update_ohclv_1m"
+            kind = "call_procedure"
+            procedure = "update_ohclv_1m"
+            window_secs = 600
+            interval_secs = 0
+            timeout_secs = 120
+            "#,
+        )
+        .unwrap();
+        let jobs = load_jobs(Some(&path));
+        std::fs::remove_file(&path).unwrap();
+
+        let jobs = jobs.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].interval_secs, 0);
+        assert!(load_jobs(Some(Path::new("/nonexistent/scheduled_jobs.toml"))).is_err());
+    }
+
+    #[test]
+    fn refresh_interval_only_overrides_refreshes() {
+        let contents = r#"
+            [[jobs]]
+            name = "refresh"
+            kind = "refresh_materialized_view"
+            view = "net_deposits_hourly"
+            interval_secs = 60
+            timeout_secs = 300
+
+            [[jobs]]
+            name = "update_ohclv_1m"
+            kind = "call_procedure"
+            procedure = "update_ohclv_1m"
+            window_secs = 600
+            interval_secs = 60
+            timeout_secs = 120
+        "#;
+        let mut jobs = parse_jobs(contents, "test").unwrap();
+        set_refresh_interval(&mut jobs, 0);
+        assert_eq!(jobs[0].interval_secs, 0);
+        assert_eq!(jobs[1].interval_secs, 60);
+    }
+
+    #[test]
+    fn quote_name_quotes_each_part() {
+        assert_eq!(
+            quote_name("net_deposits_hourly").unwrap(),
+            "\"net_deposits_hourly\""
+        );
+        assert_eq!(
+            quote_name(" public.view_1 ").unwrap(),
+            "\"public\".\"view_1\""
+        );
+    }
+
+    #[test]
+    fn quote_name_rejects_anything_but_identifiers() {
+        for name in [
+            "",
+            "  ",
+            "1view",
+            "view-name",
+            "view\"",
+            "public.",
+            "a;b",
+            "a b",
+        ] {
+            assert!(quote_name(name).is_err(), "{name:?} was accepted");
+        }
+    }
+
+    #[test]
+    fn job_sql() {
+        let now_ms = 1_700_000_000_000;
+        let refresh = JobKind::RefreshMaterializedView {
+            view: "net_deposits_hourly".to_string(),
+        };
+        assert_eq!(
+            refresh.sql(now_ms).unwrap(),
+            "REFRESH MATERIALIZED VIEW CONCURRENTLY \"net_deposits_hourly\""
+        );
+
+        let call = JobKind::CallProcedure {
+            procedure: "update_ohclv_1m".to_string(),
+            window_secs: 600,
+        };
+        assert_eq!(
+            call.sql(now_ms).unwrap(),
+            "CALL \"update_ohclv_1m\"(1699999400000::BIGINT, 1700000000000::BIGINT)"
+        );
+
+        let prune = JobKind::PruneTable {
+            table: "undecodable_events".to_string(),
+            timestamp_column: "checkpoint_timestamp_ms".to_string(),
+            retention_secs: 60,
+        };
+        assert_eq!(
+            prune.sql(now_ms).unwrap(),
+            "DELETE FROM \"undecodable_events\" WHERE \"checkpoint_timestamp_ms\" < 1699999940000"
+        );
+    }
+}
@@ -4,10 +4,10 @@
 //! Module definitions and related functions have been moved to lib.rs for centralized configuration.
 
 use serde::Serialize;
-use std::str::FromStr;
-use sui_sdk_types::{Address, Identifier, StructTag};
+use sui_sdk_types::Address;
 
 // Import types and functions from lib.rs
+use crate::event_index::EventKind;
 use crate::{get_module_type, ModuleType};
 
 /// Trait for Move structs that can be matched against event types
@@ -15,63 +15,10 @@ pub trait MoveStruct: Serialize {
     // Event type matching constants
     const MODULE: &'static str;
     const NAME: &'static str;
-    const TYPE_PARAMS: &'static [&'static str] = &[];
-
-    /// Get the list of acceptable package addresses for this event type based on environment
-    fn acceptable_package_addresses(env: crate::DeepbookEnv) -> Result<Vec<Address>, String> {
-        get_package_addresses_for_module(Self::MODULE, env)
-    }
-
-    /// Check if a struct tag matches this event type from any supported package version
-    fn matches_event_type(
-        event_type: &move_core_types::language_storage::StructTag,
-        env: crate::DeepbookEnv,
-    ) -> bool {
-        use move_core_types::account_address::AccountAddress;
-
-        // Get all possible struct types for this event
-        let all_struct_types = Self::get_all_struct_types(env);
-
-        // Check if the event type matches any of the generated struct types
-        // NOTE: We intentionally ignore type_params.len() because events may have phantom/generic type parameters
-        // that don't affect the actual event structure (e.g., DeepBurned<BaseAsset, QuoteAsset>)
-        all_struct_types.iter().any(|struct_type| {
-            event_type.address == AccountAddress::new(*struct_type.address().inner())
-                && event_type.module.as_str() == struct_type.module().as_str()
-                && event_type.name.as_str() == struct_type.name().as_str()
-        })
-    }
-
-    /// Generate all possible struct types for this event across all supported package versions
-    fn get_all_struct_types(env: crate::DeepbookEnv) -> Vec<StructTag> {
-        let acceptable_addresses = match Self::acceptable_package_addresses(env) {
-            Ok(addresses) => addresses,
-            Err(_) => return Vec::new(), // Return empty vec if module is unknown
-        };
-        let mut struct_types = Vec::new();
-
-        for address in acceptable_addresses {
-            let struct_tag = StructTag::new(
-                (*address.inner()).into(),
-                Identifier::from_str(Self::MODULE).unwrap(),
-                Identifier::from_str(Self::NAME).unwrap(),
-                Self::TYPE_PARAMS
-                    .iter()
-                    .map(|param| {
-                        sui_sdk_types::TypeTag::Struct(Box::new(StructTag::new(
-                            (*address.inner()).into(),
-                            Identifier::from_str(Self::MODULE).unwrap(),
-                            Identifier::from_str(param).unwrap(),
-                            Vec::new(),
-                        )))
-                    })
-                    .collect(),
-            );
-            struct_types.push(struct_tag);
-        }
-
-        struct_types
-    }
+    const KIND: EventKind = EventKind {
+        module: Self::MODULE,
+        name: Self::NAME,
+    };
 }
 
 /// Generic helper that reads package addresses from lib.rs at runtime
@@ -87,7 +34,7 @@ pub fn get_package_addresses_for_module(
 
             // Convert string addresses to Address types
             for addr_str in core_packages {
-                if let Ok(addr) = parse_address_from_hex(addr_str) {
+                if let Ok(addr) = parse_address_from_hex(&addr_str) {
                     addresses.push(addr);
                 }
             }
@@ -102,7 +49,7 @@ pub fn get_package_addresses_for_module(
 
             // Convert string addresses to Address types
             for addr_str in margin_packages {
-                if let Ok(addr) = parse_address_from_hex(addr_str) {
+                if let Ok(addr) = parse_address_from_hex(&addr_str) {
                     addresses.push(addr);
                 }
             }
@@ -118,6 +65,94 @@ pub fn get_package_addresses_for_module(
                 Ok(addresses)
             }
         }
+        ModuleType::MarginLiquidation => {
+            let margin_liquidation_packages = crate::get_margin_liquidation_package_addresses(env);
+            let mut addresses = Vec::new();
+
+            // Convert string addresses to Address types
+            for addr_str in margin_liquidation_packages {
+                if let Ok(addr) = parse_address_from_hex(&addr_str) {
+                    addresses.push(addr);
+                }
+            }
+
+            if addresses.is_empty() {
+                Err(format!(
+                    "The margin liquidation vault is not supported on {:?}. \
+                    The margin_liquidation package has not been deployed on this network. \
+                    Requested module: '{}'",
+                    env, module
+                ))
+            } else {
+                Ok(addresses)
+            }
+        }
+        ModuleType::Predict => {
+            let predict_packages = crate::get_predict_package_addresses(env);
+            let mut addresses = Vec::new();
+
+            // Convert string addresses to Address types
+            for addr_str in predict_packages {
+                if let Ok(addr) = parse_address_from_hex(&addr_str) {
+                    addresses.push(addr);
+                }
+            }
+
+            if addresses.is_empty() {
+                Err(format!(
+                    "Predict is not supported on {:?}. \
+                    The predict package has not been deployed on this network. \
+                    Requested module: '{}'",
+                    env, module
+                ))
+            } else {
+                Ok(addresses)
+            }
+        }
+        ModuleType::Propbook => {
+            let propbook_packages = crate::get_propbook_package_addresses(env);
+            let mut addresses = Vec::new();
+
+            // Convert string addresses to Address types
+            for addr_str in propbook_packages {
+                if let Ok(addr) = parse_address_from_hex(&addr_str) {
+                    addresses.push(addr);
+                }
+            }
+
+            if addresses.is_empty() {
+                Err(format!(
+                    "Propbook is not supported on {:?}. \
+                    The propbook package has not been deployed on this network. \
+                    Requested module: '{}'",
+                    env, module
+                ))
+            } else {
+                Ok(addresses)
+            }
+        }
+        ModuleType::Account => {
+            let account_packages = crate::get_account_package_addresses(env);
+            let mut addresses = Vec::new();
+
+            // Convert string addresses to Address types
+            for addr_str in account_packages {
+                if let Ok(addr) = parse_address_from_hex(&addr_str) {
+                    addresses.push(addr);
+                }
+            }
+
+            if addresses.is_empty() {
+                Err(format!(
+                    "Accounts are not supported on {:?}. \
+                    The account packages have not been deployed on this network. \
+                    Requested module: '{}'",
+                    env, module
+                ))
+            } else {
+                Ok(addresses)
+            }
+        }
         ModuleType::Sui => {
             const SUI_SYSTEM_ADDRESS: &str =
                 "0000000000000000000000000000000000000000000000000000000000000002";
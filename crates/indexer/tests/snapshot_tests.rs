@@ -1,5 +1,6 @@
 use bigdecimal::BigDecimal;
 use chrono::NaiveDateTime;
+use deepbook_indexer::event_index::{EventRouter, Subscriber};
 use deepbook_indexer::handlers::asset_supplied_handler::AssetSuppliedHandler;
 use deepbook_indexer::handlers::asset_withdrawn_handler::AssetWithdrawnHandler;
 use deepbook_indexer::handlers::balance_manager_event_handler::BalanceManagerEventHandler;
@@ -74,14 +75,14 @@ use sui_types::full_checkpoint_content::CheckpointData;
 
 #[tokio::test]
 async fn balances_test() -> Result<(), anyhow::Error> {
-    let handler = BalancesHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, BalancesHandler::new);
     data_test("balances", handler, ["balances"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn balance_manager_event_test() -> Result<(), anyhow::Error> {
-    let handler = BalanceManagerEventHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, BalanceManagerEventHandler::new);
     data_test(
         "balance_manager_created",
         handler,
@@ -93,7 +94,10 @@ async fn balance_manager_event_test() -> Result<(), anyhow::Error> {
 
 #[tokio::test]
 async fn deepbook_referral_created_event_test() -> Result<(), anyhow::Error> {
-    let handler = DeepBookReferralCreatedEventHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(
+        DeepbookEnv::Mainnet,
+        DeepBookReferralCreatedEventHandler::new,
+    );
     data_test(
         "deepbook_referral_created",
         handler,
@@ -105,69 +109,69 @@ async fn deepbook_referral_created_event_test() -> Result<(), anyhow::Error> {
 
 #[tokio::test]
 async fn deepbook_referral_set_event_test() -> Result<(), anyhow::Error> {
-    let handler = DeepBookReferralSetEventHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, DeepBookReferralSetEventHandler::new);
     data_test("deepbook_referral_set", handler, ["deepbook_referral_set"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn flash_loan_test() -> Result<(), anyhow::Error> {
-    let handler = FlashLoanHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, FlashLoanHandler::new);
     data_test("flash_loans", handler, ["flashloans"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn order_fill_test() -> Result<(), anyhow::Error> {
-    let handler = OrderFillHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, OrderFillHandler::new);
     data_test("order_fill", handler, ["order_fills"]).await?;
     Ok(())
 }
 #[tokio::test]
 async fn order_update_test() -> Result<(), anyhow::Error> {
-    let handler = OrderUpdateHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, OrderUpdateHandler::new);
     data_test("order_update", handler, ["order_updates"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn pool_price_test() -> Result<(), anyhow::Error> {
-    let handler = PoolPriceHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, PoolPriceHandler::new);
     data_test("pool_price", handler, ["pool_prices"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn deep_burned_test() -> Result<(), anyhow::Error> {
-    let handler = DeepBurnedHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, DeepBurnedHandler::new);
     data_test("deep_burned", handler, ["deep_burned"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn pool_created_test() -> Result<(), anyhow::Error> {
-    let handler = PoolCreatedHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, PoolCreatedHandler::new);
     data_test("pool_created", handler, ["pool_created"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn book_params_updated_test() -> Result<(), anyhow::Error> {
-    let handler = BookParamsUpdatedHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, BookParamsUpdatedHandler::new);
     data_test("book_params_updated", handler, ["book_params_updated"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn referral_claimed_test() -> Result<(), anyhow::Error> {
-    let handler = ReferralClaimedHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, ReferralClaimedHandler::new);
     data_test("referral_claimed", handler, ["referral_claimed"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn ewma_update_test() -> Result<(), anyhow::Error> {
-    let handler = EwmaUpdateHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, EwmaUpdateHandler::new);
     data_test("ewma_updates", handler, ["ewma_updates"]).await?;
     Ok(())
 }
@@ -176,7 +180,7 @@ async fn ewma_update_test() -> Result<(), anyhow::Error> {
 async fn balances_indirect_interaction_test() -> Result<(), anyhow::Error> {
     // Test that balance events from transactions that interact with DeepBook
     // indirectly (through other protocols) are still captured
-    let handler = BalancesHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, BalancesHandler::new);
     data_test("balances_indirect", handler, ["balances"]).await?;
     Ok(())
 }
@@ -184,7 +188,7 @@ async fn balances_indirect_interaction_test() -> Result<(), anyhow::Error> {
 // Margin Manager Events Tests
 #[tokio::test]
 async fn margin_manager_created_test() -> Result<(), anyhow::Error> {
-    let handler = MarginManagerCreatedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, MarginManagerCreatedHandler::new);
     data_test(
         "margin_manager_created",
         handler,
@@ -196,7 +200,7 @@ async fn margin_manager_created_test() -> Result<(), anyhow::Error> {
 
 #[tokio::test]
 async fn loan_borrowed_test() -> Result<(), anyhow::Error> {
-    let handler = LoanBorrowedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, LoanBorrowedHandler::new);
     data_test("loan_borrowed", handler, ["loan_borrowed"]).await?;
     Ok(())
 }
@@ -204,14 +208,14 @@ async fn loan_borrowed_test() -> Result<(), anyhow::Error> {
 #[tokio::test]
 #[ignore] // TODO: Add checkpoint test data
 async fn loan_repaid_test() -> Result<(), anyhow::Error> {
-    let handler = LoanRepaidHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, LoanRepaidHandler::new);
     data_test("loan_repaid", handler, ["loan_repaid"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn liquidation_test() -> Result<(), anyhow::Error> {
-    let handler = LiquidationHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, LiquidationHandler::new);
     data_test("liquidation", handler, ["liquidation"]).await?;
     Ok(())
 }
@@ -219,14 +223,14 @@ async fn liquidation_test() -> Result<(), anyhow::Error> {
 // Margin Pool Operations Events Tests
 #[tokio::test]
 async fn asset_supplied_test() -> Result<(), anyhow::Error> {
-    let handler = AssetSuppliedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, AssetSuppliedHandler::new);
     data_test("asset_supplied", handler, ["asset_supplied"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn asset_withdrawn_test() -> Result<(), anyhow::Error> {
-    let handler = AssetWithdrawnHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, AssetWithdrawnHandler::new);
     data_test("asset_withdrawn", handler, ["asset_withdrawn"]).await?;
     Ok(())
 }
@@ -234,14 +238,14 @@ async fn asset_withdrawn_test() -> Result<(), anyhow::Error> {
 // Margin Pool Admin Events Tests
 #[tokio::test]
 async fn margin_pool_created_test() -> Result<(), anyhow::Error> {
-    let handler = MarginPoolCreatedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, MarginPoolCreatedHandler::new);
     data_test("margin_pool_created", handler, ["margin_pool_created"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn deepbook_pool_updated_test() -> Result<(), anyhow::Error> {
-    let handler = DeepbookPoolUpdatedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, DeepbookPoolUpdatedHandler::new);
     data_test("deepbook_pool_updated", handler, ["deepbook_pool_updated"]).await?;
     Ok(())
 }
@@ -249,7 +253,7 @@ async fn deepbook_pool_updated_test() -> Result<(), anyhow::Error> {
 #[tokio::test]
 #[ignore] // TODO: Add checkpoint test data
 async fn interest_params_updated_test() -> Result<(), anyhow::Error> {
-    let handler = InterestParamsUpdatedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, InterestParamsUpdatedHandler::new);
     data_test(
         "interest_params_updated",
         handler,
@@ -262,7 +266,7 @@ async fn interest_params_updated_test() -> Result<(), anyhow::Error> {
 #[tokio::test]
 #[ignore] // TODO: Add checkpoint test data
 async fn margin_pool_config_updated_test() -> Result<(), anyhow::Error> {
-    let handler = MarginPoolConfigUpdatedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, MarginPoolConfigUpdatedHandler::new);
     data_test(
         "margin_pool_config_updated",
         handler,
@@ -275,7 +279,7 @@ async fn margin_pool_config_updated_test() -> Result<(), anyhow::Error> {
 // Margin Registry Events Tests
 #[tokio::test]
 async fn maintainer_cap_updated_test() -> Result<(), anyhow::Error> {
-    let handler = MaintainerCapUpdatedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, MaintainerCapUpdatedHandler::new);
     data_test(
         "maintainer_cap_updated",
         handler,
@@ -287,7 +291,7 @@ async fn maintainer_cap_updated_test() -> Result<(), anyhow::Error> {
 
 #[tokio::test]
 async fn deepbook_pool_registered_test() -> Result<(), anyhow::Error> {
-    let handler = DeepbookPoolRegisteredHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, DeepbookPoolRegisteredHandler::new);
     data_test(
         "deepbook_pool_registered",
         handler,
@@ -299,7 +303,10 @@ async fn deepbook_pool_registered_test() -> Result<(), anyhow::Error> {
 
 #[tokio::test]
 async fn deepbook_pool_updated_registry_test() -> Result<(), anyhow::Error> {
-    let handler = DeepbookPoolUpdatedRegistryHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(
+        DeepbookEnv::Testnet,
+        DeepbookPoolUpdatedRegistryHandler::new,
+    );
     data_test(
         "deepbook_pool_updated_registry",
         handler,
@@ -312,7 +319,7 @@ async fn deepbook_pool_updated_registry_test() -> Result<(), anyhow::Error> {
 #[tokio::test]
 #[ignore] // TODO: Add checkpoint test data
 async fn deepbook_pool_config_updated_test() -> Result<(), anyhow::Error> {
-    let handler = DeepbookPoolConfigUpdatedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, DeepbookPoolConfigUpdatedHandler::new);
     data_test(
         "deepbook_pool_config_updated",
         handler,
@@ -324,7 +331,7 @@ async fn deepbook_pool_config_updated_test() -> Result<(), anyhow::Error> {
 
 #[tokio::test]
 async fn current_price_updated_test() -> Result<(), anyhow::Error> {
-    let handler = CurrentPriceUpdatedHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, CurrentPriceUpdatedHandler::new);
     data_test("current_price_updated", handler, ["current_price_updated"]).await?;
     Ok(())
 }
@@ -332,7 +339,7 @@ async fn current_price_updated_test() -> Result<(), anyhow::Error> {
 #[tokio::test]
 #[ignore] // Event not emitted yet
 async fn price_tolerance_updated_test() -> Result<(), anyhow::Error> {
-    let handler = PriceToleranceUpdatedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, PriceToleranceUpdatedHandler::new);
     data_test(
         "price_tolerance_updated",
         handler,
@@ -345,7 +352,7 @@ async fn price_tolerance_updated_test() -> Result<(), anyhow::Error> {
 #[tokio::test]
 #[ignore] // Event not emitted yet
 async fn max_price_age_updated_test() -> Result<(), anyhow::Error> {
-    let handler = MaxPriceAgeUpdatedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, MaxPriceAgeUpdatedHandler::new);
     data_test("max_price_age_updated", handler, ["max_price_age_updated"]).await?;
     Ok(())
 }
@@ -353,7 +360,7 @@ async fn max_price_age_updated_test() -> Result<(), anyhow::Error> {
 #[tokio::test]
 #[ignore] // TODO: Add checkpoint test data - Event does not exist on testnet yet (checked all package versions)
 async fn maintainer_fees_withdrawn_test() -> Result<(), anyhow::Error> {
-    let handler = MaintainerFeesWithdrawnHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, MaintainerFeesWithdrawnHandler::new);
     data_test(
         "maintainer_fees_withdrawn",
         handler,
@@ -366,7 +373,7 @@ async fn maintainer_fees_withdrawn_test() -> Result<(), anyhow::Error> {
 #[tokio::test]
 #[ignore] // TODO: Add checkpoint test data - Event does not exist on testnet yet (checked all package versions)
 async fn protocol_fees_withdrawn_test() -> Result<(), anyhow::Error> {
-    let handler = ProtocolFeesWithdrawnHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, ProtocolFeesWithdrawnHandler::new);
     data_test(
         "protocol_fees_withdrawn",
         handler,
@@ -378,14 +385,14 @@ async fn protocol_fees_withdrawn_test() -> Result<(), anyhow::Error> {
 
 #[tokio::test]
 async fn supplier_cap_minted_test() -> Result<(), anyhow::Error> {
-    let handler = SupplierCapMintedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, SupplierCapMintedHandler::new);
     data_test("supplier_cap_minted", handler, ["supplier_cap_minted"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn supply_referral_minted_test() -> Result<(), anyhow::Error> {
-    let handler = SupplyReferralMintedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, SupplyReferralMintedHandler::new);
     data_test(
         "supply_referral_minted",
         handler,
@@ -398,14 +405,14 @@ async fn supply_referral_minted_test() -> Result<(), anyhow::Error> {
 #[tokio::test]
 #[ignore] // TODO: Add checkpoint test data - Event does not exist on testnet yet (checked all package versions)
 async fn pause_cap_updated_test() -> Result<(), anyhow::Error> {
-    let handler = PauseCapUpdatedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, PauseCapUpdatedHandler::new);
     data_test("pause_cap_updated", handler, ["pause_cap_updated"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn protocol_fees_increased_test() -> Result<(), anyhow::Error> {
-    let handler = ProtocolFeesIncreasedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, ProtocolFeesIncreasedHandler::new);
     data_test(
         "protocol_fees_increased",
         handler,
@@ -417,14 +424,14 @@ async fn protocol_fees_increased_test() -> Result<(), anyhow::Error> {
 
 #[tokio::test]
 async fn referral_fee_event_test() -> Result<(), anyhow::Error> {
-    let handler = ReferralFeeEventHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, ReferralFeeEventHandler::new);
     data_test("referral_fee_events", handler, ["referral_fee_events"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn referral_fees_claimed_test() -> Result<(), anyhow::Error> {
-    let handler = ReferralFeesClaimedHandler::new(DeepbookEnv::Testnet);
+    let handler = subscribe(DeepbookEnv::Testnet, ReferralFeesClaimedHandler::new);
     data_test("referral_fees_claimed", handler, ["referral_fees_claimed"]).await?;
     Ok(())
 }
@@ -433,7 +440,7 @@ async fn referral_fees_claimed_test() -> Result<(), anyhow::Error> {
 // Checkpoint 234918188 - TX: GSNpevf2UcTeq3ACPMGRsvLFRRGB9w2H4KB9BR1cEYcQ
 #[tokio::test]
 async fn deposit_collateral_test() -> Result<(), anyhow::Error> {
-    let handler = DepositCollateralHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, DepositCollateralHandler::new);
     data_test("deposit_collateral", handler, ["collateral_events"]).await?;
     Ok(())
 }
@@ -441,7 +448,7 @@ async fn deposit_collateral_test() -> Result<(), anyhow::Error> {
 // Checkpoint 234920766 - TX: 73DkKzySTo824MBEQREnhNwXbbSpX8YEEb7qbfxxaHGG
 #[tokio::test]
 async fn withdraw_collateral_test() -> Result<(), anyhow::Error> {
-    let handler = WithdrawCollateralHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, WithdrawCollateralHandler::new);
     data_test("withdraw_collateral", handler, ["collateral_events"]).await?;
     Ok(())
 }
@@ -450,7 +457,7 @@ async fn withdraw_collateral_test() -> Result<(), anyhow::Error> {
 // Checkpoint 234928955 - TX: HRj2fF9ifRA8kXipJy2g6y6UKgMFNeKvvZqfrKY2L825
 #[tokio::test]
 async fn conditional_order_added_test() -> Result<(), anyhow::Error> {
-    let handler = ConditionalOrderAddedHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, ConditionalOrderAddedHandler::new);
     data_test(
         "conditional_order_added",
         handler,
@@ -463,7 +470,7 @@ async fn conditional_order_added_test() -> Result<(), anyhow::Error> {
 // Checkpoint 234928968 - TX: 5QcwuLcE7jmunStKgUSCrHPpAw1WC8B9XQLPph3jrKGn
 #[tokio::test]
 async fn conditional_order_cancelled_test() -> Result<(), anyhow::Error> {
-    let handler = ConditionalOrderCancelledHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, ConditionalOrderCancelledHandler::new);
     data_test(
         "conditional_order_cancelled",
         handler,
@@ -476,7 +483,7 @@ async fn conditional_order_cancelled_test() -> Result<(), anyhow::Error> {
 #[tokio::test]
 #[ignore] // No mainnet transactions yet - ConditionalOrderExecuted requires price trigger
 async fn conditional_order_executed_test() -> Result<(), anyhow::Error> {
-    let handler = ConditionalOrderExecutedHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, ConditionalOrderExecutedHandler::new);
     data_test(
         "conditional_order_executed",
         handler,
@@ -489,7 +496,10 @@ async fn conditional_order_executed_test() -> Result<(), anyhow::Error> {
 #[tokio::test]
 #[ignore] // No mainnet transactions yet - ConditionalOrderInsufficientFunds requires trigger with low balance
 async fn conditional_order_insufficient_funds_test() -> Result<(), anyhow::Error> {
-    let handler = ConditionalOrderInsufficientFundsHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(
+        DeepbookEnv::Mainnet,
+        ConditionalOrderInsufficientFundsHandler::new,
+    );
     data_test(
         "conditional_order_insufficient_funds",
         handler,
@@ -502,18 +512,23 @@ async fn conditional_order_insufficient_funds_test() -> Result<(), anyhow::Error
 #[tokio::test]
 #[ignore] // Event not emitted yet on mainnet
 async fn taker_fee_penalty_test() -> Result<(), anyhow::Error> {
-    let handler = TakerFeePenaltyHandler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, TakerFeePenaltyHandler::new);
     data_test("taker_fee_penalty", handler, ["taker_fee_penalty_applied"]).await?;
     Ok(())
 }
 
 #[tokio::test]
 async fn rebates_v2_test() -> Result<(), anyhow::Error> {
-    let handler = RebatesV2Handler::new(DeepbookEnv::Mainnet);
+    let handler = subscribe(DeepbookEnv::Mainnet, RebatesV2Handler::new);
     data_test("rebates_v2", handler, ["rebates_v2"]).await?;
     Ok(())
 }
 
+/// Build a handler subscribed to a fresh router, as the indexer does
+fn subscribe<H: Subscriber>(env: DeepbookEnv, new: impl FnOnce(Arc<EventRouter>) -> H) -> H {
+    Arc::new(EventRouter::new(env)).subscribe(new)
+}
+
 async fn data_test<H, I>(
     test_name: &str,
     handler: H,
@@ -0,0 +1,380 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Package registry shared by the DeepBook indexer and server.
+//!
+//! The registry lists every package version per network and package family, together with the
+//! checkpoint each version became active at. It is read from a TOML (or JSON) file at startup;
+//! [`PackageRegistry::builtin`] is the `packages.toml` shipped next to this crate.
+
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The registry compiled into the binaries, used when no `--package-config` is given.
+pub const BUILTIN_REGISTRY: &str = include_str!("../packages.toml");
+
+/// A group of Move packages indexed together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PackageFamily {
+    /// DeepBook core (pools, orders, balance managers, governance)
+    Core,
+    /// DeepBook margin (lending, borrowing, liquidations)
+    Margin,
+    /// Margin liquidation vault
+    MarginLiquidation,
+    /// Prediction markets
+    Predict,
+    /// Oracle registry / Block Scholes feeds
+    Propbook,
+    /// Accounts / deepbook_core_account / sessions
+    Account,
+}
+
+impl PackageFamily {
+    pub const ALL: [PackageFamily; 6] = [
+        PackageFamily::Core,
+        PackageFamily::Margin,
+        PackageFamily::MarginLiquidation,
+        PackageFamily::Predict,
+        PackageFamily::Propbook,
+        PackageFamily::Account,
+    ];
+
+    /// The family's name as written in the registry file.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PackageFamily::Core => "core",
+            PackageFamily::Margin => "margin",
+            PackageFamily::MarginLiquidation => "margin_liquidation",
+            PackageFamily::Predict => "predict",
+            PackageFamily::Propbook => "propbook",
+            PackageFamily::Account => "account",
+        }
+    }
+}
+
+impl FromStr for PackageFamily {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PackageFamily::ALL
+            .into_iter()
+            .find(|family| family.as_str() == s)
+            .ok_or_else(|| anyhow::anyhow!("Unknown package family '{s}'"))
+    }
+}
+
+/// A single published version of a package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackageVersion {
+    pub id: String,
+    /// Checkpoint at which this version was published, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<u64>,
+}
+
+impl PackageVersion {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            checkpoint: None,
+        }
+    }
+}
+
+/// Package versions for one network, keyed by family. Versions are ordered oldest first.
+pub type NetworkPackages = BTreeMap<PackageFamily, Vec<PackageVersion>>;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageRegistry {
+    #[serde(flatten)]
+    networks: BTreeMap<String, NetworkPackages>,
+}
+
+impl PackageRegistry {
+    /// The registry shipped with this crate.
+    pub fn builtin() -> Self {
+        Self::from_toml_str(BUILTIN_REGISTRY).expect("built-in packages.toml is invalid")
+    }
+
+    /// Load a registry from `path`, or the built-in one if no path is given.
+    pub fn load_or_builtin(path: Option<&Path>) -> anyhow::Result<Self> {
+        match path {
+            Some(path) => Self::load(path),
+            None => Ok(Self::builtin()),
+        }
+    }
+
+    /// Load a registry file. Files ending in `.json` are parsed as JSON, anything else as TOML.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read package registry {}", path.display()))?;
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        let registry = if is_json {
+            Self::from_json_str(&contents)
+        } else {
+            Self::from_toml_str(&contents)
+        };
+        registry.with_context(|| format!("Invalid package registry {}", path.display()))
+    }
+
+    pub fn from_toml_str(contents: &str) -> anyhow::Result<Self> {
+        let registry: Self = toml::from_str(contents)?;
+        registry.validate()?;
+        Ok(registry)
+    }
+
+    pub fn from_json_str(contents: &str) -> anyhow::Result<Self> {
+        let registry: Self = serde_json::from_str(contents)?;
+        registry.validate()?;
+        Ok(registry)
+    }
+
+    /// Replace the versions of `family` on `network`. The versions are checked the same way as
+    /// a loaded registry file, and the registry is left unchanged if they are invalid.
+    pub fn set(
+        &mut self,
+        network: &str,
+        family: PackageFamily,
+        versions: Vec<PackageVersion>,
+    ) -> anyhow::Result<()> {
+        validate_versions(network, family, &versions)?;
+        self.networks
+            .entry(network.to_string())
+            .or_default()
+            .insert(family, versions);
+        Ok(())
+    }
+
+    /// Names of the networks present in the registry.
+    pub fn networks(&self) -> impl Iterator<Item = &str> {
+        self.networks.keys().map(String::as_str)
+    }
+
+    /// All versions of `family` on `network`, oldest first. Empty if the family is not deployed.
+    pub fn versions(&self, network: &str, family: PackageFamily) -> &[PackageVersion] {
+        self.networks
+            .get(network)
+            .and_then(|packages| packages.get(&family))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Package IDs of every version of `family` on `network`, oldest first.
+    pub fn package_ids(&self, network: &str, family: PackageFamily) -> Vec<&str> {
+        self.versions(network, family)
+            .iter()
+            .map(|version| version.id.as_str())
+            .collect()
+    }
+
+    /// Package IDs of every family on `network`.
+    pub fn all_package_ids(&self, network: &str) -> Vec<&str> {
+        PackageFamily::ALL
+            .iter()
+            .flat_map(|&family| self.package_ids(network, family))
+            .collect()
+    }
+
+    /// The most recent version of `family` on `network`.
+    pub fn latest(&self, network: &str, family: PackageFamily) -> Option<&PackageVersion> {
+        self.versions(network, family).last()
+    }
+
+    /// Earliest activation checkpoint across `families` on `network`. Returns `None` unless the
+    /// first version of every deployed family has a checkpoint, since starting later than an
+    /// unknown activation point could skip events.
+    pub fn first_checkpoint(&self, network: &str, families: &[PackageFamily]) -> Option<u64> {
+        let mut first = None;
+        for &family in families {
+            let Some(version) = self.versions(network, family).first() else {
+                continue;
+            };
+            let checkpoint = version.checkpoint?;
+            first = Some(first.map_or(checkpoint, |f: u64| f.min(checkpoint)));
+        }
+        first
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        for (network, packages) in &self.networks {
+            for (&family, versions) in packages {
+                validate_versions(network, family, versions)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn validate_versions(
+    network: &str,
+    family: PackageFamily,
+    versions: &[PackageVersion],
+) -> anyhow::Result<()> {
+    let mut last_checkpoint = None;
+    for (i, version) in versions.iter().enumerate() {
+        if !is_package_id(&version.id) {
+            bail!(
+                "{network}.{family:?}: '{}' is not a 0x-prefixed 32-byte package ID",
+                version.id
+            );
+        }
+        if versions[..i].iter().any(|v| v.id == version.id) {
+            bail!("{network}.{family:?}: duplicate package ID {}", version.id);
+        }
+        if let Some(checkpoint) = version.checkpoint {
+            if last_checkpoint.is_some_and(|last| checkpoint < last) {
+                bail!(
+                    "{network}.{family:?}: versions must be listed oldest first, but {} is \
+                    active from checkpoint {checkpoint}, before the previous version",
+                    version.id
+                );
+            }
+            last_checkpoint = Some(checkpoint);
+        }
+    }
+    Ok(())
+}
+
+fn is_package_id(id: &str) -> bool {
+    id.strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKAGE_A: &str = "0x000000000000000000000000000000000000000000000000000000000000000a";
+    const PACKAGE_B: &str = "0x000000000000000000000000000000000000000000000000000000000000000b";
+
+    #[test]
+    fn builtin_registry_is_valid() {
+        let registry = PackageRegistry::builtin();
+        assert!(!registry
+            .package_ids("mainnet", PackageFamily::Core)
+            .is_empty());
+        assert!(!registry
+            .package_ids("testnet", PackageFamily::Core)
+            .is_empty());
+        assert!(registry
+            .package_ids("mainnet", PackageFamily::Predict)
+            .is_empty());
+    }
+
+    #[test]
+    fn family_names_round_trip() {
+        for family in PackageFamily::ALL {
+            assert_eq!(family.as_str().parse::<PackageFamily>().unwrap(), family);
+            let json = serde_json::to_string(&family).unwrap();
+            assert_eq!(json, format!("\"{}\"", family.as_str()));
+        }
+    }
+
+    #[test]
+    fn toml_and_json_layouts_match() {
+        let toml = format!(
+            r#"
+            [testnet]
+            core = [{{ id = "{PACKAGE_A}", checkpoint = 10 }}, {{ id = "{PACKAGE_B}" }}]
+            "#
+        );
+        let json = format!(
+            r#"{{"testnet": {{"core": [{{"id": "{PACKAGE_A}", "checkpoint": 10}}, {{"id": "{PACKAGE_B}"}}]}}}}"#
+        );
+        let from_toml = PackageRegistry::from_toml_str(&toml).unwrap();
+        let from_json = PackageRegistry::from_json_str(&json).unwrap();
+        assert_eq!(from_toml, from_json);
+        assert_eq!(
+            from_toml.latest("testnet", PackageFamily::Core).unwrap().id,
+            PACKAGE_B
+        );
+    }
+
+    #[test]
+    fn first_checkpoint_requires_known_activation() {
+        let mut registry = PackageRegistry::default();
+        registry
+            .set(
+                "testnet",
+                PackageFamily::Core,
+                vec![PackageVersion {
+                    id: PACKAGE_A.to_string(),
+                    checkpoint: Some(100),
+                }],
+            )
+            .unwrap();
+        registry
+            .set(
+                "testnet",
+                PackageFamily::Margin,
+                vec![PackageVersion {
+                    id: PACKAGE_B.to_string(),
+                    checkpoint: Some(50),
+                }],
+            )
+            .unwrap();
+        let both = [PackageFamily::Core, PackageFamily::Margin];
+        assert_eq!(registry.first_checkpoint("testnet", &both), Some(50));
+
+        registry
+            .set(
+                "testnet",
+                PackageFamily::Margin,
+                vec![PackageVersion::new(PACKAGE_B)],
+            )
+            .unwrap();
+        assert_eq!(registry.first_checkpoint("testnet", &both), None);
+    }
+
+    #[test]
+    fn rejects_invalid_entries() {
+        let bad_id = r#"[testnet]
+core = [{ id = "0x1234" }]"#;
+        assert!(PackageRegistry::from_toml_str(bad_id).is_err());
+
+        let out_of_order = format!(
+            r#"[testnet]
+core = [{{ id = "{PACKAGE_A}", checkpoint = 10 }}, {{ id = "{PACKAGE_B}", checkpoint = 5 }}]"#
+        );
+        assert!(PackageRegistry::from_toml_str(&out_of_order).is_err());
+
+        let duplicate = format!(
+            r#"[testnet]
+core = [{{ id = "{PACKAGE_A}" }}, {{ id = "{PACKAGE_A}" }}]"#
+        );
+        assert!(PackageRegistry::from_toml_str(&duplicate).is_err());
+    }
+
+    #[test]
+    fn set_rejects_invalid_versions() {
+        let mut registry = PackageRegistry::default();
+        assert!(registry
+            .set(
+                "testnet",
+                PackageFamily::Predict,
+                vec![PackageVersion::new("0xabc")],
+            )
+            .is_err());
+        assert!(registry
+            .set(
+                "testnet",
+                PackageFamily::Predict,
+                vec![
+                    PackageVersion::new(PACKAGE_A),
+                    PackageVersion::new(PACKAGE_A)
+                ],
+            )
+            .is_err());
+        assert!(registry
+            .package_ids("testnet", PackageFamily::Predict)
+            .is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+DROP TABLE IF EXISTS predict_builder_fees_claimed;
+DROP TABLE IF EXISTS predict_builder_code_set;
+DROP TABLE IF EXISTS predict_builder_code_created;
+DROP TABLE IF EXISTS predict_withdraw_filled;
+DROP TABLE IF EXISTS predict_supply_filled;
+DROP TABLE IF EXISTS predict_request_cancelled;
+DROP TABLE IF EXISTS predict_withdraw_requested;
+DROP TABLE IF EXISTS predict_supply_requested;
+DROP TABLE IF EXISTS predict_order_liquidated;
+DROP TABLE IF EXISTS predict_liquidated_order_redeemed;
+DROP TABLE IF EXISTS predict_settled_order_redeemed;
+DROP TABLE IF EXISTS predict_live_order_redeemed;
+DROP TABLE IF EXISTS predict_order_minted;
+DROP TABLE IF EXISTS predict_market_settled;
+DROP TABLE IF EXISTS predict_market_created;
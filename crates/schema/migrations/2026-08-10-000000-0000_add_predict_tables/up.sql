@@ -0,0 +1,351 @@
+-- MarketCreated - expiry market creation with the snapshotted market parameters
+CREATE TABLE IF NOT EXISTS predict_market_created (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    expiry_market_id            TEXT        NOT NULL,
+    pool_vault_id               TEXT        NOT NULL,
+    propbook_underlying_id      BIGINT      NOT NULL,
+    expiry                      BIGINT      NOT NULL,
+    tick_size                   BIGINT      NOT NULL,
+    admission_tick_size         BIGINT      NOT NULL,
+    max_expiry_allocation       BIGINT      NOT NULL,
+    initial_expiry_cash         BIGINT      NOT NULL,
+    liquidation_ltv             BIGINT      NOT NULL,
+    max_admission_leverage      BIGINT      NOT NULL,
+    backing_buffer_lambda       BIGINT      NOT NULL,
+    base_fee                    BIGINT      NOT NULL,
+    min_fee                     BIGINT      NOT NULL,
+    min_entry_probability       BIGINT      NOT NULL,
+    max_entry_probability       BIGINT      NOT NULL,
+    expiry_fee_window_ms        BIGINT      NOT NULL,
+    expiry_fee_max_multiplier   BIGINT      NOT NULL,
+    no_leverage_window_ms       BIGINT      NOT NULL,
+    trading_loss_rebate_rate    BIGINT      NOT NULL,
+    max_benefit_ratio           BIGINT      NOT NULL,
+    lower_benefit_power         BIGINT      NOT NULL,
+    upper_benefit_power         BIGINT      NOT NULL
+);
+
+-- MarketSettled - expiry market settlement price
+CREATE TABLE IF NOT EXISTS predict_market_settled (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    expiry_market_id            TEXT        NOT NULL,
+    propbook_underlying_id      BIGINT      NOT NULL,
+    expiry                      BIGINT      NOT NULL,
+    settlement_price            BIGINT      NOT NULL,
+    settled_at_ms               BIGINT      NOT NULL
+);
+
+-- OrderMinted - new range order positions
+CREATE TABLE IF NOT EXISTS predict_order_minted (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    expiry_market_id            TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    order_id                    TEXT        NOT NULL,
+    position_root_id            TEXT        NOT NULL,
+    owner                       TEXT        NOT NULL,
+    lower_tick                  BIGINT      NOT NULL,
+    higher_tick                 BIGINT      NOT NULL,
+    leverage                    BIGINT      NOT NULL,
+    entry_probability           BIGINT      NOT NULL,
+    quantity                    BIGINT      NOT NULL,
+    net_premium                 BIGINT      NOT NULL,
+    trading_fee                 BIGINT      NOT NULL,
+    fee_incentive_subsidy       BIGINT      NOT NULL,
+    builder_fee                 BIGINT      NOT NULL,
+    penalty_fee                 BIGINT      NOT NULL,
+    builder_code_id             TEXT,
+    minted_at_ms                BIGINT      NOT NULL,
+    pyth_spot_source_timestamp_msBIGINT      NOT NULL,
+    block_scholes_spot_source_timestamp_msBIGINT      NOT NULL,
+    block_scholes_forward_source_timestamp_msBIGINT      NOT NULL,
+    block_scholes_svi_source_timestamp_msBIGINT      NOT NULL
+);
+
+-- LiveOrderRedeemed - orders closed before expiry
+CREATE TABLE IF NOT EXISTS predict_live_order_redeemed (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    expiry_market_id            TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    order_id                    TEXT        NOT NULL,
+    position_root_id            TEXT        NOT NULL,
+    owner                       TEXT        NOT NULL,
+    quantity_closed             BIGINT      NOT NULL,
+    remaining_quantity          BIGINT      NOT NULL,
+    replacement_order_id        TEXT,
+    redeem_amount               BIGINT      NOT NULL,
+    trading_fee                 BIGINT      NOT NULL,
+    builder_fee                 BIGINT      NOT NULL,
+    penalty_fee                 BIGINT      NOT NULL,
+    builder_code_id             TEXT,
+    redeemed_at_ms              BIGINT      NOT NULL,
+    pyth_spot_source_timestamp_msBIGINT      NOT NULL,
+    block_scholes_spot_source_timestamp_msBIGINT      NOT NULL,
+    block_scholes_forward_source_timestamp_msBIGINT      NOT NULL,
+    block_scholes_svi_source_timestamp_msBIGINT      NOT NULL
+);
+
+-- SettledOrderRedeemed - orders redeemed against the settlement price
+CREATE TABLE IF NOT EXISTS predict_settled_order_redeemed (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    expiry_market_id            TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    order_id                    TEXT        NOT NULL,
+    position_root_id            TEXT        NOT NULL,
+    owner                       TEXT        NOT NULL,
+    quantity_closed             BIGINT      NOT NULL,
+    settlement_price            BIGINT      NOT NULL,
+    payout_amount               BIGINT      NOT NULL,
+    redeemed_at_ms              BIGINT      NOT NULL
+);
+
+-- LiquidatedOrderRedeemed - liquidated orders removed from an account
+CREATE TABLE IF NOT EXISTS predict_liquidated_order_redeemed (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    expiry_market_id            TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    order_id                    TEXT        NOT NULL,
+    position_root_id            TEXT        NOT NULL,
+    owner                       TEXT        NOT NULL,
+    quantity_closed             BIGINT      NOT NULL,
+    redeemed_at_ms              BIGINT      NOT NULL
+);
+
+-- OrderLiquidated - orders liquidated when their value falls to the LTV floor
+CREATE TABLE IF NOT EXISTS predict_order_liquidated (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    expiry_market_id            TEXT        NOT NULL,
+    order_id                    TEXT        NOT NULL,
+    quantity                    BIGINT      NOT NULL,
+    gross_value                 BIGINT      NOT NULL,
+    floor_amount                BIGINT      NOT NULL,
+    liquidation_ltv             BIGINT      NOT NULL,
+    liquidated_at_ms            BIGINT      NOT NULL,
+    pyth_spot_source_timestamp_msBIGINT      NOT NULL,
+    block_scholes_spot_source_timestamp_msBIGINT      NOT NULL,
+    block_scholes_forward_source_timestamp_msBIGINT      NOT NULL,
+    block_scholes_svi_source_timestamp_msBIGINT      NOT NULL
+);
+
+-- SupplyRequested - queued PLP supply requests
+CREATE TABLE IF NOT EXISTS predict_supply_requested (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    pool_vault_id               TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    recipient                   TEXT        NOT NULL,
+    request_index               BIGINT      NOT NULL,
+    amount                      BIGINT      NOT NULL,
+    min_plp_out                 BIGINT      NOT NULL,
+    requests_pending_after      BIGINT      NOT NULL
+);
+
+-- WithdrawRequested - queued PLP withdraw requests
+CREATE TABLE IF NOT EXISTS predict_withdraw_requested (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    pool_vault_id               TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    recipient                   TEXT        NOT NULL,
+    request_index               BIGINT      NOT NULL,
+    amount                      BIGINT      NOT NULL,
+    min_dusdc_out               BIGINT      NOT NULL,
+    requests_pending_after      BIGINT      NOT NULL
+);
+
+-- RequestCancelled - supply/withdraw requests removed from the queue without filling
+CREATE TABLE IF NOT EXISTS predict_request_cancelled (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    pool_vault_id               TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    recipient                   TEXT        NOT NULL,
+    request_index               BIGINT      NOT NULL,
+    amount                      BIGINT      NOT NULL,
+    is_supply                   BOOLEAN     NOT NULL,
+    reason                      SMALLINT    NOT NULL,
+    requests_pending_after      BIGINT      NOT NULL
+);
+
+-- SupplyFilled - PLP minted for a (partially) filled supply request
+CREATE TABLE IF NOT EXISTS predict_supply_filled (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    pool_vault_id               TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    recipient                   TEXT        NOT NULL,
+    request_index               BIGINT      NOT NULL,
+    dusdc_amount                BIGINT      NOT NULL,
+    shares_minted               BIGINT      NOT NULL,
+    fee_dusdc                   BIGINT      NOT NULL,
+    dusdc_remaining             BIGINT      NOT NULL,
+    requests_pending_after      BIGINT      NOT NULL
+);
+
+-- WithdrawFilled - PLP burned for a (partially) filled withdraw request
+CREATE TABLE IF NOT EXISTS predict_withdraw_filled (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    pool_vault_id               TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    recipient                   TEXT        NOT NULL,
+    request_index               BIGINT      NOT NULL,
+    shares_burned               BIGINT      NOT NULL,
+    dusdc_amount                BIGINT      NOT NULL,
+    fee_dusdc                   BIGINT      NOT NULL,
+    shares_remaining            BIGINT      NOT NULL,
+    requests_pending_after      BIGINT      NOT NULL
+);
+
+-- BuilderCodeCreated - builder codes registered for fee attribution
+CREATE TABLE IF NOT EXISTS predict_builder_code_created (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    builder_code_id             TEXT        NOT NULL,
+    owner                       TEXT        NOT NULL,
+    builder_code_index          BIGINT      NOT NULL
+);
+
+-- BuilderCodeSet - account builder code attribution changes
+CREATE TABLE IF NOT EXISTS predict_builder_code_set (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    owner                       TEXT        NOT NULL,
+    builder_code_id             TEXT
+);
+
+-- BuilderFeesClaimed - builder fee withdrawals
+CREATE TABLE IF NOT EXISTS predict_builder_fees_claimed (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    builder_code_id             TEXT        NOT NULL,
+    owner                       TEXT        NOT NULL,
+    amount                      BIGINT      NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_predict_market_created_expiry_market_id ON predict_market_created(expiry_market_id);
+CREATE INDEX IF NOT EXISTS idx_predict_market_created_checkpoint ON predict_market_created(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_market_settled_expiry_market_id ON predict_market_settled(expiry_market_id);
+CREATE INDEX IF NOT EXISTS idx_predict_market_settled_checkpoint ON predict_market_settled(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_order_minted_expiry_market_id ON predict_order_minted(expiry_market_id);
+CREATE INDEX IF NOT EXISTS idx_predict_order_minted_account_id ON predict_order_minted(account_id);
+CREATE INDEX IF NOT EXISTS idx_predict_order_minted_order_id ON predict_order_minted(order_id);
+CREATE INDEX IF NOT EXISTS idx_predict_order_minted_checkpoint ON predict_order_minted(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_live_order_redeemed_expiry_market_id ON predict_live_order_redeemed(expiry_market_id);
+CREATE INDEX IF NOT EXISTS idx_predict_live_order_redeemed_account_id ON predict_live_order_redeemed(account_id);
+CREATE INDEX IF NOT EXISTS idx_predict_live_order_redeemed_order_id ON predict_live_order_redeemed(order_id);
+CREATE INDEX IF NOT EXISTS idx_predict_live_order_redeemed_checkpoint ON predict_live_order_redeemed(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_settled_order_redeemed_expiry_market_id ON predict_settled_order_redeemed(expiry_market_id);
+CREATE INDEX IF NOT EXISTS idx_predict_settled_order_redeemed_account_id ON predict_settled_order_redeemed(account_id);
+CREATE INDEX IF NOT EXISTS idx_predict_settled_order_redeemed_order_id ON predict_settled_order_redeemed(order_id);
+CREATE INDEX IF NOT EXISTS idx_predict_settled_order_redeemed_checkpoint ON predict_settled_order_redeemed(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_liquidated_order_redeemed_expiry_market_id ON predict_liquidated_order_redeemed(expiry_market_id);
+CREATE INDEX IF NOT EXISTS idx_predict_liquidated_order_redeemed_account_id ON predict_liquidated_order_redeemed(account_id);
+CREATE INDEX IF NOT EXISTS idx_predict_liquidated_order_redeemed_order_id ON predict_liquidated_order_redeemed(order_id);
+CREATE INDEX IF NOT EXISTS idx_predict_liquidated_order_redeemed_checkpoint ON predict_liquidated_order_redeemed(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_order_liquidated_expiry_market_id ON predict_order_liquidated(expiry_market_id);
+CREATE INDEX IF NOT EXISTS idx_predict_order_liquidated_order_id ON predict_order_liquidated(order_id);
+CREATE INDEX IF NOT EXISTS idx_predict_order_liquidated_checkpoint ON predict_order_liquidated(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_supply_requested_pool_vault_id ON predict_supply_requested(pool_vault_id);
+CREATE INDEX IF NOT EXISTS idx_predict_supply_requested_account_id ON predict_supply_requested(account_id);
+CREATE INDEX IF NOT EXISTS idx_predict_supply_requested_checkpoint ON predict_supply_requested(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_withdraw_requested_pool_vault_id ON predict_withdraw_requested(pool_vault_id);
+CREATE INDEX IF NOT EXISTS idx_predict_withdraw_requested_account_id ON predict_withdraw_requested(account_id);
+CREATE INDEX IF NOT EXISTS idx_predict_withdraw_requested_checkpoint ON predict_withdraw_requested(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_request_cancelled_pool_vault_id ON predict_request_cancelled(pool_vault_id);
+CREATE INDEX IF NOT EXISTS idx_predict_request_cancelled_account_id ON predict_request_cancelled(account_id);
+CREATE INDEX IF NOT EXISTS idx_predict_request_cancelled_checkpoint ON predict_request_cancelled(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_supply_filled_pool_vault_id ON predict_supply_filled(pool_vault_id);
+CREATE INDEX IF NOT EXISTS idx_predict_supply_filled_account_id ON predict_supply_filled(account_id);
+CREATE INDEX IF NOT EXISTS idx_predict_supply_filled_checkpoint ON predict_supply_filled(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_withdraw_filled_pool_vault_id ON predict_withdraw_filled(pool_vault_id);
+CREATE INDEX IF NOT EXISTS idx_predict_withdraw_filled_account_id ON predict_withdraw_filled(account_id);
+CREATE INDEX IF NOT EXISTS idx_predict_withdraw_filled_checkpoint ON predict_withdraw_filled(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_builder_code_created_owner ON predict_builder_code_created(owner);
+CREATE INDEX IF NOT EXISTS idx_predict_builder_code_created_checkpoint ON predict_builder_code_created(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_builder_code_set_account_id ON predict_builder_code_set(account_id);
+CREATE INDEX IF NOT EXISTS idx_predict_builder_code_set_checkpoint ON predict_builder_code_set(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_predict_builder_fees_claimed_builder_code_id ON predict_builder_fees_claimed(builder_code_id);
+CREATE INDEX IF NOT EXISTS idx_predict_builder_fees_claimed_checkpoint ON predict_builder_fees_claimed(checkpoint);
@@ -0,0 +1,7 @@
+DROP TABLE IF EXISTS propbook_block_scholes_observations;
+DROP TABLE IF EXISTS propbook_oracle_observations;
+DROP TABLE IF EXISTS propbook_block_scholes_batch_ingested;
+DROP TABLE IF EXISTS propbook_oracle_rebound;
+DROP TABLE IF EXISTS propbook_block_scholes_stores_registered;
+DROP TABLE IF EXISTS propbook_oracle_bound;
+DROP TABLE IF EXISTS propbook_oracle_source_registered;
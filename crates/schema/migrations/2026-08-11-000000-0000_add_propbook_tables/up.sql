@@ -0,0 +1,141 @@
+-- OracleSourceRegistered - provider sources wrapped as propbook oracles
+CREATE TABLE IF NOT EXISTS propbook_oracle_source_registered (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    oracle_kind                 SMALLINT    NOT NULL,
+    source_id                   BIGINT      NOT NULL,
+    propbook_oracle_id          TEXT        NOT NULL
+);
+
+-- OracleBound - first canonical assignment of an underlying/oracle/value slot
+CREATE TABLE IF NOT EXISTS propbook_oracle_bound (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    propbook_underlying_id      BIGINT      NOT NULL,
+    oracle_kind                 SMALLINT    NOT NULL,
+    source_id                   BIGINT      NOT NULL,
+    propbook_oracle_id          TEXT        NOT NULL,
+    value_kind                  SMALLINT    NOT NULL
+);
+
+-- BlockScholesStoresRegistered - canonical Block Scholes value/SVI store pair per underlying
+CREATE TABLE IF NOT EXISTS propbook_block_scholes_stores_registered (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    propbook_underlying_id      BIGINT      NOT NULL,
+    value_store_id              TEXT        NOT NULL,
+    svi_store_id                TEXT        NOT NULL,
+    block_scholes_base_asset    TEXT        NOT NULL
+);
+
+-- OracleRebound - replacement of an existing canonical binding
+CREATE TABLE IF NOT EXISTS propbook_oracle_rebound (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    propbook_underlying_id      BIGINT      NOT NULL,
+    oracle_kind                 SMALLINT    NOT NULL,
+    value_kind                  SMALLINT    NOT NULL,
+    old_source_id               BIGINT      NOT NULL,
+    old_propbook_oracle_id      TEXT        NOT NULL,
+    new_source_id               BIGINT      NOT NULL,
+    new_propbook_oracle_id      TEXT        NOT NULL
+);
+
+-- BlockScholesBatchIngested - one row per ingested Block Scholes batch
+CREATE TABLE IF NOT EXISTS propbook_block_scholes_batch_ingested (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    propbook_oracle_id          TEXT        NOT NULL,
+    series_kind                 SMALLINT    NOT NULL,
+    published_at_ms             BIGINT      NOT NULL,
+    update_count                BIGINT      NOT NULL,
+    applied                     BIGINT      NOT NULL
+);
+
+-- ObservationRecorded / ObservationInserted - Pyth spot observations accepted by an oracle lane
+CREATE TABLE IF NOT EXISTS propbook_oracle_observations (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    propbook_oracle_id          TEXT        NOT NULL,
+    inserted                    BOOLEAN     NOT NULL,
+    source_timestamp_ms         BIGINT      NOT NULL,
+    update_timestamp_ms         BIGINT      NOT NULL,
+    writer_digest               TEXT        NOT NULL,
+    pyth_source_id              BIGINT      NOT NULL,
+    price                       NUMERIC     NOT NULL,
+    exponent                    INTEGER     NOT NULL,
+    feed_update_timestamp_us    BIGINT      NOT NULL
+);
+
+-- BlockScholesObservationRecorded - spot/forward values (value) and SVI parameters (svi_*) as signed decimals
+CREATE TABLE IF NOT EXISTS propbook_block_scholes_observations (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    propbook_oracle_id          TEXT        NOT NULL,
+    sid                         TEXT        NOT NULL,
+    series_kind                 SMALLINT    NOT NULL,
+    expiry_ms                   BIGINT      NOT NULL,
+    model_timestamp_ms          BIGINT      NOT NULL,
+    published_at_ms             BIGINT      NOT NULL,
+    recorded_at_ms              BIGINT      NOT NULL,
+    writer_digest               TEXT        NOT NULL,
+    value                       NUMERIC,
+    svi_a                       NUMERIC,
+    svi_b                       NUMERIC,
+    svi_sigma                   NUMERIC,
+    svi_rho                     NUMERIC,
+    svi_m                       NUMERIC
+);
+
+CREATE INDEX IF NOT EXISTS idx_propbook_oracle_source_registered_propbook_oracle_id ON propbook_oracle_source_registered(propbook_oracle_id);
+CREATE INDEX IF NOT EXISTS idx_propbook_oracle_source_registered_checkpoint ON propbook_oracle_source_registered(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_propbook_oracle_bound_propbook_underlying_id ON propbook_oracle_bound(propbook_underlying_id);
+CREATE INDEX IF NOT EXISTS idx_propbook_oracle_bound_propbook_oracle_id ON propbook_oracle_bound(propbook_oracle_id);
+CREATE INDEX IF NOT EXISTS idx_propbook_oracle_bound_checkpoint ON propbook_oracle_bound(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_propbook_block_scholes_stores_registered_propbook_underlying_id ON propbook_block_scholes_stores_registered(propbook_underlying_id);
+CREATE INDEX IF NOT EXISTS idx_propbook_block_scholes_stores_registered_checkpoint ON propbook_block_scholes_stores_registered(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_propbook_oracle_rebound_propbook_underlying_id ON propbook_oracle_rebound(propbook_underlying_id);
+CREATE INDEX IF NOT EXISTS idx_propbook_oracle_rebound_new_propbook_oracle_id ON propbook_oracle_rebound(new_propbook_oracle_id);
+CREATE INDEX IF NOT EXISTS idx_propbook_oracle_rebound_checkpoint ON propbook_oracle_rebound(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_propbook_block_scholes_batch_ingested_propbook_oracle_id ON propbook_block_scholes_batch_ingested(propbook_oracle_id);
+CREATE INDEX IF NOT EXISTS idx_propbook_block_scholes_batch_ingested_checkpoint ON propbook_block_scholes_batch_ingested(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_propbook_oracle_observations_propbook_oracle_id_source_timestamp_ms ON propbook_oracle_observations(propbook_oracle_id, source_timestamp_ms);
+CREATE INDEX IF NOT EXISTS idx_propbook_oracle_observations_checkpoint ON propbook_oracle_observations(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_propbook_block_scholes_observations_propbook_oracle_id_model_timestamp_ms ON propbook_block_scholes_observations(propbook_oracle_id, model_timestamp_ms);
+CREATE INDEX IF NOT EXISTS idx_propbook_block_scholes_observations_sid ON propbook_block_scholes_observations(sid);
+CREATE INDEX IF NOT EXISTS idx_propbook_block_scholes_observations_checkpoint ON propbook_block_scholes_observations(checkpoint);
@@ -0,0 +1,9 @@
+DROP TABLE IF EXISTS account_session_revoked;
+DROP TABLE IF EXISTS account_session_authorized;
+DROP TABLE IF EXISTS deepbook_core_account_initialized;
+DROP TABLE IF EXISTS account_funds_settled;
+DROP TABLE IF EXISTS account_withdrawn;
+DROP TABLE IF EXISTS account_deposited;
+DROP TABLE IF EXISTS account_app_deauthorized;
+DROP TABLE IF EXISTS account_app_authorized;
+DROP TABLE IF EXISTS account_created;
@@ -0,0 +1,148 @@
+-- AccountCreated - canonical derived accounts
+CREATE TABLE IF NOT EXISTS account_created (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    wrapper_id                  TEXT        NOT NULL,
+    owner                       TEXT        NOT NULL,
+    self_owned                  BOOLEAN     NOT NULL,
+    referrer_account_id         TEXT
+);
+
+-- AppAuthorized - app witness types added to the registry whitelist
+CREATE TABLE IF NOT EXISTS account_app_authorized (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    app                         TEXT        NOT NULL
+);
+
+-- AppDeauthorized - app witness types removed from the registry whitelist
+CREATE TABLE IF NOT EXISTS account_app_deauthorized (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    app                         TEXT        NOT NULL
+);
+
+-- Deposited - coins deposited into an account
+CREATE TABLE IF NOT EXISTS account_deposited (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    coin_type                   TEXT        NOT NULL,
+    amount                      BIGINT      NOT NULL,
+    new_balance                 BIGINT      NOT NULL
+);
+
+-- Withdrawn - coins withdrawn from an account
+CREATE TABLE IF NOT EXISTS account_withdrawn (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    coin_type                   TEXT        NOT NULL,
+    amount                      BIGINT      NOT NULL,
+    new_balance                 BIGINT      NOT NULL
+);
+
+-- FundsSettled - accumulator funds settled into an account
+CREATE TABLE IF NOT EXISTS account_funds_settled (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    coin_type                   TEXT        NOT NULL,
+    amount                      BIGINT      NOT NULL,
+    new_balance                 BIGINT      NOT NULL
+);
+
+-- DeepbookCoreAccountInitialized - balance manager attached to a canonical account
+CREATE TABLE IF NOT EXISTS deepbook_core_account_initialized (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    account_owner               TEXT        NOT NULL,
+    wrapper_id                  TEXT        NOT NULL,
+    balance_manager_id          TEXT        NOT NULL
+);
+
+-- SessionAuthorized - session addresses granted (or re-granted) access to an account
+CREATE TABLE IF NOT EXISTS account_session_authorized (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    session                     TEXT        NOT NULL,
+    expires_at_ms               BIGINT      NOT NULL
+);
+
+-- SessionRevoked - session grants removed from an account
+CREATE TABLE IF NOT EXISTS account_session_revoked (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    session                     TEXT        NOT NULL,
+    expires_at_ms               BIGINT      NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_account_created_account_id ON account_created(account_id);
+CREATE INDEX IF NOT EXISTS idx_account_created_owner ON account_created(owner);
+CREATE INDEX IF NOT EXISTS idx_account_created_checkpoint ON account_created(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_account_app_authorized_app ON account_app_authorized(app);
+CREATE INDEX IF NOT EXISTS idx_account_app_authorized_checkpoint ON account_app_authorized(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_account_app_deauthorized_app ON account_app_deauthorized(app);
+CREATE INDEX IF NOT EXISTS idx_account_app_deauthorized_checkpoint ON account_app_deauthorized(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_account_deposited_account_id ON account_deposited(account_id);
+CREATE INDEX IF NOT EXISTS idx_account_deposited_checkpoint ON account_deposited(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_account_withdrawn_account_id ON account_withdrawn(account_id);
+CREATE INDEX IF NOT EXISTS idx_account_withdrawn_checkpoint ON account_withdrawn(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_account_funds_settled_account_id ON account_funds_settled(account_id);
+CREATE INDEX IF NOT EXISTS idx_account_funds_settled_checkpoint ON account_funds_settled(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_deepbook_core_account_initialized_account_id ON deepbook_core_account_initialized(account_id);
+CREATE INDEX IF NOT EXISTS idx_deepbook_core_account_initialized_balance_manager_id ON deepbook_core_account_initialized(balance_manager_id);
+CREATE INDEX IF NOT EXISTS idx_deepbook_core_account_initialized_checkpoint ON deepbook_core_account_initialized(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_account_session_authorized_account_id_session ON account_session_authorized(account_id, session);
+CREATE INDEX IF NOT EXISTS idx_account_session_authorized_checkpoint ON account_session_authorized(checkpoint);
+CREATE INDEX IF NOT EXISTS idx_account_session_revoked_account_id_session ON account_session_revoked(account_id, session);
+CREATE INDEX IF NOT EXISTS idx_account_session_revoked_checkpoint ON account_session_revoked(checkpoint);
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS liquidation_by_vault;
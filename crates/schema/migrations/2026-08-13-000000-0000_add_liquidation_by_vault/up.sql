@@ -0,0 +1,25 @@
+-- LiquidationByVault - liquidations executed by the margin_liquidation vault, linked to the matching liquidation row
+CREATE TABLE IF NOT EXISTS liquidation_by_vault (
+    event_digest                TEXT        PRIMARY KEY,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    vault_id                    TEXT        NOT NULL,
+    margin_manager_id           TEXT        NOT NULL,
+    margin_pool_id              TEXT        NOT NULL,
+    base_in                     BIGINT      NOT NULL,
+    base_out                    BIGINT      NOT NULL,
+    quote_in                    BIGINT      NOT NULL,
+    quote_out                   BIGINT      NOT NULL,
+    repay_balance_remaining     BIGINT      NOT NULL,
+    base_liquidation            BOOLEAN     NOT NULL,
+    liquidation_event_digest    TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_liquidation_by_vault_margin_pool_id ON liquidation_by_vault(margin_pool_id);
+CREATE INDEX IF NOT EXISTS idx_liquidation_by_vault_vault_id ON liquidation_by_vault(vault_id);
+CREATE INDEX IF NOT EXISTS idx_liquidation_by_vault_liquidation_event_digest ON liquidation_by_vault(liquidation_event_digest);
+CREATE INDEX IF NOT EXISTS idx_liquidation_by_vault_checkpoint ON liquidation_by_vault(checkpoint);
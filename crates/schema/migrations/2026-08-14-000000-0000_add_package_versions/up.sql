@@ -0,0 +1,15 @@
+-- Package versions discovered by following the upgrade lineage of registry packages
+CREATE TABLE IF NOT EXISTS package_versions (
+    package_id                  TEXT        PRIMARY KEY,
+    original_package_id         TEXT        NOT NULL,
+    family                      TEXT        NOT NULL,
+    version                     BIGINT      NOT NULL,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_package_versions_original_package_id ON package_versions(original_package_id);
+CREATE INDEX IF NOT EXISTS idx_package_versions_checkpoint ON package_versions(checkpoint);
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS undecodable_events;
@@ -0,0 +1,19 @@
+-- Events a pipeline could not decode, recorded instead of failing the pipeline when the indexer
+-- runs with --dead-letter-undecodable-events. One row per event and subscribed pipeline.
+CREATE TABLE IF NOT EXISTS undecodable_events (
+    pipeline                    TEXT        NOT NULL,
+    event_digest                TEXT        NOT NULL,
+    digest                      TEXT        NOT NULL,
+    sender                      TEXT        NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    timestamp                   TIMESTAMP   DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    checkpoint_timestamp_ms     BIGINT      NOT NULL,
+    package                     TEXT        NOT NULL,
+    event_index                 BIGINT      NOT NULL,
+    event_type                  TEXT        NOT NULL,
+    contents                    BYTEA       NOT NULL,
+    error                       TEXT        NOT NULL,
+    PRIMARY KEY (pipeline, event_digest)
+);
+
+CREATE INDEX IF NOT EXISTS idx_undecodable_events_checkpoint ON undecodable_events(checkpoint);
@@ -0,0 +1,23 @@
+-- Orders currently resting on a book, maintained by the sequential open_orders pipeline from
+-- order placed/modified/filled/canceled/expired events. Rows are removed once an order is fully
+-- filled, canceled or expired.
+CREATE TABLE IF NOT EXISTS open_orders (
+    pool_id                     TEXT        NOT NULL,
+    order_id                    TEXT        NOT NULL,
+    balance_manager_id          TEXT        NOT NULL,
+    trader                      TEXT        NOT NULL,
+    client_order_id             BIGINT      NOT NULL,
+    is_bid                      BOOLEAN     NOT NULL,
+    price                       BIGINT      NOT NULL,
+    original_quantity           BIGINT      NOT NULL,
+    filled_quantity             BIGINT      NOT NULL,
+    quantity                    BIGINT      NOT NULL,
+    status                      TEXT        NOT NULL,
+    expire_timestamp            BIGINT      NOT NULL,
+    placed_at                   BIGINT      NOT NULL,
+    last_updated_at             BIGINT      NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    PRIMARY KEY (pool_id, order_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_open_orders_balance_manager ON open_orders(pool_id, balance_manager_id, last_updated_at DESC);
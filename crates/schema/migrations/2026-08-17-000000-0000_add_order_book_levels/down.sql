@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_open_orders_price;
+DROP TABLE IF EXISTS order_book_levels;
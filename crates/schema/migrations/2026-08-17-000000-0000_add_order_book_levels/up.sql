@@ -0,0 +1,19 @@
+-- Open order quantity by pool, side and price, maintained by the open_orders pipeline in the same
+-- transaction as open_orders. Serves the level 1 / level 2 order book.
+CREATE TABLE IF NOT EXISTS order_book_levels (
+    pool_id                     TEXT        NOT NULL,
+    is_bid                      BOOLEAN     NOT NULL,
+    price                       BIGINT      NOT NULL,
+    quantity                    BIGINT      NOT NULL,
+    order_count                 BIGINT      NOT NULL,
+    PRIMARY KEY (pool_id, is_bid, price)
+);
+
+-- Levels are aggregated again from the open orders at the prices that changed
+CREATE INDEX IF NOT EXISTS idx_open_orders_price ON open_orders(pool_id, price);
+
+INSERT INTO order_book_levels (pool_id, is_bid, price, quantity, order_count)
+SELECT pool_id, is_bid, price, SUM(quantity)::BIGINT, COUNT(*)
+FROM open_orders
+GROUP BY pool_id, is_bid, price
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,11 @@
+-- Net amount deposited into each balance manager per asset, maintained by the sequential
+-- balance_manager_balances pipeline from balance events: deposits add to it, withdrawals
+-- subtract from it.
+CREATE TABLE IF NOT EXISTS balance_manager_balances (
+    balance_manager_id          TEXT        NOT NULL,
+    asset                       TEXT        NOT NULL,
+    net_deposited               BIGINT      NOT NULL,
+    last_updated_at             BIGINT      NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    PRIMARY KEY (balance_manager_id, asset)
+);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS trader_volume_1d;
+DROP TABLE IF EXISTS trader_volume_1h;
@@ -0,0 +1,46 @@
+-- Trading activity of each balance manager per pool, in hourly and daily buckets, maintained by
+-- the sequential trader_volume pipeline from order fills. Volumes and fees are in on-chain units;
+-- bucket_start is the start of the hour or UTC day in milliseconds. Fees not paid in DEEP are
+-- paid in the input token of the trader's side: quote for bids, base for asks.
+CREATE TABLE IF NOT EXISTS trader_volume_1h (
+    pool_id                     TEXT        NOT NULL,
+    balance_manager_id          TEXT        NOT NULL,
+    bucket_start                BIGINT      NOT NULL,
+    maker_base_volume           BIGINT      NOT NULL,
+    maker_quote_volume          BIGINT      NOT NULL,
+    taker_base_volume           BIGINT      NOT NULL,
+    taker_quote_volume          BIGINT      NOT NULL,
+    maker_trade_count           BIGINT      NOT NULL,
+    taker_trade_count           BIGINT      NOT NULL,
+    deep_fees                   BIGINT      NOT NULL,
+    base_fees                   BIGINT      NOT NULL,
+    quote_fees                  BIGINT      NOT NULL,
+    PRIMARY KEY (pool_id, balance_manager_id, bucket_start)
+);
+
+CREATE TABLE IF NOT EXISTS trader_volume_1d (
+    pool_id                     TEXT        NOT NULL,
+    balance_manager_id          TEXT        NOT NULL,
+    bucket_start                BIGINT      NOT NULL,
+    maker_base_volume           BIGINT      NOT NULL,
+    maker_quote_volume          BIGINT      NOT NULL,
+    taker_base_volume           BIGINT      NOT NULL,
+    taker_quote_volume          BIGINT      NOT NULL,
+    maker_trade_count           BIGINT      NOT NULL,
+    taker_trade_count           BIGINT      NOT NULL,
+    deep_fees                   BIGINT      NOT NULL,
+    base_fees                   BIGINT      NOT NULL,
+    quote_fees                  BIGINT      NOT NULL,
+    PRIMARY KEY (pool_id, balance_manager_id, bucket_start)
+);
+
+-- Per balance manager history across pools
+CREATE INDEX IF NOT EXISTS idx_trader_volume_1h_manager_time
+    ON trader_volume_1h (balance_manager_id, bucket_start DESC);
+CREATE INDEX IF NOT EXISTS idx_trader_volume_1d_manager_time
+    ON trader_volume_1d (balance_manager_id, bucket_start DESC);
+-- Per pool leaderboards over a time range
+CREATE INDEX IF NOT EXISTS idx_trader_volume_1h_pool_time
+    ON trader_volume_1h (pool_id, bucket_start);
+CREATE INDEX IF NOT EXISTS idx_trader_volume_1d_pool_time
+    ON trader_volume_1d (pool_id, bucket_start);
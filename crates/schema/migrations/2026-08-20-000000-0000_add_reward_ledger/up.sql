@@ -0,0 +1,18 @@
+-- Rewards earned and claimed per balance manager (trading rebates) and per referral object
+-- (referral fees), by pool, maintained by the sequential rewards pipeline. Amounts are in
+-- on-chain units of the pool's base and quote assets and DEEP; what is still owed is earned less
+-- claimed. Rebates are only reported when claimed, so for balance managers earned equals claimed.
+CREATE TABLE IF NOT EXISTS reward_ledger (
+    account_kind                TEXT        NOT NULL,
+    account_id                  TEXT        NOT NULL,
+    pool_id                     TEXT        NOT NULL,
+    earned_base                 BIGINT      NOT NULL,
+    earned_quote                BIGINT      NOT NULL,
+    earned_deep                 BIGINT      NOT NULL,
+    claimed_base                BIGINT      NOT NULL,
+    claimed_quote               BIGINT      NOT NULL,
+    claimed_deep                BIGINT      NOT NULL,
+    last_updated_at             BIGINT      NOT NULL,
+    checkpoint                  BIGINT      NOT NULL,
+    PRIMARY KEY (account_kind, account_id, pool_id)
+);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_order_updates_balance_manager_id_checkpoint;
+DROP INDEX IF EXISTS idx_order_fills_maker_balance_manager_id_checkpoint;
+DROP INDEX IF EXISTS idx_order_fills_taker_balance_manager_id_checkpoint;
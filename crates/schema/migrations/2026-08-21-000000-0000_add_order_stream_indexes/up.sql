@@ -0,0 +1,9 @@
+-- A balance manager's order updates and fills in checkpoint order, for /order_stream
+CREATE INDEX IF NOT EXISTS idx_order_updates_balance_manager_id_checkpoint
+    ON order_updates (balance_manager_id, checkpoint, event_digest);
+
+CREATE INDEX IF NOT EXISTS idx_order_fills_maker_balance_manager_id_checkpoint
+    ON order_fills (maker_balance_manager_id, checkpoint, event_digest);
+
+CREATE INDEX IF NOT EXISTS idx_order_fills_taker_balance_manager_id_checkpoint
+    ON order_fills (taker_balance_manager_id, checkpoint, event_digest);
@@ -0,0 +1,2 @@
+DROP TRIGGER IF EXISTS watermarks_notify_pipeline_commit ON watermarks;
+DROP FUNCTION IF EXISTS notify_pipeline_commit();
@@ -0,0 +1,28 @@
+-- Announces each advance of a pipeline's watermark on the `deepbook_commits` channel, so the
+-- server can push new data and invalidate its caches instead of waiting for its next poll.
+-- Sequential pipelines advance their watermark in the transaction that commits each batch;
+-- concurrent pipelines advance it once every checkpoint up to it is committed. Either way, the
+-- announced range is readable when the notification is delivered, on commit.
+--
+-- The payload is JSON: `pipeline`, the first (`checkpoint_lo`, null when the pipeline's
+-- watermark was just created) and last (`checkpoint_hi`) newly committed checkpoints, and the
+-- timestamp of the last one (`timestamp_ms_hi`).
+CREATE OR REPLACE FUNCTION notify_pipeline_commit() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'UPDATE' AND NEW.checkpoint_hi_inclusive <= OLD.checkpoint_hi_inclusive THEN
+        RETURN NEW;
+    END IF;
+
+    PERFORM pg_notify('deepbook_commits', json_build_object(
+        'pipeline', NEW.pipeline,
+        'checkpoint_lo', CASE WHEN TG_OP = 'UPDATE' THEN OLD.checkpoint_hi_inclusive + 1 END,
+        'checkpoint_hi', NEW.checkpoint_hi_inclusive,
+        'timestamp_ms_hi', NEW.timestamp_ms_hi_inclusive
+    )::text);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER watermarks_notify_pipeline_commit
+    AFTER INSERT OR UPDATE OF checkpoint_hi_inclusive ON watermarks
+    FOR EACH ROW EXECUTE FUNCTION notify_pipeline_commit();
@@ -0,0 +1,39 @@
+DROP INDEX IF EXISTS idx_asset_supplied_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_asset_withdrawn_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_collateral_events_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_deepbook_pool_config_updated_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_deepbook_pool_registered_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_deepbook_pool_updated_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_deepbook_pool_updated_registry_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_interest_params_updated_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_liquidation_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_loan_borrowed_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_loan_repaid_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_maintainer_cap_updated_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_maintainer_fees_withdrawn_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_margin_manager_created_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_margin_pool_config_updated_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_pause_cap_updated_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_propbook_block_scholes_batch_ingested_checkpoint;
+DROP INDEX IF EXISTS idx_propbook_block_scholes_observations_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_propbook_oracle_observations_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_protocol_fees_increased_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_protocol_fees_withdrawn_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_rebates_v2_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_referral_fee_events_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_referral_fees_claimed_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_supplier_cap_minted_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_supply_referral_minted_checkpoint_event_digest;
+
+DROP INDEX IF EXISTS idx_order_updates_balance_manager_id_checkpoint;
+DROP INDEX IF EXISTS idx_order_fills_maker_balance_manager_id_checkpoint;
+DROP INDEX IF EXISTS idx_order_fills_taker_balance_manager_id_checkpoint;
+
+CREATE INDEX IF NOT EXISTS idx_order_updates_balance_manager_id_checkpoint
+    ON order_updates (balance_manager_id, checkpoint, event_digest);
+
+CREATE INDEX IF NOT EXISTS idx_order_fills_maker_balance_manager_id_checkpoint
+    ON order_fills (maker_balance_manager_id, checkpoint, event_digest);
+
+CREATE INDEX IF NOT EXISTS idx_order_fills_taker_balance_manager_id_checkpoint
+    ON order_fills (taker_balance_manager_id, checkpoint, event_digest);
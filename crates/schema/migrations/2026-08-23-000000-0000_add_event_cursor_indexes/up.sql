@@ -0,0 +1,92 @@
+-- Event lists page newest first by checkpoint and event digest, comparing digests bytewise
+CREATE INDEX IF NOT EXISTS idx_asset_supplied_checkpoint_event_digest
+    ON asset_supplied (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_asset_withdrawn_checkpoint_event_digest
+    ON asset_withdrawn (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_collateral_events_checkpoint_event_digest
+    ON collateral_events (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_deepbook_pool_config_updated_checkpoint_event_digest
+    ON deepbook_pool_config_updated (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_deepbook_pool_registered_checkpoint_event_digest
+    ON deepbook_pool_registered (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_deepbook_pool_updated_checkpoint_event_digest
+    ON deepbook_pool_updated (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_deepbook_pool_updated_registry_checkpoint_event_digest
+    ON deepbook_pool_updated_registry (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_interest_params_updated_checkpoint_event_digest
+    ON interest_params_updated (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_liquidation_checkpoint_event_digest
+    ON liquidation (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_loan_borrowed_checkpoint_event_digest
+    ON loan_borrowed (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_loan_repaid_checkpoint_event_digest
+    ON loan_repaid (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_maintainer_cap_updated_checkpoint_event_digest
+    ON maintainer_cap_updated (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_maintainer_fees_withdrawn_checkpoint_event_digest
+    ON maintainer_fees_withdrawn (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_margin_manager_created_checkpoint_event_digest
+    ON margin_manager_created (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_margin_pool_config_updated_checkpoint_event_digest
+    ON margin_pool_config_updated (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_pause_cap_updated_checkpoint_event_digest
+    ON pause_cap_updated (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_propbook_block_scholes_batch_ingested_checkpoint
+    ON propbook_block_scholes_batch_ingested (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_propbook_block_scholes_observations_checkpoint_event_digest
+    ON propbook_block_scholes_observations (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_propbook_oracle_observations_checkpoint_event_digest
+    ON propbook_oracle_observations (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_protocol_fees_increased_checkpoint_event_digest
+    ON protocol_fees_increased (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_protocol_fees_withdrawn_checkpoint_event_digest
+    ON protocol_fees_withdrawn (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_rebates_v2_checkpoint_event_digest
+    ON rebates_v2 (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_referral_fee_events_checkpoint_event_digest
+    ON referral_fee_events (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_referral_fees_claimed_checkpoint_event_digest
+    ON referral_fees_claimed (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_supplier_cap_minted_checkpoint_event_digest
+    ON supplier_cap_minted (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+CREATE INDEX IF NOT EXISTS idx_supply_referral_minted_checkpoint_event_digest
+    ON supply_referral_minted (checkpoint DESC, event_digest COLLATE "C" DESC);
+
+-- /order_stream compares event digests bytewise too
+DROP INDEX IF EXISTS idx_order_updates_balance_manager_id_checkpoint;
+DROP INDEX IF EXISTS idx_order_fills_maker_balance_manager_id_checkpoint;
+DROP INDEX IF EXISTS idx_order_fills_taker_balance_manager_id_checkpoint;
+
+CREATE INDEX IF NOT EXISTS idx_order_updates_balance_manager_id_checkpoint
+    ON order_updates (balance_manager_id, checkpoint, event_digest COLLATE "C");
+
+CREATE INDEX IF NOT EXISTS idx_order_fills_maker_balance_manager_id_checkpoint
+    ON order_fills (maker_balance_manager_id, checkpoint, event_digest COLLATE "C");
+
+CREATE INDEX IF NOT EXISTS idx_order_fills_taker_balance_manager_id_checkpoint
+    ON order_fills (taker_balance_manager_id, checkpoint, event_digest COLLATE "C");
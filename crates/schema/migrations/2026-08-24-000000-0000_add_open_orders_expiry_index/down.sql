@@ -0,0 +1 @@
+DROP INDEX IF EXISTS idx_open_orders_expiry;
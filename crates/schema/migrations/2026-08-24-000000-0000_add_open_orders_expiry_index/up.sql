@@ -0,0 +1,2 @@
+-- Order book levels are served without the orders that have expired but are still on the book
+CREATE INDEX IF NOT EXISTS idx_open_orders_expiry ON open_orders(pool_id, is_bid, expire_timestamp);
@@ -0,0 +1,6 @@
+-- When each scheduled job last ran, written under the job's advisory lock, so that indexer
+-- replicas sharing the database run a job once per interval between them rather than once each.
+CREATE TABLE IF NOT EXISTS scheduled_job_runs (
+    name                TEXT        PRIMARY KEY,
+    last_run_at_ms      BIGINT      NOT NULL
+);
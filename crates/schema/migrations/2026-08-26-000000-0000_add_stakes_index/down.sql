@@ -0,0 +1 @@
+DROP INDEX IF EXISTS idx_stakes_pool_balance_manager;
@@ -0,0 +1,2 @@
+-- Governance sums up each balance manager's stakes since its last unstake
+CREATE INDEX IF NOT EXISTS idx_stakes_pool_balance_manager ON stakes(pool_id, balance_manager_id, checkpoint);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_order_updates_checkpoint_event_digest;
+DROP INDEX IF EXISTS idx_order_fills_checkpoint_event_digest;
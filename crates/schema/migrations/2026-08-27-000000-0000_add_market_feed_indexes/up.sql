@@ -0,0 +1,6 @@
+-- All pools' fills and order updates in checkpoint and event digest order, for the /ws feed
+CREATE INDEX IF NOT EXISTS idx_order_fills_checkpoint_event_digest
+    ON order_fills (checkpoint, event_digest COLLATE "C");
+
+CREATE INDEX IF NOT EXISTS idx_order_updates_checkpoint_event_digest
+    ON order_updates (checkpoint, event_digest COLLATE "C");
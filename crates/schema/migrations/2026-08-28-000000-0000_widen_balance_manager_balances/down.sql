@@ -0,0 +1,2 @@
+ALTER TABLE balance_manager_balances
+    ALTER COLUMN net_deposited TYPE BIGINT;
@@ -0,0 +1,3 @@
+-- Deposits are u64 amounts and the net is their running sum, so it can outgrow BIGINT
+ALTER TABLE balance_manager_balances
+    ALTER COLUMN net_deposited TYPE NUMERIC;
@@ -0,0 +1,17 @@
+ALTER TABLE trader_volume_1h
+    ALTER COLUMN maker_base_volume TYPE BIGINT,
+    ALTER COLUMN maker_quote_volume TYPE BIGINT,
+    ALTER COLUMN taker_base_volume TYPE BIGINT,
+    ALTER COLUMN taker_quote_volume TYPE BIGINT,
+    ALTER COLUMN deep_fees TYPE BIGINT,
+    ALTER COLUMN base_fees TYPE BIGINT,
+    ALTER COLUMN quote_fees TYPE BIGINT;
+
+ALTER TABLE trader_volume_1d
+    ALTER COLUMN maker_base_volume TYPE BIGINT,
+    ALTER COLUMN maker_quote_volume TYPE BIGINT,
+    ALTER COLUMN taker_base_volume TYPE BIGINT,
+    ALTER COLUMN taker_quote_volume TYPE BIGINT,
+    ALTER COLUMN deep_fees TYPE BIGINT,
+    ALTER COLUMN base_fees TYPE BIGINT,
+    ALTER COLUMN quote_fees TYPE BIGINT;
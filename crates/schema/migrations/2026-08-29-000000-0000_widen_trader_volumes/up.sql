@@ -0,0 +1,18 @@
+-- Volumes and fees are running sums of u64 amounts, so they can outgrow BIGINT
+ALTER TABLE trader_volume_1h
+    ALTER COLUMN maker_base_volume TYPE NUMERIC,
+    ALTER COLUMN maker_quote_volume TYPE NUMERIC,
+    ALTER COLUMN taker_base_volume TYPE NUMERIC,
+    ALTER COLUMN taker_quote_volume TYPE NUMERIC,
+    ALTER COLUMN deep_fees TYPE NUMERIC,
+    ALTER COLUMN base_fees TYPE NUMERIC,
+    ALTER COLUMN quote_fees TYPE NUMERIC;
+
+ALTER TABLE trader_volume_1d
+    ALTER COLUMN maker_base_volume TYPE NUMERIC,
+    ALTER COLUMN maker_quote_volume TYPE NUMERIC,
+    ALTER COLUMN taker_base_volume TYPE NUMERIC,
+    ALTER COLUMN taker_quote_volume TYPE NUMERIC,
+    ALTER COLUMN deep_fees TYPE NUMERIC,
+    ALTER COLUMN base_fees TYPE NUMERIC,
+    ALTER COLUMN quote_fees TYPE NUMERIC;
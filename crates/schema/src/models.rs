@@ -1,7 +1,16 @@
 use crate::schema::{
+    account_app_authorized,
+    account_app_deauthorized,
+    account_created,
+    account_deposited,
+    account_funds_settled,
+    account_session_authorized,
+    account_session_revoked,
+    account_withdrawn,
     // Margin Pool Operations Events
     asset_supplied,
     asset_withdrawn,
+    balance_manager_balances,
     balance_manager_created,
     balances,
     book_params_updated,
@@ -11,6 +20,7 @@ use crate::schema::{
     conditional_order_events,
     current_price_updated,
     deep_burned,
+    deepbook_core_account_initialized,
     deepbook_pool_config_updated,
     deepbook_pool_registered,
     deepbook_pool_updated,
@@ -21,6 +31,7 @@ use crate::schema::{
     flashloans,
     interest_params_updated,
     liquidation,
+    liquidation_by_vault,
     loan_borrowed,
     loan_repaid,
     // Margin Registry Events
@@ -35,14 +46,39 @@ use crate::schema::{
     // snapshots for analytics
     margin_pool_snapshots,
     max_price_age_updated,
+    open_orders,
+    order_book_levels,
     order_fills,
     order_updates,
+    package_versions,
     pause_cap_updated,
     points,
     pool_created,
     pool_prices,
     pools,
+    predict_builder_code_created,
+    predict_builder_code_set,
+    predict_builder_fees_claimed,
+    predict_liquidated_order_redeemed,
+    predict_live_order_redeemed,
+    predict_market_created,
+    predict_market_settled,
+    predict_order_liquidated,
+    predict_order_minted,
+    predict_request_cancelled,
+    predict_settled_order_redeemed,
+    predict_supply_filled,
+    predict_supply_requested,
+    predict_withdraw_filled,
+    predict_withdraw_requested,
     price_tolerance_updated,
+    propbook_block_scholes_batch_ingested,
+    propbook_block_scholes_observations,
+    propbook_block_scholes_stores_registered,
+    propbook_oracle_bound,
+    propbook_oracle_observations,
+    propbook_oracle_rebound,
+    propbook_oracle_source_registered,
     proposals,
     protocol_fees_increased,
     protocol_fees_withdrawn,
@@ -51,12 +87,14 @@ use crate::schema::{
     referral_claimed,
     referral_fee_events,
     referral_fees_claimed,
+    reward_ledger,
     stakes,
     sui_error_transactions,
     supplier_cap_minted,
     supply_referral_minted,
     taker_fee_penalty_applied,
     trade_params_update,
+    undecodable_events,
     votes,
 };
 use bigdecimal::BigDecimal;
@@ -69,6 +107,7 @@ use serde::{Serialize, Serializer};
 use std::str::FromStr;
 use strum_macros::{AsRefStr, EnumString};
 use sui_field_count::FieldCount;
+use utoipa::ToSchema;
 
 fn serialize_bigdecimal_option<S>(
     value: &Option<BigDecimal>,
@@ -90,7 +129,9 @@ where
     serializer.serialize_str(&value.to_string())
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = order_updates, primary_key(event_digest))]
 pub struct OrderUpdate {
     pub event_digest: String,
@@ -113,7 +154,7 @@ pub struct OrderUpdate {
     pub balance_manager_id: String,
 }
 
-#[derive(Debug, AsExpression, EnumString, AsRefStr)]
+#[derive(Debug, AsExpression, EnumString, AsRefStr, Serialize, ToSchema)]
 #[diesel(sql_type = Text)]
 pub enum OrderUpdateStatus {
     Placed,
@@ -135,7 +176,7 @@ impl ToSql<Text, Pg> for OrderUpdateStatus {
     }
 }
 
-#[derive(Debug, Clone, QueryableByName, Serialize)]
+#[derive(Debug, Clone, QueryableByName, Serialize, ToSchema)]
 pub struct OrderStatus {
     #[diesel(sql_type = diesel::sql_types::Text)]
     pub order_id: String,
@@ -159,7 +200,9 @@ pub struct OrderStatus {
     pub remaining_quantity: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = order_fills, primary_key(event_digest))]
 pub struct OrderFill {
     pub event_digest: String,
@@ -194,7 +237,7 @@ pub struct OrderFillSummary {
     pub quantity: i64,
 }
 
-#[derive(QueryableByName, Debug, Serialize, FieldCount)]
+#[derive(QueryableByName, Debug, Serialize, FieldCount, ToSchema)]
 pub struct BalancesSummary {
     #[diesel(sql_type = Text)]
     pub asset: String,
@@ -261,7 +304,9 @@ pub struct DeepBurned {
     pub burned_amount: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = pool_created, primary_key(event_digest))]
 pub struct PoolCreated {
     pub event_digest: String,
@@ -280,7 +325,9 @@ pub struct PoolCreated {
     pub treasury_address: String,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = book_params_updated, primary_key(event_digest))]
 pub struct BookParamsUpdated {
     pub event_digest: String,
@@ -328,7 +375,9 @@ pub struct Rebates {
     pub claim_amount: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = rebates_v2, primary_key(event_digest))]
 pub struct RebatesV2 {
     pub event_digest: String,
@@ -345,7 +394,9 @@ pub struct RebatesV2 {
     pub claim_deep: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = referral_fee_events, primary_key(event_digest))]
 pub struct ReferralFeeEvent {
     pub event_digest: String,
@@ -458,7 +509,9 @@ pub struct Votes {
     pub stake: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = pools, primary_key(pool_id))]
 pub struct Pools {
     pub pool_id: String,
@@ -488,7 +541,9 @@ pub struct SuiErrorTransactions {
 }
 
 // === Margin Manager Events ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = margin_manager_created, primary_key(event_digest))]
 pub struct MarginManagerCreated {
     pub event_digest: String,
@@ -504,7 +559,9 @@ pub struct MarginManagerCreated {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = loan_borrowed, primary_key(event_digest))]
 pub struct LoanBorrowed {
     pub event_digest: String,
@@ -520,7 +577,9 @@ pub struct LoanBorrowed {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = loan_repaid, primary_key(event_digest))]
 pub struct LoanRepaid {
     pub event_digest: String,
@@ -536,7 +595,9 @@ pub struct LoanRepaid {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = liquidation, primary_key(event_digest))]
 pub struct Liquidation {
     pub event_digest: String,
@@ -552,9 +613,13 @@ pub struct Liquidation {
     pub pool_default: i64,
     pub risk_ratio: i64,
     pub onchain_timestamp: i64,
+    #[schema(value_type = String)]
     pub remaining_base_asset: BigDecimal,
+    #[schema(value_type = String)]
     pub remaining_quote_asset: BigDecimal,
+    #[schema(value_type = String)]
     pub remaining_base_debt: BigDecimal,
+    #[schema(value_type = String)]
     pub remaining_quote_debt: BigDecimal,
     pub base_pyth_price: i64,
     pub base_pyth_decimals: i16,
@@ -563,7 +628,9 @@ pub struct Liquidation {
 }
 
 // === Margin Pool Operations Events ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = asset_supplied, primary_key(event_digest))]
 pub struct AssetSupplied {
     pub event_digest: String,
@@ -580,7 +647,9 @@ pub struct AssetSupplied {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = asset_withdrawn, primary_key(event_digest))]
 pub struct AssetWithdrawn {
     pub event_digest: String,
@@ -598,7 +667,9 @@ pub struct AssetWithdrawn {
 }
 
 // === Margin Pool Admin Events ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = margin_pool_created, primary_key(event_digest))]
 pub struct MarginPoolCreated {
     pub event_digest: String,
@@ -610,11 +681,14 @@ pub struct MarginPoolCreated {
     pub margin_pool_id: String,
     pub maintainer_cap_id: String,
     pub asset_type: String,
+    #[schema(value_type = Object)]
     pub config_json: serde_json::Value,
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = deepbook_pool_updated, primary_key(event_digest))]
 pub struct DeepbookPoolUpdated {
     pub event_digest: String,
@@ -630,7 +704,9 @@ pub struct DeepbookPoolUpdated {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = interest_params_updated, primary_key(event_digest))]
 pub struct InterestParamsUpdated {
     pub event_digest: String,
@@ -641,11 +717,14 @@ pub struct InterestParamsUpdated {
     pub package: String,
     pub margin_pool_id: String,
     pub pool_cap_id: String,
+    #[schema(value_type = Object)]
     pub config_json: serde_json::Value,
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = margin_pool_config_updated, primary_key(event_digest))]
 pub struct MarginPoolConfigUpdated {
     pub event_digest: String,
@@ -656,12 +735,15 @@ pub struct MarginPoolConfigUpdated {
     pub package: String,
     pub margin_pool_id: String,
     pub pool_cap_id: String,
+    #[schema(value_type = Object)]
     pub config_json: serde_json::Value,
     pub onchain_timestamp: i64,
 }
 
 // === Margin Registry Events ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = maintainer_cap_updated, primary_key(event_digest))]
 pub struct MaintainerCapUpdated {
     pub event_digest: String,
@@ -675,7 +757,9 @@ pub struct MaintainerCapUpdated {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = deepbook_pool_registered, primary_key(event_digest))]
 pub struct DeepbookPoolRegistered {
     pub event_digest: String,
@@ -685,11 +769,14 @@ pub struct DeepbookPoolRegistered {
     pub checkpoint_timestamp_ms: i64,
     pub package: String,
     pub pool_id: String,
+    #[schema(value_type = Option<Object>)]
     pub config_json: Option<serde_json::Value>,
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = deepbook_pool_updated_registry, primary_key(event_digest))]
 pub struct DeepbookPoolUpdatedRegistry {
     pub event_digest: String,
@@ -703,7 +790,9 @@ pub struct DeepbookPoolUpdatedRegistry {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = deepbook_pool_config_updated, primary_key(event_digest))]
 pub struct DeepbookPoolConfigUpdated {
     pub event_digest: String,
@@ -713,12 +802,15 @@ pub struct DeepbookPoolConfigUpdated {
     pub checkpoint_timestamp_ms: i64,
     pub package: String,
     pub pool_id: String,
+    #[schema(value_type = Object)]
     pub config_json: serde_json::Value,
     pub onchain_timestamp: i64,
 }
 
 // === Additional Margin Pool Events ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = maintainer_fees_withdrawn, primary_key(event_digest))]
 pub struct MaintainerFeesWithdrawn {
     pub event_digest: String,
@@ -733,7 +825,9 @@ pub struct MaintainerFeesWithdrawn {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = protocol_fees_withdrawn, primary_key(event_digest))]
 pub struct ProtocolFeesWithdrawn {
     pub event_digest: String,
@@ -747,7 +841,9 @@ pub struct ProtocolFeesWithdrawn {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = supplier_cap_minted, primary_key(event_digest))]
 pub struct SupplierCapMinted {
     pub event_digest: String,
@@ -760,7 +856,9 @@ pub struct SupplierCapMinted {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = supply_referral_minted, primary_key(event_digest))]
 pub struct SupplyReferralMinted {
     pub event_digest: String,
@@ -775,7 +873,9 @@ pub struct SupplyReferralMinted {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = pause_cap_updated, primary_key(event_digest))]
 pub struct PauseCapUpdated {
     pub event_digest: String,
@@ -790,7 +890,9 @@ pub struct PauseCapUpdated {
 }
 
 // === Margin Registry Price Events ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = current_price_updated, primary_key(event_digest))]
 pub struct CurrentPriceUpdated {
     pub event_digest: String,
@@ -804,7 +906,9 @@ pub struct CurrentPriceUpdated {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = price_tolerance_updated, primary_key(event_digest))]
 pub struct PriceToleranceUpdated {
     pub event_digest: String,
@@ -818,7 +922,9 @@ pub struct PriceToleranceUpdated {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = max_price_age_updated, primary_key(event_digest))]
 pub struct MaxPriceAgeUpdated {
     pub event_digest: String,
@@ -833,7 +939,9 @@ pub struct MaxPriceAgeUpdated {
 }
 
 // === Protocol Fees Events ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = protocol_fees_increased, primary_key(event_digest))]
 pub struct ProtocolFeesIncreasedEvent {
     pub event_digest: String,
@@ -850,7 +958,9 @@ pub struct ProtocolFeesIncreasedEvent {
     pub onchain_timestamp: i64,
 }
 
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = referral_fees_claimed, primary_key(event_digest))]
 pub struct ReferralFeesClaimedEvent {
     pub event_digest: String,
@@ -866,7 +976,7 @@ pub struct ReferralFeesClaimedEvent {
 }
 
 // === Margin Manager State ===
-#[derive(Queryable, Selectable, Identifiable, Debug, Serialize)]
+#[derive(Queryable, Selectable, Identifiable, Debug, Serialize, ToSchema)]
 #[diesel(table_name = margin_manager_state)]
 #[diesel(primary_key(margin_manager_id))]
 pub struct MarginManagerState {
@@ -879,39 +989,50 @@ pub struct MarginManagerState {
     pub quote_asset_id: Option<String>,
     pub quote_asset_symbol: Option<String>,
     #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
     pub risk_ratio: Option<BigDecimal>,
     #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
     pub base_asset: Option<BigDecimal>,
     #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
     pub quote_asset: Option<BigDecimal>,
     #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
     pub base_debt: Option<BigDecimal>,
     #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
     pub quote_debt: Option<BigDecimal>,
     pub base_pyth_price: Option<i64>,
     pub base_pyth_decimals: Option<i32>,
     pub quote_pyth_price: Option<i64>,
     pub quote_pyth_decimals: Option<i32>,
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = String)]
     pub created_at: chrono::NaiveDateTime,
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = String)]
     pub updated_at: chrono::NaiveDateTime,
     #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
     pub current_price: Option<BigDecimal>,
     #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
     pub lowest_trigger_above_price: Option<BigDecimal>,
     #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
     pub highest_trigger_below_price: Option<BigDecimal>,
 }
 
 // === Margin Pool Snapshots (for metrics polling) ===
-#[derive(Queryable, Selectable, Insertable, Debug, Serialize)]
+#[derive(Queryable, Selectable, Insertable, Debug, Serialize, ToSchema)]
 #[diesel(table_name = margin_pool_snapshots)]
 pub struct MarginPoolSnapshot {
     pub id: i64,
     pub margin_pool_id: String,
     pub asset_type: String,
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = String)]
     pub timestamp: chrono::NaiveDateTime,
     pub total_supply: i64,
     pub total_borrow: i64,
@@ -941,7 +1062,9 @@ pub struct NewMarginPoolSnapshot {
 }
 
 // === Collateral Events ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = collateral_events, primary_key(event_digest))]
 pub struct CollateralEvent {
     pub event_digest: String,
@@ -952,24 +1075,34 @@ pub struct CollateralEvent {
     pub package: String,
     pub event_type: String,
     pub margin_manager_id: String,
+    #[schema(value_type = String)]
     pub amount: BigDecimal,
     pub asset_type: String,
     pub pyth_decimals: i16,
+    #[schema(value_type = String)]
     pub pyth_price: BigDecimal,
     pub withdraw_base_asset: Option<bool>,
     pub base_pyth_decimals: Option<i16>,
+    #[schema(value_type = Option<String>)]
     pub base_pyth_price: Option<BigDecimal>,
     pub quote_pyth_decimals: Option<i16>,
+    #[schema(value_type = Option<String>)]
     pub quote_pyth_price: Option<BigDecimal>,
+    #[schema(value_type = Option<String>)]
     pub remaining_base_asset: Option<BigDecimal>,
+    #[schema(value_type = Option<String>)]
     pub remaining_quote_asset: Option<BigDecimal>,
+    #[schema(value_type = Option<String>)]
     pub remaining_base_debt: Option<BigDecimal>,
+    #[schema(value_type = Option<String>)]
     pub remaining_quote_debt: Option<BigDecimal>,
     pub onchain_timestamp: i64,
 }
 
 // === TPSL (Take Profit / Stop Loss) Events ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = conditional_order_events, primary_key(event_digest))]
 pub struct ConditionalOrderEvent {
     pub event_digest: String,
@@ -983,12 +1116,15 @@ pub struct ConditionalOrderEvent {
     pub pool_id: Option<String>,
     pub conditional_order_id: i64,
     pub trigger_below_price: bool,
+    #[schema(value_type = String)]
     pub trigger_price: BigDecimal,
     pub is_limit_order: bool,
     pub client_order_id: i64,
     pub order_type: i16,
     pub self_matching_option: i16,
+    #[schema(value_type = String)]
     pub price: BigDecimal,
+    #[schema(value_type = String)]
     pub quantity: BigDecimal,
     pub is_bid: bool,
     pub pay_with_deep: bool,
@@ -997,7 +1133,9 @@ pub struct ConditionalOrderEvent {
 }
 
 // === Balance Manager Event ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = balance_manager_created, primary_key(event_digest))]
 pub struct BalanceManagerEvent {
     pub event_digest: String,
@@ -1011,7 +1149,9 @@ pub struct BalanceManagerEvent {
 }
 
 // === Referral Created Event ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = deepbook_referral_created, primary_key(event_digest))]
 pub struct DeepBookReferralCreatedEvent {
     pub event_digest: String,
@@ -1025,7 +1165,9 @@ pub struct DeepBookReferralCreatedEvent {
 }
 
 // === Referral Set Event ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = deepbook_referral_set, primary_key(event_digest))]
 pub struct DeepBookReferralSetEvent {
     pub event_digest: String,
@@ -1039,7 +1181,9 @@ pub struct DeepBookReferralSetEvent {
 }
 
 // === Points ===
-#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize)]
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
 #[diesel(table_name = points, primary_key(id))]
 pub struct Points {
     pub id: i64,
@@ -1047,5 +1191,880 @@ pub struct Points {
     pub amount: i64,
     pub week: i32,
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = String)]
     pub timestamp: chrono::NaiveDateTime,
 }
+
+// === Predict Config Events ===
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_market_created, primary_key(event_digest))]
+pub struct PredictMarketCreated {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub expiry_market_id: String,
+    pub pool_vault_id: String,
+    pub propbook_underlying_id: i64,
+    pub expiry: i64,
+    pub tick_size: i64,
+    pub admission_tick_size: i64,
+    pub max_expiry_allocation: i64,
+    pub initial_expiry_cash: i64,
+    pub liquidation_ltv: i64,
+    pub max_admission_leverage: i64,
+    pub backing_buffer_lambda: i64,
+    pub base_fee: i64,
+    pub min_fee: i64,
+    pub min_entry_probability: i64,
+    pub max_entry_probability: i64,
+    pub expiry_fee_window_ms: i64,
+    pub expiry_fee_max_multiplier: i64,
+    pub no_leverage_window_ms: i64,
+    pub trading_loss_rebate_rate: i64,
+    pub max_benefit_ratio: i64,
+    pub lower_benefit_power: i64,
+    pub upper_benefit_power: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_market_settled, primary_key(event_digest))]
+pub struct PredictMarketSettled {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub expiry_market_id: String,
+    pub propbook_underlying_id: i64,
+    pub expiry: i64,
+    pub settlement_price: i64,
+    pub settled_at_ms: i64,
+}
+
+// === Predict Order Events ===
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_order_minted, primary_key(event_digest))]
+pub struct PredictOrderMinted {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub expiry_market_id: String,
+    pub account_id: String,
+    pub order_id: String,
+    pub position_root_id: String,
+    pub owner: String,
+    pub lower_tick: i64,
+    pub higher_tick: i64,
+    pub leverage: i64,
+    pub entry_probability: i64,
+    pub quantity: i64,
+    pub net_premium: i64,
+    pub trading_fee: i64,
+    pub fee_incentive_subsidy: i64,
+    pub builder_fee: i64,
+    pub penalty_fee: i64,
+    pub builder_code_id: Option<String>,
+    pub minted_at_ms: i64,
+    pub pyth_spot_source_timestamp_ms: i64,
+    pub block_scholes_spot_source_timestamp_ms: i64,
+    pub block_scholes_forward_source_timestamp_ms: i64,
+    pub block_scholes_svi_source_timestamp_ms: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_live_order_redeemed, primary_key(event_digest))]
+pub struct PredictLiveOrderRedeemed {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub expiry_market_id: String,
+    pub account_id: String,
+    pub order_id: String,
+    pub position_root_id: String,
+    pub owner: String,
+    pub quantity_closed: i64,
+    pub remaining_quantity: i64,
+    pub replacement_order_id: Option<String>,
+    pub redeem_amount: i64,
+    pub trading_fee: i64,
+    pub builder_fee: i64,
+    pub penalty_fee: i64,
+    pub builder_code_id: Option<String>,
+    pub redeemed_at_ms: i64,
+    pub pyth_spot_source_timestamp_ms: i64,
+    pub block_scholes_spot_source_timestamp_ms: i64,
+    pub block_scholes_forward_source_timestamp_ms: i64,
+    pub block_scholes_svi_source_timestamp_ms: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_settled_order_redeemed, primary_key(event_digest))]
+pub struct PredictSettledOrderRedeemed {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub expiry_market_id: String,
+    pub account_id: String,
+    pub order_id: String,
+    pub position_root_id: String,
+    pub owner: String,
+    pub quantity_closed: i64,
+    pub settlement_price: i64,
+    pub payout_amount: i64,
+    pub redeemed_at_ms: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_liquidated_order_redeemed, primary_key(event_digest))]
+pub struct PredictLiquidatedOrderRedeemed {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub expiry_market_id: String,
+    pub account_id: String,
+    pub order_id: String,
+    pub position_root_id: String,
+    pub owner: String,
+    pub quantity_closed: i64,
+    pub redeemed_at_ms: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_order_liquidated, primary_key(event_digest))]
+pub struct PredictOrderLiquidated {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub expiry_market_id: String,
+    pub order_id: String,
+    pub quantity: i64,
+    pub gross_value: i64,
+    pub floor_amount: i64,
+    pub liquidation_ltv: i64,
+    pub liquidated_at_ms: i64,
+    pub pyth_spot_source_timestamp_ms: i64,
+    pub block_scholes_spot_source_timestamp_ms: i64,
+    pub block_scholes_forward_source_timestamp_ms: i64,
+    pub block_scholes_svi_source_timestamp_ms: i64,
+}
+
+// === Predict Vault Events ===
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_supply_requested, primary_key(event_digest))]
+pub struct PredictSupplyRequested {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub pool_vault_id: String,
+    pub account_id: String,
+    pub recipient: String,
+    pub request_index: i64,
+    pub amount: i64,
+    pub min_plp_out: i64,
+    pub requests_pending_after: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_withdraw_requested, primary_key(event_digest))]
+pub struct PredictWithdrawRequested {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub pool_vault_id: String,
+    pub account_id: String,
+    pub recipient: String,
+    pub request_index: i64,
+    pub amount: i64,
+    pub min_dusdc_out: i64,
+    pub requests_pending_after: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_request_cancelled, primary_key(event_digest))]
+pub struct PredictRequestCancelled {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub pool_vault_id: String,
+    pub account_id: String,
+    pub recipient: String,
+    pub request_index: i64,
+    pub amount: i64,
+    pub is_supply: bool,
+    pub reason: i16,
+    pub requests_pending_after: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_supply_filled, primary_key(event_digest))]
+pub struct PredictSupplyFilled {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub pool_vault_id: String,
+    pub account_id: String,
+    pub recipient: String,
+    pub request_index: i64,
+    pub dusdc_amount: i64,
+    pub shares_minted: i64,
+    pub fee_dusdc: i64,
+    pub dusdc_remaining: i64,
+    pub requests_pending_after: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_withdraw_filled, primary_key(event_digest))]
+pub struct PredictWithdrawFilled {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub pool_vault_id: String,
+    pub account_id: String,
+    pub recipient: String,
+    pub request_index: i64,
+    pub shares_burned: i64,
+    pub dusdc_amount: i64,
+    pub fee_dusdc: i64,
+    pub shares_remaining: i64,
+    pub requests_pending_after: i64,
+}
+
+// === Predict Builder Code Events ===
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_builder_code_created, primary_key(event_digest))]
+pub struct PredictBuilderCodeCreated {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub builder_code_id: String,
+    pub owner: String,
+    pub builder_code_index: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_builder_code_set, primary_key(event_digest))]
+pub struct PredictBuilderCodeSet {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub account_id: String,
+    pub owner: String,
+    pub builder_code_id: Option<String>,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = predict_builder_fees_claimed, primary_key(event_digest))]
+pub struct PredictBuilderFeesClaimed {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub builder_code_id: String,
+    pub owner: String,
+    pub amount: i64,
+}
+
+// === Propbook Registry Events ===
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = propbook_oracle_source_registered, primary_key(event_digest))]
+pub struct PropbookOracleSourceRegistered {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub oracle_kind: i16,
+    pub source_id: i64,
+    pub propbook_oracle_id: String,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = propbook_oracle_bound, primary_key(event_digest))]
+pub struct PropbookOracleBound {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub propbook_underlying_id: i64,
+    pub oracle_kind: i16,
+    pub source_id: i64,
+    pub propbook_oracle_id: String,
+    pub value_kind: i16,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = propbook_block_scholes_stores_registered, primary_key(event_digest))]
+pub struct PropbookBlockScholesStoresRegistered {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub propbook_underlying_id: i64,
+    pub value_store_id: String,
+    pub svi_store_id: String,
+    pub block_scholes_base_asset: String,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = propbook_oracle_rebound, primary_key(event_digest))]
+pub struct PropbookOracleRebound {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub propbook_underlying_id: i64,
+    pub oracle_kind: i16,
+    pub value_kind: i16,
+    pub old_source_id: i64,
+    pub old_propbook_oracle_id: String,
+    pub new_source_id: i64,
+    pub new_propbook_oracle_id: String,
+}
+
+// === Propbook Feed Events ===
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = propbook_block_scholes_batch_ingested, primary_key(event_digest))]
+pub struct PropbookBlockScholesBatchIngested {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub propbook_oracle_id: String,
+    pub series_kind: i16,
+    pub published_at_ms: i64,
+    pub update_count: i64,
+    pub applied: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = propbook_oracle_observations, primary_key(event_digest))]
+pub struct PropbookOracleObservation {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub propbook_oracle_id: String,
+    pub inserted: bool,
+    pub source_timestamp_ms: i64,
+    pub update_timestamp_ms: i64,
+    pub writer_digest: String,
+    pub pyth_source_id: i64,
+    #[schema(value_type = String)]
+    pub price: BigDecimal,
+    pub exponent: i32,
+    pub feed_update_timestamp_us: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = propbook_block_scholes_observations, primary_key(event_digest))]
+pub struct PropbookBlockScholesObservation {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub propbook_oracle_id: String,
+    pub sid: String,
+    pub series_kind: i16,
+    pub expiry_ms: i64,
+    pub model_timestamp_ms: i64,
+    pub published_at_ms: i64,
+    pub recorded_at_ms: i64,
+    pub writer_digest: String,
+    #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
+    pub value: Option<BigDecimal>,
+    #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
+    pub svi_a: Option<BigDecimal>,
+    #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
+    pub svi_b: Option<BigDecimal>,
+    #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
+    pub svi_sigma: Option<BigDecimal>,
+    #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
+    pub svi_rho: Option<BigDecimal>,
+    #[serde(serialize_with = "serialize_bigdecimal_option")]
+    #[schema(value_type = Option<String>)]
+    pub svi_m: Option<BigDecimal>,
+}
+
+// === Account Events ===
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = account_created, primary_key(event_digest))]
+pub struct AccountCreated {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub account_id: String,
+    pub wrapper_id: String,
+    pub owner: String,
+    pub self_owned: bool,
+    pub referrer_account_id: Option<String>,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = account_app_authorized, primary_key(event_digest))]
+pub struct AccountAppAuthorized {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub app: String,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = account_app_deauthorized, primary_key(event_digest))]
+pub struct AccountAppDeauthorized {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub app: String,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = account_deposited, primary_key(event_digest))]
+pub struct AccountDeposited {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub account_id: String,
+    pub coin_type: String,
+    pub amount: i64,
+    pub new_balance: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = account_withdrawn, primary_key(event_digest))]
+pub struct AccountWithdrawn {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub account_id: String,
+    pub coin_type: String,
+    pub amount: i64,
+    pub new_balance: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = account_funds_settled, primary_key(event_digest))]
+pub struct AccountFundsSettled {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub account_id: String,
+    pub coin_type: String,
+    pub amount: i64,
+    pub new_balance: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = deepbook_core_account_initialized, primary_key(event_digest))]
+pub struct DeepbookCoreAccountInitialized {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub account_id: String,
+    pub account_owner: String,
+    pub wrapper_id: String,
+    pub balance_manager_id: String,
+}
+
+// === Session Events ===
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = account_session_authorized, primary_key(event_digest))]
+pub struct AccountSessionAuthorized {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub account_id: String,
+    pub session: String,
+    pub expires_at_ms: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = account_session_revoked, primary_key(event_digest))]
+pub struct AccountSessionRevoked {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub account_id: String,
+    pub session: String,
+    pub expires_at_ms: i64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = liquidation_by_vault, primary_key(event_digest))]
+pub struct LiquidationByVault {
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub vault_id: String,
+    pub margin_manager_id: String,
+    pub margin_pool_id: String,
+    pub base_in: i64,
+    pub base_out: i64,
+    pub quote_in: i64,
+    pub quote_out: i64,
+    pub repay_balance_remaining: i64,
+    pub base_liquidation: bool,
+    pub liquidation_event_digest: Option<String>,
+}
+
+// === Package Versions ===
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount, Serialize, ToSchema,
+)]
+#[diesel(table_name = package_versions, primary_key(package_id))]
+pub struct PackageVersion {
+    pub package_id: String,
+    pub original_package_id: String,
+    pub family: String,
+    pub version: i64,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+}
+
+// === Undecodable Events ===
+#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, FieldCount)]
+#[diesel(table_name = undecodable_events, primary_key(pipeline, event_digest))]
+pub struct UndecodableEvent {
+    pub pipeline: String,
+    pub event_digest: String,
+    pub digest: String,
+    pub sender: String,
+    pub checkpoint: i64,
+    pub checkpoint_timestamp_ms: i64,
+    pub package: String,
+    pub event_index: i64,
+    pub event_type: String,
+    pub contents: Vec<u8>,
+    pub error: String,
+}
+
+// === Open Orders ===
+/// An order resting on a book. `quantity` is the remaining quantity.
+#[derive(Queryable, Selectable, Insertable, Identifiable, Debug, Clone, FieldCount)]
+#[diesel(table_name = open_orders, primary_key(pool_id, order_id))]
+pub struct OpenOrder {
+    pub pool_id: String,
+    pub order_id: String, // u128
+    pub balance_manager_id: String,
+    pub trader: String,
+    pub client_order_id: i64,
+    pub is_bid: bool,
+    pub price: i64,
+    pub original_quantity: i64,
+    pub filled_quantity: i64,
+    pub quantity: i64,
+    pub status: OpenOrderStatus,
+    pub expire_timestamp: i64,
+    pub placed_at: i64,
+    pub last_updated_at: i64,
+    pub checkpoint: i64,
+}
+
+/// Open order quantity at one price of a book
+#[derive(Queryable, QueryableByName, Selectable, Debug, Clone, Serialize, ToSchema)]
+#[diesel(table_name = order_book_levels)]
+pub struct OrderBookLevel {
+    pub pool_id: String,
+    pub is_bid: bool,
+    pub price: i64,
+    pub quantity: i64,
+    pub order_count: i64,
+}
+
+/// Net amount of an asset deposited into a balance manager: deposits less withdrawals
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, Clone, Serialize, FieldCount, ToSchema,
+)]
+#[diesel(table_name = balance_manager_balances, primary_key(balance_manager_id, asset))]
+pub struct BalanceManagerBalance {
+    pub balance_manager_id: String,
+    pub asset: String,
+    #[schema(value_type = String)]
+    pub net_deposited: BigDecimal,
+    pub last_updated_at: i64,
+    pub checkpoint: i64,
+}
+
+/// Kind of account a `reward_ledger` row belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumString, AsRefStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum RewardAccountKind {
+    /// Trading rebates of a balance manager
+    BalanceManager,
+    /// Fees earned by a referral object
+    Referral,
+}
+
+/// Rewards earned and claimed by a balance manager or referral object in one pool
+#[derive(
+    Queryable, Selectable, Insertable, Identifiable, Debug, Clone, Serialize, FieldCount, ToSchema,
+)]
+#[diesel(table_name = reward_ledger, primary_key(account_kind, account_id, pool_id))]
+pub struct RewardLedgerEntry {
+    pub account_kind: String,
+    pub account_id: String,
+    pub pool_id: String,
+    pub earned_base: i64,
+    pub earned_quote: i64,
+    pub earned_deep: i64,
+    pub claimed_base: i64,
+    pub claimed_quote: i64,
+    pub claimed_deep: i64,
+    pub last_updated_at: i64,
+    pub checkpoint: i64,
+}
+
+/// A balance manager's trading in one pool over an hour or day, from `trader_volume_1h` or
+/// `trader_volume_1d`
+#[derive(Debug, Clone, QueryableByName, Serialize, ToSchema)]
+pub struct TraderVolume {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub pool_id: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub balance_manager_id: String,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub bucket_start: i64,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub maker_base_volume: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub maker_quote_volume: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub taker_base_volume: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub taker_quote_volume: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub maker_trade_count: i64,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub taker_trade_count: i64,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub deep_fees: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub base_fees: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub quote_fees: BigDecimal,
+}
+
+/// A balance manager's trading in one pool summed over a time range
+#[derive(Debug, Clone, QueryableByName, Serialize, ToSchema)]
+pub struct TraderVolumeTotal {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub balance_manager_id: String,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub maker_base_volume: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub maker_quote_volume: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub taker_base_volume: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub taker_quote_volume: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub maker_trade_count: i64,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub taker_trade_count: i64,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub deep_fees: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub base_fees: BigDecimal,
+    #[diesel(sql_type = diesel::sql_types::Numeric)]
+    #[schema(value_type = String)]
+    pub quote_fees: BigDecimal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AsExpression, EnumString, AsRefStr)]
+#[diesel(sql_type = Text)]
+#[strum(serialize_all = "snake_case")]
+pub enum OpenOrderStatus {
+    Placed,
+    PartiallyFilled,
+}
+
+impl OpenOrderStatus {
+    pub fn from_filled_quantity(filled_quantity: i64) -> Self {
+        if filled_quantity > 0 {
+            OpenOrderStatus::PartiallyFilled
+        } else {
+            OpenOrderStatus::Placed
+        }
+    }
+}
+
+impl FromSql<Text, Pg> for OpenOrderStatus {
+    fn from_sql(bytes: PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        let s = std::str::from_utf8(bytes.as_bytes())?;
+        Ok(OpenOrderStatus::from_str(s)?)
+    }
+}
+
+impl ToSql<Text, Pg> for OpenOrderStatus {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> diesel::serialize::Result {
+        <str as ToSql<Text, Pg>>::to_sql(self.as_ref(), out)
+    }
+}
@@ -1,5 +1,126 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    account_app_authorized (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        app -> Text,
+    }
+}
+
+diesel::table! {
+    account_app_deauthorized (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        app -> Text,
+    }
+}
+
+diesel::table! {
+    account_created (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        account_id -> Text,
+        wrapper_id -> Text,
+        owner -> Text,
+        self_owned -> Bool,
+        referrer_account_id -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    account_deposited (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        account_id -> Text,
+        coin_type -> Text,
+        amount -> Int8,
+        new_balance -> Int8,
+    }
+}
+
+diesel::table! {
+    account_funds_settled (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        account_id -> Text,
+        coin_type -> Text,
+        amount -> Int8,
+        new_balance -> Int8,
+    }
+}
+
+diesel::table! {
+    account_session_authorized (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        account_id -> Text,
+        session -> Text,
+        expires_at_ms -> Int8,
+    }
+}
+
+diesel::table! {
+    account_session_revoked (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        account_id -> Text,
+        session -> Text,
+        expires_at_ms -> Int8,
+    }
+}
+
+diesel::table! {
+    account_withdrawn (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        account_id -> Text,
+        coin_type -> Text,
+        amount -> Int8,
+        new_balance -> Int8,
+    }
+}
+
 diesel::table! {
     asset_supplied (event_digest) {
         event_digest -> Text,
@@ -48,6 +169,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    balance_manager_balances (balance_manager_id, asset) {
+        balance_manager_id -> Text,
+        asset -> Text,
+        net_deposited -> Numeric,
+        last_updated_at -> Int8,
+        checkpoint -> Int8,
+    }
+}
+
 diesel::table! {
     balance_manager_created (event_digest) {
         event_digest -> Text,
@@ -163,6 +294,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    deepbook_core_account_initialized (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        account_id -> Text,
+        account_owner -> Text,
+        wrapper_id -> Text,
+        balance_manager_id -> Text,
+    }
+}
+
 diesel::table! {
     deepbook_pool_config_updated (event_digest) {
         event_digest -> Text,
@@ -312,6 +459,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    liquidation_by_vault (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        vault_id -> Text,
+        margin_manager_id -> Text,
+        margin_pool_id -> Text,
+        base_in -> Int8,
+        base_out -> Int8,
+        quote_in -> Int8,
+        quote_out -> Int8,
+        repay_balance_remaining -> Int8,
+        base_liquidation -> Bool,
+        liquidation_event_digest -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     loan_borrowed (event_digest) {
         event_digest -> Text,
@@ -527,6 +696,36 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    open_orders (pool_id, order_id) {
+        pool_id -> Text,
+        order_id -> Text,
+        balance_manager_id -> Text,
+        trader -> Text,
+        client_order_id -> Int8,
+        is_bid -> Bool,
+        price -> Int8,
+        original_quantity -> Int8,
+        filled_quantity -> Int8,
+        quantity -> Int8,
+        status -> Text,
+        expire_timestamp -> Int8,
+        placed_at -> Int8,
+        last_updated_at -> Int8,
+        checkpoint -> Int8,
+    }
+}
+
+diesel::table! {
+    order_book_levels (pool_id, is_bid, price) {
+        pool_id -> Text,
+        is_bid -> Bool,
+        price -> Int8,
+        quantity -> Int8,
+        order_count -> Int8,
+    }
+}
+
 diesel::table! {
     order_fills (event_digest) {
         event_digest -> Text,
@@ -579,6 +778,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    package_versions (package_id) {
+        package_id -> Text,
+        original_package_id -> Text,
+        family -> Text,
+        version -> Int8,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+    }
+}
+
 diesel::table! {
     pause_cap_updated (event_digest) {
         event_digest -> Text,
@@ -664,6 +877,328 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    predict_builder_code_created (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        builder_code_id -> Text,
+        owner -> Text,
+        builder_code_index -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_builder_code_set (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        account_id -> Text,
+        owner -> Text,
+        builder_code_id -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    predict_builder_fees_claimed (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        builder_code_id -> Text,
+        owner -> Text,
+        amount -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_liquidated_order_redeemed (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        expiry_market_id -> Text,
+        account_id -> Text,
+        order_id -> Text,
+        position_root_id -> Text,
+        owner -> Text,
+        quantity_closed -> Int8,
+        redeemed_at_ms -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_live_order_redeemed (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        expiry_market_id -> Text,
+        account_id -> Text,
+        order_id -> Text,
+        position_root_id -> Text,
+        owner -> Text,
+        quantity_closed -> Int8,
+        remaining_quantity -> Int8,
+        replacement_order_id -> Nullable<Text>,
+        redeem_amount -> Int8,
+        trading_fee -> Int8,
+        builder_fee -> Int8,
+        penalty_fee -> Int8,
+        builder_code_id -> Nullable<Text>,
+        redeemed_at_ms -> Int8,
+        pyth_spot_source_timestamp_ms -> Int8,
+        block_scholes_spot_source_timestamp_ms -> Int8,
+        block_scholes_forward_source_timestamp_ms -> Int8,
+        block_scholes_svi_source_timestamp_ms -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_market_created (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        expiry_market_id -> Text,
+        pool_vault_id -> Text,
+        propbook_underlying_id -> Int8,
+        expiry -> Int8,
+        tick_size -> Int8,
+        admission_tick_size -> Int8,
+        max_expiry_allocation -> Int8,
+        initial_expiry_cash -> Int8,
+        liquidation_ltv -> Int8,
+        max_admission_leverage -> Int8,
+        backing_buffer_lambda -> Int8,
+        base_fee -> Int8,
+        min_fee -> Int8,
+        min_entry_probability -> Int8,
+        max_entry_probability -> Int8,
+        expiry_fee_window_ms -> Int8,
+        expiry_fee_max_multiplier -> Int8,
+        no_leverage_window_ms -> Int8,
+        trading_loss_rebate_rate -> Int8,
+        max_benefit_ratio -> Int8,
+        lower_benefit_power -> Int8,
+        upper_benefit_power -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_market_settled (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        expiry_market_id -> Text,
+        propbook_underlying_id -> Int8,
+        expiry -> Int8,
+        settlement_price -> Int8,
+        settled_at_ms -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_order_liquidated (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        expiry_market_id -> Text,
+        order_id -> Text,
+        quantity -> Int8,
+        gross_value -> Int8,
+        floor_amount -> Int8,
+        liquidation_ltv -> Int8,
+        liquidated_at_ms -> Int8,
+        pyth_spot_source_timestamp_ms -> Int8,
+        block_scholes_spot_source_timestamp_ms -> Int8,
+        block_scholes_forward_source_timestamp_ms -> Int8,
+        block_scholes_svi_source_timestamp_ms -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_order_minted (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        expiry_market_id -> Text,
+        account_id -> Text,
+        order_id -> Text,
+        position_root_id -> Text,
+        owner -> Text,
+        lower_tick -> Int8,
+        higher_tick -> Int8,
+        leverage -> Int8,
+        entry_probability -> Int8,
+        quantity -> Int8,
+        net_premium -> Int8,
+        trading_fee -> Int8,
+        fee_incentive_subsidy -> Int8,
+        builder_fee -> Int8,
+        penalty_fee -> Int8,
+        builder_code_id -> Nullable<Text>,
+        minted_at_ms -> Int8,
+        pyth_spot_source_timestamp_ms -> Int8,
+        block_scholes_spot_source_timestamp_ms -> Int8,
+        block_scholes_forward_source_timestamp_ms -> Int8,
+        block_scholes_svi_source_timestamp_ms -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_request_cancelled (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        pool_vault_id -> Text,
+        account_id -> Text,
+        recipient -> Text,
+        request_index -> Int8,
+        amount -> Int8,
+        is_supply -> Bool,
+        reason -> Int2,
+        requests_pending_after -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_settled_order_redeemed (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        expiry_market_id -> Text,
+        account_id -> Text,
+        order_id -> Text,
+        position_root_id -> Text,
+        owner -> Text,
+        quantity_closed -> Int8,
+        settlement_price -> Int8,
+        payout_amount -> Int8,
+        redeemed_at_ms -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_supply_filled (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        pool_vault_id -> Text,
+        account_id -> Text,
+        recipient -> Text,
+        request_index -> Int8,
+        dusdc_amount -> Int8,
+        shares_minted -> Int8,
+        fee_dusdc -> Int8,
+        dusdc_remaining -> Int8,
+        requests_pending_after -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_supply_requested (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        pool_vault_id -> Text,
+        account_id -> Text,
+        recipient -> Text,
+        request_index -> Int8,
+        amount -> Int8,
+        min_plp_out -> Int8,
+        requests_pending_after -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_withdraw_filled (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        pool_vault_id -> Text,
+        account_id -> Text,
+        recipient -> Text,
+        request_index -> Int8,
+        shares_burned -> Int8,
+        dusdc_amount -> Int8,
+        fee_dusdc -> Int8,
+        shares_remaining -> Int8,
+        requests_pending_after -> Int8,
+    }
+}
+
+diesel::table! {
+    predict_withdraw_requested (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        pool_vault_id -> Text,
+        account_id -> Text,
+        recipient -> Text,
+        request_index -> Int8,
+        amount -> Int8,
+        min_dusdc_out -> Int8,
+        requests_pending_after -> Int8,
+    }
+}
+
 diesel::table! {
     price_tolerance_updated (event_digest) {
         event_digest -> Text,
@@ -679,6 +1214,137 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    propbook_block_scholes_batch_ingested (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        propbook_oracle_id -> Text,
+        series_kind -> Int2,
+        published_at_ms -> Int8,
+        update_count -> Int8,
+        applied -> Int8,
+    }
+}
+
+diesel::table! {
+    propbook_block_scholes_observations (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        propbook_oracle_id -> Text,
+        sid -> Text,
+        series_kind -> Int2,
+        expiry_ms -> Int8,
+        model_timestamp_ms -> Int8,
+        published_at_ms -> Int8,
+        recorded_at_ms -> Int8,
+        writer_digest -> Text,
+        value -> Nullable<Numeric>,
+        svi_a -> Nullable<Numeric>,
+        svi_b -> Nullable<Numeric>,
+        svi_sigma -> Nullable<Numeric>,
+        svi_rho -> Nullable<Numeric>,
+        svi_m -> Nullable<Numeric>,
+    }
+}
+
+diesel::table! {
+    propbook_block_scholes_stores_registered (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        propbook_underlying_id -> Int8,
+        value_store_id -> Text,
+        svi_store_id -> Text,
+        block_scholes_base_asset -> Text,
+    }
+}
+
+diesel::table! {
+    propbook_oracle_bound (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        propbook_underlying_id -> Int8,
+        oracle_kind -> Int2,
+        source_id -> Int8,
+        propbook_oracle_id -> Text,
+        value_kind -> Int2,
+    }
+}
+
+diesel::table! {
+    propbook_oracle_observations (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        propbook_oracle_id -> Text,
+        inserted -> Bool,
+        source_timestamp_ms -> Int8,
+        update_timestamp_ms -> Int8,
+        writer_digest -> Text,
+        pyth_source_id -> Int8,
+        price -> Numeric,
+        exponent -> Int4,
+        feed_update_timestamp_us -> Int8,
+    }
+}
+
+diesel::table! {
+    propbook_oracle_rebound (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        propbook_underlying_id -> Int8,
+        oracle_kind -> Int2,
+        value_kind -> Int2,
+        old_source_id -> Int8,
+        old_propbook_oracle_id -> Text,
+        new_source_id -> Int8,
+        new_propbook_oracle_id -> Text,
+    }
+}
+
+diesel::table! {
+    propbook_oracle_source_registered (event_digest) {
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        oracle_kind -> Int2,
+        source_id -> Int8,
+        propbook_oracle_id -> Text,
+    }
+}
+
 diesel::table! {
     proposals (event_digest) {
         event_digest -> Text,
@@ -779,6 +1445,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    reward_ledger (account_kind, account_id, pool_id) {
+        account_kind -> Text,
+        account_id -> Text,
+        pool_id -> Text,
+        earned_base -> Int8,
+        earned_quote -> Int8,
+        earned_deep -> Int8,
+        claimed_base -> Int8,
+        claimed_quote -> Int8,
+        claimed_deep -> Int8,
+        last_updated_at -> Int8,
+        checkpoint -> Int8,
+    }
+}
+
+diesel::table! {
+    scheduled_job_runs (name) {
+        name -> Text,
+        last_run_at_ms -> Int8,
+    }
+}
+
 diesel::table! {
     stakes (event_digest) {
         event_digest -> Text,
@@ -854,6 +1543,57 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    trader_volume_1d (pool_id, balance_manager_id, bucket_start) {
+        pool_id -> Text,
+        balance_manager_id -> Text,
+        bucket_start -> Int8,
+        maker_base_volume -> Numeric,
+        maker_quote_volume -> Numeric,
+        taker_base_volume -> Numeric,
+        taker_quote_volume -> Numeric,
+        maker_trade_count -> Int8,
+        taker_trade_count -> Int8,
+        deep_fees -> Numeric,
+        base_fees -> Numeric,
+        quote_fees -> Numeric,
+    }
+}
+
+diesel::table! {
+    trader_volume_1h (pool_id, balance_manager_id, bucket_start) {
+        pool_id -> Text,
+        balance_manager_id -> Text,
+        bucket_start -> Int8,
+        maker_base_volume -> Numeric,
+        maker_quote_volume -> Numeric,
+        taker_base_volume -> Numeric,
+        taker_quote_volume -> Numeric,
+        maker_trade_count -> Int8,
+        taker_trade_count -> Int8,
+        deep_fees -> Numeric,
+        base_fees -> Numeric,
+        quote_fees -> Numeric,
+    }
+}
+
+diesel::table! {
+    undecodable_events (pipeline, event_digest) {
+        pipeline -> Text,
+        event_digest -> Text,
+        digest -> Text,
+        sender -> Text,
+        checkpoint -> Int8,
+        timestamp -> Timestamp,
+        checkpoint_timestamp_ms -> Int8,
+        package -> Text,
+        event_index -> Int8,
+        event_type -> Text,
+        contents -> Bytea,
+        error -> Text,
+    }
+}
+
 diesel::table! {
     votes (event_digest) {
         event_digest -> Text,
@@ -966,9 +1706,18 @@ diesel::table! {
 }
 
 diesel::allow_tables_to_appear_in_same_query!(
+    account_app_authorized,
+    account_app_deauthorized,
+    account_created,
+    account_deposited,
+    account_funds_settled,
+    account_session_authorized,
+    account_session_revoked,
+    account_withdrawn,
     asset_supplied,
     asset_withdrawn,
     assets,
+    balance_manager_balances,
     balance_manager_created,
     balances,
     book_params_updated,
@@ -976,6 +1725,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     conditional_order_events,
     current_price_updated,
     deep_burned,
+    deepbook_core_account_initialized,
     deepbook_pool_config_updated,
     deepbook_pool_registered,
     deepbook_pool_updated,
@@ -986,6 +1736,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     flashloans,
     interest_params_updated,
     liquidation,
+    liquidation_by_vault,
     loan_borrowed,
     loan_repaid,
     maintainer_cap_updated,
@@ -998,14 +1749,39 @@ diesel::allow_tables_to_appear_in_same_query!(
     max_price_age_updated,
     ohclv_1d,
     ohclv_1m,
+    open_orders,
+    order_book_levels,
     order_fills,
     order_updates,
+    package_versions,
     pause_cap_updated,
     points,
     pool_created,
     pool_prices,
     pools,
+    predict_builder_code_created,
+    predict_builder_code_set,
+    predict_builder_fees_claimed,
+    predict_liquidated_order_redeemed,
+    predict_live_order_redeemed,
+    predict_market_created,
+    predict_market_settled,
+    predict_order_liquidated,
+    predict_order_minted,
+    predict_request_cancelled,
+    predict_settled_order_redeemed,
+    predict_supply_filled,
+    predict_supply_requested,
+    predict_withdraw_filled,
+    predict_withdraw_requested,
     price_tolerance_updated,
+    propbook_block_scholes_batch_ingested,
+    propbook_block_scholes_observations,
+    propbook_block_scholes_stores_registered,
+    propbook_oracle_bound,
+    propbook_oracle_observations,
+    propbook_oracle_rebound,
+    propbook_oracle_source_registered,
     proposals,
     protocol_fees_increased,
     protocol_fees_withdrawn,
@@ -1014,12 +1790,17 @@ diesel::allow_tables_to_appear_in_same_query!(
     referral_claimed,
     referral_fee_events,
     referral_fees_claimed,
+    reward_ledger,
+    scheduled_job_runs,
     stakes,
     sui_error_transactions,
     supplier_cap_minted,
     supply_referral_minted,
     taker_fee_penalty_applied,
     trade_params_update,
+    trader_volume_1d,
+    trader_volume_1h,
+    undecodable_events,
     votes,
     watermarks,
 );
@@ -0,0 +1,97 @@
+use prometheus::{
+    register_gauge_vec_with_registry, register_histogram_with_registry,
+    register_int_counter_with_registry, GaugeVec, Histogram, IntCounter, Registry,
+};
+use std::sync::Arc;
+
+const LATENCY_SEC_BUCKETS: &[f64] = &[0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+#[derive(Clone)]
+pub struct BookReconciliationMetrics {
+    // Per-pool metrics of the `open_orders` table against the chain (labeled by pool_id,
+    // pool_name and side)
+    pub divergent_levels: GaugeVec,
+    pub quantity_divergence: GaugeVec,
+    pub compared_levels: GaugeVec,
+
+    // Operational metrics
+    pub poll_duration: Histogram,
+    pub poll_errors: IntCounter,
+    pub poll_success: IntCounter,
+}
+
+impl BookReconciliationMetrics {
+    pub fn new(registry: &Registry) -> Arc<Self> {
+        Arc::new(Self {
+            divergent_levels: register_gauge_vec_with_registry!(
+                "open_orders_divergent_levels",
+                "Price levels whose resting quantity in open_orders differs from the on-chain book",
+                &["pool_id", "pool_name", "side"],
+                registry
+            )
+            .unwrap(),
+
+            quantity_divergence: register_gauge_vec_with_registry!(
+                "open_orders_quantity_divergence",
+                "Sum of absolute quantity differences between open_orders and the on-chain book (normalized by base asset decimals)",
+                &["pool_id", "pool_name", "side"],
+                registry
+            )
+            .unwrap(),
+
+            compared_levels: register_gauge_vec_with_registry!(
+                "open_orders_compared_levels",
+                "Price levels compared with the on-chain book in the last reconciliation",
+                &["pool_id", "pool_name", "side"],
+                registry
+            )
+            .unwrap(),
+
+            // Operational
+            poll_duration: register_histogram_with_registry!(
+                "order_book_reconciliation_duration_seconds",
+                "Time taken to reconcile the indexed order book against the chain",
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry
+            )
+            .unwrap(),
+
+            poll_errors: register_int_counter_with_registry!(
+                "order_book_reconciliation_errors_total",
+                "Number of failed order book reconciliations",
+                registry
+            )
+            .unwrap(),
+
+            poll_success: register_int_counter_with_registry!(
+                "order_book_reconciliation_success_total",
+                "Number of successful order book reconciliations",
+                registry
+            )
+            .unwrap(),
+        })
+    }
+
+    pub fn update_side_metrics(
+        &self,
+        pool_id: &str,
+        pool_name: &str,
+        side: &str,
+        compared: usize,
+        divergent: usize,
+        quantity_divergence: u64,
+        base_decimals: i16,
+    ) {
+        let divisor = 10_f64.powi(base_decimals as i32);
+
+        self.compared_levels
+            .with_label_values(&[pool_id, pool_name, side])
+            .set(compared as f64);
+        self.divergent_levels
+            .with_label_values(&[pool_id, pool_name, side])
+            .set(divergent as f64);
+        self.quantity_divergence
+            .with_label_values(&[pool_id, pool_name, side])
+            .set(quantity_divergence as f64 / divisor);
+    }
+}
@@ -0,0 +1,5 @@
+mod metrics;
+mod reconciler;
+
+pub use metrics::BookReconciliationMetrics;
+pub use reconciler::BookReconciler;
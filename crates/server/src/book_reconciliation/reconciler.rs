@@ -0,0 +1,384 @@
+use super::metrics::BookReconciliationMetrics;
+use crate::order_book::{level2_ticks_from_mid, Levels};
+use anyhow::Result;
+use deepbook_schema::schema::pools;
+use diesel::sql_types::{BigInt, Bool, Text};
+use diesel::{QueryDsl, QueryableByName};
+use diesel_async::RunQueryDsl;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sui_pg_db::{Connection, Db};
+use sui_rpc::Client;
+use tokio_util::sync::CancellationToken;
+
+/// Levels requested per side from `get_level2_ticks_from_mid`, the `/orderbook` default.
+const TICKS_FROM_MID: u64 = 100;
+
+/// Mismatching ticks logged per pool and side on each run; the gauges carry the full count.
+const MAX_LOGGED_TICKS: usize = 20;
+
+#[derive(Debug, Clone)]
+struct PoolInfo {
+    pool_id: String,
+    pool_name: String,
+    base_asset_id: String,
+    base_decimals: i16,
+    quote_asset_id: String,
+}
+
+#[derive(QueryableByName, Debug)]
+struct RestingLevel {
+    #[diesel(sql_type = Bool)]
+    is_bid: bool,
+    #[diesel(sql_type = BigInt)]
+    price: i64,
+    #[diesel(sql_type = BigInt)]
+    quantity: i64,
+}
+
+/// A price level where the indexed and on-chain resting quantities differ.
+#[derive(Debug, PartialEq)]
+struct Mismatch {
+    price: u64,
+    indexed: u64,
+    onchain: u64,
+}
+
+/// Periodically compares the on-chain book of every pool with the resting quantity per price
+/// level of its unexpired orders in `open_orders`, the table the `open_orders` pipeline maintains
+/// and `/orderbook` serves. The `open_orders_*` gauges therefore measure how far that pipeline's
+/// state has drifted from the chain; they do not check the `order_updates` and `order_fills`
+/// history it is built from.
+///
+/// Orders past their expiry are left out, as the chain leaves them out of its levels even before
+/// an `OrderExpired` event removes them from `open_orders`. The indexed book trails the chain by
+/// the indexer's lag, so a single run can report levels that traded in between; it is sustained
+/// divergence that points at a missed or misapplied order event.
+pub struct BookReconciler {
+    db: Db,
+    sui_client: Client,
+    deepbook_package_id: String,
+    metrics: Arc<BookReconciliationMetrics>,
+    poll_interval: Duration,
+    cancellation_token: CancellationToken,
+}
+
+impl BookReconciler {
+    pub fn new(
+        db: Db,
+        sui_client: Client,
+        deepbook_package_id: String,
+        metrics: Arc<BookReconciliationMetrics>,
+        poll_interval_secs: u64,
+        cancellation_token: CancellationToken,
+    ) -> Self {
+        Self {
+            db,
+            sui_client,
+            deepbook_package_id,
+            metrics,
+            poll_interval: Duration::from_secs(poll_interval_secs),
+            cancellation_token,
+        }
+    }
+
+    pub async fn run(self) -> Result<()> {
+        loop {
+            tokio::select! {
+                _ = self.cancellation_token.cancelled() => {
+                    break;
+                }
+                _ = tokio::time::sleep(self.poll_interval) => {
+                    if let Err(e) = self.poll_once().await {
+                        eprintln!("[book_reconciler] Failed to reconcile order books: {}", e);
+                        self.metrics.poll_errors.inc();
+                    } else {
+                        self.metrics.poll_success.inc();
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn poll_once(&self) -> Result<()> {
+        let timer = self.metrics.poll_duration.start_timer();
+
+        let pools = self.get_pools().await?;
+
+        for pool in &pools {
+            if let Err(e) = self.reconcile_pool(pool).await {
+                eprintln!(
+                    "[book_reconciler] Failed to reconcile pool {} ({}): {}",
+                    pool.pool_name, pool.pool_id, e
+                );
+            }
+        }
+
+        timer.observe_duration();
+
+        Ok(())
+    }
+
+    async fn reconcile_pool(&self, pool: &PoolInfo) -> Result<()> {
+        let initial_shared_version =
+            crate::grpc::initial_shared_version(&self.sui_client, &pool.pool_id).await?;
+        let (onchain_bids, onchain_asks) = level2_ticks_from_mid(
+            &self.sui_client,
+            &self.deepbook_package_id,
+            &pool.pool_id,
+            initial_shared_version,
+            &pool.base_asset_id,
+            &pool.quote_asset_id,
+            TICKS_FROM_MID,
+        )
+        .await?;
+
+        let (indexed_bids, indexed_asks) = self.get_resting_levels(&pool.pool_id).await?;
+
+        for (side, is_bid, onchain, indexed) in [
+            ("bid", true, &onchain_bids, &indexed_bids),
+            ("ask", false, &onchain_asks, &indexed_asks),
+        ] {
+            let (compared, mismatches) = diff_side(onchain, indexed, is_bid, TICKS_FROM_MID);
+            let quantity_divergence = mismatches
+                .iter()
+                .map(|m| m.indexed.abs_diff(m.onchain))
+                .sum();
+
+            self.metrics.update_side_metrics(
+                &pool.pool_id,
+                &pool.pool_name,
+                side,
+                compared,
+                mismatches.len(),
+                quantity_divergence,
+                pool.base_decimals,
+            );
+
+            for m in mismatches.iter().take(MAX_LOGGED_TICKS) {
+                eprintln!(
+                    "[book_reconciler] Pool {} {} level {}: indexed {} on-chain {}",
+                    pool.pool_name, side, m.price, m.indexed, m.onchain
+                );
+            }
+            if mismatches.len() > MAX_LOGGED_TICKS {
+                eprintln!(
+                    "[book_reconciler] Pool {} {}: {} more mismatching levels",
+                    pool.pool_name,
+                    side,
+                    mismatches.len() - MAX_LOGGED_TICKS
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_pools(&self) -> Result<Vec<PoolInfo>> {
+        let mut conn = self.db.connect().await?;
+
+        let pools: Vec<(String, String, String, i16, String)> = pools::table
+            .select((
+                pools::pool_id,
+                pools::pool_name,
+                pools::base_asset_id,
+                pools::base_asset_decimals,
+                pools::quote_asset_id,
+            ))
+            .load(&mut conn)
+            .await?;
+
+        Ok(pools
+            .into_iter()
+            .map(
+                |(pool_id, pool_name, base_asset_id, base_decimals, quote_asset_id)| PoolInfo {
+                    pool_id,
+                    pool_name,
+                    base_asset_id,
+                    base_decimals,
+                    quote_asset_id,
+                },
+            )
+            .collect())
+    }
+
+    async fn get_resting_levels(
+        &self,
+        pool_id: &str,
+    ) -> Result<(BTreeMap<u64, u64>, BTreeMap<u64, u64>)> {
+        let mut conn = self.db.connect().await?;
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as i64;
+        resting_levels(&mut conn, pool_id, now_ms).await
+    }
+}
+
+/// Resting quantity per price level of the pool's open orders that are unexpired at `now_ms`, as
+/// `(bids, asks)` keyed by price.
+async fn resting_levels(
+    conn: &mut Connection<'_>,
+    pool_id: &str,
+    now_ms: i64,
+) -> Result<(BTreeMap<u64, u64>, BTreeMap<u64, u64>)> {
+    let levels: Vec<RestingLevel> = diesel::sql_query(
+        "SELECT is_bid, price, SUM(quantity)::BIGINT AS quantity \
+         FROM open_orders \
+         WHERE pool_id = $1 AND expire_timestamp > $2 \
+         GROUP BY is_bid, price",
+    )
+    .bind::<Text, _>(pool_id)
+    .bind::<BigInt, _>(now_ms)
+    .load(conn)
+    .await?;
+
+    let mut bids = BTreeMap::new();
+    let mut asks = BTreeMap::new();
+    for level in levels {
+        let side = if level.is_bid { &mut bids } else { &mut asks };
+        side.insert(level.price as u64, level.quantity as u64);
+    }
+    Ok((bids, asks))
+}
+
+/// Compare one side of the book. `onchain` is best first and holds at most `ticks` levels; when
+/// it is full, indexed levels beyond its worst price are outside the window and are skipped.
+/// Returns the number of levels compared and the ones that differ, best first.
+fn diff_side(
+    onchain: &Levels,
+    indexed: &BTreeMap<u64, u64>,
+    is_bid: bool,
+    ticks: u64,
+) -> (usize, Vec<Mismatch>) {
+    let worst = match onchain.last() {
+        Some(&(price, _)) if onchain.len() as u64 >= ticks => Some(price),
+        _ => None,
+    };
+    let in_window = |price: u64| match worst {
+        Some(worst) if is_bid => price >= worst,
+        Some(worst) => price <= worst,
+        None => true,
+    };
+
+    let mut levels: BTreeMap<u64, (u64, u64)> = BTreeMap::new();
+    for (&price, &quantity) in indexed.iter().filter(|(&price, _)| in_window(price)) {
+        levels.entry(price).or_default().0 = quantity;
+    }
+    for &(price, quantity) in onchain {
+        levels.entry(price).or_default().1 += quantity;
+    }
+
+    let compared = levels.len();
+    let mismatches = levels
+        .into_iter()
+        .filter(|(_, (indexed, onchain))| indexed != onchain)
+        .map(|(price, (indexed, onchain))| Mismatch {
+            price,
+            indexed,
+            onchain,
+        });
+    let mismatches = if is_bid {
+        mismatches.rev().collect()
+    } else {
+        mismatches.collect()
+    };
+    (compared, mismatches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_pg_db::temp::TempDb;
+    use sui_pg_db::DbArgs;
+
+    const POOL: &str = "0xb1";
+    const NOW_MS: i64 = 1_700_000_000_000;
+
+    async fn insert_order(
+        conn: &mut Connection<'_>,
+        pool_id: &str,
+        order_id: u32,
+        is_bid: bool,
+        price: i64,
+        quantity: i64,
+        expire_timestamp: i64,
+    ) {
+        diesel::sql_query(format!(
+            "INSERT INTO open_orders (pool_id, order_id, balance_manager_id, trader, \
+             client_order_id, is_bid, price, original_quantity, filled_quantity, quantity, status, \
+             expire_timestamp, placed_at, last_updated_at, checkpoint) VALUES ('{pool_id}', \
+             '{order_id}', '0xa1', '0xa2', 0, {is_bid}, {price}, {quantity}, 0, {quantity}, \
+             'placed', {expire_timestamp}, 0, 0, 1)"
+        ))
+        .execute(conn)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn resting_levels_leave_out_expired_orders() {
+        let temp_db = TempDb::new().expect("postgres binaries must be on PATH");
+        let url = temp_db.database().url().clone();
+        let db = Db::for_write(url, DbArgs::default()).await.unwrap();
+        db.run_migrations(Some(&deepbook_schema::MIGRATIONS))
+            .await
+            .unwrap();
+        let mut conn = db.connect().await.unwrap();
+
+        insert_order(&mut conn, POOL, 1, true, 100, 5, i64::MAX).await;
+        insert_order(&mut conn, POOL, 2, true, 100, 3, NOW_MS + 1).await;
+        // Expired, but not yet removed by an OrderExpired event
+        insert_order(&mut conn, POOL, 3, true, 100, 7, NOW_MS).await;
+        insert_order(&mut conn, POOL, 4, true, 99, 2, NOW_MS - 1).await;
+        insert_order(&mut conn, POOL, 5, false, 101, 4, i64::MAX).await;
+        insert_order(&mut conn, "0xb2", 6, true, 100, 9, i64::MAX).await;
+
+        let (bids, asks) = resting_levels(&mut conn, POOL, NOW_MS).await.unwrap();
+        assert_eq!(bids, BTreeMap::from([(100, 8)]));
+        assert_eq!(asks, BTreeMap::from([(101, 4)]));
+    }
+
+    #[test]
+    fn full_window_skips_indexed_levels_past_the_worst_tick() {
+        let onchain = vec![(100, 5), (99, 3)];
+        let indexed = BTreeMap::from([(100, 5), (99, 4), (98, 7)]);
+
+        let (compared, mismatches) = diff_side(&onchain, &indexed, true, 2);
+
+        assert_eq!(compared, 2);
+        assert_eq!(
+            mismatches,
+            vec![Mismatch {
+                price: 99,
+                indexed: 4,
+                onchain: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn partial_window_reports_levels_missing_on_either_side() {
+        let onchain = vec![(10, 1), (12, 2)];
+        let indexed = BTreeMap::from([(11, 6), (12, 2)]);
+
+        let (compared, mismatches) = diff_side(&onchain, &indexed, false, 100);
+
+        assert_eq!(compared, 3);
+        assert_eq!(
+            mismatches,
+            vec![
+                Mismatch {
+                    price: 10,
+                    indexed: 0,
+                    onchain: 1
+                },
+                Mismatch {
+                    price: 11,
+                    indexed: 6,
+                    onchain: 0
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The indexer's commits, as they happen.
+//!
+//! The indexer's database announces each advance of a pipeline's watermark on the
+//! [`COMMITS_CHANNEL`] channel. When enabled, one connection per server `LISTEN`s to it and fans
+//! the notifications out to in-process subscribers, which use them to refresh caches and push new
+//! data without waiting for their next poll. Subscribers keep polling regardless, since the
+//! listener is off on read replicas and notifications are lost while it reconnects.
+
+use futures::StreamExt;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio_postgres::AsyncMessage;
+use tokio_postgres_rustls::MakeRustlsConnect;
+use url::Url;
+
+pub const COMMITS_CHANNEL: &str = "deepbook_commits";
+/// Notifications buffered for each subscriber before a slow one starts missing them
+const COMMITS_CAPACITY: usize = 1024;
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Checkpoints a pipeline has newly committed.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct PipelineCommit {
+    pub pipeline: String,
+    /// The first newly committed checkpoint, unknown for a pipeline's first commit
+    pub checkpoint_lo: Option<i64>,
+    pub checkpoint_hi: i64,
+    pub timestamp_ms_hi: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct CommitNotifications {
+    sender: broadcast::Sender<Arc<PipelineCommit>>,
+}
+
+impl Default for CommitNotifications {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommitNotifications {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(COMMITS_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<PipelineCommit>> {
+        self.sender.subscribe()
+    }
+
+    /// Listen for commits on `database_url`, reconnecting whenever the connection fails.
+    pub(crate) async fn run_listener(self, database_url: Url) {
+        loop {
+            match self.listen(&database_url).await {
+                Ok(()) => tracing::warn!("Commit notification connection closed"),
+                Err(error) => tracing::warn!("Commit notification connection failed: {}", error),
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }
+
+    async fn listen(&self, database_url: &Url) -> anyhow::Result<()> {
+        let tls = MakeRustlsConnect::new(tls_config()?);
+        let (client, mut connection) = tokio_postgres::connect(database_url.as_str(), tls).await?;
+
+        // The connection delivers notifications and also has to be driven for the client's
+        // `LISTEN` to complete, so it runs alongside.
+        let sender = self.sender.clone();
+        let forward = tokio::spawn(async move {
+            let mut messages = futures::stream::poll_fn(move |cx| connection.poll_message(cx));
+            while let Some(message) = messages.next().await {
+                let AsyncMessage::Notification(notification) = message? else {
+                    continue;
+                };
+                match serde_json::from_str::<PipelineCommit>(notification.payload()) {
+                    Ok(commit) => {
+                        // Fails only when nobody is subscribed, which is fine.
+                        let _ = sender.send(Arc::new(commit));
+                    }
+                    Err(error) => tracing::warn!(
+                        payload = notification.payload(),
+                        "Invalid commit notification: {}",
+                        error
+                    ),
+                }
+            }
+            Ok::<_, tokio_postgres::Error>(())
+        });
+
+        client
+            .batch_execute(&format!("LISTEN {COMMITS_CHANNEL}"))
+            .await?;
+        tracing::info!("Listening for indexer commits");
+
+        // The client has to outlive the connection for it to stay open.
+        let result = forward.await?;
+        drop(client);
+        Ok(result?)
+    }
+}
+
+/// TLS for the listener connection, trusting the same roots as the connection pool.
+fn tls_config() -> anyhow::Result<rustls::ClientConfig> {
+    let roots = rustls::RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    Ok(rustls::ClientConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()?
+    .with_root_certificates(roots)
+    .with_no_client_auth())
+}
+
+/// Wait for the next commit of one of `pipelines`. Returns immediately if notifications have been
+/// missed or the listener is gone, so the caller can catch up.
+pub(crate) async fn next_commit_of(
+    commits: &mut broadcast::Receiver<Arc<PipelineCommit>>,
+    pipelines: &[&str],
+) {
+    loop {
+        match commits.recv().await {
+            Ok(commit) if pipelines.contains(&commit.pipeline.as_str()) => return,
+            Ok(_) => continue,
+            Err(broadcast::error::RecvError::Lagged(_)) => return,
+            Err(broadcast::error::RecvError::Closed) => {
+                // Nothing will ever arrive, so leave waking up to the caller's poll.
+                std::future::pending::<()>().await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commit_notifications_parse() {
+        let commit: PipelineCommit = serde_json::from_str(
+            r#"{"pipeline":"ohclv","checkpoint_lo":11,"checkpoint_hi":12,"timestamp_ms_hi":1000}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            commit,
+            PipelineCommit {
+                pipeline: "ohclv".to_string(),
+                checkpoint_lo: Some(11),
+                checkpoint_hi: 12,
+                timestamp_ms_hi: 1000,
+            }
+        );
+
+        let first: PipelineCommit = serde_json::from_str(
+            r#"{"pipeline":"ohclv","checkpoint_lo":null,"checkpoint_hi":0,"timestamp_ms_hi":0}"#,
+        )
+        .unwrap();
+        assert_eq!(first.checkpoint_lo, None);
+    }
+
+    #[tokio::test]
+    async fn waiting_for_commits_skips_other_pipelines() {
+        let notifications = CommitNotifications::new();
+        let mut commits = notifications.subscribe();
+        let commit = |pipeline: &str| {
+            Arc::new(PipelineCommit {
+                pipeline: pipeline.to_string(),
+                checkpoint_lo: None,
+                checkpoint_hi: 1,
+                timestamp_ms_hi: 1,
+            })
+        };
+        notifications.sender.send(commit("balances")).unwrap();
+        notifications.sender.send(commit("order_fill")).unwrap();
+
+        next_commit_of(&mut commits, &["order_fill"]).await;
+        assert!(commits.try_recv().is_err());
+    }
+}
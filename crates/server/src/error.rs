@@ -24,6 +24,9 @@ pub enum DeepBookError {
     #[error("Internal error: {0}")]
     Internal(String),
 
+    #[error("Service unavailable: {0}")]
+    Unavailable(String),
+
     #[error("Unauthorized")]
     Unauthorized,
 }
@@ -54,6 +57,10 @@ impl DeepBookError {
     pub fn internal(msg: impl Into<String>) -> Self {
         Self::Internal(msg.into())
     }
+
+    pub fn unavailable(msg: impl Into<String>) -> Self {
+        Self::Unavailable(msg.into())
+    }
 }
 
 impl IntoResponse for DeepBookError {
@@ -62,6 +69,7 @@ impl IntoResponse for DeepBookError {
             DeepBookError::NotFound { .. } => (StatusCode::NOT_FOUND, self.to_string()),
             DeepBookError::BadRequest(_) => (StatusCode::BAD_REQUEST, self.to_string()),
             DeepBookError::Unauthorized => (StatusCode::UNAUTHORIZED, self.to_string()),
+            DeepBookError::Unavailable(_) => (StatusCode::SERVICE_UNAVAILABLE, self.to_string()),
             DeepBookError::Database(_)
             | DeepBookError::Rpc(_)
             | DeepBookError::Deserialization(_)
@@ -0,0 +1,467 @@
+//! Per-epoch governance state of a pool, derived from the indexed `stakes`, `proposals`, `votes`
+//! and `trade_params_update` rows.
+//!
+//! A balance manager's stake becomes active the epoch after it is staked, and unstaking removes
+//! all of it, along with the balance manager's vote. Proposals are identified by the balance
+//! manager that submitted them, and a vote moves the voter's voting power to the proposal it
+//! names. The quorum depends on the pool's total voting power, which no event records, so the
+//! winner of an epoch is only known once its proposal takes effect: the first trade params
+//! update after the epoch's last vote, and before any governance activity two epochs later, that
+//! matches one of the epoch's proposals. When several proposals match it, the winner is unknown.
+
+use crate::error::DeepBookError;
+use crate::reader::Reader;
+use deepbook_schema::models::{Proposals, TradeParamsUpdate, Votes};
+use diesel::sql_types::{BigInt, Nullable, Text};
+use diesel::QueryableByName;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use utoipa::ToSchema;
+
+/// Stake of a balance manager at an epoch, as [`Reader::get_stakers_at_epoch`] sums it up from
+/// the stake history.
+#[derive(Debug, QueryableByName)]
+pub(crate) struct Staker {
+    #[diesel(sql_type = Text)]
+    pub balance_manager_id: String,
+    #[diesel(sql_type = BigInt)]
+    pub active_stake: i64,
+    #[diesel(sql_type = BigInt)]
+    pub inactive_stake: i64,
+    /// Checkpoint of the last unstake during the epoch, which withdrew any earlier vote
+    #[diesel(sql_type = Nullable<BigInt>)]
+    pub unstaked_at_checkpoint: Option<i64>,
+}
+
+/// Stake of a balance manager during an epoch.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+pub struct StakeState {
+    /// Staked in earlier epochs, and so counted for voting in this one
+    pub active_stake: i64,
+    /// Staked during this epoch, active from the next
+    pub inactive_stake: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ProposalState {
+    /// Balance manager that submitted the proposal
+    pub proposal_id: String,
+    pub taker_fee: i64,
+    pub maker_fee: i64,
+    pub stake_required: i64,
+    /// Voting power of the balance managers currently voting for it
+    pub votes: i64,
+    pub voters: usize,
+    pub proposed_at: i64,
+}
+
+/// The trade params a winning proposal put into effect.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct AppliedTradeParams {
+    pub event_digest: String,
+    pub checkpoint_timestamp_ms: i64,
+    pub taker_fee: i64,
+    pub maker_fee: i64,
+    pub stake_required: i64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct EpochGovernance {
+    pub pool_id: String,
+    pub pool_name: String,
+    pub epoch: i64,
+    pub total_active_stake: i64,
+    pub total_inactive_stake: i64,
+    pub stakers: usize,
+    /// Most voted first
+    pub proposals: Vec<ProposalState>,
+    /// The proposal with the most votes so far, which wins if it reaches quorum
+    pub leading_proposal_id: Option<String>,
+    /// The proposal that took effect, once the pool has moved on from the epoch. Unknown, and
+    /// so null, when several proposals proposed the trade params that took effect.
+    pub winning_proposal_id: Option<String>,
+    pub applied_trade_params: Option<AppliedTradeParams>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BalanceManagerGovernance {
+    pub pool_id: String,
+    pub pool_name: String,
+    pub epoch: i64,
+    pub balance_manager_id: String,
+    #[serde(flatten)]
+    pub stake: StakeState,
+    pub voted_proposal_id: Option<String>,
+    /// Voting power of the balance manager's vote
+    pub vote_stake: i64,
+    pub voted_proposal_is_leading: bool,
+    /// Whether the voted proposal took effect, once the pool has moved on from the epoch and
+    /// the winner is known
+    pub voted_proposal_won: Option<bool>,
+}
+
+/// Governance state of one epoch, before it is labeled with the pool.
+#[derive(Debug, Default)]
+struct Tally {
+    stakes: BTreeMap<String, StakeState>,
+    /// Proposal each voter currently votes for, with the voting power of the vote
+    votes: HashMap<String, (String, i64)>,
+    proposals: Vec<ProposalState>,
+}
+
+impl Tally {
+    /// Tally the `stakers` of an epoch, and replay its `proposals` and `votes`, oldest first.
+    fn new(stakers: &[Staker], proposals: &[Proposals], votes: &[Votes]) -> Self {
+        let mut tally = Tally::default();
+        for staker in stakers {
+            let state = StakeState {
+                active_stake: staker.active_stake,
+                inactive_stake: staker.inactive_stake,
+            };
+            if state != StakeState::default() {
+                tally
+                    .stakes
+                    .insert(staker.balance_manager_id.clone(), state);
+            }
+        }
+
+        // Votes and unstakes interleave: unstaking during the epoch withdraws the vote.
+        let unstakes = stakers.iter().filter_map(|staker| {
+            staker
+                .unstaked_at_checkpoint
+                .map(|checkpoint| (checkpoint, &staker.balance_manager_id))
+        });
+        let votes_in_order = votes
+            .iter()
+            .map(|vote| (vote.checkpoint, Some(vote), &vote.balance_manager_id));
+        let mut events: Vec<_> = unstakes
+            .map(|(checkpoint, id)| (checkpoint, None, id))
+            .chain(votes_in_order)
+            .collect();
+        events.sort_by_key(|(checkpoint, vote, _)| (*checkpoint, vote.is_some()));
+        for (_, vote, voter) in events {
+            match vote {
+                Some(vote) => {
+                    tally
+                        .votes
+                        .insert(voter.clone(), (vote.to_proposal_id.clone(), vote.stake));
+                }
+                None => {
+                    tally.votes.remove(voter);
+                }
+            }
+        }
+
+        tally.proposals = proposals
+            .iter()
+            .map(|proposal| {
+                let supporters = tally
+                    .votes
+                    .values()
+                    .filter(|(voted, _)| *voted == proposal.balance_manager_id);
+                let (votes, voters) = supporters.fold((0, 0), |(votes, voters), (_, stake)| {
+                    (votes + stake, voters + 1)
+                });
+                ProposalState {
+                    proposal_id: proposal.balance_manager_id.clone(),
+                    taker_fee: proposal.taker_fee,
+                    maker_fee: proposal.maker_fee,
+                    stake_required: proposal.stake_required,
+                    votes,
+                    voters,
+                    proposed_at: proposal.checkpoint_timestamp_ms,
+                }
+            })
+            .collect();
+        tally.proposals.sort_by(|a, b| {
+            b.votes
+                .cmp(&a.votes)
+                .then(a.proposed_at.cmp(&b.proposed_at))
+        });
+        tally
+    }
+
+    fn leading_proposal_id(&self) -> Option<&str> {
+        self.proposals
+            .first()
+            .filter(|proposal| proposal.votes > 0)
+            .map(|proposal| proposal.proposal_id.as_str())
+    }
+
+    /// The first of `updates` that puts one of the epoch's proposals into effect, with the
+    /// proposal, unless several of them proposed the same trade params.
+    fn winner<'a>(
+        &'a self,
+        updates: &'a [TradeParamsUpdate],
+    ) -> Option<(Option<&'a ProposalState>, &'a TradeParamsUpdate)> {
+        updates.iter().find_map(|update| {
+            let mut matching = self.proposals.iter().filter(|proposal| {
+                proposal.taker_fee == update.taker_fee
+                    && proposal.maker_fee == update.maker_fee
+                    && proposal.stake_required == update.stake_required
+            });
+            let proposal = matching.next()?;
+            let proposal = matching.next().is_none().then_some(proposal);
+            Some((proposal, update))
+        })
+    }
+}
+
+/// Everything indexed about one epoch of a pool's governance.
+struct EpochEvents {
+    epoch: i64,
+    tally: Tally,
+    /// Trade params updates that may have resulted from the epoch's vote
+    updates: Vec<TradeParamsUpdate>,
+}
+
+async fn load_epoch(
+    reader: &Reader,
+    pool_id: &str,
+    epoch: Option<i64>,
+) -> Result<EpochEvents, DeepBookError> {
+    let epoch = match epoch {
+        Some(epoch) => epoch,
+        None => reader
+            .get_latest_governance_epoch(pool_id)
+            .await?
+            .ok_or_else(|| DeepBookError::not_found(format!("Governance of pool {pool_id}")))?,
+    };
+
+    let stakers = reader.get_stakers_at_epoch(pool_id, epoch).await?;
+    let proposals = reader.get_proposals_in_epoch(pool_id, epoch).await?;
+    let votes = reader.get_votes_in_epoch(pool_id, epoch).await?;
+
+    let last_vote_ms = proposals
+        .iter()
+        .map(|proposal| proposal.checkpoint_timestamp_ms)
+        .chain(votes.iter().map(|vote| vote.checkpoint_timestamp_ms))
+        .max();
+    let updates = match last_vote_ms {
+        Some(after_ms) => {
+            let before_ms = reader
+                .get_governance_epoch_start(pool_id, epoch + 2)
+                .await?;
+            reader
+                .get_trade_params_updates_between(pool_id, after_ms, before_ms)
+                .await?
+        }
+        None => vec![],
+    };
+
+    Ok(EpochEvents {
+        epoch,
+        tally: Tally::new(&stakers, &proposals, &votes),
+        updates,
+    })
+}
+
+/// Governance state of a pool in `epoch`, or in the latest epoch with governance activity.
+pub async fn epoch_governance(
+    reader: &Reader,
+    pool_id: &str,
+    pool_name: &str,
+    epoch: Option<i64>,
+) -> Result<EpochGovernance, DeepBookError> {
+    let EpochEvents {
+        epoch,
+        tally,
+        updates,
+    } = load_epoch(reader, pool_id, epoch).await?;
+    let winner = tally.winner(&updates);
+
+    Ok(EpochGovernance {
+        pool_id: pool_id.to_string(),
+        pool_name: pool_name.to_string(),
+        epoch,
+        total_active_stake: tally.stakes.values().map(|s| s.active_stake).sum(),
+        total_inactive_stake: tally.stakes.values().map(|s| s.inactive_stake).sum(),
+        stakers: tally.stakes.len(),
+        leading_proposal_id: tally.leading_proposal_id().map(String::from),
+        winning_proposal_id: winner
+            .and_then(|(proposal, _)| proposal)
+            .map(|proposal| proposal.proposal_id.clone()),
+        applied_trade_params: winner.map(|(_, update)| AppliedTradeParams {
+            event_digest: update.event_digest.clone(),
+            checkpoint_timestamp_ms: update.checkpoint_timestamp_ms,
+            taker_fee: update.taker_fee,
+            maker_fee: update.maker_fee,
+            stake_required: update.stake_required,
+        }),
+        proposals: tally.proposals,
+    })
+}
+
+/// Stake and vote of `balance_manager_id` in a pool's `epoch`, or in the latest epoch with
+/// governance activity.
+pub async fn balance_manager_governance(
+    reader: &Reader,
+    pool_id: &str,
+    pool_name: &str,
+    balance_manager_id: &str,
+    epoch: Option<i64>,
+) -> Result<BalanceManagerGovernance, DeepBookError> {
+    let EpochEvents {
+        epoch,
+        tally,
+        updates,
+    } = load_epoch(reader, pool_id, epoch).await?;
+    let vote = tally.votes.get(balance_manager_id);
+    let voted_proposal_id = vote.map(|(proposal_id, _)| proposal_id.clone());
+    let winner = tally.winner(&updates);
+
+    Ok(BalanceManagerGovernance {
+        pool_id: pool_id.to_string(),
+        pool_name: pool_name.to_string(),
+        epoch,
+        balance_manager_id: balance_manager_id.to_string(),
+        stake: tally
+            .stakes
+            .get(balance_manager_id)
+            .cloned()
+            .unwrap_or_default(),
+        vote_stake: vote.map(|(_, stake)| *stake).unwrap_or_default(),
+        voted_proposal_is_leading: voted_proposal_id.is_some()
+            && voted_proposal_id.as_deref() == tally.leading_proposal_id(),
+        voted_proposal_won: winner
+            .and_then(|(proposal, _)| proposal)
+            .map(|proposal| voted_proposal_id.as_deref() == Some(proposal.proposal_id.as_str())),
+        voted_proposal_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn staker(balance_manager_id: &str, active_stake: i64, unstaked_at: Option<i64>) -> Staker {
+        Staker {
+            balance_manager_id: balance_manager_id.to_string(),
+            active_stake,
+            inactive_stake: 0,
+            unstaked_at_checkpoint: unstaked_at,
+        }
+    }
+
+    fn proposal(balance_manager_id: &str, checkpoint: i64, taker_fee: i64) -> Proposals {
+        Proposals {
+            event_digest: format!("proposal-{balance_manager_id}"),
+            digest: String::new(),
+            sender: String::new(),
+            checkpoint,
+            checkpoint_timestamp_ms: checkpoint,
+            package: String::new(),
+            pool_id: "pool".to_string(),
+            balance_manager_id: balance_manager_id.to_string(),
+            epoch: 5,
+            taker_fee,
+            maker_fee: 0,
+            stake_required: 0,
+        }
+    }
+
+    fn vote(voter: &str, checkpoint: i64, to: &str, stake: i64) -> Votes {
+        Votes {
+            event_digest: format!("vote-{voter}-{checkpoint}"),
+            digest: String::new(),
+            sender: String::new(),
+            checkpoint,
+            checkpoint_timestamp_ms: checkpoint,
+            package: String::new(),
+            pool_id: "pool".to_string(),
+            balance_manager_id: voter.to_string(),
+            epoch: 5,
+            from_proposal_id: None,
+            to_proposal_id: to.to_string(),
+            stake,
+        }
+    }
+
+    fn update(checkpoint: i64, taker_fee: i64) -> TradeParamsUpdate {
+        TradeParamsUpdate {
+            event_digest: format!("update-{checkpoint}"),
+            digest: String::new(),
+            sender: String::new(),
+            checkpoint,
+            checkpoint_timestamp_ms: checkpoint,
+            package: String::new(),
+            pool_id: "pool".to_string(),
+            taker_fee,
+            maker_fee: 0,
+            stake_required: 0,
+        }
+    }
+
+    #[test]
+    fn stakers_without_stake_are_left_out() {
+        let stakers = vec![
+            staker("a", 100, None),
+            Staker {
+                inactive_stake: 50,
+                ..staker("b", 0, Some(3))
+            },
+            staker("c", 0, Some(4)),
+        ];
+
+        let tally = Tally::new(&stakers, &[], &[]);
+
+        assert_eq!(tally.stakes.get("a").unwrap().active_stake, 100);
+        assert_eq!(
+            tally.stakes.get("b"),
+            Some(&StakeState {
+                active_stake: 0,
+                inactive_stake: 50
+            })
+        );
+        assert!(!tally.stakes.contains_key("c"));
+    }
+
+    #[test]
+    fn latest_votes_are_tallied_and_unstaking_withdraws_them() {
+        let stakers = vec![
+            staker("a", 100, None),
+            staker("b", 70, None),
+            staker("c", 0, Some(14)),
+        ];
+        let proposals = vec![proposal("a", 10, 1), proposal("b", 11, 2)];
+        let votes = vec![
+            vote("a", 10, "a", 100),
+            vote("b", 11, "b", 70),
+            vote("c", 12, "a", 90),
+            vote("a", 13, "b", 100),
+        ];
+
+        let tally = Tally::new(&stakers, &proposals, &votes);
+
+        assert_eq!(tally.leading_proposal_id(), Some("b"));
+        assert_eq!(tally.proposals[0].votes, 170);
+        assert_eq!(tally.proposals[0].voters, 2);
+        assert_eq!(tally.proposals[1].votes, 0);
+        assert!(!tally.votes.contains_key("c"));
+    }
+
+    #[test]
+    fn first_matching_update_wins() {
+        let proposals = vec![proposal("a", 10, 1), proposal("b", 11, 2)];
+        let tally = Tally::new(&[], &proposals, &[]);
+        let updates = vec![update(20, 3), update(21, 2), update(22, 1)];
+
+        let (proposal, update) = tally.winner(&updates).unwrap();
+
+        assert_eq!(proposal.unwrap().proposal_id, "b");
+        assert_eq!(update.checkpoint, 21);
+        assert!(tally.winner(&updates[..1]).is_none());
+    }
+
+    #[test]
+    fn winner_is_unknown_when_proposals_match_alike() {
+        let proposals = vec![proposal("a", 10, 1), proposal("b", 11, 1)];
+        let tally = Tally::new(&[], &proposals, &[]);
+        let updates = vec![update(20, 1)];
+
+        let (proposal, update) = tally.winner(&updates).unwrap();
+
+        assert!(proposal.is_none());
+        assert_eq!(update.checkpoint, 20);
+    }
+}
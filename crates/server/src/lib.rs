@@ -2,12 +2,21 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod admin;
+pub mod book_reconciliation;
+pub mod commit_notifications;
 pub mod error;
+mod governance;
 pub mod grpc;
 pub mod live_ohclv;
 pub mod margin_metrics;
+pub mod market_feed;
 mod metrics;
+pub mod openapi;
+pub mod order_book;
+mod order_stream;
+pub mod pagination;
 pub mod pyth;
 mod reader;
+pub mod responses;
 pub mod server;
 pub mod writer;
@@ -4,8 +4,10 @@
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
 use tokio::time::{self, MissedTickBehavior};
 
+use crate::commit_notifications::{next_commit_of, PipelineCommit};
 use crate::error::DeepBookError;
 use crate::reader::Reader;
 
@@ -14,6 +16,8 @@ pub const OHCLV_DEFAULT_LIMIT: i32 = 1000;
 pub const OHCLV_DEFAULT_WINDOW_MS: i64 = 7 * 24 * 60 * MINUTE_MS;
 const LIVE_OHCLV_POLL_LOOKBACK_MS: i64 = 10 * MINUTE_MS;
 const LIVE_OHCLV_MAX_MATERIALIZER_LAG_MS: i64 = 3 * LIVE_OHCLV_POLL_LOOKBACK_MS;
+/// Pipelines whose commits bring new fills or materialize cached ones
+const LIVE_OHCLV_PIPELINES: [&str; 2] = ["order_fill", "ohclv"];
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Candle {
@@ -304,12 +308,21 @@ impl LiveOhclvCache {
         state.latest_materialized_timestamp_ms = latest_materialized_timestamp_ms;
     }
 
-    pub(crate) async fn run_poll_loop(&self, reader: Reader, poll_interval: Duration) {
+    /// Poll on every tick, and whenever fills are indexed or materialized.
+    pub(crate) async fn run_poll_loop(
+        &self,
+        reader: Reader,
+        poll_interval: Duration,
+        mut commits: broadcast::Receiver<Arc<PipelineCommit>>,
+    ) {
         let mut ticker = time::interval(poll_interval);
         ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
         loop {
-            ticker.tick().await;
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = next_commit_of(&mut commits, &LIVE_OHCLV_PIPELINES) => {}
+            }
             if let Err(error) = self.poll_once(&reader).await {
                 tracing::warn!("Live OHCLV cache poll failed: {}", error);
             }
@@ -1,7 +1,9 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::Context;
 use clap::Parser;
+use deepbook_package_registry::{PackageFamily, PackageRegistry};
 use deepbook_server::pyth::{
     PythChartHistoryConfig, PythProConfig, DEFAULT_CHART_HISTORY_CACHE_MAX_ENTRIES,
     DEFAULT_CHART_HISTORY_CACHE_TTL_SECS, DEFAULT_CHART_HISTORY_MAX_RANGE_SECS,
@@ -9,10 +11,13 @@ use deepbook_server::pyth::{
     DEFAULT_PRO_HISTORY_URL, DEFAULT_PRO_URL,
 };
 use deepbook_server::server::run_server;
-use std::{net::SocketAddr, time::Duration};
+use std::{net::SocketAddr, path::PathBuf, time::Duration};
 use sui_pg_db::DbArgs;
 use url::Url;
 
+/// Package ID value that selects the latest version in the package registry
+const LATEST_PACKAGE: &str = "latest";
+
 #[derive(Parser)]
 #[clap(rename_all = "kebab-case", author, version)]
 struct Args {
@@ -31,6 +36,15 @@ struct Args {
     /// Full node gRPC endpoint (`sui.rpc.v2`). Same host/port as the old JSON-RPC URL.
     #[clap(env, long, default_value = "https://fullnode.mainnet.sui.io:443")]
     rpc_url: Url,
+    /// Network whose packages `latest` package IDs resolve to in the package registry.
+    #[clap(env = "DEEPBOOK_ENV", long, default_value = "mainnet")]
+    env: String,
+    /// Package registry file (TOML, or JSON with a .json extension). Defaults to the registry
+    /// compiled into the binary.
+    #[clap(env, long)]
+    package_config: Option<PathBuf>,
+    /// DeepBook package used for read-only calls. `latest` selects the latest core package of
+    /// `--env` in the package registry.
     #[clap(
         env,
         long,
@@ -53,12 +67,23 @@ struct Args {
     // Margin metrics polling configuration
     #[clap(env, long, default_value_t = 30)]
     margin_poll_interval_secs: u64,
+    /// Margin package used for read-only calls and metrics polling, which only run when it is
+    /// set. `latest` selects the latest margin package of `--env` in the package registry.
     #[clap(env, long)]
     margin_package_id: Option<String>,
+    /// Seconds between reconciliations of the indexed order book against the chain. 0 disables
+    /// them.
+    #[clap(env, long, default_value_t = 300)]
+    book_reconciliation_interval_secs: u64,
     #[clap(env = "LIVE_OHCLV_POLL_INTERVAL_MS", long, default_value_t = 1000)]
     live_ohclv_poll_interval_ms: u64,
     #[clap(env = "LIVE_OHCLV_MAX_FILLS", long, default_value_t = 5000)]
     live_ohclv_max_fills: usize,
+    /// `LISTEN` for the indexer's commit notifications on `--database-url`, so that caches and
+    /// streams refresh as soon as new data is committed rather than on their next poll. Leave it
+    /// off on read replicas, which cannot `LISTEN`.
+    #[clap(env, long)]
+    commit_notifications: bool,
     /// Comma-separated list of valid admin bearer tokens
     #[clap(env = "ADMIN_TOKENS", long)]
     admin_tokens: Option<String>,
@@ -107,13 +132,17 @@ async fn main() -> Result<(), anyhow::Error> {
         metrics_address,
         database_url,
         rpc_url,
+        env,
+        package_config,
         deepbook_package_id,
         deep_token_package_id,
         deep_treasury_id,
         margin_poll_interval_secs,
         margin_package_id,
+        book_reconciliation_interval_secs,
         live_ohclv_poll_interval_ms,
         live_ohclv_max_fills,
+        commit_notifications,
         admin_tokens,
         pyth_pro_url,
         pyth_pro_allowed_feed_ids,
@@ -126,6 +155,25 @@ async fn main() -> Result<(), anyhow::Error> {
         pyth_pro_chart_history_cache_max_entries,
         pyth_pro_chart_history_max_range_secs,
     } = Args::parse();
+    // `latest` resolves through the package registry, so an upgrade can be picked up by
+    // editing config. An empty margin ID (an unset variable passed through entry.sh) leaves
+    // margin disabled.
+    let registry = PackageRegistry::load_or_builtin(package_config.as_deref())?;
+    let resolve = |id: String, family| -> anyhow::Result<String> {
+        if id != LATEST_PACKAGE {
+            return Ok(id);
+        }
+        registry
+            .latest(&env, family)
+            .map(|version| version.id.clone())
+            .with_context(|| format!("No {family:?} package for '{env}' in the package registry"))
+    };
+    let deepbook_package_id = resolve(deepbook_package_id, PackageFamily::Core)?;
+    let margin_package_id = margin_package_id
+        .filter(|id| !id.is_empty())
+        .map(|id| resolve(id, PackageFamily::Margin))
+        .transpose()?;
+
     // Read the secret from the environment only so it never needs to appear in
     // process arguments or clap's help output.
     let pyth_pro_api_key = std::env::var("PYTH_PRO_API_KEY").ok();
@@ -154,9 +202,11 @@ async fn main() -> Result<(), anyhow::Error> {
         deep_treasury_id,
         margin_poll_interval_secs,
         margin_package_id,
+        book_reconciliation_interval_secs,
         admin_tokens,
         live_ohclv_poll_interval_ms,
         live_ohclv_max_fills,
+        commit_notifications,
         pyth_pro_url,
         pyth_pro_api_key,
         pyth_pro_config,
@@ -0,0 +1,597 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The market data feed served over `/ws`.
+//!
+//! Every connection shares one feed, published by a single poll while anyone is connected. Like
+//! `/order_stream`, the poll pages through `order_fills` and `order_updates` in checkpoint and
+//! event digest order, each up to its pipeline's watermark, so rows committed out of order are
+//! still published once. New fills become trades, tickers and 1m candles, the candles built from
+//! the fills themselves rather than the materialized OHCLV. Connections only filter the feed by
+//! the channels and pools they subscribed to, so the database load does not grow with the number
+//! of clients.
+//!
+//! Clients send `{"op": "subscribe" | "unsubscribe", "channel": <channel>, "pool": <pool name>}`
+//! where `<channel>` is one of `trades`, `order_updates`, `candles_1m` or `ticker`, and receive
+//! `{"event": "update", "channel": <channel>, "pool": <pool name>, "data": {...}}`.
+
+use axum::extract::ws::{Message, WebSocket};
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, Mutex};
+use tokio::time::{self, MissedTickBehavior};
+
+use crate::commit_notifications::{next_commit_of, PipelineCommit};
+use crate::error::DeepBookError;
+use crate::pagination::EventCursor;
+use crate::reader::Reader;
+
+const MINUTE_MS: i64 = 60_000;
+/// Messages buffered for each connection before a slow one starts missing them
+const FEED_CAPACITY: usize = 4096;
+/// The pipeline writing `order_fills`, which trades, tickers and candles come from
+const TRADES_PIPELINE: &str = "order_fill";
+/// The pipeline writing `order_updates`
+const ORDER_UPDATES_PIPELINE: &str = "order_update";
+/// Rows fetched from each table per query
+const FEED_BATCH_SIZE: usize = 1000;
+/// How long subscriptions look pools up in the same read of `pools`
+const POOLS_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+/// Subscriptions a single connection may hold
+const MAX_SUBSCRIPTIONS: usize = 200;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedChannel {
+    Trades,
+    OrderUpdates,
+    #[serde(rename = "candles_1m")]
+    Candles1m,
+    Ticker,
+}
+
+/// One update on the feed, for one channel and pool.
+#[derive(Debug)]
+pub struct FeedMessage {
+    pub channel: FeedChannel,
+    pub pool_id: String,
+    pub data: Value,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FeedTrade {
+    pub event_digest: String,
+    pub digest: String,
+    pub maker_order_id: String,
+    pub taker_order_id: String,
+    pub maker_balance_manager_id: String,
+    pub taker_balance_manager_id: String,
+    pub price: f64,
+    pub base_volume: f64,
+    pub quote_volume: f64,
+    pub timestamp: i64,
+    #[serde(rename = "type")]
+    pub trade_type: &'static str,
+    #[serde(skip)]
+    pub pool_id: String,
+    #[serde(skip)]
+    pub checkpoint: i64,
+}
+
+impl FeedTrade {
+    fn cursor(&self) -> EventCursor {
+        EventCursor {
+            checkpoint: self.checkpoint,
+            event_digest: self.event_digest.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FeedOrderUpdate {
+    pub order_id: String,
+    pub balance_manager_id: String,
+    pub status: String,
+    pub price: f64,
+    pub original_quantity: f64,
+    pub remaining_quantity: f64,
+    pub filled_quantity: f64,
+    pub timestamp: i64,
+    #[serde(rename = "type")]
+    pub order_type: &'static str,
+    #[serde(skip)]
+    pub event_digest: String,
+    #[serde(skip)]
+    pub pool_id: String,
+    #[serde(skip)]
+    pub checkpoint: i64,
+}
+
+impl FeedOrderUpdate {
+    fn cursor(&self) -> EventCursor {
+        EventCursor {
+            checkpoint: self.checkpoint,
+            event_digest: self.event_digest.clone(),
+        }
+    }
+}
+
+/// A 1m candle, built from the fills of its minute.
+#[derive(Clone, Debug, Serialize)]
+pub struct FeedCandle {
+    pub timestamp: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub base_volume: f64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct FeedTicker {
+    last_price: f64,
+    last_trade_timestamp: i64,
+}
+
+/// Where the feed is in one of the tables it publishes.
+#[derive(Debug, Default)]
+struct TableCursor {
+    /// After the last row published, `None` until the feed starts from the pipeline's watermark
+    after: Option<EventCursor>,
+    /// The checkpoint up to which every row has been published
+    published_to: Option<i64>,
+}
+
+impl TableCursor {
+    /// Where to fetch rows from, when the pipeline has committed up to `checkpoint_hi`: `None` if
+    /// every row up to it was published. The first call starts the feed after `checkpoint_hi`,
+    /// so that a fresh feed does not replay history.
+    fn next_page(&mut self, checkpoint_hi: i64) -> Option<EventCursor> {
+        let Some(after) = self.after.clone() else {
+            self.after = Some(EventCursor::after_checkpoint(checkpoint_hi));
+            self.published_to = Some(checkpoint_hi);
+            return None;
+        };
+        (self.published_to < Some(checkpoint_hi)).then_some(after)
+    }
+
+    /// Record a page fetched up to `checkpoint_hi`, ending with the row at `last`. The rows up to
+    /// `checkpoint_hi` are all published unless the page was `full`.
+    fn advance(&mut self, last: Option<EventCursor>, checkpoint_hi: i64, full: bool) {
+        if let Some(last) = last {
+            self.after = Some(last);
+        }
+        if !full {
+            self.published_to = Some(checkpoint_hi);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct FeedCursors {
+    trades: TableCursor,
+    order_updates: TableCursor,
+}
+
+/// Pool names to IDs, for looking up subscriptions.
+#[derive(Debug, Default)]
+struct PoolIds {
+    ids: HashMap<String, String>,
+    read_at: Option<Instant>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MarketFeed {
+    sender: broadcast::Sender<Arc<FeedMessage>>,
+    pools: Arc<Mutex<PoolIds>>,
+}
+
+impl Default for MarketFeed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketFeed {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(FEED_CAPACITY);
+        Self {
+            sender,
+            pools: Arc::new(Mutex::new(PoolIds::default())),
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<FeedMessage>> {
+        self.sender.subscribe()
+    }
+
+    /// The ID of the pool named `pool_name`, from a read of `pools` at most
+    /// `POOLS_REFRESH_INTERVAL` old.
+    async fn pool_id(
+        &self,
+        reader: &Reader,
+        pool_name: &str,
+    ) -> Result<Option<String>, DeepBookError> {
+        let mut pools = self.pools.lock().await;
+        if pools
+            .read_at
+            .map_or(true, |read_at| read_at.elapsed() >= POOLS_REFRESH_INTERVAL)
+        {
+            pools.ids = reader
+                .get_pools()
+                .await?
+                .into_iter()
+                .map(|pool| (pool.pool_name, pool.pool_id))
+                .collect();
+            pools.read_at = Some(Instant::now());
+        }
+        Ok(pools.ids.get(pool_name).cloned())
+    }
+
+    fn publish(&self, channel: FeedChannel, pool_id: &str, data: impl Serialize) {
+        let Ok(data) = serde_json::to_value(data) else {
+            return;
+        };
+        // Fails only when nobody is connected, which is fine.
+        let _ = self.sender.send(Arc::new(FeedMessage {
+            channel,
+            pool_id: pool_id.to_string(),
+            data,
+        }));
+    }
+
+    /// Poll on every tick, and whenever fills or order updates are indexed.
+    pub(crate) async fn run_poll_loop(
+        &self,
+        reader: Reader,
+        poll_interval: Duration,
+        mut commits: broadcast::Receiver<Arc<PipelineCommit>>,
+    ) {
+        let mut ticker = time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        let mut cursors = FeedCursors::default();
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = next_commit_of(&mut commits, &[TRADES_PIPELINE, ORDER_UPDATES_PIPELINE]) => {}
+            }
+            if let Err(error) = self.poll(&reader, &mut cursors).await {
+                tracing::warn!("Market feed poll failed: {}", error);
+            }
+        }
+    }
+
+    /// Publish every fill and order update committed since the last poll.
+    async fn poll(&self, reader: &Reader, cursors: &mut FeedCursors) -> Result<(), DeepBookError> {
+        if self.sender.receiver_count() == 0 {
+            // Start afresh for the next client rather than replaying a backlog.
+            *cursors = FeedCursors::default();
+            return Ok(());
+        }
+        while self.publish_trades(reader, &mut cursors.trades).await? {}
+        while self
+            .publish_order_updates(reader, &mut cursors.order_updates)
+            .await?
+        {}
+        Ok(())
+    }
+
+    /// Publish the next page of fills as trades, tickers and candles, returning whether there
+    /// may be more.
+    async fn publish_trades(
+        &self,
+        reader: &Reader,
+        cursor: &mut TableCursor,
+    ) -> Result<bool, DeepBookError> {
+        let Some(checkpoint_hi) = reader
+            .get_pipelines_checkpoint_hi(&[TRADES_PIPELINE])
+            .await?
+        else {
+            return Ok(false);
+        };
+        let Some(after) = cursor.next_page(checkpoint_hi) else {
+            return Ok(false);
+        };
+        let trades = reader
+            .get_feed_trades_after(&after, checkpoint_hi, FEED_BATCH_SIZE)
+            .await?;
+        let full = trades.len() >= FEED_BATCH_SIZE;
+        cursor.advance(trades.last().map(FeedTrade::cursor), checkpoint_hi, full);
+
+        // Trades arrive ordered, so the last one of each pool is its latest.
+        let mut minutes: BTreeMap<&str, BTreeSet<i64>> = BTreeMap::new();
+        let mut latest: BTreeMap<&str, &FeedTrade> = BTreeMap::new();
+        for trade in &trades {
+            minutes
+                .entry(&trade.pool_id)
+                .or_default()
+                .insert(trade.timestamp.div_euclid(MINUTE_MS) * MINUTE_MS);
+            latest.insert(&trade.pool_id, trade);
+            self.publish(FeedChannel::Trades, &trade.pool_id, trade);
+        }
+
+        for (pool_id, minutes) in minutes {
+            let minutes: Vec<i64> = minutes.into_iter().collect();
+            for candle in reader.get_feed_candles(pool_id, &minutes).await? {
+                self.publish(FeedChannel::Candles1m, pool_id, candle);
+            }
+        }
+
+        for (pool_id, trade) in latest {
+            self.publish(
+                FeedChannel::Ticker,
+                pool_id,
+                FeedTicker {
+                    last_price: trade.price,
+                    last_trade_timestamp: trade.timestamp,
+                },
+            );
+        }
+        Ok(full)
+    }
+
+    /// Publish the next page of order updates, returning whether there may be more.
+    async fn publish_order_updates(
+        &self,
+        reader: &Reader,
+        cursor: &mut TableCursor,
+    ) -> Result<bool, DeepBookError> {
+        let Some(checkpoint_hi) = reader
+            .get_pipelines_checkpoint_hi(&[ORDER_UPDATES_PIPELINE])
+            .await?
+        else {
+            return Ok(false);
+        };
+        let Some(after) = cursor.next_page(checkpoint_hi) else {
+            return Ok(false);
+        };
+        let updates = reader
+            .get_feed_order_updates_after(&after, checkpoint_hi, FEED_BATCH_SIZE)
+            .await?;
+        let full = updates.len() >= FEED_BATCH_SIZE;
+        cursor.advance(
+            updates.last().map(FeedOrderUpdate::cursor),
+            checkpoint_hi,
+            full,
+        );
+
+        for update in &updates {
+            self.publish(FeedChannel::OrderUpdates, &update.pool_id, update);
+        }
+        Ok(full)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum ClientRequest {
+    Subscribe { channel: FeedChannel, pool: String },
+    Unsubscribe { channel: FeedChannel, pool: String },
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum ServerEvent<'a> {
+    Subscribed {
+        channel: FeedChannel,
+        pool: &'a str,
+    },
+    Unsubscribed {
+        channel: FeedChannel,
+        pool: &'a str,
+    },
+    Update {
+        channel: FeedChannel,
+        pool: &'a str,
+        data: &'a Value,
+    },
+    /// The connection fell behind the feed and missed `skipped` updates
+    Lagged {
+        skipped: u64,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// A connection's subscriptions, from channel and pool ID to the pool name it subscribed with.
+#[derive(Debug, Default)]
+struct Subscriptions {
+    pools: HashMap<(FeedChannel, String), String>,
+}
+
+impl Subscriptions {
+    /// The pool name `message` is delivered under, if it is subscribed to.
+    fn pool_name(&self, message: &FeedMessage) -> Option<&str> {
+        self.pools
+            .get(&(message.channel, message.pool_id.clone()))
+            .map(String::as_str)
+    }
+}
+
+/// Serve the feed over `socket` until the client disconnects.
+pub(crate) async fn serve_socket(socket: WebSocket, feed: MarketFeed, reader: Reader) {
+    let (mut sender, mut receiver) = socket.split();
+    let mut updates = feed.subscribe();
+    let mut subscriptions = Subscriptions::default();
+
+    loop {
+        let reply = tokio::select! {
+            incoming = receiver.next() => match incoming {
+                Some(Ok(Message::Text(text))) => {
+                    handle_request(&text, &mut subscriptions, &feed, &reader).await
+                }
+                Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                // Pings are answered by axum, and nothing else is expected.
+                Some(Ok(_)) => continue,
+            },
+            update = updates.recv() => match update {
+                Ok(message) => {
+                    let Some(pool) = subscriptions.pool_name(&message) else {
+                        continue;
+                    };
+                    encode(&ServerEvent::Update {
+                        channel: message.channel,
+                        pool,
+                        data: &message.data,
+                    })
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    encode(&ServerEvent::Lagged { skipped })
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+        };
+        if sender.send(Message::Text(reply)).await.is_err() {
+            break;
+        }
+    }
+}
+
+async fn handle_request(
+    text: &str,
+    subscriptions: &mut Subscriptions,
+    feed: &MarketFeed,
+    reader: &Reader,
+) -> String {
+    let request = match serde_json::from_str::<ClientRequest>(text) {
+        Ok(request) => request,
+        Err(e) => {
+            return encode(&ServerEvent::Error {
+                message: format!("Invalid request: {e}"),
+            })
+        }
+    };
+    let (channel, pool) = match &request {
+        ClientRequest::Subscribe { channel, pool } => (channel, pool),
+        ClientRequest::Unsubscribe { channel, pool } => (channel, pool),
+    };
+
+    let pool_id = match feed.pool_id(reader, pool).await {
+        Ok(pool_id) => pool_id,
+        Err(e) => {
+            return encode(&ServerEvent::Error {
+                message: e.to_string(),
+            })
+        }
+    };
+    let Some(pool_id) = pool_id else {
+        return encode(&ServerEvent::Error {
+            message: format!("Pool '{pool}' not found"),
+        });
+    };
+
+    match &request {
+        ClientRequest::Subscribe { .. } => {
+            if subscriptions.pools.len() >= MAX_SUBSCRIPTIONS {
+                return encode(&ServerEvent::Error {
+                    message: format!("At most {MAX_SUBSCRIPTIONS} subscriptions are allowed"),
+                });
+            }
+            subscriptions
+                .pools
+                .insert((*channel, pool_id), pool.clone());
+            encode(&ServerEvent::Subscribed {
+                channel: *channel,
+                pool,
+            })
+        }
+        ClientRequest::Unsubscribe { .. } => {
+            subscriptions.pools.remove(&(*channel, pool_id));
+            encode(&ServerEvent::Unsubscribed {
+                channel: *channel,
+                pool,
+            })
+        }
+    }
+}
+
+fn encode(event: &ServerEvent<'_>) -> String {
+    serde_json::to_string(event).expect("feed events serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor(checkpoint: i64, event_digest: &str) -> EventCursor {
+        EventCursor {
+            checkpoint,
+            event_digest: event_digest.to_string(),
+        }
+    }
+
+    #[test]
+    fn table_cursor_starts_at_the_watermark() {
+        let mut table = TableCursor::default();
+        assert_eq!(table.next_page(10), None);
+        assert_eq!(table.next_page(10), None);
+        assert_eq!(table.next_page(12), Some(cursor(11, "")));
+    }
+
+    #[test]
+    fn table_cursor_pages_until_the_watermark_is_reached() {
+        let mut table = TableCursor::default();
+        table.next_page(10);
+
+        // A full page may leave rows behind at or before the watermark
+        table.advance(Some(cursor(11, "a")), 12, true);
+        assert_eq!(table.next_page(12), Some(cursor(11, "a")));
+        table.advance(Some(cursor(12, "b")), 12, false);
+        assert_eq!(table.next_page(12), None);
+
+        // Nothing new before the watermark moves on
+        table.advance(None, 13, false);
+        assert_eq!(table.next_page(13), None);
+        assert_eq!(table.next_page(14), Some(cursor(12, "b")));
+    }
+
+    #[test]
+    fn subscriptions_route_messages_by_channel_and_pool() {
+        let mut subscriptions = Subscriptions::default();
+        subscriptions.pools.insert(
+            (FeedChannel::Trades, "0x1".to_string()),
+            "SUI_USDC".to_string(),
+        );
+        let message = |channel, pool_id: &str| FeedMessage {
+            channel,
+            pool_id: pool_id.to_string(),
+            data: Value::Null,
+        };
+
+        assert_eq!(
+            subscriptions.pool_name(&message(FeedChannel::Trades, "0x1")),
+            Some("SUI_USDC")
+        );
+        assert_eq!(
+            subscriptions.pool_name(&message(FeedChannel::Ticker, "0x1")),
+            None
+        );
+        assert_eq!(
+            subscriptions.pool_name(&message(FeedChannel::Trades, "0x2")),
+            None
+        );
+    }
+
+    #[test]
+    fn client_requests_parse() {
+        let request: ClientRequest =
+            serde_json::from_str(r#"{"op":"subscribe","channel":"candles_1m","pool":"SUI_USDC"}"#)
+                .unwrap();
+        assert!(matches!(
+            request,
+            ClientRequest::Subscribe {
+                channel: FeedChannel::Candles1m,
+                ..
+            }
+        ));
+        assert!(
+            serde_json::from_str::<ClientRequest>(r#"{"op":"subscribe","channel":"x"}"#).is_err()
+        );
+    }
+}
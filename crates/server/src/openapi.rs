@@ -0,0 +1,374 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! OpenAPI document of the server, generated from the `#[utoipa::path]` annotations of its
+//! handlers and the `ToSchema` derives of their response types, and served at
+//! [`OPENAPI_PATH`](crate::server::OPENAPI_PATH). The admin routes, and the `/ws` and
+//! `/order_stream` streams, are not part of it.
+//!
+//! `ApiDoc` lists every documented handler and every schema its responses refer to, nested ones
+//! included, so a new endpoint needs adding here as well as to the router.
+
+use crate::governance::{
+    AppliedTradeParams, BalanceManagerGovernance, EpochGovernance, ProposalState, StakeState,
+};
+use crate::reader::{
+    AccountInfo, AccountSession, AccountsQueryResult, BalanceManagerReferral, LiquidationVaultPnl,
+    PortfolioCollateralBalance, PortfolioLpPosition, PortfolioMarginPosition, PortfolioQueryResult,
+    PortfolioSummary, RewardBalance, RewardsQueryResult,
+};
+use crate::responses::{
+    AddressPoints, AssetInfo, BalanceManagerDepositedAssets, BalanceManagerOrder, IndexerStatus,
+    MarginManagerInfo, Ohclv, Orderbook, PipelineStatus, PoolFees, PoolOrderUpdate, PoolSummary,
+    Ticker, Trade,
+};
+use crate::server;
+use deepbook_schema::models::{
+    AssetSupplied, AssetWithdrawn, BalanceManagerBalance, BookParamsUpdated, CollateralEvent,
+    DeepbookPoolConfigUpdated, DeepbookPoolRegistered, DeepbookPoolUpdated,
+    DeepbookPoolUpdatedRegistry, InterestParamsUpdated, Liquidation, LoanBorrowed, LoanRepaid,
+    MaintainerCapUpdated, MaintainerFeesWithdrawn, MarginManagerCreated, MarginManagerState,
+    MarginPoolConfigUpdated, MarginPoolCreated, PauseCapUpdated, PoolCreated, Pools,
+    PropbookBlockScholesBatchIngested, PropbookBlockScholesObservation,
+    PropbookBlockScholesStoresRegistered, PropbookOracleBound, PropbookOracleObservation,
+    PropbookOracleRebound, PropbookOracleSourceRegistered, ProtocolFeesIncreasedEvent,
+    ProtocolFeesWithdrawn, RebatesV2, ReferralFeeEvent, ReferralFeesClaimedEvent,
+    SupplierCapMinted, SupplyReferralMinted, TraderVolume, TraderVolumeTotal,
+};
+use utoipa::{IntoParams, OpenApi};
+
+/// Time range of a query. Without `start_time`, most endpoints cover the day before `end_time`.
+#[derive(IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TimeRangeParams {
+    /// Start of the range, in Unix seconds
+    pub start_time: Option<i64>,
+    /// End of the range, in Unix seconds (default: now)
+    pub end_time: Option<i64>,
+}
+
+/// Time range and page of an event list, newest first. See [`crate::pagination`].
+#[derive(IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventPageParams {
+    /// Start of the range, in Unix seconds
+    pub start_time: Option<i64>,
+    /// End of the range, in Unix seconds (default: now)
+    pub end_time: Option<i64>,
+    /// Maximum number of events in the page
+    pub limit: Option<i64>,
+    /// `x-next-cursor` header of the previous page
+    pub cursor: Option<String>,
+}
+
+/// Page of an event list without a time range, newest first. See [`crate::pagination`].
+#[derive(IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PageParams {
+    /// Maximum number of events in the page (default: all of them)
+    pub limit: Option<i64>,
+    /// `x-next-cursor` header of the previous page
+    pub cursor: Option<String>,
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "DeepBookV3 Indexer API",
+        description = "REST endpoints for DeepBookV3 order book and analytics data. Volumes are \
+            returned in the smallest unit of the asset unless stated otherwise."
+    ),
+    servers((
+        url = "https://deepbook-indexer.mainnet.mystenlabs.com",
+        description = "Public DeepBookV3 Indexer"
+    )),
+    paths(
+        server::health_check,
+        server::status,
+        server::get_pools,
+        server::historical_volume,
+        server::all_historical_volume,
+        server::get_historical_volume_by_balance_manager_id_with_interval,
+        server::get_historical_volume_by_balance_manager_id,
+        server::get_net_deposits,
+        server::ticker,
+        server::trades,
+        server::trade_count,
+        server::order_updates,
+        server::orders,
+        server::assets,
+        server::ohclv,
+        server::margin_manager_created,
+        server::loan_borrowed,
+        server::loan_repaid,
+        server::liquidation,
+        server::liquidation_vault_pnl,
+        server::asset_supplied,
+        server::asset_withdrawn,
+        server::margin_pool_created,
+        server::deepbook_pool_updated,
+        server::interest_params_updated,
+        server::margin_pool_config_updated,
+        server::maintainer_cap_updated,
+        server::maintainer_fees_withdrawn,
+        server::protocol_fees_withdrawn,
+        server::supplier_cap_minted,
+        server::supply_referral_minted,
+        server::pause_cap_updated,
+        server::protocol_fees_increased,
+        server::referral_fees_claimed,
+        server::referral_fee_events,
+        server::rebates_v2,
+        server::deepbook_pool_registered,
+        server::deepbook_pool_updated_registry,
+        server::deepbook_pool_config_updated,
+        server::margin_managers_info,
+        server::margin_manager_states,
+        server::deposited_assets,
+        server::balance_manager_balances,
+        server::trader_volume,
+        server::trader_volume_leaderboard,
+        server::governance,
+        server::balance_manager_governance,
+        server::rewards,
+        server::referral_rewards,
+        server::collateral_events,
+        server::get_points,
+        server::portfolio,
+        server::accounts,
+        server::pool_created,
+        server::book_params_updated,
+        server::propbook_oracle_sources,
+        server::propbook_oracle_bound,
+        server::propbook_oracle_rebound,
+        server::propbook_block_scholes_stores,
+        server::propbook_oracle_observations,
+        server::propbook_block_scholes_observations,
+        server::propbook_block_scholes_batches,
+        server::orderbook,
+        server::deep_supply,
+        server::margin_supply,
+        server::summary,
+        server::fees,
+    ),
+    components(schemas(
+        // Responses shaped by the handlers
+        AddressPoints,
+        AssetInfo,
+        BalanceManagerDepositedAssets,
+        BalanceManagerOrder,
+        IndexerStatus,
+        MarginManagerInfo,
+        Ohclv,
+        Orderbook,
+        PipelineStatus,
+        PoolFees,
+        PoolOrderUpdate,
+        PoolSummary,
+        Ticker,
+        Trade,
+        // Query results
+        AccountInfo,
+        AccountSession,
+        AccountsQueryResult,
+        AppliedTradeParams,
+        BalanceManagerGovernance,
+        BalanceManagerReferral,
+        EpochGovernance,
+        LiquidationVaultPnl,
+        PortfolioCollateralBalance,
+        PortfolioLpPosition,
+        PortfolioMarginPosition,
+        PortfolioQueryResult,
+        PortfolioSummary,
+        ProposalState,
+        RewardBalance,
+        RewardsQueryResult,
+        StakeState,
+        // Indexed rows
+        AssetSupplied,
+        AssetWithdrawn,
+        BalanceManagerBalance,
+        BookParamsUpdated,
+        CollateralEvent,
+        DeepbookPoolConfigUpdated,
+        DeepbookPoolRegistered,
+        DeepbookPoolUpdated,
+        DeepbookPoolUpdatedRegistry,
+        InterestParamsUpdated,
+        Liquidation,
+        LoanBorrowed,
+        LoanRepaid,
+        MaintainerCapUpdated,
+        MaintainerFeesWithdrawn,
+        MarginManagerCreated,
+        MarginManagerState,
+        MarginPoolConfigUpdated,
+        MarginPoolCreated,
+        PauseCapUpdated,
+        PoolCreated,
+        Pools,
+        PropbookBlockScholesBatchIngested,
+        PropbookBlockScholesObservation,
+        PropbookBlockScholesStoresRegistered,
+        PropbookOracleBound,
+        PropbookOracleObservation,
+        PropbookOracleRebound,
+        PropbookOracleSourceRegistered,
+        ProtocolFeesIncreasedEvent,
+        ProtocolFeesWithdrawn,
+        RebatesV2,
+        ReferralFeeEvent,
+        ReferralFeesClaimedEvent,
+        SupplierCapMinted,
+        SupplyReferralMinted,
+        TraderVolume,
+        TraderVolumeTotal,
+    )),
+    tags(
+        (name = "Health"),
+        (name = "Pools"),
+        (name = "Volume"),
+        (name = "Market Data"),
+        (name = "Order Flow"),
+        (name = "Reference"),
+        (name = "Governance"),
+        (name = "Rewards"),
+        (name = "Accounts"),
+        (name = "Margin"),
+        (name = "Propbook"),
+        (name = "Pyth"),
+    )
+)]
+pub struct ApiDoc;
+
+/// The server's OpenAPI document, including the Pyth routes
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut doc = ApiDoc::openapi();
+    doc.merge(crate::pyth::PythApi::openapi());
+    doc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pyth::{LATEST_PRICE_PATH, PRICE_AT_TIMESTAMP_PATH, TRADINGVIEW_HISTORY_PATH};
+    use crate::server::*;
+
+    /// `path` with axum's `:param` segments written OpenAPI's `{param}` way
+    fn openapi_path(path: &str) -> String {
+        path.split('/')
+            .map(|segment| match segment.strip_prefix(':') {
+                Some(param) => format!("{{{param}}}"),
+                None => segment.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    #[test]
+    fn documents_every_public_route() {
+        let pyth_paths = [
+            LATEST_PRICE_PATH,
+            PRICE_AT_TIMESTAMP_PATH,
+            TRADINGVIEW_HISTORY_PATH,
+        ]
+        .map(|path| format!("/pyth{path}"));
+        let routes = [
+            "/",
+            GET_POOLS_PATH,
+            GET_HISTORICAL_VOLUME_BY_BALANCE_MANAGER_ID_WITH_INTERVAL,
+            GET_HISTORICAL_VOLUME_BY_BALANCE_MANAGER_ID,
+            HISTORICAL_VOLUME_PATH,
+            ALL_HISTORICAL_VOLUME_PATH,
+            GET_NET_DEPOSITS,
+            TICKER_PATH,
+            TRADES_PATH,
+            ORDER_UPDATES_PATH,
+            ORDERS_PATH,
+            TRADE_COUNT_PATH,
+            ASSETS_PATH,
+            SUMMARY_PATH,
+            LEVEL2_PATH,
+            DEEP_SUPPLY_PATH,
+            MARGIN_SUPPLY_PATH,
+            OHCLV_PATH,
+            FEES_PATH,
+            MARGIN_MANAGER_CREATED_PATH,
+            LOAN_BORROWED_PATH,
+            LOAN_REPAID_PATH,
+            LIQUIDATION_PATH,
+            LIQUIDATION_VAULT_PNL_PATH,
+            ASSET_SUPPLIED_PATH,
+            ASSET_WITHDRAWN_PATH,
+            MARGIN_POOL_CREATED_PATH,
+            DEEPBOOK_POOL_UPDATED_PATH,
+            INTEREST_PARAMS_UPDATED_PATH,
+            MARGIN_POOL_CONFIG_UPDATED_PATH,
+            MAINTAINER_CAP_UPDATED_PATH,
+            MAINTAINER_FEES_WITHDRAWN_PATH,
+            PROTOCOL_FEES_WITHDRAWN_PATH,
+            SUPPLIER_CAP_MINTED_PATH,
+            SUPPLY_REFERRAL_MINTED_PATH,
+            PAUSE_CAP_UPDATED_PATH,
+            PROTOCOL_FEES_INCREASED_PATH,
+            REFERRAL_FEES_CLAIMED_PATH,
+            REFERRAL_FEE_EVENTS_PATH,
+            REBATES_V2_PATH,
+            DEEPBOOK_POOL_REGISTERED_PATH,
+            DEEPBOOK_POOL_UPDATED_REGISTRY_PATH,
+            DEEPBOOK_POOL_CONFIG_UPDATED_PATH,
+            MARGIN_MANAGERS_INFO_PATH,
+            MARGIN_MANAGER_STATES_PATH,
+            STATUS_PATH,
+            DEPOSITED_ASSETS_PATH,
+            BALANCE_MANAGER_BALANCES_PATH,
+            TRADER_VOLUME_PATH,
+            TRADER_VOLUME_LEADERBOARD_PATH,
+            GOVERNANCE_PATH,
+            GOVERNANCE_BALANCE_MANAGER_PATH,
+            REWARDS_PATH,
+            REFERRAL_REWARDS_PATH,
+            COLLATERAL_EVENTS_PATH,
+            GET_POINTS_PATH,
+            PORTFOLIO_PATH,
+            POOL_CREATED_PATH,
+            BOOK_PARAMS_UPDATED_PATH,
+            ACCOUNTS_PATH,
+            PROPBOOK_ORACLE_SOURCES_PATH,
+            PROPBOOK_ORACLE_BOUND_PATH,
+            PROPBOOK_ORACLE_REBOUND_PATH,
+            PROPBOOK_BLOCK_SCHOLES_STORES_PATH,
+            PROPBOOK_ORACLE_OBSERVATIONS_PATH,
+            PROPBOOK_BLOCK_SCHOLES_OBSERVATIONS_PATH,
+            PROPBOOK_BLOCK_SCHOLES_BATCHES_PATH,
+        ]
+        .map(openapi_path);
+
+        let mut expected: Vec<String> = routes.into_iter().chain(pyth_paths).collect();
+        expected.sort();
+        let mut documented: Vec<String> = openapi().paths.paths.into_keys().collect();
+        documented.sort();
+        assert_eq!(documented, expected);
+    }
+
+    #[test]
+    fn every_schema_reference_resolves() {
+        let doc = serde_json::to_value(openapi()).unwrap();
+        let schemas = &doc["components"]["schemas"];
+        let mut pending = vec![&doc];
+        while let Some(value) = pending.pop() {
+            match value {
+                serde_json::Value::Object(fields) => {
+                    if let Some(reference) = fields.get("$ref").and_then(|r| r.as_str()) {
+                        let name = reference.trim_start_matches("#/components/schemas/");
+                        assert!(schemas.get(name).is_some(), "unresolved {reference}");
+                    }
+                    pending.extend(fields.values());
+                }
+                serde_json::Value::Array(values) => pending.extend(values),
+                _ => {}
+            }
+        }
+    }
+}
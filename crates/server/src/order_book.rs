@@ -0,0 +1,65 @@
+//! On-chain level 2 reads, shared by the `/orderbook` route and the book reconciler.
+
+use crate::error::DeepBookError;
+use crate::server::{LEVEL2_FUNCTION, LEVEL2_MODULE};
+use std::str::FromStr;
+use sui_rpc::Client;
+use sui_sdk_types::TypeTag;
+
+/// Price levels as `(price, quantity)`, best first.
+pub type Levels = Vec<(u64, u64)>;
+
+/// Bid and ask levels within `ticks_from_mid` of the mid price, from a
+/// `pool::get_level2_ticks_from_mid` simulate call against a full node.
+pub async fn level2_ticks_from_mid(
+    client: &Client,
+    deepbook_package_id: &str,
+    pool_id: &str,
+    initial_shared_version: u64,
+    base_asset_id: &str,
+    quote_asset_id: &str,
+    ticks_from_mid: u64,
+) -> Result<(Levels, Levels), DeepBookError> {
+    let mut ptb = crate::grpc::read_only_tx();
+    let pool = ptb.object(crate::grpc::shared_input(pool_id, initial_shared_version)?);
+    let ticks = ptb.pure(&ticks_from_mid);
+    let clock = ptb.object(crate::grpc::clock_input());
+
+    let coin_type = |type_str: &str| {
+        TypeTag::from_str(type_str)
+            .map_err(|e| DeepBookError::bad_request(format!("Invalid type '{}': {}", type_str, e)))
+    };
+
+    ptb.move_call(
+        crate::grpc::function(
+            deepbook_package_id,
+            LEVEL2_MODULE,
+            LEVEL2_FUNCTION,
+            vec![coin_type(base_asset_id)?, coin_type(quote_asset_id)?],
+        )?,
+        vec![pool, ticks, clock],
+    );
+
+    let results = crate::grpc::simulate_returns(client, ptb).await?;
+
+    // One command returning four vectors: bid prices, bid quantities, ask prices, ask quantities.
+    let level2 = results
+        .first()
+        .ok_or(DeepBookError::rpc("No return values for level2 ticks"))?;
+    let decode = |index: usize, what: &str| -> Result<Vec<u64>, DeepBookError> {
+        let bytes = level2
+            .get(index)
+            .ok_or_else(|| DeepBookError::rpc(format!("No {} data found", what)))?;
+        bcs::from_bytes(bytes)
+            .map_err(|_| DeepBookError::deserialization(format!("Failed to deserialize {}", what)))
+    };
+    let bid_prices = decode(0, "bid prices")?;
+    let bid_quantities = decode(1, "bid quantities")?;
+    let ask_prices = decode(2, "ask prices")?;
+    let ask_quantities = decode(3, "ask quantities")?;
+
+    Ok((
+        bid_prices.into_iter().zip(bid_quantities).collect(),
+        ask_prices.into_iter().zip(ask_quantities).collect(),
+    ))
+}
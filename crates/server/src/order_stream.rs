@@ -0,0 +1,169 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Server-sent events of a balance manager's order lifecycle, served under `/order_stream`.
+//!
+//! The stream carries the balance manager's `order_updates` and `order_fills` rows in checkpoint
+//! and event digest order. Each event's id is its [`EventCursor`], so a client that reconnects
+//! with it as `Last-Event-ID` resumes right after the last event it received.
+//!
+//! Both pipelines commit checkpoints concurrently and out of order, so rows are only streamed once
+//! both pipelines' watermarks have passed their checkpoint: a row committed behind the stream's
+//! position would otherwise never be sent.
+
+use axum::response::sse::Event;
+use deepbook_schema::models::{OrderFill, OrderUpdate};
+use futures::Stream;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::time::{self, Interval, MissedTickBehavior};
+
+use crate::commit_notifications::{next_commit_of, PipelineCommit};
+use crate::error::DeepBookError;
+use crate::pagination::{EventCursor, IndexedEvent};
+use crate::reader::Reader;
+
+/// The pipelines that write the streamed tables
+const ORDER_STREAM_PIPELINES: [&str; 2] = ["order_update", "order_fill"];
+/// How often to check for new rows when no commits are announced
+const ORDER_STREAM_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Rows fetched from each table per query
+const ORDER_STREAM_BATCH_SIZE: usize = 500;
+
+enum OrderEvent {
+    Update(OrderUpdate),
+    Fill(OrderFill),
+}
+
+impl OrderEvent {
+    fn cursor(&self) -> EventCursor {
+        match self {
+            OrderEvent::Update(update) => update.cursor(),
+            OrderEvent::Fill(fill) => fill.cursor(),
+        }
+    }
+
+    fn to_sse(&self) -> Result<Event, axum::Error> {
+        let event = Event::default().id(self.cursor().to_string());
+        match self {
+            OrderEvent::Update(update) => event.event("order_update").json_data(update),
+            OrderEvent::Fill(fill) => event.event("order_fill").json_data(fill),
+        }
+    }
+}
+
+struct OrderStream {
+    reader: Reader,
+    balance_manager_id: String,
+    /// `None` until the stream starts from the pipelines' current watermark
+    cursor: Option<EventCursor>,
+    /// The checkpoint every row up to which has been fetched
+    fetched_to: Option<i64>,
+    pending: VecDeque<Event>,
+    ticker: Interval,
+    commits: broadcast::Receiver<Arc<PipelineCommit>>,
+}
+
+impl OrderStream {
+    /// Fetch the next rows into `pending`, returning whether there may be more to fetch right
+    /// away.
+    async fn fetch(&mut self) -> Result<bool, DeepBookError> {
+        let Some(checkpoint_hi) = self
+            .reader
+            .get_pipelines_checkpoint_hi(&ORDER_STREAM_PIPELINES)
+            .await?
+        else {
+            return Ok(false);
+        };
+        let Some(cursor) = self.cursor.clone() else {
+            self.cursor = Some(EventCursor::after_checkpoint(checkpoint_hi));
+            self.fetched_to = Some(checkpoint_hi);
+            return Ok(false);
+        };
+        if self.fetched_to >= Some(checkpoint_hi) {
+            return Ok(false);
+        }
+
+        let limit = ORDER_STREAM_BATCH_SIZE as i64;
+        let (updates, fills) = futures::try_join!(
+            self.reader.get_order_updates_after(
+                &self.balance_manager_id,
+                &cursor,
+                checkpoint_hi,
+                limit,
+            ),
+            self.reader.get_order_fills_after(
+                &self.balance_manager_id,
+                &cursor,
+                checkpoint_hi,
+                limit
+            ),
+        )?;
+
+        // Either table may have more rows after its batch, so only the earliest batch's worth of
+        // both is known to be complete.
+        let mut events: Vec<OrderEvent> = updates
+            .into_iter()
+            .map(OrderEvent::Update)
+            .chain(fills.into_iter().map(OrderEvent::Fill))
+            .collect();
+        events.sort_by_key(OrderEvent::cursor);
+        let more = events.len() >= ORDER_STREAM_BATCH_SIZE;
+        events.truncate(ORDER_STREAM_BATCH_SIZE);
+        if !more {
+            self.fetched_to = Some(checkpoint_hi);
+        }
+
+        for event in events {
+            self.cursor = Some(event.cursor());
+            match event.to_sse() {
+                Ok(sse) => self.pending.push_back(sse),
+                Err(error) => tracing::warn!("Failed to encode order stream event: {}", error),
+            }
+        }
+        Ok(more)
+    }
+}
+
+/// `balance_manager_id`'s order updates and fills as they are indexed, starting after
+/// `resume_from`, or from the next indexed checkpoint without it. New rows are fetched as soon as
+/// `commits` announces either pipeline's progress.
+pub(crate) fn order_stream(
+    reader: Reader,
+    commits: broadcast::Receiver<Arc<PipelineCommit>>,
+    balance_manager_id: String,
+    resume_from: Option<EventCursor>,
+) -> impl Stream<Item = Result<Event, Infallible>> {
+    let mut ticker = time::interval(ORDER_STREAM_POLL_INTERVAL);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let stream = OrderStream {
+        reader,
+        balance_manager_id,
+        cursor: resume_from,
+        fetched_to: None,
+        pending: VecDeque::new(),
+        ticker,
+        commits,
+    };
+
+    futures::stream::unfold((stream, false), |(mut stream, mut more)| async move {
+        loop {
+            if let Some(event) = stream.pending.pop_front() {
+                return Some((Ok(event), (stream, more)));
+            }
+            if !more {
+                tokio::select! {
+                    _ = stream.ticker.tick() => {}
+                    _ = next_commit_of(&mut stream.commits, &ORDER_STREAM_PIPELINES) => {}
+                }
+            }
+            more = stream.fetch().await.unwrap_or_else(|error| {
+                tracing::warn!("Order stream poll failed: {}", error);
+                false
+            });
+        }
+    })
+}
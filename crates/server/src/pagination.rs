@@ -0,0 +1,267 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cursors over indexed events.
+//!
+//! Every event row is identified by its checkpoint and event digest, and event lists are ordered
+//! by the pair, comparing digests bytewise (`COLLATE "C"` in SQL) whatever the database's
+//! collation. A client pages through an event list by passing each page's `X-Next-Cursor` header
+//! back as the `cursor` query parameter, which returns the events after it, however many of them
+//! share a timestamp.
+
+use axum::http::{HeaderName, HeaderValue};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use deepbook_schema::models::{
+    AssetSupplied, AssetWithdrawn, BookParamsUpdated, CollateralEvent, DeepbookPoolConfigUpdated,
+    DeepbookPoolRegistered, DeepbookPoolUpdated, DeepbookPoolUpdatedRegistry,
+    InterestParamsUpdated, Liquidation, LoanBorrowed, LoanRepaid, MaintainerCapUpdated,
+    MaintainerFeesWithdrawn, MarginManagerCreated, MarginPoolConfigUpdated, MarginPoolCreated,
+    OrderFill, OrderUpdate, PauseCapUpdated, PoolCreated, PropbookBlockScholesBatchIngested,
+    PropbookBlockScholesObservation, PropbookOracleBound, PropbookOracleObservation,
+    PropbookOracleRebound, ProtocolFeesIncreasedEvent, ProtocolFeesWithdrawn, RebatesV2,
+    ReferralFeeEvent, ReferralFeesClaimedEvent, SupplierCapMinted, SupplyReferralMinted,
+};
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::DeepBookError;
+
+/// The response header carrying the cursor of an event list's next page.
+pub const NEXT_CURSOR_HEADER: HeaderName = HeaderName::from_static("x-next-cursor");
+
+/// A position between events, written `<checkpoint>:<event_digest>`: just after the event with
+/// this checkpoint and event digest in ascending order, or just before it in descending order.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct EventCursor {
+    pub checkpoint: i64,
+    pub event_digest: String,
+}
+
+impl EventCursor {
+    /// After every event, for the first page of a newest-first list.
+    pub fn newest() -> Self {
+        Self {
+            checkpoint: i64::MAX,
+            event_digest: String::new(),
+        }
+    }
+
+    /// Before every event of checkpoints after `checkpoint`.
+    pub fn after_checkpoint(checkpoint: i64) -> Self {
+        Self {
+            checkpoint: checkpoint + 1,
+            event_digest: String::new(),
+        }
+    }
+}
+
+impl fmt::Display for EventCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.checkpoint, self.event_digest)
+    }
+}
+
+impl FromStr for EventCursor {
+    type Err = DeepBookError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            DeepBookError::bad_request(format!(
+                "Invalid cursor '{s}', expected <checkpoint>:<event_digest>"
+            ))
+        };
+        let (checkpoint, event_digest) = s.split_once(':').ok_or_else(invalid)?;
+        Ok(Self {
+            checkpoint: checkpoint.parse().map_err(|_| invalid())?,
+            event_digest: event_digest.to_string(),
+        })
+    }
+}
+
+/// An indexed event, positioned by its checkpoint and event digest.
+pub trait IndexedEvent {
+    fn cursor(&self) -> EventCursor;
+}
+
+macro_rules! impl_indexed_event {
+    ($($model:ty),* $(,)?) => {
+        $(
+            impl IndexedEvent for $model {
+                fn cursor(&self) -> EventCursor {
+                    EventCursor {
+                        checkpoint: self.checkpoint,
+                        event_digest: self.event_digest.clone(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_indexed_event!(
+    AssetSupplied,
+    AssetWithdrawn,
+    BookParamsUpdated,
+    CollateralEvent,
+    DeepbookPoolConfigUpdated,
+    DeepbookPoolRegistered,
+    DeepbookPoolUpdated,
+    DeepbookPoolUpdatedRegistry,
+    InterestParamsUpdated,
+    Liquidation,
+    LoanBorrowed,
+    LoanRepaid,
+    MaintainerCapUpdated,
+    MaintainerFeesWithdrawn,
+    MarginManagerCreated,
+    MarginPoolConfigUpdated,
+    MarginPoolCreated,
+    OrderFill,
+    OrderUpdate,
+    PauseCapUpdated,
+    PoolCreated,
+    PropbookBlockScholesBatchIngested,
+    PropbookBlockScholesObservation,
+    PropbookOracleBound,
+    PropbookOracleObservation,
+    PropbookOracleRebound,
+    ProtocolFeesIncreasedEvent,
+    ProtocolFeesWithdrawn,
+    RebatesV2,
+    ReferralFeeEvent,
+    ReferralFeesClaimedEvent,
+    SupplierCapMinted,
+    SupplyReferralMinted,
+);
+
+/// A page of an event list, newest first. It responds with the JSON array of its events, and
+/// the cursor of the next page in the [`NEXT_CURSOR_HEADER`] header unless this is the last one.
+#[derive(Debug)]
+pub struct Page<T> {
+    pub events: Vec<T>,
+    pub next_cursor: Option<EventCursor>,
+}
+
+impl<T: IndexedEvent> Page<T> {
+    /// The page of `events` fetched with `limit`. Only a full page may have more events after it.
+    pub fn new(events: Vec<T>, limit: i64) -> Self {
+        let next_cursor = if limit > 0 && events.len() as i64 >= limit {
+            events.last().map(IndexedEvent::cursor)
+        } else {
+            None
+        };
+        Self {
+            events,
+            next_cursor,
+        }
+    }
+}
+
+impl<T> Page<T> {
+    /// The same page with its events converted by `f`, such as into their response format.
+    pub fn map_events<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            events: self.events.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+impl<T: Serialize> IntoResponse for Page<T> {
+    fn into_response(self) -> Response {
+        with_next_cursor(Json(self.events).into_response(), self.next_cursor)
+    }
+}
+
+/// A page of at most one event, for endpoints returning the latest event. It responds with the
+/// event, or null, and the cursor of the event before it like [`Page`].
+#[derive(Debug)]
+pub struct Latest<T>(pub Page<T>);
+
+impl<T: Serialize> IntoResponse for Latest<T> {
+    fn into_response(self) -> Response {
+        let Page {
+            events,
+            next_cursor,
+        } = self.0;
+        with_next_cursor(Json(events.into_iter().next()).into_response(), next_cursor)
+    }
+}
+
+fn with_next_cursor(mut response: Response, next_cursor: Option<EventCursor>) -> Response {
+    let next_cursor =
+        next_cursor.and_then(|cursor| HeaderValue::from_str(&cursor.to_string()).ok());
+    if let Some(next_cursor) = next_cursor {
+        response
+            .headers_mut()
+            .insert(NEXT_CURSOR_HEADER, next_cursor);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor(checkpoint: i64, event_digest: &str) -> EventCursor {
+        EventCursor {
+            checkpoint,
+            event_digest: event_digest.to_string(),
+        }
+    }
+
+    struct Event(EventCursor);
+
+    impl IndexedEvent for Event {
+        fn cursor(&self) -> EventCursor {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn cursors_round_trip_through_strings() {
+        let parsed: EventCursor = "1234:Abc9".parse().unwrap();
+        assert_eq!(parsed, cursor(1234, "Abc9"));
+        assert_eq!(parsed.to_string(), "1234:Abc9");
+        assert!("Abc9".parse::<EventCursor>().is_err());
+        assert!("x:Abc9".parse::<EventCursor>().is_err());
+    }
+
+    #[test]
+    fn cursors_order_by_checkpoint_then_event_digest() {
+        assert!(cursor(1, "b") < cursor(2, "a"));
+        assert!(cursor(2, "B") < cursor(2, "a"));
+        // Starting after a checkpoint comes after all of its events and before the next ones'.
+        assert!(cursor(2, "z") < EventCursor::after_checkpoint(2));
+        assert!(EventCursor::after_checkpoint(2) < cursor(3, "a"));
+        assert!(cursor(i64::MAX - 1, "z") < EventCursor::newest());
+    }
+
+    #[test]
+    fn only_full_pages_have_a_next_cursor() {
+        let events = || vec![Event(cursor(3, "b")), Event(cursor(3, "a"))];
+        assert_eq!(Page::new(events(), 2).next_cursor, Some(cursor(3, "a")));
+        assert_eq!(Page::new(events(), 3).next_cursor, None);
+        assert_eq!(Page::<Event>::new(vec![], 0).next_cursor, None);
+    }
+
+    #[test]
+    fn mapped_pages_keep_their_next_cursor() {
+        let page = Page::new(vec![Event(cursor(3, "b")), Event(cursor(3, "a"))], 2);
+        let page = page.map_events(|event| event.0.checkpoint);
+        assert_eq!(page.events, vec![3, 3]);
+        assert_eq!(page.next_cursor, Some(cursor(3, "a")));
+    }
+
+    #[test]
+    fn latest_responds_with_its_event_and_the_cursor_before_it() {
+        let response =
+            Latest(Page::new(vec![Event(cursor(3, "a"))], 1).map_events(|_| 7)).into_response();
+        assert_eq!(response.headers()[NEXT_CURSOR_HEADER], "3:a");
+
+        let response = Latest(Page::<Event>::new(vec![], 1).map_events(|_| 7)).into_response();
+        assert!(response.headers().get(NEXT_CURSOR_HEADER).is_none());
+    }
+}
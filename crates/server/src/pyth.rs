@@ -14,7 +14,7 @@ pub use config::{
     DEFAULT_PRO_HISTORY_URL, DEFAULT_PRO_URL, LATEST_PRICE_PATH, PRICE_AT_TIMESTAMP_PATH,
     TRADINGVIEW_HISTORY_PATH,
 };
-pub use proxy::{routes, PythProxy};
+pub use proxy::{routes, PythApi, PythProxy};
 
 #[cfg(test)]
 mod tests;
@@ -3,6 +3,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::{collections::HashSet, time::Duration};
+use utoipa::ToSchema;
 
 pub(super) const MICROS_PER_SECOND: u64 = 1_000_000;
 const SECONDS_PER_MINUTE: u64 = 60;
@@ -289,12 +290,12 @@ impl JsonScalar {
     }
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, ToSchema)]
 pub(super) struct PriceResponse {
     pub(super) parsed: Vec<PriceUpdate>,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, ToSchema)]
 pub(super) struct PriceUpdate {
     id: String,
     price: Price,
@@ -303,16 +304,16 @@ pub(super) struct PriceUpdate {
     metadata: PriceMetadata,
 }
 
-#[derive(Clone, Debug, Serialize)]
-struct Price {
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub(super) struct Price {
     price: String,
     conf: String,
     expo: i16,
     publish_time: u64,
 }
 
-#[derive(Clone, Debug, Serialize)]
-struct PriceMetadata {
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub(super) struct PriceMetadata {
     publish_time_us: String,
 }
 
@@ -6,8 +6,8 @@ use super::{
     config::{PythProConfig, LATEST_PRICE_PATH, PRICE_AT_TIMESTAMP_PATH, TRADINGVIEW_HISTORY_PATH},
     error::PythError,
     models::{
-        normalize_history_symbol, ChartHistoryQuery, PriceQuery, PriceResponse, PriceUpdate,
-        MICROS_PER_SECOND,
+        normalize_history_symbol, ChartHistoryQuery, Price, PriceMetadata, PriceQuery,
+        PriceResponse, PriceUpdate, MICROS_PER_SECOND,
     },
 };
 use axum::{
@@ -26,6 +26,7 @@ use std::{
 };
 use tokio::{sync::Mutex, time::Instant};
 use url::Url;
+use utoipa::OpenApi;
 
 const LATEST_RESPONSE_CACHE_MAX_ENTRIES: u64 = 128;
 
@@ -434,6 +435,14 @@ fn json_bytes_response(body: Bytes) -> Response {
     response
 }
 
+/// OpenAPI document of the Pyth routes, as nested under `/pyth` by the server
+#[derive(OpenApi)]
+#[openapi(
+    paths(latest_price, price_at_timestamp, tradingview_history),
+    components(schemas(Price, PriceMetadata, PriceResponse, PriceUpdate))
+)]
+pub struct PythApi;
+
 pub fn routes(proxy: PythProxy) -> Router {
     Router::new()
         .route(LATEST_PRICE_PATH, get(latest_price))
@@ -442,6 +451,35 @@ pub fn routes(proxy: PythProxy) -> Router {
         .with_state(proxy)
 }
 
+/// Latest parsed Pyth Pro price updates. The first request after the latest snapshot expires
+/// loads every allowed feed, and later requests read the snapshot until it expires again.
+#[utoipa::path(
+    get,
+    path = "/pyth/updates/price/latest",
+    tag = "Pyth",
+    params(
+        (
+            "ids[]" = Vec<u32>,
+            Query,
+            description = "Repeatable numeric Pyth Pro feed IDs from the server allowlist",
+            style = Form,
+            explode
+        ),
+        ("parsed" = Option<bool>, Query, description = "Must be true, binary is not supported"),
+        (
+            "ignore_invalid_price_ids" = Option<bool>,
+            Query,
+            description = "Omit feed IDs missing from the server allowlist"
+        ),
+    ),
+    responses(
+        (status = 200, description = "Parsed Hermes-like price response", body = PriceResponse),
+        (status = 400, description = "Invalid query, or a feed ID is not allowed"),
+        (status = 429, description = "Pyth Pro rate limit response"),
+        (status = 502, description = "Pyth Pro is unreachable or returned an invalid response"),
+        (status = 503, description = "Pyth Pro is not configured"),
+    )
+)]
 async fn latest_price(State(proxy): State<PythProxy>, RawQuery(query): RawQuery) -> Response {
     match PriceQuery::parse(query.as_deref()) {
         Ok(query) => proxy.latest(query).await,
@@ -449,6 +487,36 @@ async fn latest_price(State(proxy): State<PythProxy>, RawQuery(query): RawQuery)
     }
 }
 
+/// Parsed Pyth Pro prices at a timestamp, cached per feed ID and timestamp
+#[utoipa::path(
+    get,
+    path = "/pyth/updates/price/{publish_time}",
+    tag = "Pyth",
+    params(
+        ("publish_time" = u64, Path, description = "Unix timestamp in seconds"),
+        (
+            "ids[]" = Vec<u32>,
+            Query,
+            description = "Repeatable numeric Pyth Pro feed IDs from the server allowlist",
+            style = Form,
+            explode
+        ),
+        ("parsed" = Option<bool>, Query, description = "Must be true, binary is not supported"),
+        (
+            "ignore_invalid_price_ids" = Option<bool>,
+            Query,
+            description = "Omit feed IDs for which no historical price is returned"
+        ),
+    ),
+    responses(
+        (status = 200, description = "Parsed Hermes-like price response", body = PriceResponse),
+        (status = 400, description = "Invalid query or timestamp, or a feed ID is not allowed"),
+        (status = 404, description = "Pyth Pro has no price for a requested feed and timestamp"),
+        (status = 429, description = "Pyth Pro rate limit response"),
+        (status = 502, description = "Pyth Pro is unreachable or returned an invalid response"),
+        (status = 503, description = "Pyth Pro or its history service is unavailable"),
+    )
+)]
 async fn price_at_timestamp(
     Path(publish_time): Path<u64>,
     State(proxy): State<PythProxy>,
@@ -460,6 +528,33 @@ async fn price_at_timestamp(
     }
 }
 
+/// TradingView-compatible OHLC chart history, cached per symbol, resolution and range
+#[utoipa::path(
+    get,
+    path = "/pyth/shims/tradingview/history",
+    tag = "Pyth",
+    params(
+        ("symbol" = String, Query, description = "Configured symbol, such as Crypto.BTC/USD"),
+        (
+            "resolution" = String,
+            Query,
+            description = "1, 2, 5, 15, 30, 60, 120, 240, 360, 720, D, W or M"
+        ),
+        ("from" = u64, Query, description = "Inclusive start time in Unix seconds"),
+        ("to" = u64, Query, description = "Inclusive end time in Unix seconds"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Status `s` and the `t`, `o`, `h`, `l`, `c` and `v` arrays of the bars",
+            body = Object
+        ),
+        (status = 400, description = "Invalid query, range too long, or symbol not configured"),
+        (status = 429, description = "Pyth Pro rate limit response"),
+        (status = 502, description = "Pyth Pro is unreachable or returned an invalid response"),
+        (status = 503, description = "Pyth Pro is not configured"),
+    )
+)]
 async fn tradingview_history(
     State(proxy): State<PythProxy>,
     RawQuery(query): RawQuery,
@@ -1,14 +1,22 @@
 use crate::error::DeepBookError;
+use crate::governance::Staker;
 use crate::live_ohclv::{Candle, LiveFill, MinuteKey};
+use crate::market_feed::{FeedCandle, FeedOrderUpdate, FeedTrade};
 use crate::metrics::RpcMetrics;
+use crate::pagination::{EventCursor, Page};
 use deepbook_schema::models::{
-    AssetSupplied, AssetWithdrawn, BookParamsUpdated, CollateralEvent, DeepbookPoolConfigUpdated,
-    DeepbookPoolRegistered, DeepbookPoolUpdated, DeepbookPoolUpdatedRegistry,
-    InterestParamsUpdated, Liquidation, LoanBorrowed, LoanRepaid, MaintainerCapUpdated,
-    MaintainerFeesWithdrawn, MarginManagerCreated, MarginManagerState, MarginPoolConfigUpdated,
-    MarginPoolCreated, OrderFillSummary, OrderStatus, PauseCapUpdated, PoolCreated, Pools,
-    ProtocolFeesIncreasedEvent, ProtocolFeesWithdrawn, RebatesV2, ReferralFeeEvent,
-    ReferralFeesClaimedEvent, SupplierCapMinted, SupplyReferralMinted,
+    AssetSupplied, AssetWithdrawn, BalanceManagerBalance, BookParamsUpdated, CollateralEvent,
+    DeepbookPoolConfigUpdated, DeepbookPoolRegistered, DeepbookPoolUpdated,
+    DeepbookPoolUpdatedRegistry, InterestParamsUpdated, Liquidation, LoanBorrowed, LoanRepaid,
+    MaintainerCapUpdated, MaintainerFeesWithdrawn, MarginManagerCreated, MarginManagerState,
+    MarginPoolConfigUpdated, MarginPoolCreated, OrderBookLevel, OrderFill, OrderFillSummary,
+    OrderStatus, OrderUpdate, PauseCapUpdated, PoolCreated, Pools,
+    PropbookBlockScholesBatchIngested, PropbookBlockScholesObservation,
+    PropbookBlockScholesStoresRegistered, PropbookOracleBound, PropbookOracleObservation,
+    PropbookOracleRebound, PropbookOracleSourceRegistered, Proposals, ProtocolFeesIncreasedEvent,
+    ProtocolFeesWithdrawn, RebatesV2, ReferralFeeEvent, ReferralFeesClaimedEvent,
+    RewardAccountKind, RewardLedgerEntry, SupplierCapMinted, SupplyReferralMinted,
+    TradeParamsUpdate, TraderVolume, TraderVolumeTotal, Votes,
 };
 use deepbook_schema::schema;
 use diesel::deserialize::FromSqlRow;
@@ -17,10 +25,10 @@ use diesel::expression::QueryMetadata;
 use diesel::pg::Pg;
 use diesel::query_builder::{Query, QueryFragment, QueryId};
 use diesel::query_dsl::CompatibleType;
-use diesel::sql_types::{Array, BigInt, Double, Integer, Nullable, SmallInt, Text};
+use diesel::sql_types::{Array, BigInt, Bool, Double, Integer, Nullable, SmallInt, Text};
 use diesel::{
-    BoolExpressionMethods, ExpressionMethods, QueryDsl, QueryableByName, SelectableHelper,
-    TextExpressionMethods,
+    BoolExpressionMethods, ExpressionMethods, OptionalExtension, QueryDsl, QueryableByName,
+    SelectableHelper, TextExpressionMethods,
 };
 
 /// Converts an empty string to "%" for SQL LIKE pattern matching.
@@ -34,6 +42,45 @@ fn to_pattern(s: &str) -> String {
     }
 }
 
+/// Restricts an event table query to the page of up to `$limit` events before the
+/// `Option<EventCursor>` `$cursor`, newest first. Event digests compare bytewise, as cursors do,
+/// whatever the database's collation.
+macro_rules! newest_first_page {
+    ($query:expr, $cursor:expr, $limit:expr) => {{
+        let cursor: EventCursor = $cursor.unwrap_or_else(EventCursor::newest);
+        $query
+            .filter(
+                sql::<Bool>(r#"(checkpoint, event_digest COLLATE "C") < ("#)
+                    .bind::<BigInt, _>(cursor.checkpoint)
+                    .sql(", ")
+                    .bind::<Text, _>(cursor.event_digest)
+                    .sql(")"),
+            )
+            .order_by(sql::<Text>(
+                r#"checkpoint DESC, event_digest COLLATE "C" DESC"#,
+            ))
+            .limit($limit)
+    }};
+}
+
+/// Restricts an event table query to the page of up to `$limit` events after the `EventCursor`
+/// `$cursor`, oldest first, comparing event digests like [`newest_first_page`].
+macro_rules! oldest_first_page {
+    ($query:expr, $cursor:expr, $limit:expr) => {{
+        let cursor: &EventCursor = $cursor;
+        $query
+            .filter(
+                sql::<Bool>(r#"(checkpoint, event_digest COLLATE "C") > ("#)
+                    .bind::<BigInt, _>(cursor.checkpoint)
+                    .sql(", ")
+                    .bind::<Text, _>(cursor.event_digest.clone())
+                    .sql(")"),
+            )
+            .order_by(sql::<Text>(r#"checkpoint, event_digest COLLATE "C""#))
+            .limit($limit)
+    }};
+}
+
 use diesel_async::methods::LoadQuery;
 use diesel_async::{AsyncPgConnection, RunQueryDsl};
 use prometheus::Registry;
@@ -41,6 +88,7 @@ use std::sync::Arc;
 use sui_indexer_alt_metrics::db::DbConnectionStatsCollector;
 use sui_pg_db::{Db, DbArgs};
 use url::Url;
+use utoipa::ToSchema;
 
 #[derive(QueryableByName, Debug)]
 struct OhclvRow {
@@ -129,6 +177,151 @@ struct LiveOhclvLatestWatermarkRow {
     last_trade_timestamp: i64,
 }
 
+#[derive(QueryableByName, Debug)]
+struct FeedTradeRow {
+    #[diesel(sql_type = Text)]
+    event_digest: String,
+    #[diesel(sql_type = Text)]
+    digest: String,
+    #[diesel(sql_type = Text)]
+    pool_id: String,
+    #[diesel(sql_type = Text)]
+    maker_order_id: String,
+    #[diesel(sql_type = Text)]
+    taker_order_id: String,
+    #[diesel(sql_type = Text)]
+    maker_balance_manager_id: String,
+    #[diesel(sql_type = Text)]
+    taker_balance_manager_id: String,
+    #[diesel(sql_type = BigInt)]
+    price: i64,
+    #[diesel(sql_type = BigInt)]
+    base_quantity: i64,
+    #[diesel(sql_type = BigInt)]
+    quote_quantity: i64,
+    #[diesel(sql_type = BigInt)]
+    checkpoint: i64,
+    #[diesel(sql_type = BigInt)]
+    checkpoint_timestamp_ms: i64,
+    #[diesel(sql_type = Bool)]
+    taker_is_bid: bool,
+    #[diesel(sql_type = SmallInt)]
+    base_asset_decimals: i16,
+    #[diesel(sql_type = SmallInt)]
+    quote_asset_decimals: i16,
+}
+
+impl From<FeedTradeRow> for FeedTrade {
+    fn from(row: FeedTradeRow) -> Self {
+        let price_factor =
+            10_f64.powi(9 - row.base_asset_decimals as i32 + row.quote_asset_decimals as i32);
+        Self {
+            event_digest: row.event_digest,
+            digest: row.digest,
+            maker_order_id: row.maker_order_id,
+            taker_order_id: row.taker_order_id,
+            maker_balance_manager_id: row.maker_balance_manager_id,
+            taker_balance_manager_id: row.taker_balance_manager_id,
+            price: row.price as f64 / price_factor,
+            base_volume: row.base_quantity as f64 / 10_f64.powi(row.base_asset_decimals as i32),
+            quote_volume: row.quote_quantity as f64 / 10_f64.powi(row.quote_asset_decimals as i32),
+            timestamp: row.checkpoint_timestamp_ms,
+            trade_type: if row.taker_is_bid { "buy" } else { "sell" },
+            pool_id: row.pool_id,
+            checkpoint: row.checkpoint,
+        }
+    }
+}
+
+#[derive(QueryableByName, Debug)]
+struct FeedOrderUpdateRow {
+    #[diesel(sql_type = Text)]
+    event_digest: String,
+    #[diesel(sql_type = Text)]
+    pool_id: String,
+    #[diesel(sql_type = Text)]
+    order_id: String,
+    #[diesel(sql_type = Text)]
+    balance_manager_id: String,
+    #[diesel(sql_type = Text)]
+    status: String,
+    #[diesel(sql_type = BigInt)]
+    price: i64,
+    #[diesel(sql_type = BigInt)]
+    original_quantity: i64,
+    #[diesel(sql_type = BigInt)]
+    quantity: i64,
+    #[diesel(sql_type = BigInt)]
+    filled_quantity: i64,
+    #[diesel(sql_type = BigInt)]
+    checkpoint: i64,
+    #[diesel(sql_type = BigInt)]
+    checkpoint_timestamp_ms: i64,
+    #[diesel(sql_type = Bool)]
+    is_bid: bool,
+    #[diesel(sql_type = SmallInt)]
+    base_asset_decimals: i16,
+    #[diesel(sql_type = SmallInt)]
+    quote_asset_decimals: i16,
+}
+
+impl From<FeedOrderUpdateRow> for FeedOrderUpdate {
+    fn from(row: FeedOrderUpdateRow) -> Self {
+        let price_factor =
+            10_f64.powi(9 - row.base_asset_decimals as i32 + row.quote_asset_decimals as i32);
+        let base_factor = 10_f64.powi(row.base_asset_decimals as i32);
+        Self {
+            order_id: row.order_id,
+            balance_manager_id: row.balance_manager_id,
+            status: row.status,
+            price: row.price as f64 / price_factor,
+            original_quantity: row.original_quantity as f64 / base_factor,
+            remaining_quantity: row.quantity as f64 / base_factor,
+            filled_quantity: row.filled_quantity as f64 / base_factor,
+            timestamp: row.checkpoint_timestamp_ms,
+            order_type: if row.is_bid { "buy" } else { "sell" },
+            event_digest: row.event_digest,
+            pool_id: row.pool_id,
+            checkpoint: row.checkpoint,
+        }
+    }
+}
+
+#[derive(QueryableByName, Debug)]
+struct FeedCandleRow {
+    #[diesel(sql_type = BigInt)]
+    timestamp_ms: i64,
+    #[diesel(sql_type = BigInt)]
+    open: i64,
+    #[diesel(sql_type = BigInt)]
+    high: i64,
+    #[diesel(sql_type = BigInt)]
+    low: i64,
+    #[diesel(sql_type = BigInt)]
+    close: i64,
+    #[diesel(sql_type = Double)]
+    base_quantity: f64,
+    #[diesel(sql_type = SmallInt)]
+    base_asset_decimals: i16,
+    #[diesel(sql_type = SmallInt)]
+    quote_asset_decimals: i16,
+}
+
+impl From<FeedCandleRow> for FeedCandle {
+    fn from(row: FeedCandleRow) -> Self {
+        let price_factor =
+            10_f64.powi(9 - row.base_asset_decimals as i32 + row.quote_asset_decimals as i32);
+        Self {
+            timestamp: row.timestamp_ms,
+            open: row.open as f64 / price_factor,
+            high: row.high as f64 / price_factor,
+            low: row.low as f64 / price_factor,
+            close: row.close as f64 / price_factor,
+            base_volume: row.base_quantity / 10_f64.powi(row.base_asset_decimals as i32),
+        }
+    }
+}
+
 #[derive(QueryableByName, Debug)]
 struct MarginPositionRow {
     #[diesel(sql_type = Text)]
@@ -183,6 +376,99 @@ struct LpPositionRow {
     supplied_usd: f64,
 }
 
+/// Liquidation vault performance for one DeepBook pool. Asset amounts are raw on-chain units;
+/// `net_*` amounts are what the vault received minus what it paid in. `pnl_usd` values each
+/// liquidation's net base/quote at the Pyth prices recorded on the matching `liquidation` row.
+#[derive(QueryableByName, Debug, serde::Serialize, ToSchema)]
+pub struct LiquidationVaultPnl {
+    #[diesel(sql_type = Nullable<Text>)]
+    pub pool_id: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub pool_name: Option<String>,
+    #[diesel(sql_type = BigInt)]
+    pub liquidations: i64,
+    #[diesel(sql_type = BigInt)]
+    pub base_in: i64,
+    #[diesel(sql_type = BigInt)]
+    pub base_out: i64,
+    #[diesel(sql_type = BigInt)]
+    pub quote_in: i64,
+    #[diesel(sql_type = BigInt)]
+    pub quote_out: i64,
+    #[diesel(sql_type = BigInt)]
+    pub net_base: i64,
+    #[diesel(sql_type = BigInt)]
+    pub net_quote: i64,
+    #[diesel(sql_type = BigInt)]
+    pub pool_reward: i64,
+    #[diesel(sql_type = BigInt)]
+    pub pool_default: i64,
+    #[diesel(sql_type = Nullable<Double>)]
+    pub pnl_usd: Option<f64>,
+}
+
+/// Bucket size of the `trader_volume_*` rollups maintained by the indexer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraderVolumeInterval {
+    Hour,
+    Day,
+}
+
+impl TraderVolumeInterval {
+    pub fn parse(interval: &str) -> Option<Self> {
+        match interval {
+            "1h" => Some(Self::Hour),
+            "1d" => Some(Self::Day),
+            _ => None,
+        }
+    }
+
+    fn table(&self) -> &'static str {
+        match self {
+            Self::Hour => "trader_volume_1h",
+            Self::Day => "trader_volume_1d",
+        }
+    }
+}
+
+#[derive(QueryableByName, Debug)]
+struct GovernanceTimestamp {
+    #[diesel(sql_type = Nullable<BigInt>)]
+    value: Option<i64>,
+}
+
+#[derive(QueryableByName, Debug)]
+struct AccountRow {
+    #[diesel(sql_type = Text)]
+    account_id: String,
+    #[diesel(sql_type = Text)]
+    wrapper_id: String,
+    #[diesel(sql_type = Bool)]
+    self_owned: bool,
+    #[diesel(sql_type = Nullable<Text>)]
+    referrer_account_id: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    balance_manager_id: Option<String>,
+    #[diesel(sql_type = BigInt)]
+    created_at_ms: i64,
+}
+
+#[derive(QueryableByName, Debug)]
+struct AuthorizedAppRow {
+    #[diesel(sql_type = Text)]
+    app: String,
+}
+
+#[derive(QueryableByName, Debug)]
+struct AccountSessionRow {
+    #[diesel(sql_type = Text)]
+    account_id: String,
+    #[diesel(sql_type = Text)]
+    session: String,
+    #[diesel(sql_type = BigInt)]
+    expires_at_ms: i64,
+}
+
 #[derive(Clone)]
 pub struct Reader {
     db: Db,
@@ -340,39 +626,17 @@ impl Reader {
 
     pub async fn get_orders(
         &self,
-        pool_name: String,
         pool_id: String,
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         maker_balance_manager: Option<String>,
         taker_balance_manager: Option<String>,
         balance_manager: Option<String>,
-    ) -> Result<
-        Vec<(
-            String,
-            String,
-            String,
-            String,
-            i64,
-            i64,
-            i64,
-            i64,
-            i64,
-            i64,
-            bool,
-            String,
-            String,
-            bool,
-            bool,
-            i64,
-            i64,
-        )>,
-        DeepBookError,
-    > {
-        let mut connection = self.db.connect().await?;
-        // Build the query dynamically
+    ) -> Result<Page<OrderFill>, DeepBookError> {
         let mut query = schema::order_fills::table
+            .select(OrderFill::as_select())
             .filter(schema::order_fills::pool_id.eq(pool_id))
             .filter(schema::order_fills::checkpoint_timestamp_ms.between(start_time, end_time))
             .into_boxed();
@@ -392,64 +656,8 @@ impl Reader {
             );
         }
 
-        let _guard = self.metrics.db_latency.start_timer();
-
-        // Fetch latest trades (sorted by timestamp in descending order) within the time range, applying the limit
-        let res = query
-            .order_by(schema::order_fills::checkpoint_timestamp_ms.desc()) // Ensures latest trades come first
-            .limit(limit) // Apply limit to get the most recent trades
-            .select((
-                schema::order_fills::event_digest,
-                schema::order_fills::digest,
-                schema::order_fills::maker_order_id,
-                schema::order_fills::taker_order_id,
-                schema::order_fills::maker_client_order_id,
-                schema::order_fills::taker_client_order_id,
-                schema::order_fills::price,
-                schema::order_fills::base_quantity,
-                schema::order_fills::quote_quantity,
-                schema::order_fills::checkpoint_timestamp_ms,
-                schema::order_fills::taker_is_bid,
-                schema::order_fills::maker_balance_manager_id,
-                schema::order_fills::taker_balance_manager_id,
-                schema::order_fills::taker_fee_is_deep,
-                schema::order_fills::maker_fee_is_deep,
-                schema::order_fills::taker_fee,
-                schema::order_fills::maker_fee,
-            ))
-            .load::<(
-                String,
-                String,
-                String,
-                String,
-                i64,
-                i64,
-                i64,
-                i64,
-                i64,
-                i64,
-                bool,
-                String,
-                String,
-                bool,
-                bool,
-                i64,
-                i64,
-            )>(&mut connection)
-            .await
-            .map_err(|_| {
-                DeepBookError::not_found(format!(
-                    "Trades for pool '{}' in the specified time range",
-                    pool_name
-                ))
-            });
-
-        if res.is_ok() {
-            self.metrics.db_requests_succeeded.inc();
-        } else {
-            self.metrics.db_requests_failed.inc();
-        }
-        res
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_order_updates(
@@ -458,26 +666,14 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         balance_manager_filter: Option<String>,
         status_filter: Option<String>,
-    ) -> Result<Vec<(String, i64, i64, i64, i64, i64, bool, String, String)>, DeepBookError> {
-        let mut connection = self.db.connect().await?;
+    ) -> Result<Page<OrderUpdate>, DeepBookError> {
         let mut query = schema::order_updates::table
+            .select(OrderUpdate::as_select())
             .filter(schema::order_updates::checkpoint_timestamp_ms.between(start_time, end_time))
             .filter(schema::order_updates::pool_id.eq(pool_id))
-            .order_by(schema::order_updates::checkpoint_timestamp_ms.desc())
-            .select((
-                schema::order_updates::order_id,
-                schema::order_updates::price,
-                schema::order_updates::original_quantity,
-                schema::order_updates::quantity,
-                schema::order_updates::filled_quantity,
-                schema::order_updates::checkpoint_timestamp_ms,
-                schema::order_updates::is_bid,
-                schema::order_updates::balance_manager_id,
-                schema::order_updates::status,
-            ))
-            .limit(limit)
             .into_boxed();
 
         if let Some(manager_id) = balance_manager_filter {
@@ -488,19 +684,8 @@ impl Reader {
             query = query.filter(schema::order_updates::status.eq(status));
         }
 
-        let _guard = self.metrics.db_latency.start_timer();
-
-        let res = query
-            .load::<(String, i64, i64, i64, i64, i64, bool, String, String)>(&mut connection)
-            .await
-            .map_err(|_| DeepBookError::database("Error fetching trade details"));
-
-        if res.is_ok() {
-            self.metrics.db_requests_succeeded.inc();
-        } else {
-            self.metrics.db_requests_failed.inc();
-        }
-        res
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_orders_status(
@@ -602,6 +787,55 @@ impl Reader {
         res
     }
 
+    /// Orders currently resting on the book of `pool_id`, whatever their age, from the
+    /// `open_orders` table maintained by the indexer.
+    pub async fn get_open_orders(
+        &self,
+        pool_id: String,
+        limit: i64,
+        balance_manager_id: String,
+        status_filter: Option<Vec<String>>,
+    ) -> Result<Vec<OrderStatus>, DeepBookError> {
+        let mut connection = self.db.connect().await?;
+        let _guard = self.metrics.db_latency.start_timer();
+
+        let res = diesel::sql_query(
+            r#"
+            SELECT
+                order_id,
+                balance_manager_id,
+                is_bid,
+                status AS current_status,
+                price,
+                placed_at,
+                last_updated_at,
+                original_quantity,
+                filled_quantity,
+                quantity AS remaining_quantity
+            FROM open_orders
+            WHERE pool_id = $1
+                AND balance_manager_id = $2
+                AND ($3 IS NULL OR status = ANY($3))
+            ORDER BY last_updated_at DESC
+            LIMIT $4
+            "#,
+        )
+        .bind::<Text, _>(&pool_id)
+        .bind::<Text, _>(&balance_manager_id)
+        .bind::<Nullable<Array<Text>>, _>(&status_filter)
+        .bind::<BigInt, _>(limit)
+        .load::<OrderStatus>(&mut connection)
+        .await
+        .map_err(|e| DeepBookError::database(format!("Error fetching open orders: {}", e)));
+
+        if res.is_ok() {
+            self.metrics.db_requests_succeeded.inc();
+        } else {
+            self.metrics.db_requests_failed.inc();
+        }
+        res
+    }
+
     pub async fn get_ohclv(
         &self,
         pool_id: String,
@@ -699,6 +933,135 @@ impl Reader {
         res
     }
 
+    /// The fills with the given event digests, as published on the market feed, in the order
+    /// they happened.
+    /// Up to `limit` fills after `cursor`, up to `to_checkpoint`, across all pools, in checkpoint
+    /// and event digest order.
+    pub(crate) async fn get_feed_trades_after(
+        &self,
+        cursor: &EventCursor,
+        to_checkpoint: i64,
+        limit: usize,
+    ) -> Result<Vec<FeedTrade>, DeepBookError> {
+        let mut connection = self.db.connect().await?;
+        let _guard = self.metrics.db_latency.start_timer();
+
+        let res = diesel::sql_query(
+            r#"SELECT
+                f.event_digest, f.digest, f.pool_id, f.maker_order_id, f.taker_order_id,
+                f.maker_balance_manager_id, f.taker_balance_manager_id, f.price,
+                f.base_quantity, f.quote_quantity, f.checkpoint, f.checkpoint_timestamp_ms,
+                f.taker_is_bid, p.base_asset_decimals, p.quote_asset_decimals
+             FROM order_fills f
+             INNER JOIN pools p ON f.pool_id = p.pool_id
+             WHERE (f.checkpoint, f.event_digest COLLATE "C") > ($1, $2) AND f.checkpoint <= $3
+             ORDER BY f.checkpoint ASC, f.event_digest COLLATE "C" ASC
+             LIMIT $4"#,
+        )
+        .bind::<BigInt, _>(cursor.checkpoint)
+        .bind::<Text, _>(&cursor.event_digest)
+        .bind::<BigInt, _>(to_checkpoint)
+        .bind::<BigInt, _>(limit as i64)
+        .load::<FeedTradeRow>(&mut connection)
+        .await
+        .map_err(|e| DeepBookError::database(format!("Error fetching feed trades: {}", e)))
+        .map(|rows| rows.into_iter().map(FeedTrade::from).collect());
+
+        if res.is_ok() {
+            self.metrics.db_requests_succeeded.inc();
+        } else {
+            self.metrics.db_requests_failed.inc();
+        }
+        res
+    }
+
+    /// Up to `limit` order updates after `cursor`, up to `to_checkpoint`, across all pools, in
+    /// checkpoint and event digest order.
+    pub(crate) async fn get_feed_order_updates_after(
+        &self,
+        cursor: &EventCursor,
+        to_checkpoint: i64,
+        limit: usize,
+    ) -> Result<Vec<FeedOrderUpdate>, DeepBookError> {
+        let mut connection = self.db.connect().await?;
+        let _guard = self.metrics.db_latency.start_timer();
+
+        let res = diesel::sql_query(
+            r#"SELECT
+                u.event_digest, u.pool_id, u.order_id, u.balance_manager_id, u.status, u.price,
+                u.original_quantity, u.quantity, u.filled_quantity, u.checkpoint,
+                u.checkpoint_timestamp_ms, u.is_bid, p.base_asset_decimals, p.quote_asset_decimals
+             FROM order_updates u
+             INNER JOIN pools p ON u.pool_id = p.pool_id
+             WHERE (u.checkpoint, u.event_digest COLLATE "C") > ($1, $2) AND u.checkpoint <= $3
+             ORDER BY u.checkpoint ASC, u.event_digest COLLATE "C" ASC
+             LIMIT $4"#,
+        )
+        .bind::<BigInt, _>(cursor.checkpoint)
+        .bind::<Text, _>(&cursor.event_digest)
+        .bind::<BigInt, _>(to_checkpoint)
+        .bind::<BigInt, _>(limit as i64)
+        .load::<FeedOrderUpdateRow>(&mut connection)
+        .await
+        .map_err(|e| DeepBookError::database(format!("Error fetching feed order updates: {}", e)))
+        .map(|rows| rows.into_iter().map(FeedOrderUpdate::from).collect());
+
+        if res.is_ok() {
+            self.metrics.db_requests_succeeded.inc();
+        } else {
+            self.metrics.db_requests_failed.inc();
+        }
+        res
+    }
+
+    /// The 1m candles of `pool_id` starting at `minutes`, from the fills indexed so far, oldest
+    /// first. Minutes without fills have no candle.
+    pub(crate) async fn get_feed_candles(
+        &self,
+        pool_id: &str,
+        minutes: &[i64],
+    ) -> Result<Vec<FeedCandle>, DeepBookError> {
+        let (Some(&first_minute), Some(&last_minute)) = (minutes.first(), minutes.last()) else {
+            return Ok(vec![]);
+        };
+        let mut connection = self.db.connect().await?;
+        let _guard = self.metrics.db_latency.start_timer();
+
+        let res = diesel::sql_query(
+            r#"SELECT
+                f.checkpoint_timestamp_ms / 60000 * 60000 AS timestamp_ms,
+                (ARRAY_AGG(f.price ORDER BY f.checkpoint_timestamp_ms, f.event_digest))[1] AS open,
+                MAX(f.price) AS high,
+                MIN(f.price) AS low,
+                (ARRAY_AGG(f.price ORDER BY f.checkpoint_timestamp_ms DESC, f.event_digest DESC))[1]
+                    AS close,
+                SUM(f.base_quantity)::DOUBLE PRECISION AS base_quantity,
+                p.base_asset_decimals, p.quote_asset_decimals
+             FROM order_fills f
+             INNER JOIN pools p ON f.pool_id = p.pool_id
+             WHERE f.pool_id = $1
+                AND f.checkpoint_timestamp_ms >= $2 AND f.checkpoint_timestamp_ms < $3
+                AND f.checkpoint_timestamp_ms / 60000 * 60000 = ANY($4)
+             GROUP BY 1, p.base_asset_decimals, p.quote_asset_decimals
+             ORDER BY 1"#,
+        )
+        .bind::<Text, _>(pool_id)
+        .bind::<BigInt, _>(first_minute)
+        .bind::<BigInt, _>(last_minute + 60_000)
+        .bind::<Array<BigInt>, _>(minutes)
+        .load::<FeedCandleRow>(&mut connection)
+        .await
+        .map_err(|e| DeepBookError::database(format!("Error fetching feed candles: {}", e)))
+        .map(|rows| rows.into_iter().map(FeedCandle::from).collect());
+
+        if res.is_ok() {
+            self.metrics.db_requests_succeeded.inc();
+        } else {
+            self.metrics.db_requests_failed.inc();
+        }
+        res
+    }
+
     pub(crate) async fn get_live_ohclv_latest_materialized_timestamp(
         &self,
     ) -> Result<Option<i64>, DeepBookError> {
@@ -781,14 +1144,14 @@ impl Reader {
         start_time: Option<i64>,
         end_time: Option<i64>,
         limit: i64,
+        cursor: Option<EventCursor>,
         owner_filter: Option<String>,
-    ) -> Result<Vec<MarginManagerCreated>, DeepBookError> {
+    ) -> Result<Page<MarginManagerCreated>, DeepBookError> {
         let mut connection = self.db.connect().await?;
-        let mut query = schema::margin_manager_created::table
+        let query = schema::margin_manager_created::table
             .select(MarginManagerCreated::as_select())
-            .order_by(schema::margin_manager_created::checkpoint_timestamp_ms.desc())
-            .limit(limit)
             .into_boxed();
+        let mut query = newest_first_page!(query, cursor, limit);
 
         if let Some(start) = start_time {
             query = query.filter(schema::margin_manager_created::checkpoint_timestamp_ms.ge(start));
@@ -801,7 +1164,7 @@ impl Reader {
         }
 
         let _guard = self.metrics.db_latency.start_timer();
-        Ok(query.get_results(&mut connection).await?)
+        Ok(Page::new(query.get_results(&mut connection).await?, limit))
     }
 
     pub async fn get_loan_borrowed(
@@ -809,9 +1172,10 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_manager_id_filter: String,
         margin_pool_id_filter: String,
-    ) -> Result<Vec<LoanBorrowed>, DeepBookError> {
+    ) -> Result<Page<LoanBorrowed>, DeepBookError> {
         let query = schema::loan_borrowed::table
             .select(LoanBorrowed::as_select())
             .filter(schema::loan_borrowed::checkpoint_timestamp_ms.between(start_time, end_time))
@@ -819,11 +1183,10 @@ impl Reader {
                 schema::loan_borrowed::margin_manager_id
                     .like(to_pattern(&margin_manager_id_filter)),
             )
-            .filter(schema::loan_borrowed::margin_pool_id.like(to_pattern(&margin_pool_id_filter)))
-            .order_by(schema::loan_borrowed::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            .filter(schema::loan_borrowed::margin_pool_id.like(to_pattern(&margin_pool_id_filter)));
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_loan_repaid(
@@ -831,20 +1194,20 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_manager_id_filter: String,
         margin_pool_id_filter: String,
-    ) -> Result<Vec<LoanRepaid>, DeepBookError> {
+    ) -> Result<Page<LoanRepaid>, DeepBookError> {
         let query = schema::loan_repaid::table
             .select(LoanRepaid::as_select())
             .filter(schema::loan_repaid::checkpoint_timestamp_ms.between(start_time, end_time))
             .filter(
                 schema::loan_repaid::margin_manager_id.like(to_pattern(&margin_manager_id_filter)),
             )
-            .filter(schema::loan_repaid::margin_pool_id.like(to_pattern(&margin_pool_id_filter)))
-            .order_by(schema::loan_repaid::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            .filter(schema::loan_repaid::margin_pool_id.like(to_pattern(&margin_pool_id_filter)));
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_liquidation(
@@ -852,20 +1215,20 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_manager_id_filter: String,
         margin_pool_id_filter: String,
-    ) -> Result<Vec<Liquidation>, DeepBookError> {
+    ) -> Result<Page<Liquidation>, DeepBookError> {
         let query = schema::liquidation::table
             .select(Liquidation::as_select())
             .filter(schema::liquidation::checkpoint_timestamp_ms.between(start_time, end_time))
             .filter(
                 schema::liquidation::margin_manager_id.like(to_pattern(&margin_manager_id_filter)),
             )
-            .filter(schema::liquidation::margin_pool_id.like(to_pattern(&margin_pool_id_filter)))
-            .order_by(schema::liquidation::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            .filter(schema::liquidation::margin_pool_id.like(to_pattern(&margin_pool_id_filter)));
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_asset_supplied(
@@ -873,18 +1236,18 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_pool_id_filter: String,
         supplier_filter: String,
-    ) -> Result<Vec<AssetSupplied>, DeepBookError> {
+    ) -> Result<Page<AssetSupplied>, DeepBookError> {
         let query = schema::asset_supplied::table
             .select(AssetSupplied::as_select())
             .filter(schema::asset_supplied::checkpoint_timestamp_ms.between(start_time, end_time))
             .filter(schema::asset_supplied::margin_pool_id.like(to_pattern(&margin_pool_id_filter)))
-            .filter(schema::asset_supplied::supplier.like(to_pattern(&supplier_filter)))
-            .order_by(schema::asset_supplied::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            .filter(schema::asset_supplied::supplier.like(to_pattern(&supplier_filter)));
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_asset_withdrawn(
@@ -892,35 +1255,37 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_pool_id_filter: String,
         supplier_filter: String,
-    ) -> Result<Vec<AssetWithdrawn>, DeepBookError> {
+    ) -> Result<Page<AssetWithdrawn>, DeepBookError> {
         let query = schema::asset_withdrawn::table
             .select(AssetWithdrawn::as_select())
             .filter(schema::asset_withdrawn::checkpoint_timestamp_ms.between(start_time, end_time))
             .filter(
                 schema::asset_withdrawn::margin_pool_id.like(to_pattern(&margin_pool_id_filter)),
             )
-            .filter(schema::asset_withdrawn::supplier.like(to_pattern(&supplier_filter)))
-            .order_by(schema::asset_withdrawn::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            .filter(schema::asset_withdrawn::supplier.like(to_pattern(&supplier_filter)));
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_margin_pool_created(
         &self,
+        limit: i64,
+        cursor: Option<EventCursor>,
         margin_pool_id_filter: String,
-    ) -> Result<Vec<MarginPoolCreated>, DeepBookError> {
+    ) -> Result<Page<MarginPoolCreated>, DeepBookError> {
         let query = schema::margin_pool_created::table
             .select(MarginPoolCreated::as_select())
             .filter(
                 schema::margin_pool_created::margin_pool_id
                     .like(to_pattern(&margin_pool_id_filter)),
-            )
-            .order_by(schema::margin_pool_created::checkpoint_timestamp_ms.desc());
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_deepbook_pool_updated(
@@ -928,9 +1293,10 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_pool_id_filter: String,
         deepbook_pool_id_filter: String,
-    ) -> Result<Vec<DeepbookPoolUpdated>, DeepBookError> {
+    ) -> Result<Page<DeepbookPoolUpdated>, DeepBookError> {
         let query = schema::deepbook_pool_updated::table
             .select(DeepbookPoolUpdated::as_select())
             .filter(
@@ -944,11 +1310,10 @@ impl Reader {
             .filter(
                 schema::deepbook_pool_updated::deepbook_pool_id
                     .like(to_pattern(&deepbook_pool_id_filter)),
-            )
-            .order_by(schema::deepbook_pool_updated::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_interest_params_updated(
@@ -956,8 +1321,9 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_pool_id_filter: String,
-    ) -> Result<Vec<InterestParamsUpdated>, DeepBookError> {
+    ) -> Result<Page<InterestParamsUpdated>, DeepBookError> {
         let query = schema::interest_params_updated::table
             .select(InterestParamsUpdated::as_select())
             .filter(
@@ -967,11 +1333,10 @@ impl Reader {
             .filter(
                 schema::interest_params_updated::margin_pool_id
                     .like(to_pattern(&margin_pool_id_filter)),
-            )
-            .order_by(schema::interest_params_updated::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_margin_pool_config_updated(
@@ -979,8 +1344,9 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_pool_id_filter: String,
-    ) -> Result<Vec<MarginPoolConfigUpdated>, DeepBookError> {
+    ) -> Result<Page<MarginPoolConfigUpdated>, DeepBookError> {
         let query = schema::margin_pool_config_updated::table
             .select(MarginPoolConfigUpdated::as_select())
             .filter(
@@ -990,11 +1356,10 @@ impl Reader {
             .filter(
                 schema::margin_pool_config_updated::margin_pool_id
                     .like(to_pattern(&margin_pool_id_filter)),
-            )
-            .order_by(schema::margin_pool_config_updated::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_maintainer_cap_updated(
@@ -1002,8 +1367,9 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         maintainer_cap_id_filter: String,
-    ) -> Result<Vec<MaintainerCapUpdated>, DeepBookError> {
+    ) -> Result<Page<MaintainerCapUpdated>, DeepBookError> {
         let query = schema::maintainer_cap_updated::table
             .select(MaintainerCapUpdated::as_select())
             .filter(
@@ -1013,11 +1379,10 @@ impl Reader {
             .filter(
                 schema::maintainer_cap_updated::maintainer_cap_id
                     .like(to_pattern(&maintainer_cap_id_filter)),
-            )
-            .order_by(schema::maintainer_cap_updated::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_maintainer_fees_withdrawn(
@@ -1025,8 +1390,9 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_pool_id_filter: String,
-    ) -> Result<Vec<MaintainerFeesWithdrawn>, DeepBookError> {
+    ) -> Result<Page<MaintainerFeesWithdrawn>, DeepBookError> {
         let query = schema::maintainer_fees_withdrawn::table
             .select(MaintainerFeesWithdrawn::as_select())
             .filter(
@@ -1036,11 +1402,10 @@ impl Reader {
             .filter(
                 schema::maintainer_fees_withdrawn::margin_pool_id
                     .like(to_pattern(&margin_pool_id_filter)),
-            )
-            .order_by(schema::maintainer_fees_withdrawn::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_protocol_fees_withdrawn(
@@ -1048,8 +1413,9 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_pool_id_filter: String,
-    ) -> Result<Vec<ProtocolFeesWithdrawn>, DeepBookError> {
+    ) -> Result<Page<ProtocolFeesWithdrawn>, DeepBookError> {
         let query = schema::protocol_fees_withdrawn::table
             .select(ProtocolFeesWithdrawn::as_select())
             .filter(
@@ -1059,11 +1425,10 @@ impl Reader {
             .filter(
                 schema::protocol_fees_withdrawn::margin_pool_id
                     .like(to_pattern(&margin_pool_id_filter)),
-            )
-            .order_by(schema::protocol_fees_withdrawn::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_supplier_cap_minted(
@@ -1071,8 +1436,9 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         supplier_cap_id_filter: String,
-    ) -> Result<Vec<SupplierCapMinted>, DeepBookError> {
+    ) -> Result<Page<SupplierCapMinted>, DeepBookError> {
         let query = schema::supplier_cap_minted::table
             .select(SupplierCapMinted::as_select())
             .filter(
@@ -1081,11 +1447,10 @@ impl Reader {
             .filter(
                 schema::supplier_cap_minted::supplier_cap_id
                     .like(to_pattern(&supplier_cap_id_filter)),
-            )
-            .order_by(schema::supplier_cap_minted::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_supply_referral_minted(
@@ -1093,9 +1458,10 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_pool_id_filter: String,
         owner_filter: String,
-    ) -> Result<Vec<SupplyReferralMinted>, DeepBookError> {
+    ) -> Result<Page<SupplyReferralMinted>, DeepBookError> {
         let query = schema::supply_referral_minted::table
             .select(SupplyReferralMinted::as_select())
             .filter(
@@ -1106,11 +1472,10 @@ impl Reader {
                 schema::supply_referral_minted::margin_pool_id
                     .like(to_pattern(&margin_pool_id_filter)),
             )
-            .filter(schema::supply_referral_minted::owner.like(to_pattern(&owner_filter)))
-            .order_by(schema::supply_referral_minted::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            .filter(schema::supply_referral_minted::owner.like(to_pattern(&owner_filter)));
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_pause_cap_updated(
@@ -1118,18 +1483,18 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         pause_cap_id_filter: String,
-    ) -> Result<Vec<PauseCapUpdated>, DeepBookError> {
+    ) -> Result<Page<PauseCapUpdated>, DeepBookError> {
         let query = schema::pause_cap_updated::table
             .select(PauseCapUpdated::as_select())
             .filter(
                 schema::pause_cap_updated::checkpoint_timestamp_ms.between(start_time, end_time),
             )
-            .filter(schema::pause_cap_updated::pause_cap_id.like(to_pattern(&pause_cap_id_filter)))
-            .order_by(schema::pause_cap_updated::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            .filter(schema::pause_cap_updated::pause_cap_id.like(to_pattern(&pause_cap_id_filter)));
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_protocol_fees_increased(
@@ -1137,8 +1502,9 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_pool_id_filter: String,
-    ) -> Result<Vec<ProtocolFeesIncreasedEvent>, DeepBookError> {
+    ) -> Result<Page<ProtocolFeesIncreasedEvent>, DeepBookError> {
         let query = schema::protocol_fees_increased::table
             .select(ProtocolFeesIncreasedEvent::as_select())
             .filter(
@@ -1148,11 +1514,10 @@ impl Reader {
             .filter(
                 schema::protocol_fees_increased::margin_pool_id
                     .like(to_pattern(&margin_pool_id_filter)),
-            )
-            .order_by(schema::protocol_fees_increased::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_referral_fees_claimed(
@@ -1160,9 +1525,10 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         referral_id_filter: String,
         owner_filter: String,
-    ) -> Result<Vec<ReferralFeesClaimedEvent>, DeepBookError> {
+    ) -> Result<Page<ReferralFeesClaimedEvent>, DeepBookError> {
         let query = schema::referral_fees_claimed::table
             .select(ReferralFeesClaimedEvent::as_select())
             .filter(
@@ -1172,11 +1538,10 @@ impl Reader {
             .filter(
                 schema::referral_fees_claimed::referral_id.like(to_pattern(&referral_id_filter)),
             )
-            .filter(schema::referral_fees_claimed::owner.like(to_pattern(&owner_filter)))
-            .order_by(schema::referral_fees_claimed::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            .filter(schema::referral_fees_claimed::owner.like(to_pattern(&owner_filter)));
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_referral_fee_events(
@@ -1184,20 +1549,20 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         pool_id_filter: String,
         referral_id_filter: String,
-    ) -> Result<Vec<ReferralFeeEvent>, DeepBookError> {
+    ) -> Result<Page<ReferralFeeEvent>, DeepBookError> {
         let query = schema::referral_fee_events::table
             .select(ReferralFeeEvent::as_select())
             .filter(
                 schema::referral_fee_events::checkpoint_timestamp_ms.between(start_time, end_time),
             )
             .filter(schema::referral_fee_events::pool_id.like(to_pattern(&pool_id_filter)))
-            .filter(schema::referral_fee_events::referral_id.like(to_pattern(&referral_id_filter)))
-            .order_by(schema::referral_fee_events::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            .filter(schema::referral_fee_events::referral_id.like(to_pattern(&referral_id_filter)));
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_rebates_v2(
@@ -1205,18 +1570,18 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         balance_manager_id_filter: String,
-    ) -> Result<Vec<RebatesV2>, DeepBookError> {
+    ) -> Result<Page<RebatesV2>, DeepBookError> {
         let query = schema::rebates_v2::table
             .select(RebatesV2::as_select())
             .filter(schema::rebates_v2::checkpoint_timestamp_ms.between(start_time, end_time))
             .filter(
                 schema::rebates_v2::balance_manager_id.like(to_pattern(&balance_manager_id_filter)),
-            )
-            .order_by(schema::rebates_v2::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_deepbook_pool_registered(
@@ -1224,19 +1589,19 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         pool_id_filter: String,
-    ) -> Result<Vec<DeepbookPoolRegistered>, DeepBookError> {
+    ) -> Result<Page<DeepbookPoolRegistered>, DeepBookError> {
         let query = schema::deepbook_pool_registered::table
             .select(DeepbookPoolRegistered::as_select())
             .filter(
                 schema::deepbook_pool_registered::checkpoint_timestamp_ms
                     .between(start_time, end_time),
             )
-            .filter(schema::deepbook_pool_registered::pool_id.like(to_pattern(&pool_id_filter)))
-            .order_by(schema::deepbook_pool_registered::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            .filter(schema::deepbook_pool_registered::pool_id.like(to_pattern(&pool_id_filter)));
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_deepbook_pool_updated_registry(
@@ -1244,8 +1609,9 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         pool_id_filter: String,
-    ) -> Result<Vec<DeepbookPoolUpdatedRegistry>, DeepBookError> {
+    ) -> Result<Page<DeepbookPoolUpdatedRegistry>, DeepBookError> {
         let query = schema::deepbook_pool_updated_registry::table
             .select(DeepbookPoolUpdatedRegistry::as_select())
             .filter(
@@ -1254,11 +1620,10 @@ impl Reader {
             )
             .filter(
                 schema::deepbook_pool_updated_registry::pool_id.like(to_pattern(&pool_id_filter)),
-            )
-            .order_by(schema::deepbook_pool_updated_registry::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_deepbook_pool_config_updated(
@@ -1266,19 +1631,21 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         pool_id_filter: String,
-    ) -> Result<Vec<DeepbookPoolConfigUpdated>, DeepBookError> {
+    ) -> Result<Page<DeepbookPoolConfigUpdated>, DeepBookError> {
         let query = schema::deepbook_pool_config_updated::table
             .select(DeepbookPoolConfigUpdated::as_select())
             .filter(
                 schema::deepbook_pool_config_updated::checkpoint_timestamp_ms
                     .between(start_time, end_time),
             )
-            .filter(schema::deepbook_pool_config_updated::pool_id.like(to_pattern(&pool_id_filter)))
-            .order_by(schema::deepbook_pool_config_updated::checkpoint_timestamp_ms.desc())
-            .limit(limit);
+            .filter(
+                schema::deepbook_pool_config_updated::pool_id.like(to_pattern(&pool_id_filter)),
+            );
 
-        Ok(self.results(query).await?)
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 
     pub async fn get_margin_managers_info(
@@ -1428,6 +1795,140 @@ impl Reader {
         res.map_err(|_| DeepBookError::database("Error fetching margin manager states"))
     }
 
+    /// The `limit` best price levels of one side of the book of `pool_id`, as maintained by the
+    /// indexer: the highest bids or the lowest asks first. Orders that expired before `now_ms`
+    /// are left out, since they stay on the book until the chain removes them.
+    pub async fn get_order_book_levels(
+        &self,
+        pool_id: &str,
+        is_bid: bool,
+        limit: i64,
+        now_ms: i64,
+    ) -> Result<Vec<OrderBookLevel>, DeepBookError> {
+        let mut connection = self.db.connect().await?;
+        let _guard = self.metrics.db_latency.start_timer();
+
+        let order = if is_bid { "DESC" } else { "ASC" };
+        let res = diesel::sql_query(format!(
+            r#"
+            SELECT
+                l.pool_id,
+                l.is_bid,
+                l.price,
+                l.quantity - COALESCE(e.quantity, 0) AS quantity,
+                l.order_count - COALESCE(e.order_count, 0) AS order_count
+            FROM order_book_levels l
+            LEFT JOIN (
+                SELECT price, SUM(quantity)::BIGINT AS quantity, COUNT(*) AS order_count
+                FROM open_orders
+                WHERE pool_id = $1 AND is_bid = $2 AND expire_timestamp < $3
+                GROUP BY price
+            ) e ON e.price = l.price
+            WHERE l.pool_id = $1
+                AND l.is_bid = $2
+                AND l.order_count > COALESCE(e.order_count, 0)
+            ORDER BY l.price {order}
+            LIMIT $4
+            "#
+        ))
+        .bind::<Text, _>(pool_id)
+        .bind::<Bool, _>(is_bid)
+        .bind::<BigInt, _>(now_ms)
+        .bind::<BigInt, _>(limit)
+        .load::<OrderBookLevel>(&mut connection)
+        .await
+        .map_err(|e| DeepBookError::database(format!("Error fetching order book: {}", e)));
+
+        if res.is_ok() {
+            self.metrics.db_requests_succeeded.inc();
+        } else {
+            self.metrics.db_requests_failed.inc();
+        }
+        res
+    }
+
+    /// Timestamp of the last checkpoint committed by `pipeline`, if it has committed any
+    pub async fn get_pipeline_timestamp_ms(
+        &self,
+        pipeline: &str,
+    ) -> Result<Option<i64>, DeepBookError> {
+        let mut connection = self.db.connect().await?;
+        let _guard = self.metrics.db_latency.start_timer();
+
+        let res = schema::watermarks::table
+            .filter(schema::watermarks::pipeline.eq(pipeline))
+            .select(schema::watermarks::timestamp_ms_hi_inclusive)
+            .first::<i64>(&mut connection)
+            .await
+            .optional()
+            .map_err(|_| DeepBookError::database("Error fetching watermark"));
+
+        if res.is_ok() {
+            self.metrics.db_requests_succeeded.inc();
+        } else {
+            self.metrics.db_requests_failed.inc();
+        }
+        res
+    }
+
+    /// The checkpoint up to which both `pipelines` have committed every checkpoint, if both have
+    /// committed any.
+    pub(crate) async fn get_pipelines_checkpoint_hi(
+        &self,
+        pipelines: &[&str],
+    ) -> Result<Option<i64>, DeepBookError> {
+        let query = schema::watermarks::table
+            .filter(
+                schema::watermarks::pipeline
+                    .eq_any(pipelines.iter().map(|p| p.to_string()).collect::<Vec<_>>()),
+            )
+            .select(schema::watermarks::checkpoint_hi_inclusive);
+        let checkpoints: Vec<i64> = self.results(query).await?;
+        if checkpoints.len() < pipelines.len() {
+            return Ok(None);
+        }
+        Ok(checkpoints.into_iter().min())
+    }
+
+    /// Up to `limit` of `balance_manager_id`'s order updates after `cursor`, up to
+    /// `to_checkpoint`, in checkpoint and event digest order.
+    pub(crate) async fn get_order_updates_after(
+        &self,
+        balance_manager_id: &str,
+        cursor: &EventCursor,
+        to_checkpoint: i64,
+        limit: i64,
+    ) -> Result<Vec<OrderUpdate>, DeepBookError> {
+        let query = schema::order_updates::table
+            .filter(schema::order_updates::balance_manager_id.eq(balance_manager_id.to_string()))
+            .filter(schema::order_updates::checkpoint.le(to_checkpoint))
+            .select(OrderUpdate::as_select());
+        let query = oldest_first_page!(query, cursor, limit);
+        Ok(self.results(query).await?)
+    }
+
+    /// Up to `limit` of the fills `balance_manager_id` made or took after `cursor`, up to
+    /// `to_checkpoint`, in checkpoint and event digest order.
+    pub(crate) async fn get_order_fills_after(
+        &self,
+        balance_manager_id: &str,
+        cursor: &EventCursor,
+        to_checkpoint: i64,
+        limit: i64,
+    ) -> Result<Vec<OrderFill>, DeepBookError> {
+        let query = schema::order_fills::table
+            .filter(
+                schema::order_fills::maker_balance_manager_id
+                    .eq(balance_manager_id.to_string())
+                    .or(schema::order_fills::taker_balance_manager_id
+                        .eq(balance_manager_id.to_string())),
+            )
+            .filter(schema::order_fills::checkpoint.le(to_checkpoint))
+            .select(OrderFill::as_select());
+        let query = oldest_first_page!(query, cursor, limit);
+        Ok(self.results(query).await?)
+    }
+
     pub async fn get_watermarks(&self) -> Result<Vec<(String, i64, i64, i64)>, DeepBookError> {
         let mut connection = self.db.connect().await?;
         let _guard = self.metrics.db_latency.start_timer();
@@ -1580,14 +2081,15 @@ impl Reader {
         start_time: i64,
         end_time: i64,
         limit: i64,
+        cursor: Option<EventCursor>,
         margin_manager_id_filter: String,
         event_type_filter: String,
         is_base_filter: Option<bool>,
-    ) -> Result<Vec<CollateralEvent>, DeepBookError> {
+    ) -> Result<Page<CollateralEvent>, DeepBookError> {
         let mut connection = self.db.connect().await?;
         let _guard = self.metrics.db_latency.start_timer();
 
-        let mut query = schema::collateral_events::table
+        let query = schema::collateral_events::table
             .select(CollateralEvent::as_select())
             .filter(
                 schema::collateral_events::checkpoint_timestamp_ms.between(start_time, end_time),
@@ -1597,9 +2099,8 @@ impl Reader {
                     .like(to_pattern(&margin_manager_id_filter)),
             )
             .filter(schema::collateral_events::event_type.like(to_pattern(&event_type_filter)))
-            .order_by(schema::collateral_events::checkpoint_timestamp_ms.desc())
-            .limit(limit)
             .into_boxed();
+        let mut query = newest_first_page!(query, cursor, limit);
 
         if let Some(is_base) = is_base_filter {
             query = query.filter(schema::collateral_events::withdraw_base_asset.eq(Some(is_base)));
@@ -1613,7 +2114,8 @@ impl Reader {
             self.metrics.db_requests_failed.inc();
         }
 
-        res.map_err(|_| DeepBookError::database("Error fetching collateral events"))
+        res.map(|events| Page::new(events, limit))
+            .map_err(|_| DeepBookError::database("Error fetching collateral events"))
     }
 
     pub async fn get_points(
@@ -1983,29 +2485,647 @@ impl Reader {
         })
     }
 
-    pub async fn get_pool_created(&self) -> Result<Vec<PoolCreated>, DeepBookError> {
-        let query = schema::pool_created::table
-            .select(PoolCreated::as_select())
-            .order_by(schema::pool_created::checkpoint_timestamp_ms.desc());
+    pub async fn get_liquidation_vault_pnl(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        vault_id_filter: String,
+    ) -> Result<Vec<LiquidationVaultPnl>, DeepBookError> {
+        let mut connection = self.db.connect().await?;
+        let _guard = self.metrics.db_latency.start_timer();
+
+        let res = diesel::sql_query(
+            r#"
+            WITH vault_liquidations AS (
+                SELECT
+                    m.deepbook_pool_id AS pool_id,
+                    v.base_in,
+                    v.base_out,
+                    v.quote_in,
+                    v.quote_out,
+                    v.base_out - v.base_in
+                        + CASE WHEN v.base_liquidation THEN v.repay_balance_remaining ELSE 0 END AS net_base,
+                    v.quote_out - v.quote_in
+                        + CASE WHEN v.base_liquidation THEN 0 ELSE v.repay_balance_remaining END AS net_quote,
+                    l.pool_reward,
+                    l.pool_default,
+                    l.base_pyth_price,
+                    l.base_pyth_decimals,
+                    l.quote_pyth_price,
+                    l.quote_pyth_decimals
+                FROM liquidation_by_vault v
+                LEFT JOIN liquidation l ON l.event_digest = v.liquidation_event_digest
+                LEFT JOIN margin_manager_created m ON m.margin_manager_id = v.margin_manager_id
+                WHERE v.checkpoint_timestamp_ms BETWEEN $1 AND $2
+                  AND v.vault_id LIKE $3
+            )
+            SELECT
+                vl.pool_id,
+                p.pool_name::text,
+                COUNT(*)::bigint AS liquidations,
+                SUM(vl.base_in)::bigint AS base_in,
+                SUM(vl.base_out)::bigint AS base_out,
+                SUM(vl.quote_in)::bigint AS quote_in,
+                SUM(vl.quote_out)::bigint AS quote_out,
+                SUM(vl.net_base)::bigint AS net_base,
+                SUM(vl.net_quote)::bigint AS net_quote,
+                COALESCE(SUM(vl.pool_reward), 0)::bigint AS pool_reward,
+                COALESCE(SUM(vl.pool_default), 0)::bigint AS pool_default,
+                SUM(
+                    vl.net_base::numeric / POWER(10, p.base_asset_decimals)
+                        * vl.base_pyth_price / POWER(10, vl.base_pyth_decimals)
+                    + vl.net_quote::numeric / POWER(10, p.quote_asset_decimals)
+                        * vl.quote_pyth_price / POWER(10, vl.quote_pyth_decimals)
+                )::float8 AS pnl_usd
+            FROM vault_liquidations vl
+            LEFT JOIN pools p ON p.pool_id = vl.pool_id
+            GROUP BY vl.pool_id, p.pool_name
+            ORDER BY p.pool_name
+            "#,
+        )
+        .bind::<BigInt, _>(start_time)
+        .bind::<BigInt, _>(end_time)
+        .bind::<Text, _>(to_pattern(&vault_id_filter))
+        .load::<LiquidationVaultPnl>(&mut connection)
+        .await;
+
+        match res {
+            Ok(v) => {
+                self.metrics.db_requests_succeeded.inc();
+                Ok(v)
+            }
+            Err(e) => {
+                self.metrics.db_requests_failed.inc();
+                Err(DeepBookError::database(format!(
+                    "Error fetching liquidation vault PnL: {}",
+                    e
+                )))
+            }
+        }
+    }
+
+    /// Accounts owned by `owner`, the registry-wide authorized apps, and the sessions that can
+    /// currently act for any of the owner's accounts (latest grant not revoked and not expired
+    /// at `now_ms`).
+    pub async fn get_accounts(
+        &self,
+        owner: &str,
+        now_ms: i64,
+    ) -> Result<AccountsQueryResult, DeepBookError> {
+        // Validate: must be 0x-prefixed 64-character hex string
+        if !owner.starts_with("0x")
+            || owner.len() != 66
+            || !owner[2..].chars().all(|c| c.is_ascii_hexdigit())
+        {
+            return Err(DeepBookError::bad_request(
+                "Invalid owner address: expected 0x-prefixed 64-character hex string",
+            ));
+        }
+
+        let mut connection = self.db.connect().await?;
+        let _guard = self.metrics.db_latency.start_timer();
+
+        // --- Accounts ---
+        let accounts_res = diesel::sql_query(
+            r#"
+            SELECT
+                c.account_id,
+                c.wrapper_id,
+                c.self_owned,
+                c.referrer_account_id,
+                i.balance_manager_id,
+                c.checkpoint_timestamp_ms AS created_at_ms
+            FROM account_created c
+            LEFT JOIN deepbook_core_account_initialized i ON c.account_id = i.account_id
+            WHERE c.owner = $1
+            ORDER BY c.checkpoint_timestamp_ms DESC
+            "#,
+        )
+        .bind::<Text, _>(owner)
+        .load::<AccountRow>(&mut connection)
+        .await;
+
+        // --- Authorized apps (latest authorize/deauthorize event per app wins) ---
+        let apps_res = diesel::sql_query(
+            r#"
+            SELECT app FROM (
+                SELECT DISTINCT ON (app) app, authorized
+                FROM (
+                    SELECT app, TRUE AS authorized, checkpoint, event_digest
+                    FROM account_app_authorized
+                    UNION ALL
+                    SELECT app, FALSE AS authorized, checkpoint, event_digest
+                    FROM account_app_deauthorized
+                ) e
+                ORDER BY app, checkpoint DESC, event_digest DESC
+            ) latest
+            WHERE authorized
+            ORDER BY app
+            "#,
+        )
+        .load::<AuthorizedAppRow>(&mut connection)
+        .await;
+
+        // --- Live sessions (latest authorize/revoke event per session wins) ---
+        let sessions_res = diesel::sql_query(
+            r#"
+            SELECT account_id, session, expires_at_ms FROM (
+                SELECT DISTINCT ON (account_id, session) account_id, session, expires_at_ms, authorized
+                FROM (
+                    SELECT account_id, session, expires_at_ms, TRUE AS authorized, checkpoint, event_digest
+                    FROM account_session_authorized
+                    UNION ALL
+                    SELECT account_id, session, expires_at_ms, FALSE AS authorized, checkpoint, event_digest
+                    FROM account_session_revoked
+                ) e
+                WHERE account_id IN (SELECT account_id FROM account_created WHERE owner = $1)
+                ORDER BY account_id, session, checkpoint DESC, event_digest DESC
+            ) latest
+            WHERE authorized AND expires_at_ms > $2
+            ORDER BY expires_at_ms
+            "#,
+        )
+        .bind::<Text, _>(owner)
+        .bind::<BigInt, _>(now_ms)
+        .load::<AccountSessionRow>(&mut connection)
+        .await;
+
+        let accounts = match accounts_res {
+            Ok(v) => {
+                self.metrics.db_requests_succeeded.inc();
+                v
+            }
+            Err(e) => {
+                self.metrics.db_requests_failed.inc();
+                return Err(DeepBookError::database(format!(
+                    "Error fetching accounts: {}",
+                    e
+                )));
+            }
+        };
+        let authorized_apps = match apps_res {
+            Ok(v) => {
+                self.metrics.db_requests_succeeded.inc();
+                v
+            }
+            Err(e) => {
+                self.metrics.db_requests_failed.inc();
+                return Err(DeepBookError::database(format!(
+                    "Error fetching authorized apps: {}",
+                    e
+                )));
+            }
+        };
+        let sessions = match sessions_res {
+            Ok(v) => {
+                self.metrics.db_requests_succeeded.inc();
+                v
+            }
+            Err(e) => {
+                self.metrics.db_requests_failed.inc();
+                return Err(DeepBookError::database(format!(
+                    "Error fetching sessions: {}",
+                    e
+                )));
+            }
+        };
+
+        Ok(AccountsQueryResult {
+            accounts: accounts
+                .into_iter()
+                .map(|a| AccountInfo {
+                    account_id: a.account_id,
+                    wrapper_id: a.wrapper_id,
+                    self_owned: a.self_owned,
+                    referrer_account_id: a.referrer_account_id,
+                    balance_manager_id: a.balance_manager_id,
+                    created_at_ms: a.created_at_ms,
+                })
+                .collect(),
+            authorized_apps: authorized_apps.into_iter().map(|a| a.app).collect(),
+            sessions: sessions
+                .into_iter()
+                .map(|s| AccountSession {
+                    account_id: s.account_id,
+                    session: s.session,
+                    expires_at_ms: s.expires_at_ms,
+                })
+                .collect(),
+        })
+    }
+
+    pub async fn get_pool_created(
+        &self,
+        limit: i64,
+        cursor: Option<EventCursor>,
+    ) -> Result<Page<PoolCreated>, DeepBookError> {
+        let query = schema::pool_created::table.select(PoolCreated::as_select());
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
+    }
+
+    /// Net deposits of each asset into `balance_manager_id`, as maintained by the indexer
+    pub async fn get_balance_manager_balances(
+        &self,
+        balance_manager_id: &str,
+    ) -> Result<Vec<BalanceManagerBalance>, DeepBookError> {
+        let query = schema::balance_manager_balances::table
+            .select(BalanceManagerBalance::as_select())
+            .filter(schema::balance_manager_balances::balance_manager_id.eq(balance_manager_id))
+            .order_by(schema::balance_manager_balances::asset.asc());
+        Ok(self.results(query).await?)
+    }
+
+    /// Buckets of `balance_manager_id`'s trading starting in `[start_time, end_time)`, newest
+    /// first, optionally restricted to `pool_ids`
+    pub async fn get_trader_volume(
+        &self,
+        interval: TraderVolumeInterval,
+        balance_manager_id: &str,
+        pool_ids: Option<Vec<String>>,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+    ) -> Result<Vec<TraderVolume>, DeepBookError> {
+        let mut connection = self.db.connect().await?;
+        let _guard = self.metrics.db_latency.start_timer();
+
+        let res = diesel::sql_query(format!(
+            "SELECT * FROM {} \
+             WHERE balance_manager_id = $1 \
+                AND ($2 IS NULL OR pool_id = ANY($2)) \
+                AND bucket_start >= $3 AND bucket_start < $4 \
+             ORDER BY bucket_start DESC, pool_id \
+             LIMIT $5",
+            interval.table()
+        ))
+        .bind::<Text, _>(balance_manager_id)
+        .bind::<Nullable<Array<Text>>, _>(&pool_ids)
+        .bind::<BigInt, _>(start_time)
+        .bind::<BigInt, _>(end_time)
+        .bind::<BigInt, _>(limit)
+        .load::<TraderVolume>(&mut connection)
+        .await
+        .map_err(|e| DeepBookError::database(format!("Error fetching trader volume: {}", e)));
+
+        if res.is_ok() {
+            self.metrics.db_requests_succeeded.inc();
+        } else {
+            self.metrics.db_requests_failed.inc();
+        }
+        res
+    }
+
+    /// Balance managers' trading in `pool_id` summed over the buckets starting in
+    /// `[start_time, end_time)`, by maker plus taker quote volume, largest first
+    pub async fn get_trader_volume_leaderboard(
+        &self,
+        interval: TraderVolumeInterval,
+        pool_id: &str,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+    ) -> Result<Vec<TraderVolumeTotal>, DeepBookError> {
+        let mut connection = self.db.connect().await?;
+        let _guard = self.metrics.db_latency.start_timer();
+
+        let res = diesel::sql_query(format!(
+            "SELECT \
+                balance_manager_id, \
+                SUM(maker_base_volume) AS maker_base_volume, \
+                SUM(maker_quote_volume) AS maker_quote_volume, \
+                SUM(taker_base_volume) AS taker_base_volume, \
+                SUM(taker_quote_volume) AS taker_quote_volume, \
+                SUM(maker_trade_count)::BIGINT AS maker_trade_count, \
+                SUM(taker_trade_count)::BIGINT AS taker_trade_count, \
+                SUM(deep_fees) AS deep_fees, \
+                SUM(base_fees) AS base_fees, \
+                SUM(quote_fees) AS quote_fees \
+             FROM {} \
+             WHERE pool_id = $1 AND bucket_start >= $2 AND bucket_start < $3 \
+             GROUP BY balance_manager_id \
+             ORDER BY SUM(maker_quote_volume) + SUM(taker_quote_volume) DESC, \
+                balance_manager_id \
+             LIMIT $4",
+            interval.table()
+        ))
+        .bind::<Text, _>(pool_id)
+        .bind::<BigInt, _>(start_time)
+        .bind::<BigInt, _>(end_time)
+        .bind::<BigInt, _>(limit)
+        .load::<TraderVolumeTotal>(&mut connection)
+        .await
+        .map_err(|e| {
+            DeepBookError::database(format!("Error fetching trader volume leaderboard: {}", e))
+        });
+
+        if res.is_ok() {
+            self.metrics.db_requests_succeeded.inc();
+        } else {
+            self.metrics.db_requests_failed.inc();
+        }
+        res
+    }
+
+    /// The latest epoch in which `pool_id` saw a stake, proposal or vote
+    pub async fn get_latest_governance_epoch(
+        &self,
+        pool_id: &str,
+    ) -> Result<Option<i64>, DeepBookError> {
+        let query = diesel::sql_query(
+            "SELECT MAX(epoch) AS value FROM ( \
+                SELECT epoch FROM stakes WHERE pool_id = $1 \
+                UNION ALL SELECT epoch FROM proposals WHERE pool_id = $1 \
+                UNION ALL SELECT epoch FROM votes WHERE pool_id = $1 \
+             ) governance",
+        )
+        .bind::<Text, _>(pool_id.to_string());
+        let rows: Vec<GovernanceTimestamp> = self.results(query).await?;
+        Ok(rows.into_iter().next().and_then(|row| row.value))
+    }
+
+    /// When the first stake, proposal or vote of `pool_id` in `epoch` or later was indexed
+    pub async fn get_governance_epoch_start(
+        &self,
+        pool_id: &str,
+        epoch: i64,
+    ) -> Result<Option<i64>, DeepBookError> {
+        let query = diesel::sql_query(
+            "SELECT MIN(checkpoint_timestamp_ms) AS value FROM ( \
+                SELECT checkpoint_timestamp_ms FROM stakes WHERE pool_id = $1 AND epoch >= $2 \
+                UNION ALL SELECT checkpoint_timestamp_ms FROM proposals \
+                    WHERE pool_id = $1 AND epoch >= $2 \
+                UNION ALL SELECT checkpoint_timestamp_ms FROM votes \
+                    WHERE pool_id = $1 AND epoch >= $2 \
+             ) governance",
+        )
+        .bind::<Text, _>(pool_id.to_string())
+        .bind::<BigInt, _>(epoch);
+        let rows: Vec<GovernanceTimestamp> = self.results(query).await?;
+        Ok(rows.into_iter().next().and_then(|row| row.value))
+    }
+
+    /// Stake of each balance manager in `pool_id` at `epoch`: what it staked up to and including
+    /// `epoch` since it last unstaked, and that unstake if it was during `epoch`. Only one row per
+    /// balance manager leaves the database, however long its stake history.
+    pub async fn get_stakers_at_epoch(
+        &self,
+        pool_id: &str,
+        epoch: i64,
+    ) -> Result<Vec<Staker>, DeepBookError> {
+        let query = diesel::sql_query(
+            "WITH last_unstake AS ( \
+                SELECT DISTINCT ON (balance_manager_id) \
+                    balance_manager_id, checkpoint, event_digest, epoch \
+                FROM stakes WHERE pool_id = $1 AND epoch <= $2 AND NOT stake \
+                ORDER BY balance_manager_id, checkpoint DESC, event_digest DESC \
+             ), staked AS ( \
+                SELECT s.balance_manager_id, \
+                    SUM(s.amount) FILTER (WHERE s.epoch < $2) AS active_stake, \
+                    SUM(s.amount) FILTER (WHERE s.epoch = $2) AS inactive_stake \
+                FROM stakes s LEFT JOIN last_unstake u USING (balance_manager_id) \
+                WHERE s.pool_id = $1 AND s.epoch <= $2 AND s.stake \
+                    AND (u.checkpoint IS NULL \
+                        OR (s.checkpoint, s.event_digest) > (u.checkpoint, u.event_digest)) \
+                GROUP BY s.balance_manager_id \
+             ) \
+             SELECT balance_manager_id, \
+                COALESCE(s.active_stake, 0)::BIGINT AS active_stake, \
+                COALESCE(s.inactive_stake, 0)::BIGINT AS inactive_stake, \
+                u.checkpoint AS unstaked_at_checkpoint \
+             FROM staked s \
+             FULL JOIN (SELECT * FROM last_unstake WHERE epoch = $2) u \
+                USING (balance_manager_id) \
+             ORDER BY balance_manager_id",
+        )
+        .bind::<Text, _>(pool_id.to_string())
+        .bind::<BigInt, _>(epoch);
+        Ok(self.results(query).await?)
+    }
+
+    /// Proposals submitted in `pool_id` during `epoch`, oldest first
+    pub async fn get_proposals_in_epoch(
+        &self,
+        pool_id: &str,
+        epoch: i64,
+    ) -> Result<Vec<Proposals>, DeepBookError> {
+        let query = schema::proposals::table
+            .select(Proposals::as_select())
+            .filter(schema::proposals::pool_id.eq(pool_id.to_string()))
+            .filter(schema::proposals::epoch.eq(epoch))
+            .order_by((
+                schema::proposals::checkpoint.asc(),
+                schema::proposals::event_digest.asc(),
+            ));
+        Ok(self.results(query).await?)
+    }
+
+    /// Votes cast in `pool_id` during `epoch`, oldest first
+    pub async fn get_votes_in_epoch(
+        &self,
+        pool_id: &str,
+        epoch: i64,
+    ) -> Result<Vec<Votes>, DeepBookError> {
+        let query = schema::votes::table
+            .select(Votes::as_select())
+            .filter(schema::votes::pool_id.eq(pool_id.to_string()))
+            .filter(schema::votes::epoch.eq(epoch))
+            .order_by((
+                schema::votes::checkpoint.asc(),
+                schema::votes::event_digest.asc(),
+            ));
+        Ok(self.results(query).await?)
+    }
+
+    /// Trade params updates of `pool_id` indexed after `after_ms` and, if given, before
+    /// `before_ms`, oldest first
+    pub async fn get_trade_params_updates_between(
+        &self,
+        pool_id: &str,
+        after_ms: i64,
+        before_ms: Option<i64>,
+    ) -> Result<Vec<TradeParamsUpdate>, DeepBookError> {
+        let query = schema::trade_params_update::table
+            .select(TradeParamsUpdate::as_select())
+            .filter(schema::trade_params_update::pool_id.eq(pool_id.to_string()))
+            .filter(schema::trade_params_update::checkpoint_timestamp_ms.gt(after_ms))
+            .filter(
+                schema::trade_params_update::checkpoint_timestamp_ms
+                    .lt(before_ms.unwrap_or(i64::MAX)),
+            )
+            .order_by(schema::trade_params_update::checkpoint_timestamp_ms.asc());
         Ok(self.results(query).await?)
     }
 
+    /// Rewards of `account_id` per pool, as maintained by the indexer
+    pub async fn get_rewards(
+        &self,
+        account_kind: RewardAccountKind,
+        account_id: &str,
+    ) -> Result<Vec<RewardBalance>, DeepBookError> {
+        let query = schema::reward_ledger::table
+            .select(RewardLedgerEntry::as_select())
+            .filter(schema::reward_ledger::account_kind.eq(account_kind.as_ref().to_string()))
+            .filter(schema::reward_ledger::account_id.eq(account_id.to_string()))
+            .order_by(schema::reward_ledger::pool_id.asc());
+        let entries: Vec<RewardLedgerEntry> = self.results(query).await?;
+        Ok(entries.into_iter().map(RewardBalance::from).collect())
+    }
+
+    /// The referral objects set on `balance_manager_id`, the latest one per pool. A referral
+    /// object's pool is only known from the referral fees it earned or claimed, so those that
+    /// have none yet are listed each on its own, without a pool.
+    pub async fn get_balance_manager_referrals(
+        &self,
+        balance_manager_id: &str,
+    ) -> Result<Vec<BalanceManagerReferral>, DeepBookError> {
+        // Unsetting records the zero address as the referral
+        let query = diesel::sql_query(
+            "WITH referral_sets AS ( \
+                SELECT referral_id, checkpoint, checkpoint_timestamp_ms, event_digest \
+                FROM deepbook_referral_set WHERE balance_manager_id = $1 \
+             ), referral_pools AS ( \
+                SELECT DISTINCT account_id, pool_id FROM reward_ledger WHERE account_kind = $2 \
+             ) \
+             SELECT DISTINCT ON (COALESCE(p.pool_id, s.referral_id)) \
+                p.pool_id, s.referral_id, s.checkpoint_timestamp_ms AS set_at, \
+                EXISTS ( \
+                    SELECT 1 FROM referral_sets u \
+                    WHERE u.referral_id ~ '^0x0+$' AND u.checkpoint >= s.checkpoint \
+                ) AS possibly_unset \
+             FROM referral_sets s \
+             LEFT JOIN referral_pools p ON p.account_id = s.referral_id \
+             WHERE s.referral_id !~ '^0x0+$' \
+             ORDER BY COALESCE(p.pool_id, s.referral_id), s.checkpoint DESC, s.event_digest DESC",
+        )
+        .bind::<Text, _>(balance_manager_id.to_string())
+        .bind::<Text, _>(RewardAccountKind::Referral.as_ref().to_string());
+        Ok(self.results(query).await?)
+    }
+
+    /// The pool's latest book params update before `cursor`, as a page of at most one event.
     pub async fn get_book_params_updated(
         &self,
         pool_id: String,
-    ) -> Result<Option<BookParamsUpdated>, DeepBookError> {
+        cursor: Option<EventCursor>,
+    ) -> Result<Page<BookParamsUpdated>, DeepBookError> {
         let query = schema::book_params_updated::table
             .select(BookParamsUpdated::as_select())
-            .filter(schema::book_params_updated::pool_id.eq(pool_id))
-            .order_by(schema::book_params_updated::checkpoint_timestamp_ms.desc())
-            .limit(1);
-        Ok(self.results(query).await?.into_iter().next())
+            .filter(schema::book_params_updated::pool_id.eq(pool_id));
+        let query = newest_first_page!(query, cursor, 1);
+        Ok(Page::new(self.results(query).await?, 1))
+    }
+
+    pub async fn get_propbook_oracle_sources(
+        &self,
+    ) -> Result<Vec<PropbookOracleSourceRegistered>, DeepBookError> {
+        let query = schema::propbook_oracle_source_registered::table
+            .select(PropbookOracleSourceRegistered::as_select())
+            .order_by(schema::propbook_oracle_source_registered::checkpoint_timestamp_ms.desc());
+        Ok(self.results(query).await?)
+    }
+
+    pub async fn get_propbook_oracle_bound(
+        &self,
+        limit: i64,
+        cursor: Option<EventCursor>,
+    ) -> Result<Page<PropbookOracleBound>, DeepBookError> {
+        let query = schema::propbook_oracle_bound::table.select(PropbookOracleBound::as_select());
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
+    }
+
+    pub async fn get_propbook_oracle_rebound(
+        &self,
+        limit: i64,
+        cursor: Option<EventCursor>,
+    ) -> Result<Page<PropbookOracleRebound>, DeepBookError> {
+        let query =
+            schema::propbook_oracle_rebound::table.select(PropbookOracleRebound::as_select());
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
+    }
+
+    pub async fn get_propbook_block_scholes_stores(
+        &self,
+    ) -> Result<Vec<PropbookBlockScholesStoresRegistered>, DeepBookError> {
+        let query = schema::propbook_block_scholes_stores_registered::table
+            .select(PropbookBlockScholesStoresRegistered::as_select())
+            .order_by(
+                schema::propbook_block_scholes_stores_registered::checkpoint_timestamp_ms.desc(),
+            );
+        Ok(self.results(query).await?)
+    }
+
+    pub async fn get_propbook_oracle_observations(
+        &self,
+        propbook_oracle_id: String,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        cursor: Option<EventCursor>,
+    ) -> Result<Page<PropbookOracleObservation>, DeepBookError> {
+        let query = schema::propbook_oracle_observations::table
+            .select(PropbookOracleObservation::as_select())
+            .filter(schema::propbook_oracle_observations::propbook_oracle_id.eq(propbook_oracle_id))
+            .filter(
+                schema::propbook_oracle_observations::source_timestamp_ms
+                    .between(start_time, end_time),
+            );
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
+    }
+
+    pub async fn get_propbook_block_scholes_observations(
+        &self,
+        propbook_oracle_id: String,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        cursor: Option<EventCursor>,
+        sid_filter: String,
+    ) -> Result<Page<PropbookBlockScholesObservation>, DeepBookError> {
+        let query = schema::propbook_block_scholes_observations::table
+            .select(PropbookBlockScholesObservation::as_select())
+            .filter(
+                schema::propbook_block_scholes_observations::propbook_oracle_id
+                    .eq(propbook_oracle_id),
+            )
+            .filter(
+                schema::propbook_block_scholes_observations::model_timestamp_ms
+                    .between(start_time, end_time),
+            )
+            .filter(schema::propbook_block_scholes_observations::sid.like(to_pattern(&sid_filter)));
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
+    }
+
+    pub async fn get_propbook_block_scholes_batches(
+        &self,
+        propbook_oracle_id: String,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        cursor: Option<EventCursor>,
+    ) -> Result<Page<PropbookBlockScholesBatchIngested>, DeepBookError> {
+        let query = schema::propbook_block_scholes_batch_ingested::table
+            .select(PropbookBlockScholesBatchIngested::as_select())
+            .filter(
+                schema::propbook_block_scholes_batch_ingested::propbook_oracle_id
+                    .eq(propbook_oracle_id),
+            )
+            .filter(
+                schema::propbook_block_scholes_batch_ingested::checkpoint_timestamp_ms
+                    .between(start_time, end_time),
+            );
+        let query = newest_first_page!(query, cursor, limit);
+        Ok(Page::new(self.results(query).await?, limit))
     }
 }
 
 // --- Portfolio response types ---
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Serialize, ToSchema)]
 pub struct PortfolioQueryResult {
     pub margin_positions: Vec<PortfolioMarginPosition>,
     pub collateral_balances: Vec<PortfolioCollateralBalance>,
@@ -2013,7 +3133,7 @@ pub struct PortfolioQueryResult {
     pub summary: PortfolioSummary,
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Serialize, ToSchema)]
 pub struct PortfolioMarginPosition {
     pub margin_manager_id: String,
     pub pool: String,
@@ -2032,14 +3152,14 @@ pub struct PortfolioMarginPosition {
     pub risk_ratio: f64,
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Serialize, ToSchema)]
 pub struct PortfolioCollateralBalance {
     pub asset: String,
     pub balance: f64,
     pub balance_usd: f64,
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Serialize, ToSchema)]
 pub struct PortfolioLpPosition {
     pub margin_pool_id: String,
     pub asset: String,
@@ -2048,9 +3168,101 @@ pub struct PortfolioLpPosition {
     pub supplied_usd: f64,
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Serialize, ToSchema)]
 pub struct PortfolioSummary {
     pub total_equity_usd: f64,
     pub total_debt_usd: f64,
     pub net_value_usd: f64,
 }
+
+// --- Account response types ---
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct AccountsQueryResult {
+    pub accounts: Vec<AccountInfo>,
+    pub authorized_apps: Vec<String>,
+    pub sessions: Vec<AccountSession>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct AccountInfo {
+    pub account_id: String,
+    pub wrapper_id: String,
+    pub self_owned: bool,
+    pub referrer_account_id: Option<String>,
+    /// Balance manager attached by `deepbook_core_account`, if initialized
+    pub balance_manager_id: Option<String>,
+    pub created_at_ms: i64,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct AccountSession {
+    pub account_id: String,
+    pub session: String,
+    pub expires_at_ms: i64,
+}
+
+/// Rewards of a balance manager, or of a referral object, across pools.
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct RewardsQueryResult {
+    pub account_id: String,
+    /// Referral objects the balance manager's trades pay referral fees to, per pool
+    pub referrals: Vec<BalanceManagerReferral>,
+    pub rewards: Vec<RewardBalance>,
+}
+
+/// A referral object set on a balance manager, which its trades in the referral object's pool
+/// pay referral fees to.
+#[derive(Debug, QueryableByName, serde::Serialize, ToSchema)]
+pub struct BalanceManagerReferral {
+    /// Pool of the referral object, known once it has earned or claimed referral fees
+    #[diesel(sql_type = Nullable<Text>)]
+    pub pool_id: Option<String>,
+    #[diesel(sql_type = Text)]
+    pub referral_id: String,
+    /// When it was set, in Unix milliseconds
+    #[diesel(sql_type = BigInt)]
+    pub set_at: i64,
+    /// Whether the balance manager has unset a referral since. Unsetting does not record the
+    /// pool, so this referral object may no longer be set.
+    #[diesel(sql_type = Bool)]
+    pub possibly_unset: bool,
+}
+
+/// Rewards earned, claimed and still owed in one pool, in on-chain units.
+///
+/// Trading rebates are only reported on chain when they are claimed, so for a balance manager
+/// `earned_*` equals `claimed_*` and `outstanding_*` is always 0: rebates it could still claim
+/// are not included. Only referral objects have outstanding rewards.
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct RewardBalance {
+    pub pool_id: String,
+    pub earned_base: i64,
+    pub earned_quote: i64,
+    pub earned_deep: i64,
+    pub claimed_base: i64,
+    pub claimed_quote: i64,
+    pub claimed_deep: i64,
+    pub outstanding_base: i64,
+    pub outstanding_quote: i64,
+    pub outstanding_deep: i64,
+    pub last_updated_at: i64,
+}
+
+impl From<RewardLedgerEntry> for RewardBalance {
+    fn from(entry: RewardLedgerEntry) -> Self {
+        Self {
+            outstanding_base: entry.earned_base - entry.claimed_base,
+            outstanding_quote: entry.earned_quote - entry.claimed_quote,
+            outstanding_deep: entry.earned_deep - entry.claimed_deep,
+            pool_id: entry.pool_id,
+            earned_base: entry.earned_base,
+            earned_quote: entry.earned_quote,
+            earned_deep: entry.earned_deep,
+            claimed_base: entry.claimed_base,
+            claimed_quote: entry.claimed_quote,
+            claimed_deep: entry.claimed_deep,
+            last_updated_at: entry.last_updated_at,
+        }
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Response bodies of the endpoints that shape their own JSON rather than serving indexed rows
+//! as they are. Prices and quantities are scaled by the pool's decimals unless noted otherwise.
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Health of the indexer, from the watermark of each pipeline
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IndexerStatus {
+    /// `OK`, or `UNHEALTHY` when a pipeline lags more than the requested thresholds
+    pub status: String,
+    pub latest_onchain_checkpoint: u64,
+    pub current_time_ms: i64,
+    /// Lowest checkpoint indexed by every non-backfill pipeline
+    pub earliest_checkpoint: i64,
+    pub max_lag_pipeline: String,
+    pub pipelines: Vec<PipelineStatus>,
+    pub max_checkpoint_lag: i64,
+    pub max_time_lag_seconds: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PipelineStatus {
+    pub pipeline: String,
+    pub indexed_checkpoint: i64,
+    pub indexed_epoch: i64,
+    pub indexed_timestamp_ms: i64,
+    pub checkpoint_lag: i64,
+    pub time_lag_seconds: i64,
+    pub latest_onchain_checkpoint: u64,
+    /// Backfill pipelines don't count towards the indexer's health
+    pub is_backfill: bool,
+}
+
+/// Last price and volumes of a pool
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Ticker {
+    /// Price of the last trade in the past 24 hours, or 0
+    pub last_price: f64,
+    pub base_volume: f64,
+    pub quote_volume: f64,
+    /// Always 0, as every indexed pool is active
+    #[serde(rename = "isFrozen")]
+    pub is_frozen: u8,
+}
+
+/// Market summary of a pool over the past 24 hours
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PoolSummary {
+    pub trading_pairs: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+    pub last_price: f64,
+    pub base_volume: f64,
+    pub quote_volume: f64,
+    pub price_change_percent_24h: f64,
+    pub highest_price_24h: f64,
+    pub lowest_price_24h: f64,
+    pub highest_bid: f64,
+    pub lowest_ask: f64,
+}
+
+/// Price levels of a pool, best first on each side, each a `[price, quantity]` pair of decimal
+/// strings
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Orderbook {
+    /// Time in milliseconds the book is current as of
+    pub timestamp: String,
+    #[schema(value_type = Vec<Vec<String>>)]
+    pub bids: Vec<[String; 2]>,
+    #[schema(value_type = Vec<Vec<String>>)]
+    pub asks: Vec<[String; 2]>,
+}
+
+/// Candles of a pool, oldest first, each `[timestamp_ms, open, high, low, close, base_volume]`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Ohclv {
+    #[schema(value_type = Vec<Vec<f64>>)]
+    pub candles: Vec<(i64, f64, f64, f64, f64, f64)>,
+}
+
+/// A change to an order in a pool
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PoolOrderUpdate {
+    pub order_id: String,
+    pub price: f64,
+    pub original_quantity: f64,
+    pub remaining_quantity: f64,
+    pub filled_quantity: f64,
+    pub timestamp: u64,
+    /// `buy` or `sell`
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub balance_manager_id: String,
+    pub status: String,
+}
+
+/// An order of a balance manager, with its latest status
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BalanceManagerOrder {
+    pub order_id: String,
+    pub balance_manager_id: String,
+    /// `buy` or `sell`
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub current_status: String,
+    pub price: f64,
+    pub placed_at: u64,
+    pub last_updated_at: u64,
+    pub original_quantity: f64,
+    pub filled_quantity: f64,
+    pub remaining_quantity: f64,
+}
+
+/// A fill between a maker and a taker order. Fees paid in DEEP are scaled by DEEP's decimals.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Trade {
+    pub event_digest: String,
+    pub digest: String,
+    pub trade_id: String,
+    pub maker_order_id: String,
+    pub taker_order_id: String,
+    pub maker_client_order_id: String,
+    pub taker_client_order_id: String,
+    pub maker_balance_manager_id: String,
+    pub taker_balance_manager_id: String,
+    pub price: f64,
+    pub base_volume: f64,
+    pub quote_volume: f64,
+    pub timestamp: u64,
+    /// `buy` or `sell`, from the taker's side
+    #[serde(rename = "type")]
+    pub trade_type: String,
+    pub taker_is_bid: bool,
+    pub taker_fee: f64,
+    pub maker_fee: f64,
+    pub taker_fee_is_deep: bool,
+    pub maker_fee_is_deep: bool,
+}
+
+/// An asset traded on DeepBook
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssetInfo {
+    pub name: String,
+    pub asset_type: String,
+    /// Always `"true"`
+    pub can_withdraw: String,
+    /// Always `"true"`
+    pub can_deposit: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unified_cryptoasset_id: Option<String>,
+    #[serde(rename = "contractAddressUrl", skip_serializing_if = "Option::is_none")]
+    pub contract_address_url: Option<String>,
+    #[serde(rename = "contractAddress", skip_serializing_if = "Option::is_none")]
+    pub contract_address: Option<String>,
+}
+
+/// Trade params of a pool: fees as fractions of the traded amount, and the DEEP stake required
+/// for reduced fees
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PoolFees {
+    pub pool_id: String,
+    pub taker_fee: f64,
+    pub maker_fee: f64,
+    pub stake_required: f64,
+}
+
+/// A margin manager with the pools and assets it trades
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MarginManagerInfo {
+    pub margin_manager_id: String,
+    pub deepbook_pool_id: Option<String>,
+    pub base_asset_id: Option<String>,
+    pub base_asset_symbol: Option<String>,
+    pub quote_asset_id: Option<String>,
+    pub quote_asset_symbol: Option<String>,
+    pub base_margin_pool_id: Option<String>,
+    pub quote_margin_pool_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BalanceManagerDepositedAssets {
+    pub balance_manager_id: String,
+    pub assets: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AddressPoints {
+    pub address: String,
+    pub total_points: i64,
+}
@@ -1,22 +1,31 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::commit_notifications::CommitNotifications;
 use crate::error::DeepBookError;
+use crate::governance::{BalanceManagerGovernance, EpochGovernance};
 use axum::http::Method;
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
+    extract::{ws::WebSocketUpgrade, Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Response,
+    },
     routing::get,
     Json, Router,
 };
 use deepbook_schema::models::{
-    AssetSupplied, AssetWithdrawn, BookParamsUpdated, CollateralEvent, DeepbookPoolConfigUpdated,
-    DeepbookPoolRegistered, DeepbookPoolUpdated, DeepbookPoolUpdatedRegistry,
-    InterestParamsUpdated, Liquidation, LoanBorrowed, LoanRepaid, MaintainerCapUpdated,
-    MaintainerFeesWithdrawn, MarginManagerCreated, MarginManagerState, MarginPoolConfigUpdated,
-    MarginPoolCreated, PauseCapUpdated, PoolCreated, Pools, ProtocolFeesIncreasedEvent,
+    AssetSupplied, AssetWithdrawn, BalanceManagerBalance, BookParamsUpdated, CollateralEvent,
+    DeepbookPoolConfigUpdated, DeepbookPoolRegistered, DeepbookPoolUpdated,
+    DeepbookPoolUpdatedRegistry, InterestParamsUpdated, Liquidation, LoanBorrowed, LoanRepaid,
+    MaintainerCapUpdated, MaintainerFeesWithdrawn, MarginManagerCreated, MarginManagerState,
+    MarginPoolConfigUpdated, MarginPoolCreated, OrderBookLevel, PauseCapUpdated, PoolCreated,
+    Pools, PropbookBlockScholesBatchIngested, PropbookBlockScholesObservation,
+    PropbookBlockScholesStoresRegistered, PropbookOracleBound, PropbookOracleObservation,
+    PropbookOracleRebound, PropbookOracleSourceRegistered, ProtocolFeesIncreasedEvent,
     ProtocolFeesWithdrawn, RebatesV2, ReferralFeeEvent, ReferralFeesClaimedEvent,
-    SupplierCapMinted, SupplyReferralMinted,
+    RewardAccountKind, SupplierCapMinted, SupplyReferralMinted, TraderVolume, TraderVolumeTotal,
 };
 use deepbook_schema::*;
 use diesel::dsl::count_star;
@@ -25,7 +34,7 @@ use diesel::{ExpressionMethods, QueryDsl};
 use governor::{Quota, RateLimiter};
 use secrecy::{ExposeSecret, Secret};
 use serde::Deserialize;
-use serde_json::Value;
+use std::convert::Infallible;
 use std::net::{IpAddr, Ipv4Addr};
 use std::num::NonZeroU32;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -35,16 +44,30 @@ use tokio::net::TcpListener;
 use tokio::sync::oneshot;
 use tower_http::cors::{AllowMethods, Any, CorsLayer};
 use url::Url;
+use utoipa::IntoParams;
 
 use crate::admin::routes::admin_routes;
 use crate::live_ohclv::{LiveOhclvCache, OHCLV_DEFAULT_LIMIT, OHCLV_DEFAULT_WINDOW_MS};
+use crate::market_feed::{self, MarketFeed};
 use crate::metrics::middleware::track_metrics;
 use crate::metrics::RpcMetrics;
+use crate::openapi::{EventPageParams, PageParams, TimeRangeParams};
+use crate::order_stream;
+use crate::pagination::{EventCursor, Latest, Page, NEXT_CURSOR_HEADER};
 use crate::pyth::{PythProConfig, PythProxy};
-use crate::reader::{PortfolioQueryResult, Reader};
+use crate::reader::{
+    AccountsQueryResult, LiquidationVaultPnl, PortfolioQueryResult, Reader, RewardsQueryResult,
+    TraderVolumeInterval,
+};
+use crate::responses::{
+    AddressPoints, AssetInfo, BalanceManagerDepositedAssets, BalanceManagerOrder, IndexerStatus,
+    MarginManagerInfo, Ohclv, Orderbook, PipelineStatus, PoolFees, PoolOrderUpdate, PoolSummary,
+    Ticker, Trade,
+};
 use crate::writer::Writer;
 use axum::middleware::from_fn_with_state;
 use futures::future::join_all;
+use futures::Stream;
 use prometheus::Registry;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -68,6 +91,20 @@ diesel::define_sql_function! {
 
 /// Default lookback window for the /orders endpoint when no start_time is provided (7 days in ms).
 const DEFAULT_ORDERS_LOOKBACK_MS: i64 = 7 * 24 * 60 * 60 * 1000;
+/// Default window of hourly trader volume (1 day in ms)
+const TRADER_VOLUME_HOURLY_LOOKBACK_MS: i64 = 24 * 60 * 60 * 1000;
+/// Default window of daily trader volume (30 days in ms)
+const TRADER_VOLUME_DAILY_LOOKBACK_MS: i64 = 30 * 24 * 60 * 60 * 1000;
+const TRADER_VOLUME_DEFAULT_LIMIT: i64 = 1000;
+/// Larger trader volume limits are clamped to this
+const TRADER_VOLUME_MAX_LIMIT: i64 = 10_000;
+const TRADER_VOLUME_LEADERBOARD_DEFAULT_LIMIT: i64 = 100;
+/// Larger leaderboard limits are clamped to this
+const TRADER_VOLUME_LEADERBOARD_MAX_LIMIT: i64 = 1000;
+/// Statuses of orders still on the book, served from `open_orders` regardless of age
+const OPEN_ORDER_STATUSES: &[&str] = &["placed", "partially_filled"];
+/// Indexer pipeline that maintains `open_orders` and `order_book_levels`
+const OPEN_ORDERS_PIPELINE: &str = "open_orders";
 
 fn current_time_ms() -> i64 {
     SystemTime::now()
@@ -88,6 +125,7 @@ pub const TICKER_PATH: &str = "/ticker";
 pub const TRADES_PATH: &str = "/trades/:pool_name";
 pub const ORDER_UPDATES_PATH: &str = "/order_updates/:pool_name";
 pub const ORDERS_PATH: &str = "/orders/:pool_name/:balance_manager_id";
+pub const ORDER_STREAM_PATH: &str = "/order_stream/:balance_manager_id";
 pub const TRADE_COUNT_PATH: &str = "/trade_count";
 pub const ASSETS_PATH: &str = "/assets";
 pub const SUMMARY_PATH: &str = "/summary";
@@ -109,6 +147,7 @@ pub const MARGIN_MANAGER_CREATED_PATH: &str = "/margin_manager_created";
 pub const LOAN_BORROWED_PATH: &str = "/loan_borrowed";
 pub const LOAN_REPAID_PATH: &str = "/loan_repaid";
 pub const LIQUIDATION_PATH: &str = "/liquidation";
+pub const LIQUIDATION_VAULT_PNL_PATH: &str = "/liquidation_vault_pnl";
 pub const ASSET_SUPPLIED_PATH: &str = "/asset_supplied";
 pub const ASSET_WITHDRAWN_PATH: &str = "/asset_withdrawn";
 pub const MARGIN_POOL_CREATED_PATH: &str = "/margin_pool_created";
@@ -132,11 +171,32 @@ pub const MARGIN_MANAGERS_INFO_PATH: &str = "/margin_managers_info";
 pub const MARGIN_MANAGER_STATES_PATH: &str = "/margin_manager_states";
 pub const STATUS_PATH: &str = "/status";
 pub const DEPOSITED_ASSETS_PATH: &str = "/deposited_assets/:balance_manager_ids";
+pub const BALANCE_MANAGER_BALANCES_PATH: &str = "/balance_managers/:balance_manager_id/balances";
+pub const TRADER_VOLUME_PATH: &str = "/trader_volume/:balance_manager_id";
+pub const TRADER_VOLUME_LEADERBOARD_PATH: &str = "/trader_volume_leaderboard/:pool_name";
+pub const GOVERNANCE_PATH: &str = "/governance/:pool_name";
+pub const GOVERNANCE_BALANCE_MANAGER_PATH: &str = "/governance/:pool_name/:balance_manager_id";
+pub const REWARDS_PATH: &str = "/rewards/:balance_manager_id";
+pub const REFERRAL_REWARDS_PATH: &str = "/referral_rewards/:referral_id";
+pub const WS_PATH: &str = "/ws";
 pub const COLLATERAL_EVENTS_PATH: &str = "/collateral_events";
 pub const GET_POINTS_PATH: &str = "/get_points";
 pub const PORTFOLIO_PATH: &str = "/portfolio/:wallet_address";
 pub const POOL_CREATED_PATH: &str = "/pool_created";
 pub const BOOK_PARAMS_UPDATED_PATH: &str = "/book_params_updated";
+pub const ACCOUNTS_PATH: &str = "/accounts/:owner";
+pub const OPENAPI_PATH: &str = "/openapi.json";
+// Propbook paths
+pub const PROPBOOK_ORACLE_SOURCES_PATH: &str = "/propbook/oracle_sources";
+pub const PROPBOOK_ORACLE_BOUND_PATH: &str = "/propbook/oracle_bound";
+pub const PROPBOOK_ORACLE_REBOUND_PATH: &str = "/propbook/oracle_rebound";
+pub const PROPBOOK_BLOCK_SCHOLES_STORES_PATH: &str = "/propbook/block_scholes_stores";
+pub const PROPBOOK_ORACLE_OBSERVATIONS_PATH: &str =
+    "/propbook/oracle_observations/:propbook_oracle_id";
+pub const PROPBOOK_BLOCK_SCHOLES_OBSERVATIONS_PATH: &str =
+    "/propbook/block_scholes_observations/:propbook_oracle_id";
+pub const PROPBOOK_BLOCK_SCHOLES_BATCHES_PATH: &str =
+    "/propbook/block_scholes_batches/:propbook_oracle_id";
 
 type AdminRateLimiter = RateLimiter<
     governor::state::NotKeyed,
@@ -149,6 +209,8 @@ pub struct AppState {
     reader: Reader,
     writer: Writer,
     live_ohclv: LiveOhclvCache,
+    market_feed: MarketFeed,
+    commit_notifications: CommitNotifications,
     metrics: Arc<RpcMetrics>,
     sui_client: Client,
     deepbook_package_id: String,
@@ -212,6 +274,8 @@ impl AppState {
             reader,
             writer,
             live_ohclv,
+            market_feed: MarketFeed::new(),
+            commit_notifications: CommitNotifications::new(),
             metrics,
             sui_client: Client::new(rpc_url.as_str())?,
             deepbook_package_id,
@@ -240,13 +304,31 @@ impl AppState {
     pub fn start_live_ohclv_poller(&self, poll_interval: Duration) -> tokio::task::JoinHandle<()> {
         let live_ohclv_cache = self.live_ohclv.clone();
         let live_ohclv_reader = self.reader.clone();
+        let commits = self.commit_notifications.subscribe();
         tokio::spawn(async move {
             live_ohclv_cache
-                .run_poll_loop(live_ohclv_reader, poll_interval)
+                .run_poll_loop(live_ohclv_reader, poll_interval, commits)
+                .await;
+        })
+    }
+
+    pub fn start_market_feed_poller(&self, poll_interval: Duration) -> tokio::task::JoinHandle<()> {
+        let market_feed = self.market_feed.clone();
+        let market_feed_reader = self.reader.clone();
+        let commits = self.commit_notifications.subscribe();
+        tokio::spawn(async move {
+            market_feed
+                .run_poll_loop(market_feed_reader, poll_interval, commits)
                 .await;
         })
     }
 
+    /// Listen for the indexer's commits on `database_url`, so that caches and streams react to
+    /// new data before their next poll.
+    pub fn start_commit_listener(&self, database_url: Url) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(self.commit_notifications.clone().run_listener(database_url))
+    }
+
     pub fn is_valid_admin_token(&self, token: &str) -> bool {
         use subtle::ConstantTimeEq;
         self.admin_tokens
@@ -260,7 +342,8 @@ impl AppState {
 }
 
 /// Query parameters for the /status endpoint
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct StatusQueryParams {
     /// Maximum acceptable checkpoint lag for "healthy" status (default: 100)
     #[serde(default = "default_max_checkpoint_lag")]
@@ -289,9 +372,11 @@ pub async fn run_server(
     deep_treasury_id: String,
     margin_poll_interval_secs: u64,
     margin_package_id: Option<String>,
+    book_reconciliation_interval_secs: u64,
     admin_tokens: Option<String>,
     live_ohclv_poll_interval_ms: u64,
     live_ohclv_max_fills: usize,
+    commit_notifications: bool,
     pyth_pro_url: Url,
     pyth_pro_api_key: Option<String>,
     pyth_pro_config: PythProConfig,
@@ -306,7 +391,7 @@ pub async fn run_server(
         db_arg.clone(),
         metrics.registry(),
         rpc_url.clone(),
-        deepbook_package_id,
+        deepbook_package_id.clone(),
         deep_token_package_id,
         deep_treasury_id,
         admin_tokens,
@@ -321,6 +406,10 @@ pub async fn run_server(
 
     println!("Server started successfully on port {}", server_port);
 
+    if commit_notifications {
+        state.start_commit_listener(database_url.clone());
+        println!("Commit notification listener started");
+    }
     let live_ohclv_poll_interval = Duration::from_millis(live_ohclv_poll_interval_ms.max(1));
     state.start_live_ohclv_poller(live_ohclv_poll_interval);
     println!(
@@ -328,13 +417,14 @@ pub async fn run_server(
         live_ohclv_poll_interval_ms.max(1),
         live_ohclv_max_fills
     );
+    state.start_market_feed_poller(live_ohclv_poll_interval);
 
     // Start margin metrics poller if margin_package_id is provided
     // Must be done before spawning the metrics service since we need access to the registry
     if let Some(margin_pkg_id) = margin_package_id {
         let cancellation_token = tokio_util::sync::CancellationToken::new();
         let margin_metrics = crate::margin_metrics::MarginMetrics::new(metrics.registry());
-        let margin_db = sui_pg_db::Db::for_write(database_url, db_arg).await?;
+        let margin_db = sui_pg_db::Db::for_write(database_url.clone(), db_arg.clone()).await?;
         let margin_poller = crate::margin_metrics::MarginPoller::new(
             margin_db,
             state.sui_client().clone(),
@@ -354,6 +444,31 @@ pub async fn run_server(
         );
     }
 
+    // Start order book reconciliation against the chain unless disabled
+    if book_reconciliation_interval_secs > 0 {
+        let cancellation_token = tokio_util::sync::CancellationToken::new();
+        let reconciliation_metrics =
+            crate::book_reconciliation::BookReconciliationMetrics::new(metrics.registry());
+        let reconciliation_db = sui_pg_db::Db::for_write(database_url, db_arg).await?;
+        let book_reconciler = crate::book_reconciliation::BookReconciler::new(
+            reconciliation_db,
+            state.sui_client().clone(),
+            deepbook_package_id,
+            reconciliation_metrics,
+            book_reconciliation_interval_secs,
+            cancellation_token,
+        );
+        tokio::spawn(async move {
+            if let Err(e) = book_reconciler.run().await {
+                eprintln!("[book_reconciler] Book reconciler failed: {}", e);
+            }
+        });
+        println!(
+            "Order book reconciler started (interval: {}s)",
+            book_reconciliation_interval_secs
+        );
+    }
+
     let s_metrics = metrics.run().await?;
 
     let listener = TcpListener::bind(socket_address).await?;
@@ -388,10 +503,12 @@ pub fn make_router(state: Arc<AppState>) -> Router {
             Method::OPTIONS,
         ]))
         .allow_headers(Any)
+        .expose_headers([NEXT_CURSOR_HEADER])
         .allow_origin(Any);
 
     let db_routes = Router::new()
         .route("/", get(health_check))
+        .route(OPENAPI_PATH, get(openapi_json))
         .route(GET_POOLS_PATH, get(get_pools))
         .route(HISTORICAL_VOLUME_PATH, get(historical_volume))
         .route(ALL_HISTORICAL_VOLUME_PATH, get(all_historical_volume))
@@ -409,6 +526,7 @@ pub fn make_router(state: Arc<AppState>) -> Router {
         .route(TRADE_COUNT_PATH, get(trade_count))
         .route(ORDER_UPDATES_PATH, get(order_updates))
         .route(ORDERS_PATH, get(orders))
+        .route(ORDER_STREAM_PATH, get(order_stream))
         .route(ASSETS_PATH, get(assets))
         .route(OHCLV_PATH, get(ohclv))
         // Deepbook Margin Events
@@ -416,6 +534,7 @@ pub fn make_router(state: Arc<AppState>) -> Router {
         .route(LOAN_BORROWED_PATH, get(loan_borrowed))
         .route(LOAN_REPAID_PATH, get(loan_repaid))
         .route(LIQUIDATION_PATH, get(liquidation))
+        .route(LIQUIDATION_VAULT_PNL_PATH, get(liquidation_vault_pnl))
         .route(ASSET_SUPPLIED_PATH, get(asset_supplied))
         .route(ASSET_WITHDRAWN_PATH, get(asset_withdrawn))
         .route(MARGIN_POOL_CREATED_PATH, get(margin_pool_created))
@@ -450,11 +569,46 @@ pub fn make_router(state: Arc<AppState>) -> Router {
         .route(MARGIN_MANAGERS_INFO_PATH, get(margin_managers_info))
         .route(MARGIN_MANAGER_STATES_PATH, get(margin_manager_states))
         .route(DEPOSITED_ASSETS_PATH, get(deposited_assets))
+        .route(BALANCE_MANAGER_BALANCES_PATH, get(balance_manager_balances))
+        .route(TRADER_VOLUME_PATH, get(trader_volume))
+        .route(
+            TRADER_VOLUME_LEADERBOARD_PATH,
+            get(trader_volume_leaderboard),
+        )
+        .route(GOVERNANCE_PATH, get(governance))
+        .route(
+            GOVERNANCE_BALANCE_MANAGER_PATH,
+            get(balance_manager_governance),
+        )
+        .route(REWARDS_PATH, get(rewards))
+        .route(REFERRAL_REWARDS_PATH, get(referral_rewards))
+        .route(WS_PATH, get(ws))
         .route(COLLATERAL_EVENTS_PATH, get(collateral_events))
         .route(GET_POINTS_PATH, get(get_points))
         .route(PORTFOLIO_PATH, get(portfolio))
+        .route(ACCOUNTS_PATH, get(accounts))
         .route(POOL_CREATED_PATH, get(pool_created))
         .route(BOOK_PARAMS_UPDATED_PATH, get(book_params_updated))
+        // Propbook Events
+        .route(PROPBOOK_ORACLE_SOURCES_PATH, get(propbook_oracle_sources))
+        .route(PROPBOOK_ORACLE_BOUND_PATH, get(propbook_oracle_bound))
+        .route(PROPBOOK_ORACLE_REBOUND_PATH, get(propbook_oracle_rebound))
+        .route(
+            PROPBOOK_BLOCK_SCHOLES_STORES_PATH,
+            get(propbook_block_scholes_stores),
+        )
+        .route(
+            PROPBOOK_ORACLE_OBSERVATIONS_PATH,
+            get(propbook_oracle_observations),
+        )
+        .route(
+            PROPBOOK_BLOCK_SCHOLES_OBSERVATIONS_PATH,
+            get(propbook_block_scholes_observations),
+        )
+        .route(
+            PROPBOOK_BLOCK_SCHOLES_BATCHES_PATH,
+            get(propbook_block_scholes_batches),
+        )
         .with_state(state.clone());
 
     let rpc_routes = Router::new()
@@ -477,15 +631,38 @@ pub fn make_router(state: Arc<AppState>) -> Router {
         .layer(from_fn_with_state(state, track_metrics))
 }
 
+/// Basic health check
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "Health",
+    responses((status = 200, description = "Server is running"))
+)]
 async fn health_check() -> StatusCode {
     StatusCode::OK
 }
 
+/// The server's OpenAPI document, generated from its handlers
+async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(crate::openapi::openapi())
+}
+
 /// Get indexer status including checkpoint lag
+#[utoipa::path(
+    get,
+    path = "/status",
+    tag = "Health",
+    params(
+        StatusQueryParams,
+    ),
+    responses(
+        (status = 200, description = "Indexer status and health metrics", body = IndexerStatus)
+    )
+)]
 async fn status(
     Query(params): Query<StatusQueryParams>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<serde_json::Value>, DeepBookError> {
+) -> Result<Json<IndexerStatus>, DeepBookError> {
     // Get watermarks from the database
     let watermarks = state.reader.get_watermarks().await?;
 
@@ -523,27 +700,22 @@ async fn status(
             }
         }
 
-        pipelines.push(serde_json::json!({
-            "pipeline": pipeline,
-            "indexed_checkpoint": checkpoint_hi,
-            "indexed_epoch": epoch_hi,
-            "indexed_timestamp_ms": timestamp_ms_hi,
-            "checkpoint_lag": checkpoint_lag,
-            "time_lag_seconds": time_lag_seconds,
-            "latest_onchain_checkpoint": latest_checkpoint,
-            "is_backfill": is_backfill,
-        }));
+        pipelines.push(PipelineStatus {
+            pipeline,
+            indexed_checkpoint: checkpoint_hi,
+            indexed_epoch: epoch_hi,
+            indexed_timestamp_ms: timestamp_ms_hi,
+            checkpoint_lag,
+            time_lag_seconds,
+            latest_onchain_checkpoint: latest_checkpoint,
+            is_backfill,
+        });
     }
 
     let max_time_lag_seconds = pipelines
         .iter()
-        .filter_map(|p| {
-            if p["is_backfill"].as_bool() == Some(true) {
-                None
-            } else {
-                p["time_lag_seconds"].as_i64()
-            }
-        })
+        .filter(|p| !p.is_backfill)
+        .map(|p| p.time_lag_seconds)
         .max()
         .unwrap_or(0);
 
@@ -558,23 +730,51 @@ async fn status(
         && max_time_lag_seconds < params.max_time_lag_seconds;
     let status_str = if is_healthy { "OK" } else { "UNHEALTHY" };
 
-    Ok(Json(serde_json::json!({
-        "status": status_str,
-        "latest_onchain_checkpoint": latest_checkpoint,
-        "current_time_ms": current_time_ms,
-        "earliest_checkpoint": earliest_checkpoint,
-        "max_lag_pipeline": max_lag_pipeline_name,
-        "pipelines": pipelines,
-        "max_checkpoint_lag": max_checkpoint_lag,
-        "max_time_lag_seconds": max_time_lag_seconds,
-    })))
+    Ok(Json(IndexerStatus {
+        status: status_str.to_string(),
+        latest_onchain_checkpoint: latest_checkpoint,
+        current_time_ms,
+        earliest_checkpoint,
+        max_lag_pipeline: max_lag_pipeline_name,
+        pipelines,
+        max_checkpoint_lag,
+        max_time_lag_seconds,
+    }))
 }
 
 /// Get all pools stored in database
+#[utoipa::path(
+    get,
+    path = "/get_pools",
+    tag = "Pools",
+    responses((status = 200, description = "Pools", body = [Pools]))
+)]
 async fn get_pools(State(state): State<Arc<AppState>>) -> Result<Json<Vec<Pools>>, DeepBookError> {
     Ok(Json(state.reader.get_pools().await?))
 }
 
+/// Historical volume for pools in a time range
+#[utoipa::path(
+    get,
+    path = "/historical_volume/{pool_names}",
+    tag = "Volume",
+    params(
+        ("pool_names" = String, Path, description = "Comma-separated pool names"),
+        TimeRangeParams,
+        (
+            "volume_in_base" = Option<bool>,
+            Query,
+            description = "Volume in the base asset rather than the quote asset (default: false)"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Volume per pool name, in on-chain units",
+            body = HashMap<String, u64>
+        )
+    )
+)]
 async fn historical_volume(
     Path(pool_names): Path<String>,
     Query(params): Query<HashMap<String, String>>,
@@ -636,6 +836,26 @@ async fn historical_volume_with_pools(
 }
 
 /// Get all historical volume for all pools
+#[utoipa::path(
+    get,
+    path = "/all_historical_volume",
+    tag = "Volume",
+    params(
+        TimeRangeParams,
+        (
+            "volume_in_base" = Option<bool>,
+            Query,
+            description = "Volume in the base asset rather than the quote asset (default: false)"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Volume per pool name, in on-chain units",
+            body = HashMap<String, u64>
+        )
+    )
+)]
 async fn all_historical_volume(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
@@ -658,6 +878,29 @@ async fn all_historical_volume_with_pools(
     historical_volume_with_pools(&pool_names, params, state, pools).await
 }
 
+/// Historical maker/taker volume for a balance manager
+#[utoipa::path(
+    get,
+    path = "/historical_volume_by_balance_manager_id/{pool_names}/{balance_manager_id}",
+    tag = "Volume",
+    params(
+        ("pool_names" = String, Path, description = "Comma-separated pool names"),
+        ("balance_manager_id" = String, Path, description = "Balance manager ID"),
+        TimeRangeParams,
+        (
+            "volume_in_base" = Option<bool>,
+            Query,
+            description = "Volume in the base asset rather than the quote asset (default: false)"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "[maker_volume, taker_volume] per pool name, in on-chain units",
+            body = HashMap<String, Vec<i64>>
+        )
+    )
+)]
 async fn get_historical_volume_by_balance_manager_id(
     Path((pool_names, balance_manager_id)): Path<(String, String)>,
     Query(params): Query<HashMap<String, String>>,
@@ -719,6 +962,34 @@ async fn get_historical_volume_by_balance_manager_id(
     Ok(Json(volume_by_pool))
 }
 
+/// Intervalized maker/taker volume for a balance manager
+#[utoipa::path(
+    get,
+    path = "/historical_volume_by_balance_manager_id_with_interval/{pool_names}/{balance_manager_id}",
+    tag = "Volume",
+    params(
+        ("pool_names" = String, Path, description = "Comma-separated pool names"),
+        ("balance_manager_id" = String, Path, description = "Balance manager ID"),
+        TimeRangeParams,
+        (
+            "interval" = Option<i64>,
+            Query,
+            description = "Interval length in seconds (default: 3600)"
+        ),
+        (
+            "volume_in_base" = Option<bool>,
+            Query,
+            description = "Volume in the base asset rather than the quote asset (default: false)"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "[maker_volume, taker_volume] per pool name and `[start, end]` interval",
+            body = HashMap<String, HashMap<String, Vec<i64>>>
+        )
+    )
+)]
 async fn get_historical_volume_by_balance_manager_id_with_interval(
     Path((pool_names, balance_manager_id)): Path<(String, String)>,
     Query(params): Query<HashMap<String, String>>,
@@ -808,10 +1079,20 @@ async fn get_historical_volume_by_balance_manager_id_with_interval(
     Ok(Json(metrics_by_interval))
 }
 
+/// Ticker info for all trading pairs
+#[utoipa::path(
+    get,
+    path = "/ticker",
+    tag = "Market Data",
+    params(
+        TimeRangeParams,
+    ),
+    responses((status = 200, description = "Ticker per pool name", body = HashMap<String, Ticker>))
+)]
 async fn ticker(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<HashMap<String, HashMap<String, Value>>>, DeepBookError> {
+) -> Result<Json<HashMap<String, Ticker>>, DeepBookError> {
     // Fetch pools data once for reuse
     let pools = state.reader.get_pools().await?;
 
@@ -864,25 +1145,14 @@ async fn ticker(
 
         response.insert(
             pool_name.clone(),
-            HashMap::from([
-                (
-                    "last_price".to_string(),
-                    Value::from(
-                        last_price
-                            .map(|price| price as f64 / price_factor as f64)
-                            .unwrap_or(0.0),
-                    ),
-                ),
-                (
-                    "base_volume".to_string(),
-                    Value::from(base_volume as f64 / base_factor as f64),
-                ),
-                (
-                    "quote_volume".to_string(),
-                    Value::from(quote_volume as f64 / quote_factor as f64),
-                ),
-                ("isFrozen".to_string(), Value::from(0)), // Fixed to 0 because all pools in pools table are active
-            ]),
+            Ticker {
+                last_price: last_price
+                    .map(|price| price as f64 / price_factor as f64)
+                    .unwrap_or(0.0),
+                base_volume: base_volume as f64 / base_factor as f64,
+                quote_volume: quote_volume as f64 / quote_factor as f64,
+                is_frozen: 0, // Fixed to 0 because all pools in pools table are active
+            },
         );
     }
 
@@ -903,10 +1173,17 @@ async fn fetch_historical_volume_with_pools(
         .map(|Json(volumes)| volumes)
 }
 
+/// Summary for all trading pairs
 #[allow(clippy::get_first)]
+#[utoipa::path(
+    get,
+    path = "/summary",
+    tag = "Market Data",
+    responses((status = 200, description = "Summary per pool", body = [PoolSummary]))
+)]
 async fn summary(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<HashMap<String, Value>>>, DeepBookError> {
+) -> Result<Json<Vec<PoolSummary>>, DeepBookError> {
     // Fetch pools metadata first since it's required for other functions
     let pools = state.reader.get_pools().await?;
     let pool_metadata: HashMap<String, (String, (i16, i16))> = pools
@@ -959,22 +1236,6 @@ async fn summary(
 
     for ((pool_name, ticker_info), orderbook_result) in ticker_map.iter().zip(orderbook_results) {
         if let Some((pool_id, _)) = pool_metadata.get(pool_name) {
-            // Extract data from the ticker function response
-            let last_price = ticker_info
-                .get("last_price")
-                .and_then(|price| price.as_f64())
-                .unwrap_or(0.0);
-
-            let base_volume = ticker_info
-                .get("base_volume")
-                .and_then(|volume| volume.as_f64())
-                .unwrap_or(0.0);
-
-            let quote_volume = ticker_info
-                .get("quote_volume")
-                .and_then(|volume| volume.as_f64())
-                .unwrap_or(0.0);
-
             // Fetch the 24-hour price change percent
             let price_change_percent = price_change_map.get(pool_name).copied().unwrap_or(0.0);
 
@@ -985,50 +1246,36 @@ async fn summary(
             // Process the parallel orderbook result
             let orderbook_data = orderbook_result.ok().map(|Json(data)| data);
 
+            let best_price = |levels: &[[String; 2]]| {
+                levels
+                    .get(0)
+                    .and_then(|[price, _]| price.parse::<f64>().ok())
+                    .unwrap_or(0.0)
+            };
             let highest_bid = orderbook_data
                 .as_ref()
-                .and_then(|data| data.get("bids"))
-                .and_then(|bids| bids.as_array())
-                .and_then(|bids| bids.get(0))
-                .and_then(|bid| bid.as_array())
-                .and_then(|bid| bid.get(0))
-                .and_then(|price| price.as_str()?.parse::<f64>().ok())
-                .unwrap_or(0.0);
-
+                .map_or(0.0, |data| best_price(&data.bids));
             let lowest_ask = orderbook_data
                 .as_ref()
-                .and_then(|data| data.get("asks"))
-                .and_then(|asks| asks.as_array())
-                .and_then(|asks| asks.get(0))
-                .and_then(|ask| ask.as_array())
-                .and_then(|ask| ask.get(0))
-                .and_then(|price| price.as_str()?.parse::<f64>().ok())
-                .unwrap_or(0.0);
-
-            let mut summary_data = HashMap::new();
-            summary_data.insert(
-                "trading_pairs".to_string(),
-                Value::String(pool_name.clone()),
-            );
+                .map_or(0.0, |data| best_price(&data.asks));
+
             let parts: Vec<&str> = pool_name.split('_').collect();
             let base_currency = parts.get(0).unwrap_or(&"Unknown").to_string();
             let quote_currency = parts.get(1).unwrap_or(&"Unknown").to_string();
 
-            summary_data.insert("base_currency".to_string(), Value::String(base_currency));
-            summary_data.insert("quote_currency".to_string(), Value::String(quote_currency));
-            summary_data.insert("last_price".to_string(), Value::from(last_price));
-            summary_data.insert("base_volume".to_string(), Value::from(base_volume));
-            summary_data.insert("quote_volume".to_string(), Value::from(quote_volume));
-            summary_data.insert(
-                "price_change_percent_24h".to_string(),
-                Value::from(price_change_percent),
-            );
-            summary_data.insert("highest_price_24h".to_string(), Value::from(highest_price));
-            summary_data.insert("lowest_price_24h".to_string(), Value::from(lowest_price));
-            summary_data.insert("highest_bid".to_string(), Value::from(highest_bid));
-            summary_data.insert("lowest_ask".to_string(), Value::from(lowest_ask));
-
-            response.push(summary_data);
+            response.push(PoolSummary {
+                trading_pairs: pool_name.clone(),
+                base_currency,
+                quote_currency,
+                last_price: ticker_info.last_price,
+                base_volume: ticker_info.base_volume,
+                quote_volume: ticker_info.quote_volume,
+                price_change_percent_24h: price_change_percent,
+                highest_price_24h: highest_price,
+                lowest_price_24h: lowest_price,
+                highest_bid,
+                lowest_ask,
+            });
         }
     }
 
@@ -1125,11 +1372,39 @@ async fn price_change_24h(
     Ok(response)
 }
 
+/// Recently placed or canceled orders in a pool
+#[utoipa::path(
+    get,
+    path = "/order_updates/{pool_name}",
+    tag = "Order Flow",
+    params(
+        ("pool_name" = String, Path, description = "Pool name, such as SUI_USDC"),
+        EventPageParams,
+        (
+            "balance_manager_id" = Option<String>,
+            Query,
+            description = "Only orders of this balance manager"
+        ),
+        (
+            "status" = Option<String>,
+            Query,
+            description = "Only updates with this status, such as Placed or Canceled"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Order updates, newest first",
+            body = [PoolOrderUpdate],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn order_updates(
     Path(pool_name): Path<String>,
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<HashMap<String, Value>>>, DeepBookError> {
+) -> Result<Page<PoolOrderUpdate>, DeepBookError> {
     // Fetch pool data with proper error handling
     let (pool_id, base_decimals, quote_decimals) =
         state.reader.get_pool_decimals(&pool_name).await?;
@@ -1143,17 +1418,19 @@ async fn order_updates(
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
 
     let limit = params.limit();
+    let cursor = params.cursor()?;
 
     let balance_manager_filter = params.get("balance_manager_id").cloned();
     let status_filter = params.get("status").cloned();
 
-    let trades = state
+    let updates = state
         .reader
         .get_order_updates(
             pool_id,
             start_time,
             end_time,
             limit,
+            cursor,
             balance_manager_filter,
             status_filter,
         )
@@ -1162,59 +1439,40 @@ async fn order_updates(
     let base_factor = 10u64.pow(base_decimals as u32);
     let price_factor = 10u64.pow((9 - base_decimals + quote_decimals) as u32);
 
-    let trade_data: Vec<HashMap<String, Value>> = trades
-        .into_iter()
-        .map(
-            |(
-                order_id,
-                price,
-                original_quantity,
-                quantity,
-                filled_quantity,
-                timestamp,
-                is_bid,
-                balance_manager_id,
-                status,
-            )| {
-                let trade_type = if is_bid { "buy" } else { "sell" };
-                HashMap::from([
-                    ("order_id".to_string(), Value::from(order_id)),
-                    (
-                        "price".to_string(),
-                        Value::from(price as f64 / price_factor as f64),
-                    ),
-                    (
-                        "original_quantity".to_string(),
-                        Value::from(original_quantity as f64 / base_factor as f64),
-                    ),
-                    (
-                        "remaining_quantity".to_string(),
-                        Value::from(quantity as f64 / base_factor as f64),
-                    ),
-                    (
-                        "filled_quantity".to_string(),
-                        Value::from(filled_quantity as f64 / base_factor as f64),
-                    ),
-                    ("timestamp".to_string(), Value::from(timestamp as u64)),
-                    ("type".to_string(), Value::from(trade_type)),
-                    (
-                        "balance_manager_id".to_string(),
-                        Value::from(balance_manager_id),
-                    ),
-                    ("status".to_string(), Value::from(status)),
-                ])
-            },
-        )
-        .collect();
-
-    Ok(Json(trade_data))
+    Ok(updates.map_events(|update| {
+        let trade_type = if update.is_bid { "buy" } else { "sell" };
+        PoolOrderUpdate {
+            order_id: update.order_id,
+            price: update.price as f64 / price_factor as f64,
+            original_quantity: update.original_quantity as f64 / base_factor as f64,
+            remaining_quantity: update.quantity as f64 / base_factor as f64,
+            filled_quantity: update.filled_quantity as f64 / base_factor as f64,
+            timestamp: update.checkpoint_timestamp_ms as u64,
+            order_type: trade_type.to_string(),
+            balance_manager_id: update.balance_manager_id,
+            status: update.status.as_ref().to_string(),
+        }
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/orders/{pool_name}/{balance_manager_id}",
+    tag = "Order Flow",
+    params(
+        ("pool_name" = String, Path, description = "Pool name, such as SUI_USDC"),
+        ("balance_manager_id" = String, Path, description = "Balance manager ID"),
+        TimeRangeParams,
+        ("limit" = Option<i64>, Query, description = "Maximum number of results (default: 1000)"),
+        ("status" = Option<String>, Query, description = "Comma-separated statuses to include"),
+    ),
+    responses((status = 200, description = "Orders", body = [BalanceManagerOrder]))
+)]
 async fn orders(
     Path((pool_name, balance_manager_id)): Path<(String, String)>,
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<HashMap<String, Value>>>, DeepBookError> {
+) -> Result<Json<Vec<BalanceManagerOrder>>, DeepBookError> {
     let (pool_id, base_decimals, quote_decimals) =
         state.reader.get_pool_decimals(&pool_name).await?;
     let base_decimals = base_decimals as u8;
@@ -1231,74 +1489,107 @@ async fn orders(
             .collect::<Vec<_>>()
     });
 
-    let end_time = params.end_time();
-    let start_time = params
-        .start_time()
-        .unwrap_or_else(|| end_time - DEFAULT_ORDERS_LOOKBACK_MS);
-
-    let orders = state
-        .reader
-        .get_orders_status(
-            pool_id,
-            limit,
-            Some(balance_manager_id),
-            status_filter,
-            start_time,
-            end_time,
-        )
-        .await?;
+    // Open orders are looked up in `open_orders`, so they are found however long ago they were
+    // placed. Other statuses, explicit time ranges, and deployments where the `open_orders`
+    // pipeline has not committed anything yet go through the order history.
+    let open_only = status_filter.as_ref().is_some_and(|statuses| {
+        statuses
+            .iter()
+            .all(|status| OPEN_ORDER_STATUSES.contains(&status.as_str()))
+    });
+    let from_open_orders = open_only
+        && params.start_time().is_none()
+        && state
+            .reader
+            .get_pipeline_timestamp_ms(OPEN_ORDERS_PIPELINE)
+            .await?
+            .is_some();
+    let orders = if from_open_orders {
+        state
+            .reader
+            .get_open_orders(pool_id, limit, balance_manager_id, status_filter)
+            .await?
+    } else {
+        let end_time = params.end_time();
+        let start_time = params
+            .start_time()
+            .unwrap_or_else(|| end_time - DEFAULT_ORDERS_LOOKBACK_MS);
+        state
+            .reader
+            .get_orders_status(
+                pool_id,
+                limit,
+                Some(balance_manager_id),
+                status_filter,
+                start_time,
+                end_time,
+            )
+            .await?
+    };
 
     let base_factor = 10u64.pow(base_decimals as u32);
     let price_factor = 10u64.pow((9 - base_decimals + quote_decimals) as u32);
 
-    let order_data: Vec<HashMap<String, Value>> = orders
+    let order_data = orders
         .into_iter()
         .map(|order| {
             let order_type = if order.is_bid { "buy" } else { "sell" };
-            HashMap::from([
-                ("order_id".to_string(), Value::from(order.order_id)),
-                (
-                    "balance_manager_id".to_string(),
-                    Value::from(order.balance_manager_id),
-                ),
-                ("type".to_string(), Value::from(order_type)),
-                (
-                    "current_status".to_string(),
-                    Value::from(order.current_status),
-                ),
-                (
-                    "price".to_string(),
-                    Value::from(order.price as f64 / price_factor as f64),
-                ),
-                ("placed_at".to_string(), Value::from(order.placed_at as u64)),
-                (
-                    "last_updated_at".to_string(),
-                    Value::from(order.last_updated_at as u64),
-                ),
-                (
-                    "original_quantity".to_string(),
-                    Value::from(order.original_quantity as f64 / base_factor as f64),
-                ),
-                (
-                    "filled_quantity".to_string(),
-                    Value::from(order.filled_quantity as f64 / base_factor as f64),
-                ),
-                (
-                    "remaining_quantity".to_string(),
-                    Value::from(order.remaining_quantity as f64 / base_factor as f64),
-                ),
-            ])
+            BalanceManagerOrder {
+                order_id: order.order_id,
+                balance_manager_id: order.balance_manager_id,
+                order_type: order_type.to_string(),
+                current_status: order.current_status,
+                price: order.price as f64 / price_factor as f64,
+                placed_at: order.placed_at as u64,
+                last_updated_at: order.last_updated_at as u64,
+                original_quantity: order.original_quantity as f64 / base_factor as f64,
+                filled_quantity: order.filled_quantity as f64 / base_factor as f64,
+                remaining_quantity: order.remaining_quantity as f64 / base_factor as f64,
+            }
         })
         .collect();
 
     Ok(Json(order_data))
 }
 
+/// Recent trades for a pool
+#[utoipa::path(
+    get,
+    path = "/trades/{pool_name}",
+    tag = "Market Data",
+    params(
+        ("pool_name" = String, Path, description = "Pool name, such as SUI_USDC"),
+        EventPageParams,
+        (
+            "maker_balance_manager_id" = Option<String>,
+            Query,
+            description = "Only trades with this maker"
+        ),
+        (
+            "taker_balance_manager_id" = Option<String>,
+            Query,
+            description = "Only trades with this taker"
+        ),
+        (
+            "balance_manager_id" = Option<String>,
+            Query,
+            description = "Only trades with this maker or taker"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Trades, newest first",
+            body = [Trade],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn trades(
     Path(pool_name): Path<String>,
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<HashMap<String, Value>>>, DeepBookError> {
+) -> Result<Page<Trade>, DeepBookError> {
     // Fetch all pools to map names to IDs and decimals
     let (pool_id, base_decimals, quote_decimals) =
         state.reader.get_pool_decimals(&pool_name).await?;
@@ -1310,6 +1601,7 @@ async fn trades(
 
     // Parse limit (default to 1 if not provided)
     let limit = params.limit();
+    let cursor = params.cursor()?;
 
     // Parse optional filters for balance managers
     let maker_balance_manager_filter = params.get("maker_balance_manager_id").cloned();
@@ -1322,11 +1614,11 @@ async fn trades(
     let trades = state
         .reader
         .get_orders(
-            pool_name,
             pool_id,
             start_time,
             end_time,
             limit,
+            cursor,
             maker_balance_manager_filter,
             taker_balance_manager_filter,
             balance_manager_filter,
@@ -1339,109 +1631,66 @@ async fn trades(
     let deep_factor = 10u64.pow(6 as u32);
     let price_factor = 10u64.pow((9 - base_decimals + quote_decimals) as u32);
 
-    // Map trades to JSON format
-    let trade_data = trades
-        .into_iter()
-        .map(
-            |(
-                event_digest,
-                digest,
-                maker_order_id,
-                taker_order_id,
-                maker_client_order_id,
-                taker_client_order_id,
-                price,
-                base_quantity,
-                quote_quantity,
-                timestamp,
-                taker_is_bid,
-                maker_balance_manager_id,
-                taker_balance_manager_id,
-                taker_fee_is_deep,
-                maker_fee_is_deep,
-                taker_fee,
-                maker_fee,
-            )| {
-                let trade_id = calculate_trade_id(&maker_order_id, &taker_order_id).unwrap_or(0);
-                let trade_type = if taker_is_bid { "buy" } else { "sell" };
-
-                // Scale taker_fee based on taker_is_bid and taker_fee_is_deep
-                let scaled_taker_fee = if taker_fee_is_deep {
-                    taker_fee as f64 / deep_factor as f64
-                } else if taker_is_bid {
-                    // taker is buying, fee paid in quote asset
-                    taker_fee as f64 / quote_factor as f64
-                } else {
-                    // taker is selling, fee paid in base asset
-                    taker_fee as f64 / base_factor as f64
-                };
-
-                // Scale maker_fee based on taker_is_bid and maker_fee_is_deep
-                let scaled_maker_fee = if maker_fee_is_deep {
-                    maker_fee as f64 / deep_factor as f64
-                } else if taker_is_bid {
-                    // taker is buying, maker is selling, fee paid in base asset
-                    maker_fee as f64 / base_factor as f64
-                } else {
-                    // taker is selling, maker is buying, fee paid in quote asset
-                    maker_fee as f64 / quote_factor as f64
-                };
-
-                HashMap::from([
-                    ("event_digest".to_string(), Value::from(event_digest)),
-                    ("digest".to_string(), Value::from(digest)),
-                    ("trade_id".to_string(), Value::from(trade_id.to_string())),
-                    ("maker_order_id".to_string(), Value::from(maker_order_id)),
-                    ("taker_order_id".to_string(), Value::from(taker_order_id)),
-                    (
-                        "maker_client_order_id".to_string(),
-                        Value::from(maker_client_order_id.to_string()),
-                    ),
-                    (
-                        "taker_client_order_id".to_string(),
-                        Value::from(taker_client_order_id.to_string()),
-                    ),
-                    (
-                        "maker_balance_manager_id".to_string(),
-                        Value::from(maker_balance_manager_id),
-                    ),
-                    (
-                        "taker_balance_manager_id".to_string(),
-                        Value::from(taker_balance_manager_id),
-                    ),
-                    (
-                        "price".to_string(),
-                        Value::from(price as f64 / price_factor as f64),
-                    ),
-                    (
-                        "base_volume".to_string(),
-                        Value::from(base_quantity as f64 / base_factor as f64),
-                    ),
-                    (
-                        "quote_volume".to_string(),
-                        Value::from(quote_quantity as f64 / quote_factor as f64),
-                    ),
-                    ("timestamp".to_string(), Value::from(timestamp as u64)),
-                    ("type".to_string(), Value::from(trade_type)),
-                    ("taker_is_bid".to_string(), Value::from(taker_is_bid)),
-                    ("taker_fee".to_string(), Value::from(scaled_taker_fee)),
-                    ("maker_fee".to_string(), Value::from(scaled_maker_fee)),
-                    (
-                        "taker_fee_is_deep".to_string(),
-                        Value::from(taker_fee_is_deep),
-                    ),
-                    (
-                        "maker_fee_is_deep".to_string(),
-                        Value::from(maker_fee_is_deep),
-                    ),
-                ])
-            },
-        )
-        .collect();
+    // Map trades to their response format
+    Ok(trades.map_events(|fill| {
+        let trade_id = calculate_trade_id(&fill.maker_order_id, &fill.taker_order_id).unwrap_or(0);
+        let trade_type = if fill.taker_is_bid { "buy" } else { "sell" };
+
+        // Scale taker_fee based on taker_is_bid and taker_fee_is_deep
+        let scaled_taker_fee = if fill.taker_fee_is_deep {
+            fill.taker_fee as f64 / deep_factor as f64
+        } else if fill.taker_is_bid {
+            // taker is buying, fee paid in quote asset
+            fill.taker_fee as f64 / quote_factor as f64
+        } else {
+            // taker is selling, fee paid in base asset
+            fill.taker_fee as f64 / base_factor as f64
+        };
 
-    Ok(Json(trade_data))
+        // Scale maker_fee based on taker_is_bid and maker_fee_is_deep
+        let scaled_maker_fee = if fill.maker_fee_is_deep {
+            fill.maker_fee as f64 / deep_factor as f64
+        } else if fill.taker_is_bid {
+            // taker is buying, maker is selling, fee paid in base asset
+            fill.maker_fee as f64 / base_factor as f64
+        } else {
+            // taker is selling, maker is buying, fee paid in quote asset
+            fill.maker_fee as f64 / quote_factor as f64
+        };
+
+        Trade {
+            event_digest: fill.event_digest,
+            digest: fill.digest,
+            trade_id: trade_id.to_string(),
+            maker_order_id: fill.maker_order_id,
+            taker_order_id: fill.taker_order_id,
+            maker_client_order_id: fill.maker_client_order_id.to_string(),
+            taker_client_order_id: fill.taker_client_order_id.to_string(),
+            maker_balance_manager_id: fill.maker_balance_manager_id,
+            taker_balance_manager_id: fill.taker_balance_manager_id,
+            price: fill.price as f64 / price_factor as f64,
+            base_volume: fill.base_quantity as f64 / base_factor as f64,
+            quote_volume: fill.quote_quantity as f64 / quote_factor as f64,
+            timestamp: fill.checkpoint_timestamp_ms as u64,
+            trade_type: trade_type.to_string(),
+            taker_is_bid: fill.taker_is_bid,
+            taker_fee: scaled_taker_fee,
+            maker_fee: scaled_maker_fee,
+            taker_fee_is_deep: fill.taker_fee_is_deep,
+            maker_fee_is_deep: fill.maker_fee_is_deep,
+        }
+    }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/trade_count",
+    tag = "Market Data",
+    params(
+        TimeRangeParams,
+    ),
+    responses((status = 200, description = "Number of trades", body = i64))
+)]
 async fn trade_count(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
@@ -1477,9 +1726,16 @@ fn calculate_trade_id(maker_id: &str, taker_id: &str) -> Result<u128, DeepBookEr
     Ok(maker_id + taker_id)
 }
 
+/// Asset information for all coins
+#[utoipa::path(
+    get,
+    path = "/assets",
+    tag = "Reference",
+    responses((status = 200, description = "Assets per symbol", body = HashMap<String, AssetInfo>))
+)]
 pub async fn assets(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<HashMap<String, HashMap<String, Value>>>, DeepBookError> {
+) -> Result<Json<HashMap<String, AssetInfo>>, DeepBookError> {
     let query = schema::assets::table.select((
         schema::assets::symbol,
         schema::assets::name,
@@ -1503,41 +1759,57 @@ pub async fn assets(
     let mut response = HashMap::new();
 
     for (symbol, name, ucid, package_address_url, package_id, asset_type) in assets {
-        let mut asset_info = HashMap::new();
-        asset_info.insert("name".to_string(), Value::String(name));
-        asset_info.insert("asset_type".to_string(), Value::String(asset_type));
-        asset_info.insert(
-            "can_withdraw".to_string(),
-            Value::String("true".to_string()),
+        response.insert(
+            symbol,
+            AssetInfo {
+                name,
+                asset_type,
+                can_withdraw: "true".to_string(),
+                can_deposit: "true".to_string(),
+                unified_cryptoasset_id: ucid.map(|ucid| ucid.to_string()),
+                contract_address_url: package_address_url,
+                contract_address: package_id,
+            },
         );
-        asset_info.insert("can_deposit".to_string(), Value::String("true".to_string()));
-
-        if let Some(ucid) = ucid {
-            asset_info.insert(
-                "unified_cryptoasset_id".to_string(),
-                Value::String(ucid.to_string()),
-            );
-        }
-        if let Some(addresses) = package_address_url {
-            asset_info.insert("contractAddressUrl".to_string(), Value::String(addresses));
-        }
-
-        if let Some(addresses) = package_id {
-            asset_info.insert("contractAddress".to_string(), Value::String(addresses));
-        }
-
-        response.insert(symbol, asset_info);
     }
 
     Ok(Json(response))
 }
 
 /// Level2 data for all pools
+#[utoipa::path(
+    get,
+    path = "/orderbook/{pool_name}",
+    tag = "Market Data",
+    params(
+        ("pool_name" = String, Path, description = "Pool name, such as SUI_USDC"),
+        (
+            "depth" = Option<u64>,
+            Query,
+            description = "Number of price levels on both sides together, 0 for the whole book"
+        ),
+        (
+            "level" = Option<u64>,
+            Query,
+            description = "1 for the best bid and ask, 2 for the levels within depth (default: 2)"
+        ),
+        (
+            "source" = Option<String>,
+            Query,
+            description = "db (default) for the indexed levels, or rpc for a full node read. \
+                The default falls back to rpc until the levels have been indexed"
+        ),
+    ),
+    responses(
+        (status = 200, description = "Order book snapshot", body = Orderbook),
+        (status = 503, description = "source=db, but no levels have been indexed yet"),
+    )
+)]
 async fn orderbook(
     Path(pool_name): Path<String>,
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<HashMap<String, Value>>, DeepBookError> {
+) -> Result<Json<Orderbook>, DeepBookError> {
     let depth = params
         .get("depth")
         .map(|v| v.parse::<u64>())
@@ -1588,89 +1860,133 @@ async fn orderbook(
     let base_decimals = base_decimals as u8;
     let quote_decimals = quote_decimals as u8;
 
-    let client = state.sui_client();
-    let initial_shared_version = crate::grpc::initial_shared_version(client, &pool_id)
-        .await
-        .map_err(|e| DeepBookError::rpc(format!("Pool '{}': {}", pool_name, e)))?;
+    let source = params.get("source").map(String::as_str);
+    let indexed = match source {
+        None | Some("db") => level2_from_db(&state, &pool_id, ticks_from_mid).await?,
+        Some("rpc") => None,
+        Some(_) => return Err(DeepBookError::bad_request("Source must be db or rpc")),
+    };
+    let (timestamp, bid_levels, ask_levels) = match indexed {
+        Some(level2) => level2,
+        None if source == Some("db") => {
+            return Err(DeepBookError::unavailable(
+                "The open_orders pipeline has not indexed any checkpoint yet; use source=rpc",
+            ))
+        }
+        None => {
+            level2_from_rpc(
+                &state,
+                &pool_name,
+                &pool_id,
+                &base_asset_id,
+                &quote_asset_id,
+                ticks_from_mid,
+            )
+            .await?
+        }
+    };
 
-    let mut ptb = crate::grpc::read_only_tx();
-    let pool = ptb.object(crate::grpc::shared_input(&pool_id, initial_shared_version)?);
-    let ticks = ptb.pure(&ticks_from_mid);
-    let clock = ptb.object(crate::grpc::clock_input());
+    let price_factor = 10u64.pow((9 - base_decimals + quote_decimals).into());
+    let quantity_factor = 10u64.pow((base_decimals).into());
+    let to_values = |levels: Vec<(u64, u64)>| -> Vec<[String; 2]> {
+        levels
+            .into_iter()
+            .take(ticks_from_mid as usize)
+            .map(|(price, quantity)| {
+                [
+                    (price as f64 / price_factor as f64).to_string(),
+                    (quantity as f64 / quantity_factor as f64).to_string(),
+                ]
+            })
+            .collect()
+    };
 
-    let base_coin_type = parse_type_input(&base_asset_id)?;
-    let quote_coin_type = parse_type_input(&quote_asset_id)?;
+    Ok(Json(Orderbook {
+        timestamp: timestamp.to_string(),
+        bids: to_values(bid_levels),
+        asks: to_values(ask_levels),
+    }))
+}
 
-    ptb.move_call(
-        crate::grpc::function(
-            &state.deepbook_package_id,
-            LEVEL2_MODULE,
-            LEVEL2_FUNCTION,
-            vec![base_coin_type, quote_coin_type],
-        )?,
-        vec![pool, ticks, clock],
+/// Price levels of a pool as `(price, quantity)`, best first on each side, with the time they
+/// are current as of
+type Level2 = (i64, Vec<(u64, u64)>, Vec<(u64, u64)>);
+
+/// Level 2 book from `order_book_levels`, current as of the last checkpoint indexed by the
+/// `open_orders` pipeline, without orders that are past their expiry. `None` until the pipeline
+/// has indexed a checkpoint.
+async fn level2_from_db(
+    state: &AppState,
+    pool_id: &str,
+    ticks_from_mid: u64,
+) -> Result<Option<Level2>, DeepBookError> {
+    // Without a watermark the pipeline has not indexed anything, so the levels are not a book
+    let Some(timestamp) = state
+        .reader
+        .get_pipeline_timestamp_ms(OPEN_ORDERS_PIPELINE)
+        .await?
+    else {
+        return Ok(None);
+    };
+    let limit = ticks_from_mid as i64;
+    let now_ms = current_time_ms();
+    let (bids, asks) = join!(
+        state
+            .reader
+            .get_order_book_levels(pool_id, true, limit, now_ms),
+        state
+            .reader
+            .get_order_book_levels(pool_id, false, limit, now_ms),
     );
-
-    let results = crate::grpc::simulate_returns(client, ptb).await?;
-
-    // One command returning four vectors: bid prices, bid quantities, ask prices, ask quantities.
-    let level2 = results
-        .first()
-        .ok_or(DeepBookError::rpc("No return values for level2 ticks"))?;
-    let decode = |index: usize, what: &str| -> Result<Vec<u64>, DeepBookError> {
-        let bytes = level2
-            .get(index)
-            .ok_or_else(|| DeepBookError::rpc(format!("No {} data found", what)))?;
-        bcs::from_bytes(bytes)
-            .map_err(|_| DeepBookError::deserialization(format!("Failed to deserialize {}", what)))
+    let to_levels = |levels: Vec<OrderBookLevel>| -> Vec<(u64, u64)> {
+        levels
+            .into_iter()
+            .map(|level| (level.price as u64, level.quantity as u64))
+            .collect()
     };
-    let bid_parsed_prices = decode(0, "bid prices")?;
-    let bid_parsed_quantities = decode(1, "bid quantities")?;
-    let ask_parsed_prices = decode(2, "ask prices")?;
-    let ask_parsed_quantities = decode(3, "ask quantities")?;
+    Ok(Some((timestamp, to_levels(bids?), to_levels(asks?))))
+}
+
+/// Level 2 book from a `get_level2_ticks_from_mid` simulate call against a full node
+async fn level2_from_rpc(
+    state: &AppState,
+    pool_name: &str,
+    pool_id: &str,
+    base_asset_id: &str,
+    quote_asset_id: &str,
+    ticks_from_mid: u64,
+) -> Result<Level2, DeepBookError> {
+    let client = state.sui_client();
+    let initial_shared_version = crate::grpc::initial_shared_version(client, pool_id)
+        .await
+        .map_err(|e| DeepBookError::rpc(format!("Pool '{}': {}", pool_name, e)))?;
 
-    let mut result = HashMap::new();
+    let (bids, asks) = crate::order_book::level2_ticks_from_mid(
+        client,
+        &state.deepbook_package_id,
+        pool_id,
+        initial_shared_version,
+        base_asset_id,
+        quote_asset_id,
+        ticks_from_mid,
+    )
+    .await?;
 
     let timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map_err(|_| DeepBookError::internal("System time error"))?
         .as_millis() as i64;
-    result.insert("timestamp".to_string(), Value::from(timestamp.to_string()));
-
-    let bids: Vec<Value> = bid_parsed_prices
-        .into_iter()
-        .zip(bid_parsed_quantities.into_iter())
-        .take(ticks_from_mid as usize)
-        .map(|(price, quantity)| {
-            let price_factor = 10u64.pow((9 - base_decimals + quote_decimals).into());
-            let quantity_factor = 10u64.pow((base_decimals).into());
-            Value::Array(vec![
-                Value::from((price as f64 / price_factor as f64).to_string()),
-                Value::from((quantity as f64 / quantity_factor as f64).to_string()),
-            ])
-        })
-        .collect();
-    result.insert("bids".to_string(), Value::Array(bids));
-
-    let asks: Vec<Value> = ask_parsed_prices
-        .into_iter()
-        .zip(ask_parsed_quantities.into_iter())
-        .take(ticks_from_mid as usize)
-        .map(|(price, quantity)| {
-            let price_factor = 10u64.pow((9 - base_decimals + quote_decimals).into());
-            let quantity_factor = 10u64.pow((base_decimals).into());
-            Value::Array(vec![
-                Value::from((price as f64 / price_factor as f64).to_string()),
-                Value::from((quantity as f64 / quantity_factor as f64).to_string()),
-            ])
-        })
-        .collect();
-    result.insert("asks".to_string(), Value::Array(asks));
 
-    Ok(Json(result))
+    Ok((timestamp, bids, asks))
 }
 
 /// DEEP total supply
+#[utoipa::path(
+    get,
+    path = "/deep_supply",
+    tag = "Reference",
+    responses((status = 200, description = "DEEP total supply, in on-chain units", body = u64))
+)]
 async fn deep_supply(State(state): State<Arc<AppState>>) -> Result<Json<u64>, DeepBookError> {
     let client = state.sui_client();
     let initial_shared_version =
@@ -1705,15 +2021,19 @@ async fn deep_supply(State(state): State<Arc<AppState>>) -> Result<Json<u64>, De
     Ok(Json(total_supply_value))
 }
 
-#[derive(serde::Serialize)]
-struct PoolFees {
-    pool_id: String,
-    taker_fee: f64,
-    maker_fee: f64,
-    stake_required: f64,
-}
-
 /// Returns maker_fee, taker_fee, and stake_required for all pools via a single PTB
+#[utoipa::path(
+    get,
+    path = "/fees",
+    tag = "Pools",
+    responses(
+        (
+            status = 200,
+            description = "Trade params per pool name",
+            body = HashMap<String, PoolFees>
+        )
+    )
+)]
 async fn fees(
     State(state): State<Arc<AppState>>,
 ) -> Result<Json<HashMap<String, PoolFees>>, DeepBookError> {
@@ -1805,6 +2125,18 @@ async fn fees(
 }
 
 /// Get total supply for all margin pools
+#[utoipa::path(
+    get,
+    path = "/margin_supply",
+    tag = "Margin",
+    responses(
+        (
+            status = 200,
+            description = "Total supply per margin pool asset, in on-chain units",
+            body = HashMap<String, u64>
+        )
+    )
+)]
 async fn margin_supply(
     State(state): State<Arc<AppState>>,
 ) -> Result<Json<HashMap<String, u64>>, DeepBookError> {
@@ -1880,6 +2212,22 @@ async fn margin_supply(
     Ok(Json(result))
 }
 
+#[utoipa::path(
+    get,
+    path = "/get_net_deposits/{asset_ids}/{timestamp}",
+    tag = "Volume",
+    params(
+        ("asset_ids" = String, Path, description = "Comma-separated asset types"),
+        ("timestamp" = i64, Path, description = "Unix seconds"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Net deposits per asset type, in on-chain units",
+            body = HashMap<String, i64>
+        )
+    )
+)]
 async fn get_net_deposits(
     Path((asset_ids, timestamp)): Path<(String, String)>,
     State(state): State<Arc<AppState>>,
@@ -1899,22 +2247,652 @@ async fn get_net_deposits(
     Ok(Json(net_deposits))
 }
 
+/// Net deposits of each asset into a balance manager, from the incrementally maintained
+/// `balance_manager_balances` table
+#[utoipa::path(
+    get,
+    path = "/balance_managers/{balance_manager_id}/balances",
+    tag = "Order Flow",
+    params(
+        ("balance_manager_id" = String, Path, description = "Balance manager ID"),
+    ),
+    responses(
+        (status = 200, description = "Net deposits per asset", body = [BalanceManagerBalance])
+    )
+)]
+async fn balance_manager_balances(
+    Path(balance_manager_id): Path<String>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<BalanceManagerBalance>>, DeepBookError> {
+    Ok(Json(
+        state
+            .reader
+            .get_balance_manager_balances(&balance_manager_id)
+            .await?,
+    ))
+}
+
+/// The `interval` (`1h` or `1d`, default `1d`) and `[start_time, end_time)` window, in seconds,
+/// of a trader volume query. Without a start time, the window covers the last day of hourly
+/// buckets or the last 30 daily ones.
+fn trader_volume_window(
+    params: &HashMap<String, String>,
+) -> Result<(TraderVolumeInterval, i64, i64), DeepBookError> {
+    let interval = params.get("interval").map(String::as_str).unwrap_or("1d");
+    let interval = TraderVolumeInterval::parse(interval)
+        .ok_or_else(|| DeepBookError::bad_request("interval must be one of: 1h, 1d"))?;
+    let end_time = params.end_time();
+    let start_time = params.start_time().unwrap_or_else(|| {
+        end_time
+            - match interval {
+                TraderVolumeInterval::Hour => TRADER_VOLUME_HOURLY_LOOKBACK_MS,
+                TraderVolumeInterval::Day => TRADER_VOLUME_DAILY_LOOKBACK_MS,
+            }
+    });
+    Ok((interval, start_time, end_time))
+}
+
+/// The `limit` query parameter, `default` without one and at most `max`
+fn trader_volume_limit(
+    params: &HashMap<String, String>,
+    default: i64,
+    max: i64,
+) -> Result<i64, DeepBookError> {
+    let Some(limit) = params.get("limit") else {
+        return Ok(default);
+    };
+    match limit.parse::<i64>() {
+        Ok(limit) if limit >= 0 => Ok(limit.min(max)),
+        _ => Err(DeepBookError::bad_request(
+            "limit must be a non-negative integer",
+        )),
+    }
+}
+
+/// Hourly or daily trading of a balance manager per pool: maker and taker volume, trade counts
+/// and fees paid in DEEP, base and quote, all in on-chain units. `pool_names` restricts it to
+/// some pools.
+#[utoipa::path(
+    get,
+    path = "/trader_volume/{balance_manager_id}",
+    tag = "Volume",
+    params(
+        ("balance_manager_id" = String, Path, description = "Balance manager ID"),
+        TimeRangeParams,
+        ("interval" = Option<String>, Query, description = "Bucket size, 1h or 1d (default: 1d)"),
+        (
+            "pool_names" = Option<String>,
+            Query,
+            description = "Comma-separated pool names to include"
+        ),
+        (
+            "limit" = Option<i64>,
+            Query,
+            description = "Maximum number of buckets (default: 1000, at most 10000)"
+        ),
+    ),
+    responses((status = 200, description = "Volume per bucket and pool", body = [TraderVolume]))
+)]
+async fn trader_volume(
+    Path(balance_manager_id): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<TraderVolume>>, DeepBookError> {
+    let (interval, start_time, end_time) = trader_volume_window(&params)?;
+    let pool_ids = match params.get("pool_names") {
+        Some(pool_names) => {
+            let pool_name_to_id: HashMap<String, String> = state
+                .reader
+                .get_pools()
+                .await?
+                .into_iter()
+                .map(|pool| (pool.pool_name, pool.pool_id))
+                .collect();
+            let pool_ids: Vec<String> = pool_names
+                .split(',')
+                .filter_map(|name| pool_name_to_id.get(name).cloned())
+                .collect();
+            if pool_ids.is_empty() {
+                return Err(DeepBookError::bad_request("No valid pool names provided"));
+            }
+            Some(pool_ids)
+        }
+        None => None,
+    };
+    let limit = trader_volume_limit(
+        &params,
+        TRADER_VOLUME_DEFAULT_LIMIT,
+        TRADER_VOLUME_MAX_LIMIT,
+    )?;
+
+    Ok(Json(
+        state
+            .reader
+            .get_trader_volume(
+                interval,
+                &balance_manager_id,
+                pool_ids,
+                start_time,
+                end_time,
+                limit,
+            )
+            .await?,
+    ))
+}
+
+/// Balance managers with the most quote volume in a pool over a window, with their trading
+/// totals
+#[utoipa::path(
+    get,
+    path = "/trader_volume_leaderboard/{pool_name}",
+    tag = "Volume",
+    params(
+        ("pool_name" = String, Path, description = "Pool name, such as SUI_USDC"),
+        TimeRangeParams,
+        ("interval" = Option<String>, Query, description = "Bucket size, 1h or 1d (default: 1d)"),
+        (
+            "limit" = Option<i64>,
+            Query,
+            description = "Maximum number of balance managers (default: 100, at most 1000)"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Balance managers, most quote volume first",
+            body = [TraderVolumeTotal]
+        )
+    )
+)]
+async fn trader_volume_leaderboard(
+    Path(pool_name): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<TraderVolumeTotal>>, DeepBookError> {
+    let (interval, start_time, end_time) = trader_volume_window(&params)?;
+    let (pool_id, _, _) = state.reader.get_pool_decimals(&pool_name).await?;
+    let limit = trader_volume_limit(
+        &params,
+        TRADER_VOLUME_LEADERBOARD_DEFAULT_LIMIT,
+        TRADER_VOLUME_LEADERBOARD_MAX_LIMIT,
+    )?;
+
+    Ok(Json(
+        state
+            .reader
+            .get_trader_volume_leaderboard(interval, &pool_id, start_time, end_time, limit)
+            .await?,
+    ))
+}
+
+/// The `epoch` query parameter, if given
+fn governance_epoch(params: &HashMap<String, String>) -> Result<Option<i64>, DeepBookError> {
+    params
+        .get("epoch")
+        .map(|epoch| {
+            epoch
+                .parse::<i64>()
+                .map_err(|_| DeepBookError::bad_request("epoch must be an integer"))
+        })
+        .transpose()
+}
+
+/// Stake, proposals and vote tallies of a pool in `epoch` (default: the latest epoch with
+/// governance activity), with the proposal that took effect once the epoch is over
+#[utoipa::path(
+    get,
+    path = "/governance/{pool_name}",
+    tag = "Governance",
+    params(
+        ("pool_name" = String, Path, description = "Pool name, such as SUI_USDC"),
+        (
+            "epoch" = Option<i64>,
+            Query,
+            description = "Epoch (default: the latest epoch with governance activity)"
+        ),
+    ),
+    responses((status = 200, description = "Governance of the epoch", body = EpochGovernance))
+)]
+async fn governance(
+    Path(pool_name): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<EpochGovernance>, DeepBookError> {
+    let epoch = governance_epoch(&params)?;
+    let (pool_id, _, _) = state.reader.get_pool_decimals(&pool_name).await?;
+    Ok(Json(
+        crate::governance::epoch_governance(&state.reader, &pool_id, &pool_name, epoch).await?,
+    ))
+}
+
+/// A balance manager's stake in a pool and whether the proposal it voted for is winning
+#[utoipa::path(
+    get,
+    path = "/governance/{pool_name}/{balance_manager_id}",
+    tag = "Governance",
+    params(
+        ("pool_name" = String, Path, description = "Pool name, such as SUI_USDC"),
+        ("balance_manager_id" = String, Path, description = "Balance manager ID"),
+        (
+            "epoch" = Option<i64>,
+            Query,
+            description = "Epoch (default: the latest epoch with governance activity)"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Governance of the balance manager in the epoch",
+            body = BalanceManagerGovernance
+        )
+    )
+)]
+async fn balance_manager_governance(
+    Path((pool_name, balance_manager_id)): Path<(String, String)>,
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<BalanceManagerGovernance>, DeepBookError> {
+    let epoch = governance_epoch(&params)?;
+    let (pool_id, _, _) = state.reader.get_pool_decimals(&pool_name).await?;
+    Ok(Json(
+        crate::governance::balance_manager_governance(
+            &state.reader,
+            &pool_id,
+            &pool_name,
+            &balance_manager_id,
+            epoch,
+        )
+        .await?,
+    ))
+}
+
+/// Trading rebates a balance manager has claimed per pool, with the referral objects its trades
+/// pay referral fees to in each pool.
+///
+/// Rebates are only reported on chain when claimed, so `outstanding_*` is always 0 here: this
+/// does not show the rebates a balance manager could still claim. Referral objects are listed
+/// without a pool until they earn referral fees, and since unsetting a referral does not record
+/// its pool, referrals set before one are flagged `possibly_unset`.
+#[utoipa::path(
+    get,
+    path = "/rewards/{balance_manager_id}",
+    tag = "Rewards",
+    params(
+        ("balance_manager_id" = String, Path, description = "Balance manager ID"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Claimed rebates per pool, with no outstanding rebates, and referrals",
+            body = RewardsQueryResult
+        )
+    )
+)]
+async fn rewards(
+    Path(balance_manager_id): Path<String>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<RewardsQueryResult>, DeepBookError> {
+    let rewards = state
+        .reader
+        .get_rewards(RewardAccountKind::BalanceManager, &balance_manager_id)
+        .await?;
+    let referrals = state
+        .reader
+        .get_balance_manager_referrals(&balance_manager_id)
+        .await?;
+    Ok(Json(RewardsQueryResult {
+        account_id: balance_manager_id,
+        referrals,
+        rewards,
+    }))
+}
+
+/// Referral fees a referral object has earned, claimed and is still owed per pool
+#[utoipa::path(
+    get,
+    path = "/referral_rewards/{referral_id}",
+    tag = "Rewards",
+    params(
+        ("referral_id" = String, Path, description = "Referral object ID"),
+    ),
+    responses((status = 200, description = "Rewards per pool", body = RewardsQueryResult))
+)]
+async fn referral_rewards(
+    Path(referral_id): Path<String>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<RewardsQueryResult>, DeepBookError> {
+    let rewards = state
+        .reader
+        .get_rewards(RewardAccountKind::Referral, &referral_id)
+        .await?;
+    Ok(Json(RewardsQueryResult {
+        account_id: referral_id,
+        referrals: vec![],
+        rewards,
+    }))
+}
+
+/// `balance_manager_id`'s order updates and fills as server-sent events, as they are indexed. A
+/// `Last-Event-ID` header resumes after the event it names.
+async fn order_stream(
+    Path(balance_manager_id): Path<String>,
+    headers: HeaderMap,
+    State(state): State<Arc<AppState>>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, DeepBookError> {
+    let resume_from = headers
+        .get("last-event-id")
+        .map(|value| {
+            value
+                .to_str()
+                .map_err(|_| DeepBookError::bad_request("Invalid Last-Event-ID header"))?
+                .parse::<EventCursor>()
+        })
+        .transpose()?;
+    let stream = order_stream::order_stream(
+        state.reader.clone(),
+        state.commit_notifications.subscribe(),
+        balance_manager_id,
+        resume_from,
+    );
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+/// Live trades, order updates, 1m candles and tickers, for the channels and pools a client
+/// subscribes to. See [`market_feed`] for the protocol.
+async fn ws(ws: WebSocketUpgrade, State(state): State<Arc<AppState>>) -> Response {
+    let feed = state.market_feed.clone();
+    let reader = state.reader.clone();
+    ws.on_upgrade(move |socket| market_feed::serve_socket(socket, feed, reader))
+}
+
+/// Accounts owned by `owner`, authorized apps and the session keys that can currently trade
+/// on the owner's behalf.
+#[utoipa::path(
+    get,
+    path = "/accounts/{owner}",
+    tag = "Accounts",
+    params(
+        ("owner" = String, Path, description = "Owner address"),
+    ),
+    responses(
+        (status = 200, description = "Accounts, apps and sessions", body = AccountsQueryResult)
+    )
+)]
+async fn accounts(
+    Path(owner): Path<String>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<AccountsQueryResult>, DeepBookError> {
+    let result = state.reader.get_accounts(&owner, current_time_ms()).await?;
+    Ok(Json(result))
+}
+
+#[utoipa::path(
+    get,
+    path = "/pool_created",
+    tag = "Pools",
+    params(PageParams),
+    responses(
+        (
+            status = 200,
+            description = "Pool creation events, newest first",
+            body = [PoolCreated],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn pool_created(
+    Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<PoolCreated>>, DeepBookError> {
-    Ok(Json(state.reader.get_pool_created().await?))
+) -> Result<Page<PoolCreated>, DeepBookError> {
+    let limit = params.limit_or(i64::MAX);
+    let cursor = params.cursor()?;
+    state.reader.get_pool_created(limit, cursor).await
 }
 
+#[utoipa::path(
+    get,
+    path = "/book_params_updated",
+    tag = "Pools",
+    params(
+        ("pool_id" = String, Query, description = "Pool ID"),
+        (
+            "cursor" = Option<String>,
+            Query,
+            description = "`x-next-cursor` header of a response, for the update before its own"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Latest book params of the pool, or null",
+            body = BookParamsUpdated,
+            headers(("x-next-cursor" = String, description = "Cursor of the previous update"))
+        )
+    )
+)]
 async fn book_params_updated(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Option<BookParamsUpdated>>, DeepBookError> {
+) -> Result<Latest<BookParamsUpdated>, DeepBookError> {
     let pool_id = params
         .get("pool_id")
         .cloned()
         .filter(|s| !s.is_empty())
         .ok_or_else(|| DeepBookError::bad_request("pool_id is required"))?;
-    Ok(Json(state.reader.get_book_params_updated(pool_id).await?))
+    let cursor = params.cursor()?;
+    Ok(Latest(
+        state
+            .reader
+            .get_book_params_updated(pool_id, cursor)
+            .await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/propbook/oracle_sources",
+    tag = "Propbook",
+    responses(
+        (status = 200, description = "Oracle sources", body = [PropbookOracleSourceRegistered])
+    )
+)]
+async fn propbook_oracle_sources(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<PropbookOracleSourceRegistered>>, DeepBookError> {
+    Ok(Json(state.reader.get_propbook_oracle_sources().await?))
+}
+
+#[utoipa::path(
+    get,
+    path = "/propbook/oracle_bound",
+    tag = "Propbook",
+    params(PageParams),
+    responses(
+        (
+            status = 200,
+            description = "Oracle bound events, newest first",
+            body = [PropbookOracleBound],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
+async fn propbook_oracle_bound(
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Page<PropbookOracleBound>, DeepBookError> {
+    let limit = params.limit_or(i64::MAX);
+    let cursor = params.cursor()?;
+    state.reader.get_propbook_oracle_bound(limit, cursor).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/propbook/oracle_rebound",
+    tag = "Propbook",
+    params(PageParams),
+    responses(
+        (
+            status = 200,
+            description = "Oracle rebound events, newest first",
+            body = [PropbookOracleRebound],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
+async fn propbook_oracle_rebound(
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Page<PropbookOracleRebound>, DeepBookError> {
+    let limit = params.limit_or(i64::MAX);
+    let cursor = params.cursor()?;
+    state
+        .reader
+        .get_propbook_oracle_rebound(limit, cursor)
+        .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/propbook/block_scholes_stores",
+    tag = "Propbook",
+    responses(
+        (
+            status = 200,
+            description = "Block Scholes stores",
+            body = [PropbookBlockScholesStoresRegistered]
+        )
+    )
+)]
+async fn propbook_block_scholes_stores(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<PropbookBlockScholesStoresRegistered>>, DeepBookError> {
+    Ok(Json(
+        state.reader.get_propbook_block_scholes_stores().await?,
+    ))
+}
+
+/// Pyth spot observations for a propbook oracle with source timestamps in the requested range,
+/// newest first.
+#[utoipa::path(
+    get,
+    path = "/propbook/oracle_observations/{propbook_oracle_id}",
+    tag = "Propbook",
+    params(
+        ("propbook_oracle_id" = String, Path, description = "Propbook oracle ID"),
+        EventPageParams,
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Observations, newest first",
+            body = [PropbookOracleObservation],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
+async fn propbook_oracle_observations(
+    Path(propbook_oracle_id): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Page<PropbookOracleObservation>, DeepBookError> {
+    let end_time = params.end_time();
+    let start_time = params
+        .start_time()
+        .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
+    let limit = params.limit();
+    let cursor = params.cursor()?;
+
+    let results = state
+        .reader
+        .get_propbook_oracle_observations(propbook_oracle_id, start_time, end_time, limit, cursor)
+        .await?;
+
+    Ok(results)
+}
+
+/// Block Scholes spot/forward/SVI observations for a propbook oracle with model timestamps in the
+/// requested range, newest first. Optionally filtered to one series with `sid`.
+#[utoipa::path(
+    get,
+    path = "/propbook/block_scholes_observations/{propbook_oracle_id}",
+    tag = "Propbook",
+    params(
+        ("propbook_oracle_id" = String, Path, description = "Propbook oracle ID"),
+        EventPageParams,
+        ("sid" = Option<String>, Query, description = "Only observations of this series"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Observations, newest first",
+            body = [PropbookBlockScholesObservation],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
+async fn propbook_block_scholes_observations(
+    Path(propbook_oracle_id): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Page<PropbookBlockScholesObservation>, DeepBookError> {
+    let end_time = params.end_time();
+    let start_time = params
+        .start_time()
+        .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
+    let limit = params.limit();
+    let cursor = params.cursor()?;
+    let sid_filter = params.get("sid").cloned().unwrap_or_default();
+
+    let results = state
+        .reader
+        .get_propbook_block_scholes_observations(
+            propbook_oracle_id,
+            start_time,
+            end_time,
+            limit,
+            cursor,
+            sid_filter,
+        )
+        .await?;
+
+    Ok(results)
+}
+
+#[utoipa::path(
+    get,
+    path = "/propbook/block_scholes_batches/{propbook_oracle_id}",
+    tag = "Propbook",
+    params(
+        ("propbook_oracle_id" = String, Path, description = "Propbook oracle ID"),
+        EventPageParams,
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Batches, newest first",
+            body = [PropbookBlockScholesBatchIngested],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
+async fn propbook_block_scholes_batches(
+    Path(propbook_oracle_id): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Page<PropbookBlockScholesBatchIngested>, DeepBookError> {
+    let end_time = params.end_time();
+    let start_time = params
+        .start_time()
+        .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
+    let limit = params.limit();
+    let cursor = params.cursor()?;
+
+    let results = state
+        .reader
+        .get_propbook_block_scholes_batches(propbook_oracle_id, start_time, end_time, limit, cursor)
+        .await?;
+
+    Ok(results)
 }
 
 fn parse_type_input(type_str: &str) -> Result<TypeTag, DeepBookError> {
@@ -1928,6 +2906,8 @@ trait ParameterUtil {
     fn volume_in_base(&self) -> bool;
 
     fn limit(&self) -> i64;
+    fn limit_or(&self, default: i64) -> i64;
+    fn cursor(&self) -> Result<Option<EventCursor>, DeepBookError>;
 }
 
 impl ParameterUtil for HashMap<String, String> {
@@ -1951,17 +2931,50 @@ impl ParameterUtil for HashMap<String, String> {
     }
 
     fn limit(&self) -> i64 {
+        self.limit_or(1)
+    }
+
+    fn limit_or(&self, default: i64) -> i64 {
         self.get("limit")
             .and_then(|v| v.parse::<i64>().ok())
-            .unwrap_or(1)
+            .unwrap_or(default)
+    }
+
+    fn cursor(&self) -> Result<Option<EventCursor>, DeepBookError> {
+        self.get("cursor").map(|v| v.parse()).transpose()
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/ohclv/{pool_name}",
+    tag = "Market Data",
+    params(
+        ("pool_name" = String, Path, description = "Pool name, such as SUI_USDC"),
+        (
+            "interval" = Option<String>,
+            Query,
+            description = "Candle size: 1m, 5m, 15m, 30m, 1h, 4h, 1d or 1w (default: 1m)"
+        ),
+        (
+            "start_time" = Option<i64>,
+            Query,
+            description = "Start of the range in Unix milliseconds"
+        ),
+        (
+            "end_time" = Option<i64>,
+            Query,
+            description = "End of the range in Unix milliseconds (default: now)"
+        ),
+        ("limit" = Option<i32>, Query, description = "Maximum number of candles"),
+    ),
+    responses((status = 200, description = "Candles", body = Ohclv))
+)]
 async fn ohclv(
     Path(pool_name): Path<String>,
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<HashMap<String, Value>>, DeepBookError> {
+) -> Result<Json<Ohclv>, DeepBookError> {
     let pools = state.reader.get_pools().await?;
     let pool = pools
         .iter()
@@ -2008,56 +3021,94 @@ async fn ohclv(
         limit,
         candles,
     );
-    let candles_array: Vec<Value> = candles
+    let candles = candles
         .into_iter()
         .map(|candle| {
-            Value::Array(vec![
-                Value::from(candle.timestamp_ms),
-                Value::from(candle.open),
-                Value::from(candle.high),
-                Value::from(candle.low),
-                Value::from(candle.close),
-                Value::from(candle.base_volume),
-            ])
+            (
+                candle.timestamp_ms,
+                candle.open,
+                candle.high,
+                candle.low,
+                candle.close,
+                candle.base_volume,
+            )
         })
         .collect();
 
-    let mut response = HashMap::new();
-    response.insert("candles".to_string(), Value::Array(candles_array));
-
-    Ok(Json(response))
+    Ok(Json(Ohclv { candles }))
 }
 
 // === Margin Manager Events Handlers ===
+#[utoipa::path(
+    get,
+    path = "/margin_manager_created",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("owner" = Option<String>, Query, description = "Only margin managers of this owner"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [MarginManagerCreated],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn margin_manager_created(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<MarginManagerCreated>>, DeepBookError> {
+) -> Result<Page<MarginManagerCreated>, DeepBookError> {
     let start_time = params.start_time();
     let end_time = params
         .get("end_time")
         .and_then(|v| v.parse::<i64>().ok())
         .map(|t| t * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let owner_filter = params.get("owner").cloned();
 
     let results = state
         .reader
-        .get_margin_manager_created(start_time, end_time, limit, owner_filter)
+        .get_margin_manager_created(start_time, end_time, limit, cursor, owner_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/loan_borrowed",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        (
+            "margin_manager_id" = Option<String>,
+            Query,
+            description = "Only events of this margin manager"
+        ),
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [LoanBorrowed],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn loan_borrowed(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<LoanBorrowed>>, DeepBookError> {
+) -> Result<Page<LoanBorrowed>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_manager_id_filter = params.get("margin_manager_id").cloned().unwrap_or_default();
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
 
@@ -2067,23 +3118,47 @@ async fn loan_borrowed(
             start_time,
             end_time,
             limit,
+            cursor,
             margin_manager_id_filter,
             margin_pool_id_filter,
         )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/loan_repaid",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        (
+            "margin_manager_id" = Option<String>,
+            Query,
+            description = "Only events of this margin manager"
+        ),
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [LoanRepaid],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn loan_repaid(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<LoanRepaid>>, DeepBookError> {
+) -> Result<Page<LoanRepaid>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_manager_id_filter = params.get("margin_manager_id").cloned().unwrap_or_default();
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
 
@@ -2093,23 +3168,47 @@ async fn loan_repaid(
             start_time,
             end_time,
             limit,
+            cursor,
             margin_manager_id_filter,
             margin_pool_id_filter,
         )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/liquidation",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        (
+            "margin_manager_id" = Option<String>,
+            Query,
+            description = "Only events of this margin manager"
+        ),
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [Liquidation],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn liquidation(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<Liquidation>>, DeepBookError> {
+) -> Result<Page<Liquidation>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_manager_id_filter = params.get("margin_manager_id").cloned().unwrap_or_default();
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
 
@@ -2119,24 +3218,72 @@ async fn liquidation(
             start_time,
             end_time,
             limit,
+            cursor,
             margin_manager_id_filter,
             margin_pool_id_filter,
         )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
 // === Margin Pool Operations Events Handlers ===
+/// Liquidation vault PnL per DeepBook pool. Defaults to all time; `vault_id` narrows the summary
+/// to a single vault.
+#[utoipa::path(
+    get,
+    path = "/liquidation_vault_pnl",
+    tag = "Margin",
+    params(
+        TimeRangeParams,
+        ("vault_id" = Option<String>, Query, description = "Only liquidations by this vault"),
+    ),
+    responses((status = 200, description = "PnL per pool", body = [LiquidationVaultPnl]))
+)]
+async fn liquidation_vault_pnl(
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<LiquidationVaultPnl>>, DeepBookError> {
+    let end_time = params.end_time();
+    let start_time = params.start_time().unwrap_or(0);
+    let vault_id_filter = params.get("vault_id").cloned().unwrap_or_default();
+
+    let results = state
+        .reader
+        .get_liquidation_vault_pnl(start_time, end_time, vault_id_filter)
+        .await?;
+
+    Ok(Json(results))
+}
+
+#[utoipa::path(
+    get,
+    path = "/asset_supplied",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+        ("supplier" = Option<String>, Query, description = "Only supplies by this supplier"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [AssetSupplied],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn asset_supplied(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<AssetSupplied>>, DeepBookError> {
+) -> Result<Page<AssetSupplied>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
     let supplier_filter = params.get("supplier").cloned().unwrap_or_default();
 
@@ -2146,23 +3293,43 @@ async fn asset_supplied(
             start_time,
             end_time,
             limit,
+            cursor,
             margin_pool_id_filter,
             supplier_filter,
         )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/asset_withdrawn",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+        ("supplier" = Option<String>, Query, description = "Only withdrawals by this supplier"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [AssetWithdrawn],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn asset_withdrawn(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<AssetWithdrawn>>, DeepBookError> {
+) -> Result<Page<AssetWithdrawn>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
     let supplier_filter = params.get("supplier").cloned().unwrap_or_default();
 
@@ -2172,38 +3339,81 @@ async fn asset_withdrawn(
             start_time,
             end_time,
             limit,
+            cursor,
             margin_pool_id_filter,
             supplier_filter,
         )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
 // === Margin Pool Admin Events Handlers ===
+#[utoipa::path(
+    get,
+    path = "/margin_pool_created",
+    tag = "Margin",
+    params(
+        PageParams,
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Margin pools, newest first",
+            body = [MarginPoolCreated],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn margin_pool_created(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<MarginPoolCreated>>, DeepBookError> {
+) -> Result<Page<MarginPoolCreated>, DeepBookError> {
+    let limit = params.limit_or(i64::MAX);
+    let cursor = params.cursor()?;
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_margin_pool_created(margin_pool_id_filter)
+        .get_margin_pool_created(limit, cursor, margin_pool_id_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/deepbook_pool_updated",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+        (
+            "deepbook_pool_id" = Option<String>,
+            Query,
+            description = "Only events of this DeepBook pool"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [DeepbookPoolUpdated],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn deepbook_pool_updated(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<DeepbookPoolUpdated>>, DeepBookError> {
+) -> Result<Page<DeepbookPoolUpdated>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
     let deepbook_pool_id_filter = params.get("deepbook_pool_id").cloned().unwrap_or_default();
 
@@ -2213,138 +3423,280 @@ async fn deepbook_pool_updated(
             start_time,
             end_time,
             limit,
+            cursor,
             margin_pool_id_filter,
             deepbook_pool_id_filter,
         )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/interest_params_updated",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [InterestParamsUpdated],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn interest_params_updated(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<InterestParamsUpdated>>, DeepBookError> {
+) -> Result<Page<InterestParamsUpdated>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_interest_params_updated(start_time, end_time, limit, margin_pool_id_filter)
+        .get_interest_params_updated(start_time, end_time, limit, cursor, margin_pool_id_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/margin_pool_config_updated",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [MarginPoolConfigUpdated],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn margin_pool_config_updated(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<MarginPoolConfigUpdated>>, DeepBookError> {
+) -> Result<Page<MarginPoolConfigUpdated>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_margin_pool_config_updated(start_time, end_time, limit, margin_pool_id_filter)
+        .get_margin_pool_config_updated(start_time, end_time, limit, cursor, margin_pool_id_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
 // === Margin Registry Events Handlers ===
+#[utoipa::path(
+    get,
+    path = "/maintainer_cap_updated",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        (
+            "maintainer_cap_id" = Option<String>,
+            Query,
+            description = "Only events of this maintainer cap"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [MaintainerCapUpdated],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn maintainer_cap_updated(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<MaintainerCapUpdated>>, DeepBookError> {
+) -> Result<Page<MaintainerCapUpdated>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let maintainer_cap_id_filter = params.get("maintainer_cap_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_maintainer_cap_updated(start_time, end_time, limit, maintainer_cap_id_filter)
+        .get_maintainer_cap_updated(
+            start_time,
+            end_time,
+            limit,
+            cursor,
+            maintainer_cap_id_filter,
+        )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/maintainer_fees_withdrawn",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [MaintainerFeesWithdrawn],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn maintainer_fees_withdrawn(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<MaintainerFeesWithdrawn>>, DeepBookError> {
+) -> Result<Page<MaintainerFeesWithdrawn>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_maintainer_fees_withdrawn(start_time, end_time, limit, margin_pool_id_filter)
+        .get_maintainer_fees_withdrawn(start_time, end_time, limit, cursor, margin_pool_id_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/protocol_fees_withdrawn",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [ProtocolFeesWithdrawn],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn protocol_fees_withdrawn(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<ProtocolFeesWithdrawn>>, DeepBookError> {
+) -> Result<Page<ProtocolFeesWithdrawn>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_protocol_fees_withdrawn(start_time, end_time, limit, margin_pool_id_filter)
+        .get_protocol_fees_withdrawn(start_time, end_time, limit, cursor, margin_pool_id_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/supplier_cap_minted",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        (
+            "supplier_cap_id" = Option<String>,
+            Query,
+            description = "Only events of this supplier cap"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [SupplierCapMinted],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn supplier_cap_minted(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<SupplierCapMinted>>, DeepBookError> {
+) -> Result<Page<SupplierCapMinted>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let supplier_cap_id_filter = params.get("supplier_cap_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_supplier_cap_minted(start_time, end_time, limit, supplier_cap_id_filter)
+        .get_supplier_cap_minted(start_time, end_time, limit, cursor, supplier_cap_id_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/supply_referral_minted",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+        ("owner" = Option<String>, Query, description = "Only referrals of this owner"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [SupplyReferralMinted],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn supply_referral_minted(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<SupplyReferralMinted>>, DeepBookError> {
+) -> Result<Page<SupplyReferralMinted>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
     let owner_filter = params.get("owner").cloned().unwrap_or_default();
 
@@ -2354,61 +3706,117 @@ async fn supply_referral_minted(
             start_time,
             end_time,
             limit,
+            cursor,
             margin_pool_id_filter,
             owner_filter,
         )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/pause_cap_updated",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("pause_cap_id" = Option<String>, Query, description = "Only events of this pause cap"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [PauseCapUpdated],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn pause_cap_updated(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<PauseCapUpdated>>, DeepBookError> {
+) -> Result<Page<PauseCapUpdated>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let pause_cap_id_filter = params.get("pause_cap_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_pause_cap_updated(start_time, end_time, limit, pause_cap_id_filter)
+        .get_pause_cap_updated(start_time, end_time, limit, cursor, pause_cap_id_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/protocol_fees_increased",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("margin_pool_id" = Option<String>, Query, description = "Only events of this margin pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [ProtocolFeesIncreasedEvent],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn protocol_fees_increased(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<ProtocolFeesIncreasedEvent>>, DeepBookError> {
+) -> Result<Page<ProtocolFeesIncreasedEvent>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_pool_id_filter = params.get("margin_pool_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_protocol_fees_increased(start_time, end_time, limit, margin_pool_id_filter)
+        .get_protocol_fees_increased(start_time, end_time, limit, cursor, margin_pool_id_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/referral_fees_claimed",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("referral_id" = Option<String>, Query, description = "Only claims of this referral"),
+        ("owner" = Option<String>, Query, description = "Only claims by this owner"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [ReferralFeesClaimedEvent],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn referral_fees_claimed(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<ReferralFeesClaimedEvent>>, DeepBookError> {
+) -> Result<Page<ReferralFeesClaimedEvent>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let referral_id_filter = params.get("referral_id").cloned().unwrap_or_default();
     let owner_filter = params.get("owner").cloned().unwrap_or_default();
 
@@ -2418,23 +3826,43 @@ async fn referral_fees_claimed(
             start_time,
             end_time,
             limit,
+            cursor,
             referral_id_filter,
             owner_filter,
         )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/referral_fee_events",
+    tag = "Rewards",
+    params(
+        EventPageParams,
+        ("pool_id" = Option<String>, Query, description = "Only events of this pool"),
+        ("referral_id" = Option<String>, Query, description = "Only fees of this referral"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [ReferralFeeEvent],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn referral_fee_events(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<ReferralFeeEvent>>, DeepBookError> {
+) -> Result<Page<ReferralFeeEvent>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let pool_id_filter = params.get("pool_id").cloned().unwrap_or_default();
     let referral_id_filter = params.get("referral_id").cloned().unwrap_or_default();
 
@@ -2444,18 +3872,40 @@ async fn referral_fee_events(
             start_time,
             end_time,
             limit,
+            cursor,
             pool_id_filter,
             referral_id_filter,
         )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/rebates_v2",
+    tag = "Rewards",
+    params(
+        EventPageParams,
+        (
+            "balance_manager_id" = Option<String>,
+            Query,
+            description = "Only rebates of this balance manager"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [RebatesV2],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn rebates_v2(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<RebatesV2>>, DeepBookError> {
+) -> Result<Page<RebatesV2>, DeepBookError> {
     let start_time = params.start_time().unwrap_or(0);
     let end_time = params
         .get("end_time")
@@ -2466,6 +3916,7 @@ async fn rebates_v2(
         .get("limit")
         .and_then(|v| v.parse::<i64>().ok())
         .unwrap_or(10_000_000);
+    let cursor = params.cursor()?;
     let balance_manager_id_filter = params
         .get("balance_manager_id")
         .cloned()
@@ -2473,75 +3924,141 @@ async fn rebates_v2(
 
     let results = state
         .reader
-        .get_rebates_v2(start_time, end_time, limit, balance_manager_id_filter)
+        .get_rebates_v2(
+            start_time,
+            end_time,
+            limit,
+            cursor,
+            balance_manager_id_filter,
+        )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/deepbook_pool_registered",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("pool_id" = Option<String>, Query, description = "Only events of this pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [DeepbookPoolRegistered],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn deepbook_pool_registered(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<DeepbookPoolRegistered>>, DeepBookError> {
+) -> Result<Page<DeepbookPoolRegistered>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let pool_id_filter = params.get("pool_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_deepbook_pool_registered(start_time, end_time, limit, pool_id_filter)
+        .get_deepbook_pool_registered(start_time, end_time, limit, cursor, pool_id_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/deepbook_pool_updated_registry",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("pool_id" = Option<String>, Query, description = "Only events of this pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [DeepbookPoolUpdatedRegistry],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn deepbook_pool_updated_registry(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<DeepbookPoolUpdatedRegistry>>, DeepBookError> {
+) -> Result<Page<DeepbookPoolUpdatedRegistry>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let pool_id_filter = params.get("pool_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_deepbook_pool_updated_registry(start_time, end_time, limit, pool_id_filter)
+        .get_deepbook_pool_updated_registry(start_time, end_time, limit, cursor, pool_id_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/deepbook_pool_config_updated",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        ("pool_id" = Option<String>, Query, description = "Only events of this pool"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [DeepbookPoolConfigUpdated],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn deepbook_pool_config_updated(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<DeepbookPoolConfigUpdated>>, DeepBookError> {
+) -> Result<Page<DeepbookPoolConfigUpdated>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let pool_id_filter = params.get("pool_id").cloned().unwrap_or_default();
 
     let results = state
         .reader
-        .get_deepbook_pool_config_updated(start_time, end_time, limit, pool_id_filter)
+        .get_deepbook_pool_config_updated(start_time, end_time, limit, cursor, pool_id_filter)
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
+#[utoipa::path(
+    get,
+    path = "/margin_managers_info",
+    tag = "Margin",
+    responses((status = 200, description = "Margin managers", body = [MarginManagerInfo]))
+)]
 async fn margin_managers_info(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<HashMap<String, Value>>>, DeepBookError> {
+) -> Result<Json<Vec<MarginManagerInfo>>, DeepBookError> {
     let results = state.reader.get_margin_managers_info().await?;
 
-    let data: Vec<HashMap<String, Value>> = results
+    let data = results
         .into_iter()
         .map(
             |(
@@ -2553,41 +4070,15 @@ async fn margin_managers_info(
                 quote_asset_symbol,
                 base_margin_pool_id,
                 quote_margin_pool_id,
-            )| {
-                HashMap::from([
-                    (
-                        "margin_manager_id".to_string(),
-                        Value::from(margin_manager_id),
-                    ),
-                    (
-                        "deepbook_pool_id".to_string(),
-                        deepbook_pool_id.map_or(Value::Null, Value::from),
-                    ),
-                    (
-                        "base_asset_id".to_string(),
-                        base_asset_id.map_or(Value::Null, Value::from),
-                    ),
-                    (
-                        "base_asset_symbol".to_string(),
-                        base_asset_symbol.map_or(Value::Null, Value::from),
-                    ),
-                    (
-                        "quote_asset_id".to_string(),
-                        quote_asset_id.map_or(Value::Null, Value::from),
-                    ),
-                    (
-                        "quote_asset_symbol".to_string(),
-                        quote_asset_symbol.map_or(Value::Null, Value::from),
-                    ),
-                    (
-                        "base_margin_pool_id".to_string(),
-                        base_margin_pool_id.map_or(Value::Null, Value::from),
-                    ),
-                    (
-                        "quote_margin_pool_id".to_string(),
-                        quote_margin_pool_id.map_or(Value::Null, Value::from),
-                    ),
-                ])
+            )| MarginManagerInfo {
+                margin_manager_id,
+                deepbook_pool_id,
+                base_asset_id,
+                base_asset_symbol,
+                quote_asset_id,
+                quote_asset_symbol,
+                base_margin_pool_id,
+                quote_margin_pool_id,
             },
         )
         .collect();
@@ -2595,6 +4086,29 @@ async fn margin_managers_info(
     Ok(Json(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/margin_manager_states",
+    tag = "Margin",
+    params(
+        (
+            "max_risk_ratio" = Option<f64>,
+            Query,
+            description = "Only margin managers at or below this risk ratio"
+        ),
+        (
+            "deepbook_pool_id" = Option<String>,
+            Query,
+            description = "Only margin managers of this DeepBook pool"
+        ),
+        (
+            "pool" = Option<String>,
+            Query,
+            description = "Only margin managers of this pool name, such as SUI_USDC"
+        ),
+    ),
+    responses((status = 200, description = "Margin manager states", body = [MarginManagerState]))
+)]
 async fn margin_manager_states(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
@@ -2630,12 +4144,21 @@ async fn margin_manager_states(
     Ok(Json(states))
 }
 
-#[derive(serde::Serialize)]
-struct BalanceManagerDepositedAssets {
-    balance_manager_id: String,
-    assets: Vec<String>,
-}
-
+#[utoipa::path(
+    get,
+    path = "/deposited_assets/{balance_manager_ids}",
+    tag = "Order Flow",
+    params(
+        ("balance_manager_ids" = String, Path, description = "Comma-separated balance manager IDs"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Deposited assets per balance manager, in the requested order",
+            body = [BalanceManagerDepositedAssets]
+        )
+    )
+)]
 async fn deposited_assets(
     Path(balance_manager_ids): Path<String>,
     State(state): State<Arc<AppState>>,
@@ -2679,15 +4202,43 @@ async fn deposited_assets(
     Ok(Json(response))
 }
 
+#[utoipa::path(
+    get,
+    path = "/collateral_events",
+    tag = "Margin",
+    params(
+        EventPageParams,
+        (
+            "margin_manager_id" = Option<String>,
+            Query,
+            description = "Only events of this margin manager"
+        ),
+        ("type" = Option<String>, Query, description = "Only events of this type"),
+        (
+            "is_base" = Option<bool>,
+            Query,
+            description = "Only events in the base asset, or in the quote asset"
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Events, newest first",
+            body = [CollateralEvent],
+            headers(("x-next-cursor" = String, description = "Cursor of the next page"))
+        )
+    )
+)]
 async fn collateral_events(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<CollateralEvent>>, DeepBookError> {
+) -> Result<Page<CollateralEvent>, DeepBookError> {
     let end_time = params.end_time();
     let start_time = params
         .start_time()
         .unwrap_or_else(|| end_time - 24 * 60 * 60 * 1000);
     let limit = params.limit();
+    let cursor = params.cursor()?;
     let margin_manager_id_filter = params.get("margin_manager_id").cloned().unwrap_or_default();
     let event_type_filter = params.get("type").cloned().unwrap_or_default();
     let is_base_filter = params.get("is_base").and_then(|v| v.parse::<bool>().ok());
@@ -2698,25 +4249,43 @@ async fn collateral_events(
             start_time,
             end_time,
             limit,
+            cursor,
             margin_manager_id_filter,
             event_type_filter,
             is_base_filter,
         )
         .await?;
 
-    Ok(Json(results))
+    Ok(results)
 }
 
 // === Points ===
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 struct GetPointsQuery {
+    /// Comma-separated wallet addresses
     addresses: Option<String>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/get_points",
+    tag = "Rewards",
+    params(
+        GetPointsQuery,
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Points per address, in the requested order",
+            body = [AddressPoints]
+        )
+    )
+)]
 async fn get_points(
     Query(params): Query<GetPointsQuery>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<serde_json::Value>>, DeepBookError> {
+) -> Result<Json<Vec<AddressPoints>>, DeepBookError> {
     let addresses = params
         .addresses
         .map(|s| {
@@ -2736,17 +4305,24 @@ async fn get_points(
 
     let response = requested
         .iter()
-        .map(|addr| {
-            serde_json::json!({
-                "address": addr,
-                "total_points": results_map.get(addr).copied().unwrap_or(0)
-            })
+        .map(|addr| AddressPoints {
+            address: addr.clone(),
+            total_points: results_map.get(addr).copied().unwrap_or(0),
         })
         .collect();
 
     Ok(Json(response))
 }
 
+#[utoipa::path(
+    get,
+    path = "/portfolio/{wallet_address}",
+    tag = "Accounts",
+    params(
+        ("wallet_address" = String, Path, description = "Wallet address"),
+    ),
+    responses((status = 200, description = "Portfolio", body = PortfolioQueryResult))
+)]
 async fn portfolio(
     Path(wallet_address): Path<String>,
     State(state): State<Arc<AppState>>,
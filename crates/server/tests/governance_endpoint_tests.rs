@@ -0,0 +1,212 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::Router;
+use diesel_async::RunQueryDsl;
+use http_body_util::BodyExt;
+use prometheus::Registry;
+use serde_json::Value;
+use std::sync::Arc;
+use sui_pg_db::temp::TempDb;
+use sui_pg_db::{Db, DbArgs};
+use tower::ServiceExt;
+use url::Url;
+
+use deepbook_server::{
+    pyth::{PythProConfig, DEFAULT_PRO_URL},
+    server::{make_router, AppState},
+};
+
+const POOL_NAME: &str = "BASE_USDC";
+const POOL_ID: &str = "pool-1";
+const EPOCH: i64 = 5;
+
+async fn setup() -> (TempDb, Db, Router) {
+    let temp_db = TempDb::new().expect("postgres binaries must be on PATH");
+    let url: Url = temp_db.database().url().clone();
+    let db = Db::for_write(url.clone(), DbArgs::default()).await.unwrap();
+    db.run_migrations(Some(&deepbook_schema::MIGRATIONS))
+        .await
+        .unwrap();
+
+    execute(
+        &db,
+        format!(
+            "INSERT INTO pools (
+                pool_id, pool_name,
+                base_asset_id, base_asset_decimals, base_asset_symbol, base_asset_name,
+                quote_asset_id, quote_asset_decimals, quote_asset_symbol, quote_asset_name,
+                min_size, lot_size, tick_size
+            ) VALUES (
+                '{POOL_ID}', '{POOL_NAME}',
+                'base-coin', 9, 'BASE', 'Base Coin',
+                'quote-coin', 9, 'USDC', 'USD Coin',
+                1, 1, 1
+            )"
+        ),
+    )
+    .await;
+
+    let registry = Registry::new();
+    let rpc_url: Url = "http://localhost:1/".parse().unwrap();
+    let state = Arc::new(
+        AppState::new(
+            url,
+            DbArgs::default(),
+            &registry,
+            rpc_url,
+            "deepbook-package".to_string(),
+            "deep-token-package".to_string(),
+            "deep-treasury".to_string(),
+            None,
+            None,
+            100,
+            Url::parse(DEFAULT_PRO_URL).unwrap(),
+            None,
+            PythProConfig::default(),
+        )
+        .await
+        .unwrap(),
+    );
+
+    let router = make_router(state);
+    (temp_db, db, router)
+}
+
+async fn execute(db: &Db, sql: String) {
+    let mut conn = db.connect().await.unwrap();
+    diesel::sql_query(sql).execute(&mut conn).await.unwrap();
+}
+
+/// A stake of `amount`, or an unstake when it is 0
+async fn stake(db: &Db, balance_manager_id: &str, epoch: i64, checkpoint: i64, amount: i64) {
+    execute(
+        db,
+        format!(
+            "INSERT INTO stakes (
+                event_digest, digest, sender, checkpoint, checkpoint_timestamp_ms, package,
+                pool_id, balance_manager_id, epoch, amount, stake
+            ) VALUES (
+                'stake-{checkpoint}', 'tx-{checkpoint}', 'sender', {checkpoint}, {checkpoint}000,
+                'package', '{POOL_ID}', '{balance_manager_id}', {epoch}, {amount}, {}
+            )",
+            amount > 0
+        ),
+    )
+    .await;
+}
+
+async fn propose(db: &Db, balance_manager_id: &str, checkpoint: i64, taker_fee: i64) {
+    execute(
+        db,
+        format!(
+            "INSERT INTO proposals (
+                event_digest, digest, sender, checkpoint, checkpoint_timestamp_ms, package,
+                pool_id, balance_manager_id, epoch, taker_fee, maker_fee, stake_required
+            ) VALUES (
+                'proposal-{checkpoint}', 'tx-{checkpoint}', 'sender', {checkpoint},
+                {checkpoint}000, 'package', '{POOL_ID}', '{balance_manager_id}', {EPOCH},
+                {taker_fee}, 0, 0
+            )"
+        ),
+    )
+    .await;
+}
+
+async fn vote(db: &Db, voter: &str, checkpoint: i64, to: &str, stake: i64) {
+    execute(
+        db,
+        format!(
+            "INSERT INTO votes (
+                event_digest, digest, sender, checkpoint, checkpoint_timestamp_ms, package,
+                pool_id, balance_manager_id, epoch, from_proposal_id, to_proposal_id, stake
+            ) VALUES (
+                'vote-{checkpoint}', 'tx-{checkpoint}', 'sender', {checkpoint},
+                {checkpoint}000, 'package', '{POOL_ID}', '{voter}', {EPOCH}, NULL, '{to}',
+                {stake}
+            )"
+        ),
+    )
+    .await;
+}
+
+async fn update_trade_params(db: &Db, checkpoint: i64, taker_fee: i64) {
+    execute(
+        db,
+        format!(
+            "INSERT INTO trade_params_update (
+                event_digest, digest, sender, checkpoint, checkpoint_timestamp_ms, package,
+                pool_id, taker_fee, maker_fee, stake_required
+            ) VALUES (
+                'update-{checkpoint}', 'tx-{checkpoint}', 'sender', {checkpoint},
+                {checkpoint}000, 'package', '{POOL_ID}', {taker_fee}, 0, 0
+            )"
+        ),
+    )
+    .await;
+}
+
+async fn get(router: &Router, uri: &str) -> Value {
+    let response = router
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "GET {uri}");
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn stake_counts_from_the_last_unstake() {
+    let (_temp_db, db, router) = setup().await;
+    // a: staked before the epoch and during it
+    stake(&db, "a", 3, 1, 100).await;
+    stake(&db, "a", EPOCH, 10, 50).await;
+    // b: unstaked, then staked again
+    stake(&db, "b", 2, 2, 40).await;
+    stake(&db, "b", 3, 3, 0).await;
+    stake(&db, "b", 4, 4, 70).await;
+    // c: unstaked during the epoch, after voting
+    stake(&db, "c", 4, 5, 30).await;
+    vote(&db, "c", 9, "a", 30).await;
+    stake(&db, "c", EPOCH, 11, 0).await;
+    // d: staked after the epoch
+    stake(&db, "d", EPOCH + 1, 20, 999).await;
+    propose(&db, "a", 8, 1).await;
+    vote(&db, "a", 12, "a", 100).await;
+
+    let governance = get(&router, &format!("/governance/{POOL_NAME}?epoch={EPOCH}")).await;
+    assert_eq!(governance["total_active_stake"], 170);
+    assert_eq!(governance["total_inactive_stake"], 50);
+    assert_eq!(governance["stakers"], 2);
+    assert_eq!(governance["proposals"][0]["votes"], 100);
+    assert_eq!(governance["proposals"][0]["voters"], 1);
+    assert_eq!(governance["leading_proposal_id"], "a");
+
+    let b = get(&router, &format!("/governance/{POOL_NAME}/b?epoch={EPOCH}")).await;
+    assert_eq!(b["active_stake"], 70);
+    assert_eq!(b["inactive_stake"], 0);
+
+    let c = get(&router, &format!("/governance/{POOL_NAME}/c?epoch={EPOCH}")).await;
+    assert_eq!(c["active_stake"], 0);
+    assert_eq!(c["voted_proposal_id"], Value::Null);
+}
+
+#[tokio::test]
+async fn winner_is_unknown_when_proposals_match_alike() {
+    let (_temp_db, db, router) = setup().await;
+    stake(&db, "a", 3, 1, 100).await;
+    propose(&db, "a", 8, 1).await;
+    propose(&db, "b", 9, 1).await;
+    vote(&db, "a", 10, "a", 100).await;
+    update_trade_params(&db, 30, 1).await;
+
+    let governance = get(&router, &format!("/governance/{POOL_NAME}?epoch={EPOCH}")).await;
+    assert_eq!(governance["winning_proposal_id"], Value::Null);
+    assert_eq!(governance["applied_trade_params"]["taker_fee"], 1);
+
+    let a = get(&router, &format!("/governance/{POOL_NAME}/a?epoch={EPOCH}")).await;
+    assert_eq!(a["voted_proposal_id"], "a");
+    assert_eq!(a["voted_proposal_won"], Value::Null);
+}